@@ -0,0 +1,71 @@
+use serde::Deserialize;
+use serde::Serialize;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::language::model::I18nTextValue;
+
+use crate::core::shared::dto::DtoDeleteForm;
+use crate::core::shared::dto::DtoUploadedFile;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoAddGallery {
+    pub form: DtoGalleryForm,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoDeleteGallery {
+    pub form: DtoDeleteForm,
+    pub gallery_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoDeleteGalleryImage {
+    pub gallery_id: String,
+    pub image_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoEditGallery {
+    pub form: DtoGalleryForm,
+    pub gallery_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoEditGalleryImage {
+    pub caption: I18nTextValue,
+    pub gallery_id: String,
+    pub image_id: String,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DtoGalleriesPaginated {
+    pub galleries: Vec<Gallery>,
+    pub total_results: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGalleryForm {
+    pub name: I18nTextValue,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetGalleriesPaginated {
+    pub current_page: i64,
+    pub results_per_page: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetGallery {
+    pub gallery_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoReorderGalleryImages {
+    pub gallery_id: String,
+    pub image_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoUploadGalleryImages {
+    pub files: Vec<DtoUploadedFile>,
+    pub gallery_id: String,
+}
@@ -0,0 +1,2 @@
+mod dto_gallery;
+pub use dto_gallery::*;
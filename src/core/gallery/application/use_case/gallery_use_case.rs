@@ -0,0 +1,288 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::gallery::error;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GalleryImageFileName;
+use tukosmo_domain::core::gallery::model::GalleryImageId;
+use tukosmo_domain::core::gallery::model::GallerySearchCriteria;
+use tukosmo_domain::core::language::model::I18nTextValue;
+use tukosmo_domain::core::shared::error as error_shared;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::TransactionExecutor;
+use tukosmo_domain::core::shared::repository::DataRepository;
+use tukosmo_domain::core::shared::repository::PageCacheRepository;
+use uuid::Uuid;
+
+use crate::core::shared::service::run_transaction;
+use super::super::dto::DtoAddGallery;
+use super::super::dto::DtoDeleteGallery;
+use super::super::dto::DtoDeleteGalleryImage;
+use super::super::dto::DtoEditGallery;
+use super::super::dto::DtoEditGalleryImage;
+use super::super::dto::DtoGalleriesPaginated;
+use super::super::dto::DtoGetGalleriesPaginated;
+use super::super::dto::DtoGetGallery;
+use super::super::dto::DtoReorderGalleryImages;
+use super::super::dto::DtoUploadGalleryImages;
+
+pub struct GalleryUseCase {
+    data_repository: Rc<RefCell<dyn DataRepository>>,
+    page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+    transaction_executor: Rc<RefCell<dyn TransactionExecutor>>,
+}
+
+impl GalleryUseCase {
+    pub fn add(&self, dto: DtoAddGallery) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let gallery = Gallery::new(dto.form.name)?;
+
+            gallery_repository.add(gallery)?;
+            Ok(())
+        })
+    }
+
+    pub fn delete(&self, dto: DtoDeleteGallery) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let gallery_id = GalleryId::from_string(&dto.gallery_id)?;
+
+            let data_repository = self.data_repository.borrow_mut();
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            if !dto.form.requested {
+                return Err(error_shared::FIELD_CANNOT_BE_EMPTY);
+            }
+            let gallery = gallery_repository.get(gallery_id.clone())?;
+            gallery_repository.delete(gallery_id)?;
+
+            for image in gallery.images {
+                data_repository.remove_uploaded_file(image.file_name.value())?;
+            }
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn delete_image(
+        &self,
+        dto: DtoDeleteGalleryImage
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let gallery_id = GalleryId::from_string(&dto.gallery_id)?;
+            let image_id = GalleryImageId::from_string(&dto.image_id)?;
+
+            let data_repository = self.data_repository.borrow_mut();
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let mut gallery = gallery_repository.get(gallery_id)?;
+            let removed_image = gallery.remove_image(&image_id)?;
+
+            gallery_repository.update(gallery)?;
+            data_repository.remove_uploaded_file(
+                removed_image.file_name.value()
+            )?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn edit(&self, dto: DtoEditGallery) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let gallery_id = GalleryId::from_string(&dto.gallery_id)?;
+
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let mut gallery = gallery_repository.get(gallery_id)?;
+            gallery.modify(dto.form.name)?;
+
+            gallery_repository.update(gallery)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn edit_image(
+        &self,
+        dto: DtoEditGalleryImage
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let gallery_id = GalleryId::from_string(&dto.gallery_id)?;
+            let image_id = GalleryImageId::from_string(&dto.image_id)?;
+
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let mut gallery = gallery_repository.get(gallery_id)?;
+            gallery.modify_image_caption(&image_id, dto.caption)?;
+
+            gallery_repository.update(gallery)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn get(&self, dto: DtoGetGallery) -> Result<Gallery, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let gallery_id = GalleryId::from_string(&dto.gallery_id)?;
+
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+            let gallery = gallery_repository.get(gallery_id)?;
+
+            Ok(gallery)
+        })
+    }
+
+    pub fn get_all_galleries(&self) -> Result<Vec<Gallery>, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let galleries = gallery_repository.find(
+                GallerySearchCriteria::all_ordered()
+            )?;
+
+            Ok(galleries)
+        })
+    }
+
+    pub fn get_galleries_paginated(
+        &self,
+        dto: DtoGetGalleriesPaginated
+    ) -> Result<DtoGalleriesPaginated, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let galleries = gallery_repository.find(
+                GallerySearchCriteria::paginated(
+                    dto.current_page,
+                    dto.results_per_page
+                )
+            )?;
+            let total_results = gallery_repository.count(
+                GallerySearchCriteria::all().filter
+            )?;
+
+            Ok(DtoGalleriesPaginated { galleries, total_results })
+        })
+    }
+
+    pub fn init(
+        data_repository: Rc<RefCell<dyn DataRepository>>,
+        page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+        transaction_executor: Rc<RefCell<dyn TransactionExecutor>>
+    ) -> Self {
+        Self { data_repository, page_cache_repository, transaction_executor }
+    }
+
+    pub fn reorder_images(
+        &self,
+        dto: DtoReorderGalleryImages
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let gallery_id = GalleryId::from_string(&dto.gallery_id)?;
+            let image_ids = dto.image_ids
+                .iter()
+                .map(|image_id| GalleryImageId::from_string(image_id))
+                .collect::<Result<Vec<GalleryImageId>, DomainError>>()?;
+
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let mut gallery = gallery_repository.get(gallery_id)?;
+            gallery.reorder_images(image_ids)?;
+
+            gallery_repository.update(gallery)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn upload_images(
+        &self,
+        dto: DtoUploadGalleryImages
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let gallery_id = GalleryId::from_string(&dto.gallery_id)?;
+
+            if dto.files.is_empty() {
+                return Err(error::NO_IMAGE_FILE_UPLOADED);
+            }
+
+            let data_repository = self.data_repository.borrow_mut();
+            let mut gallery_repository =
+                transaction.gallery_repository.borrow_mut();
+
+            let mut gallery = gallery_repository.get(gallery_id)?;
+
+            let mut files_to_write: Vec<(String, Vec<u8>)> = vec![];
+            for file in dto.files {
+                let extension = GalleryImageFileName::extension(
+                    &file.original_name
+                ).ok_or(error::INVALID_IMAGE_FILE_EXTENSION)?;
+                let file_name = format!("{}.{}", Uuid::new_v4(), extension);
+
+                gallery.add_image(
+                    file_name.clone(),
+                    I18nTextValue {
+                        default_text: "".to_string(),
+                        translations: vec![],
+                    }
+                )?;
+                files_to_write.push((file_name, file.content));
+            }
+
+            gallery_repository.update(gallery)?;
+
+            for (file_name, content) in files_to_write {
+                data_repository.write_uploaded_file(&file_name, &content)?;
+            }
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+}
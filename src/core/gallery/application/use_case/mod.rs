@@ -0,0 +1,4 @@
+#[cfg(feature = "ssr")]
+mod gallery_use_case;
+#[cfg(feature = "ssr")]
+pub use gallery_use_case::*;
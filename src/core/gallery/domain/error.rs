@@ -0,0 +1,72 @@
+use crate::core::shared::model::CoreSubmoduleName::Gallery;
+use crate::core::shared::model::DomainError;
+use crate::core::shared::model::DomainErrorId;
+use crate::core::shared::model::DomainErrorVisibility;
+use crate::core::shared::model::ModuleName::Core;
+use super::model::GalleryImageId;
+
+pub const GALLERY_IMAGES_DONT_MATCH: DomainError = get_domain_error(
+    "GALLERY_IMAGES_DONT_MATCH",
+    "The given images don't match the images of the gallery.",
+    DomainErrorVisibility::Public
+);
+
+pub const GALLERY_NOT_FOUND: DomainError = get_domain_error(
+    "GALLERY_NOT_FOUND",
+    "Gallery not found.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_IMAGE_FILE_EXTENSION: DomainError = get_domain_error(
+    "INVALID_IMAGE_FILE_EXTENSION",
+    "File doesn't have a valid image extension.",
+    DomainErrorVisibility::Public
+);
+
+pub const NO_IMAGE_FILE_UPLOADED: DomainError = get_domain_error(
+    "NO_IMAGE_FILE_UPLOADED",
+    "No image file has been uploaded.",
+    DomainErrorVisibility::Public
+);
+
+const fn get_domain_error(
+    error_code: &'static str,
+    message: &'static str,
+    visibility: DomainErrorVisibility
+) -> DomainError {
+    DomainError {
+        context: vec![],
+        id: DomainErrorId {
+            error_code,
+            module: Core(Gallery),
+        },
+        message,
+        visibility,
+    }
+}
+
+fn get_domain_error_with_context(
+    error_code: &'static str,
+    message: &'static str,
+    visibility: DomainErrorVisibility,
+    context: Vec<(String, String)>
+) -> DomainError {
+    DomainError {
+        context,
+        id: DomainErrorId {
+            error_code,
+            module: Core(Gallery),
+        },
+        message,
+        visibility,
+    }
+}
+
+pub fn gallery_image_not_found(id: &GalleryImageId) -> DomainError {
+    get_domain_error_with_context(
+        "GALLERY_IMAGE_NOT_FOUND",
+        "Gallery image not found.",
+        DomainErrorVisibility::Public,
+        vec![("id".to_string(), id.value().to_string())]
+    )
+}
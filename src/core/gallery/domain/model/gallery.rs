@@ -0,0 +1,265 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::core::language::model::I18nText;
+use crate::core::language::model::I18nTextValue;
+use crate::core::shared::error;
+use crate::core::shared::model::DomainError;
+use crate::core::shared::model::PaginationCriteria;
+use super::super::error as error_gallery;
+use super::GalleryImage;
+use super::GalleryImageId;
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct Gallery {
+    pub creation_date: GalleryCreationDate,
+    pub id: GalleryId,
+    pub images: Vec<GalleryImage>,
+    pub name: I18nText,
+    pub update_date: GalleryUpdateDate,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct GalleryCreationDate(DateTime<Utc>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GalleryId(Uuid);
+
+#[derive(Clone)]
+pub struct GallerySearchCriteria {
+    pub filter: GallerySearchFilterCriteria,
+    pub order_by: Option<GallerySearchCriteriaOrderBy>,
+    pub pagination: Option<PaginationCriteria>,
+}
+
+#[derive(Clone)]
+pub enum GallerySearchCriteriaOrderBy {
+    CreationDate,
+}
+
+#[derive(Clone)]
+pub struct GallerySearchFilterCriteria {
+    pub id: Option<GalleryId>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct GalleryUpdateDate(DateTime<Utc>);
+
+impl Gallery {
+    pub fn add_image(
+        &mut self,
+        file_name_value: String,
+        caption_value: I18nTextValue
+    ) -> Result<(), DomainError> {
+        let position = self.images.len() as i32;
+        let image = GalleryImage::new(
+            file_name_value,
+            caption_value,
+            position
+        )?;
+        self.images.push(image);
+        self.update_date = GalleryUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn modify(
+        &mut self,
+        name_value: I18nTextValue
+    ) -> Result<(), DomainError> {
+        self.name.modify(
+            name_value,
+            Self::validate_name_default_value,
+            Self::validate_name_translation_value
+        )?;
+        self.update_date = GalleryUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn modify_image_caption(
+        &mut self,
+        image_id: &GalleryImageId,
+        caption_value: I18nTextValue
+    ) -> Result<(), DomainError> {
+        let image = self.images
+            .iter_mut()
+            .find(|i| &i.id == image_id)
+            .ok_or(error_gallery::gallery_image_not_found(image_id))?;
+        image.modify_caption(caption_value)?;
+        self.update_date = GalleryUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn new(name_value: I18nTextValue) -> Result<Self, DomainError> {
+        let id = GalleryId::new();
+        let name = I18nText::new(
+            name_value,
+            Self::validate_name_default_value,
+            Self::validate_name_translation_value
+        )?;
+        let creation_date = GalleryCreationDate::new();
+        let update_date = GalleryUpdateDate::new();
+
+        Ok(Gallery {
+            creation_date,
+            id,
+            images: vec![],
+            name,
+            update_date,
+        })
+    }
+
+    pub fn remove_image(
+        &mut self,
+        image_id: &GalleryImageId
+    ) -> Result<GalleryImage, DomainError> {
+        let index = self.images
+            .iter()
+            .position(|i| &i.id == image_id)
+            .ok_or(error_gallery::gallery_image_not_found(image_id))?;
+        let removed_image = self.images.remove(index);
+        self.renumber_images();
+        self.update_date = GalleryUpdateDate::new();
+        Ok(removed_image)
+    }
+
+    fn renumber_images(&mut self) {
+        for (position, image) in self.images.iter_mut().enumerate() {
+            image.move_to(position as i32);
+        }
+    }
+
+    pub fn reorder_images(
+        &mut self,
+        image_ids: Vec<GalleryImageId>
+    ) -> Result<(), DomainError> {
+        let same_length = image_ids.len() == self.images.len();
+        let same_images = image_ids
+            .iter()
+            .all(|image_id| self.images.iter().any(|i| &i.id == image_id));
+        if !same_length || !same_images {
+            return Err(error_gallery::GALLERY_IMAGES_DONT_MATCH);
+        }
+
+        let mut reordered_images: Vec<GalleryImage> = vec![];
+        for image_id in image_ids {
+            let index = self.images
+                .iter()
+                .position(|i| i.id == image_id)
+                .ok_or(error_gallery::GALLERY_IMAGES_DONT_MATCH)?;
+            reordered_images.push(self.images.remove(index));
+        }
+        self.images = reordered_images;
+        self.renumber_images();
+        self.update_date = GalleryUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn validate_name_default_value(value: &String) -> Option<DomainError> {
+        if 0 == value.len() {
+            return Some(error::FIELD_CANNOT_BE_EMPTY);
+        }
+        Self::validate_name_value(value)
+    }
+
+    pub fn validate_name_translation_value(
+        value: &String
+    ) -> Option<DomainError> {
+        Self::validate_name_value(value)
+    }
+
+    pub fn validate_name_value(_value: &String) -> Option<DomainError> {
+        None
+    }
+}
+
+impl GalleryCreationDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl GalleryId {
+    pub fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+
+    pub fn from_string(value: &str) -> Result<Self, DomainError> {
+        match Uuid::parse_str(value) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(error::INVALID_UUID),
+        }
+    }
+
+    fn new() -> Self {
+        let value = Uuid::new_v4();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl GallerySearchCriteria {
+    pub fn all() -> Self {
+        let criteria = Self::default();
+        criteria
+    }
+
+    pub fn all_ordered() -> Self {
+        let mut criteria = Self::default();
+        criteria.order_by = Some(GallerySearchCriteriaOrderBy::CreationDate);
+        criteria
+    }
+
+    fn default() -> Self {
+        Self {
+            filter: GallerySearchFilterCriteria { id: None },
+            order_by: None,
+            pagination: None,
+        }
+    }
+
+    pub fn has_id(gallery_id: GalleryId) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.id = Some(gallery_id);
+        criteria
+    }
+
+    pub fn paginated(page: i64, results_per_page: i64) -> Self {
+        let mut criteria = Self::default();
+        criteria.pagination = Some(PaginationCriteria {
+            page,
+            results_per_page,
+        });
+        criteria.order_by = Some(GallerySearchCriteriaOrderBy::CreationDate);
+        criteria
+    }
+}
+
+impl GalleryUpdateDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
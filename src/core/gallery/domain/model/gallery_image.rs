@@ -0,0 +1,250 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::core::language::model::I18nText;
+use crate::core::language::model::I18nTextValue;
+use crate::core::shared::error;
+use crate::core::shared::model::DomainError;
+use super::super::error as error_gallery;
+use super::GalleryId;
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct GalleryImage {
+    pub caption: I18nText,
+    pub creation_date: GalleryImageCreationDate,
+    pub file_name: GalleryImageFileName,
+    pub id: GalleryImageId,
+    pub position: GalleryImagePosition,
+    pub update_date: GalleryImageUpdateDate,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct GalleryImageCreationDate(DateTime<Utc>);
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct GalleryImageFileName(String);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GalleryImageId(Uuid);
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct GalleryImagePosition(i32);
+
+#[derive(Clone)]
+pub struct GalleryImageSearchCriteria {
+    pub filter: GalleryImageSearchFilterCriteria,
+}
+
+#[derive(Clone)]
+pub struct GalleryImageSearchFilterCriteria {
+    pub gallery_id: Option<GalleryId>,
+    pub gallery_id_in: Option<Vec<GalleryId>>,
+    pub id_not_in: Option<Vec<GalleryImageId>>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct GalleryImageUpdateDate(DateTime<Utc>);
+
+const ALLOWED_IMAGE_FILE_EXTENSIONS: [&'static str; 5] = [
+    "gif",
+    "jpeg",
+    "jpg",
+    "png",
+    "webp",
+];
+
+impl GalleryImage {
+    pub fn modify_caption(
+        &mut self,
+        caption_value: I18nTextValue
+    ) -> Result<(), DomainError> {
+        self.caption.modify(
+            caption_value,
+            Self::validate_caption_value,
+            Self::validate_caption_value
+        )?;
+        self.update_date = GalleryImageUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn move_to(&mut self, position_value: i32) {
+        if self.position.value() != &position_value {
+            self.position = GalleryImagePosition::new(position_value);
+            self.update_date = GalleryImageUpdateDate::new();
+        }
+    }
+
+    pub fn new(
+        file_name_value: String,
+        caption_value: I18nTextValue,
+        position_value: i32
+    ) -> Result<Self, DomainError> {
+        let id = GalleryImageId::new();
+        let caption = I18nText::new(
+            caption_value,
+            Self::validate_caption_value,
+            Self::validate_caption_value
+        )?;
+        let file_name = GalleryImageFileName::new(file_name_value)?;
+        let position = GalleryImagePosition::new(position_value);
+        let creation_date = GalleryImageCreationDate::new();
+        let update_date = GalleryImageUpdateDate::new();
+
+        Ok(GalleryImage {
+            caption,
+            creation_date,
+            file_name,
+            id,
+            position,
+            update_date,
+        })
+    }
+
+    pub fn validate_caption_value(_value: &String) -> Option<DomainError> {
+        // Captions are optional, so an empty text is a valid one
+        None
+    }
+}
+
+impl GalleryImageCreationDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl GalleryImageFileName {
+    pub fn extension(value: &str) -> Option<String> {
+        value
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_lowercase())
+    }
+
+    pub fn from(value: String) -> Self {
+        Self(value)
+    }
+
+    fn new(value: String) -> Result<Self, DomainError> {
+        match Self::validate(&value) {
+            Some(validation_error) => Err(validation_error),
+            None => Ok(Self(value)),
+        }
+    }
+
+    pub fn validate(value: &String) -> Option<DomainError> {
+        if 0 == value.len() {
+            return Some(error::FIELD_CANNOT_BE_EMPTY);
+        }
+        let has_allowed_extension = match Self::extension(value) {
+            Some(extension) =>
+                ALLOWED_IMAGE_FILE_EXTENSIONS.iter().any(
+                    |&allowed| allowed == extension
+                ),
+            None => false,
+        };
+        if !has_allowed_extension {
+            return Some(error_gallery::INVALID_IMAGE_FILE_EXTENSION);
+        }
+        None
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl GalleryImageId {
+    pub fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+
+    pub fn from_string(value: &str) -> Result<Self, DomainError> {
+        match Uuid::parse_str(value) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(error::INVALID_UUID),
+        }
+    }
+
+    fn new() -> Self {
+        let value = Uuid::new_v4();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl GalleryImagePosition {
+    pub fn from(value: i32) -> Self {
+        Self(value)
+    }
+
+    fn new(value: i32) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> &i32 {
+        &self.0
+    }
+}
+
+impl GalleryImageSearchCriteria {
+    fn default() -> Self {
+        Self {
+            filter: GalleryImageSearchFilterCriteria {
+                gallery_id: None,
+                gallery_id_in: None,
+                id_not_in: None,
+            },
+        }
+    }
+
+    pub fn has_gallery_id(gallery_id: GalleryId) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.gallery_id = Some(gallery_id);
+        criteria
+    }
+
+    pub fn has_gallery_id_and_id_not_in(
+        gallery_id: GalleryId,
+        gallery_image_ids: Vec<GalleryImageId>
+    ) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.gallery_id = Some(gallery_id);
+        criteria.filter.id_not_in = Some(gallery_image_ids);
+        criteria
+    }
+
+    pub fn has_gallery_id_in(gallery_ids: Vec<GalleryId>) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.gallery_id_in = Some(gallery_ids);
+        criteria
+    }
+}
+
+impl GalleryImageUpdateDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
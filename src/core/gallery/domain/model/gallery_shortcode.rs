@@ -0,0 +1,187 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::GalleryId;
+
+// Content bodies can embed a gallery by writing `[gallery id="<uuid>"]`
+// anywhere inside their HTML.
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum GalleryShortcodeSegment {
+    Gallery(GalleryId),
+    Html(String),
+}
+
+pub struct GalleryShortcode;
+
+const SHORTCODE_END: &'static str = "]";
+const SHORTCODE_ID_ATTRIBUTE: &'static str = "id=";
+const SHORTCODE_START: &'static str = "[gallery ";
+
+impl GalleryShortcode {
+    pub fn from_gallery_id(gallery_id: &GalleryId) -> String {
+        format!(
+            "{}{}\"{}\"{}",
+            SHORTCODE_START,
+            SHORTCODE_ID_ATTRIBUTE,
+            gallery_id.value(),
+            SHORTCODE_END
+        )
+    }
+
+    fn parse_gallery_id(shortcode_attributes: &str) -> Option<GalleryId> {
+        let id_value = shortcode_attributes
+            .trim()
+            .strip_prefix(SHORTCODE_ID_ATTRIBUTE)?
+            .trim_matches(|c| c == '"' || c == '\'');
+        GalleryId::from_string(id_value).ok()
+    }
+
+    pub fn split(body: &str) -> Vec<GalleryShortcodeSegment> {
+        let mut segments: Vec<GalleryShortcodeSegment> = vec![];
+        let mut html = String::new();
+        let mut remaining_body = body;
+
+        while let Some(start_index) = remaining_body.find(SHORTCODE_START) {
+            let (before, from_start) = remaining_body.split_at(start_index);
+            html.push_str(before);
+
+            let attributes_start = SHORTCODE_START.len();
+            let gallery_id = from_start[attributes_start..]
+                .find(SHORTCODE_END)
+                .and_then(|end_index| {
+                    let attributes =
+                        &from_start[attributes_start..attributes_start +
+                        end_index];
+                    Self::parse_gallery_id(attributes).map(|gallery_id| (
+                        gallery_id,
+                        attributes_start + end_index + SHORTCODE_END.len(),
+                    ))
+                });
+
+            match gallery_id {
+                Some((gallery_id, shortcode_length)) => {
+                    if !html.is_empty() {
+                        segments.push(
+                            GalleryShortcodeSegment::Html(html.clone())
+                        );
+                        html.clear();
+                    }
+                    segments.push(GalleryShortcodeSegment::Gallery(gallery_id));
+                    remaining_body = &from_start[shortcode_length..];
+                }
+                None => {
+                    // Not a valid shortcode, so it's kept as regular text
+                    html.push_str(SHORTCODE_START);
+                    remaining_body = &from_start[SHORTCODE_START.len()..];
+                }
+            }
+        }
+
+        html.push_str(remaining_body);
+        if !html.is_empty() {
+            segments.push(GalleryShortcodeSegment::Html(html));
+        }
+
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GALLERY_ID_1: &'static str = "0b0c6e0f-7a4e-4a53-9a4b-2f0b3c1c7d11";
+    const GALLERY_ID_2: &'static str = "5f1d2c3b-8e9a-4b7c-a6d5-e4f3a2b1c0d9";
+
+    fn gallery(value: &str) -> GalleryShortcodeSegment {
+        GalleryShortcodeSegment::Gallery(
+            GalleryId::from_string(value).unwrap()
+        )
+    }
+
+    fn html(value: &str) -> GalleryShortcodeSegment {
+        GalleryShortcodeSegment::Html(value.to_string())
+    }
+
+    fn shortcode(value: &str) -> String {
+        GalleryShortcode::from_gallery_id(
+            &GalleryId::from_string(value).unwrap()
+        )
+    }
+
+    #[test]
+    fn keeps_a_body_without_shortcodes() {
+        let body = "<p>No galleries [here]</p>";
+
+        assert_eq!(GalleryShortcode::split(body), vec![html(body)]);
+        assert_eq!(GalleryShortcode::split(""), vec![]);
+    }
+
+    #[test]
+    fn splits_the_html_around_a_shortcode() {
+        let body = format!(
+            "<p>Before</p>{}<p>After</p>",
+            shortcode(GALLERY_ID_1)
+        );
+
+        assert_eq!(
+            GalleryShortcode::split(&body),
+            vec![
+                html("<p>Before</p>"),
+                gallery(GALLERY_ID_1),
+                html("<p>After</p>"),
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_adjacent_shortcodes() {
+        let body = format!(
+            "{}{}",
+            shortcode(GALLERY_ID_1),
+            shortcode(GALLERY_ID_2)
+        );
+
+        assert_eq!(
+            GalleryShortcode::split(&body),
+            vec![gallery(GALLERY_ID_1), gallery(GALLERY_ID_2)]
+        );
+    }
+
+    #[test]
+    fn accepts_single_quotes_and_spaces() {
+        let body = format!("[gallery   id='{}' ]", GALLERY_ID_1);
+
+        assert_eq!(GalleryShortcode::split(&body), vec![gallery(GALLERY_ID_1)]);
+    }
+
+    // Whether the gallery exists is only known when it's rendered (a missing
+    // one is rendered empty), but an ID that isn't a UUID is kept as text
+    #[test]
+    fn keeps_shortcodes_with_invalid_ids_as_html() {
+        let body = format!(
+            "<p>[gallery id=\"unknown\"]</p>[gallery name=\"{}\"]",
+            GALLERY_ID_1
+        );
+
+        assert_eq!(GalleryShortcode::split(&body), vec![html(&body)]);
+    }
+
+    #[test]
+    fn keeps_unclosed_shortcodes_as_html() {
+        let body = format!("<p>Text</p>[gallery id=\"{}\"", GALLERY_ID_1);
+
+        assert_eq!(GalleryShortcode::split(&body), vec![html(&body)]);
+    }
+
+    #[test]
+    fn does_not_let_an_unclosed_shortcode_swallow_the_next_one() {
+        let body = format!("[gallery <p>{}</p>", shortcode(GALLERY_ID_1));
+
+        assert_eq!(
+            GalleryShortcode::split(&body),
+            vec![html("[gallery <p>"), gallery(GALLERY_ID_1), html("</p>")]
+        );
+    }
+}
@@ -0,0 +1,8 @@
+mod gallery;
+pub use gallery::*;
+
+mod gallery_image;
+pub use gallery_image::*;
+
+mod gallery_shortcode;
+pub use gallery_shortcode::*;
@@ -0,0 +1,25 @@
+use crate::core::shared::model::DomainError;
+use super::super::model::Gallery;
+use super::super::model::GalleryId;
+use super::super::model::GallerySearchCriteria;
+use super::super::model::GallerySearchFilterCriteria;
+
+pub trait GalleryRepository {
+    fn add(&mut self, gallery: Gallery) -> Result<(), DomainError>;
+
+    fn count(
+        &mut self,
+        criteria: GallerySearchFilterCriteria
+    ) -> Result<i64, DomainError>;
+
+    fn delete(&mut self, gallery_id: GalleryId) -> Result<(), DomainError>;
+
+    fn find(
+        &mut self,
+        criteria: GallerySearchCriteria
+    ) -> Result<Vec<Gallery>, DomainError>;
+
+    fn get(&mut self, gallery_id: GalleryId) -> Result<Gallery, DomainError>;
+
+    fn update(&mut self, gallery: Gallery) -> Result<(), DomainError>;
+}
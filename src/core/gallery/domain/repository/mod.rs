@@ -0,0 +1,2 @@
+mod gallery_repository;
+pub use gallery_repository::*;
@@ -0,0 +1,63 @@
+use chrono::DateTime;
+use chrono::Utc;
+use diesel::pg::Pg;
+use diesel::prelude::AsChangeset;
+use diesel::prelude::Identifiable;
+use diesel::prelude::Insertable;
+use diesel::prelude::Queryable;
+use diesel::prelude::Selectable;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::gallery::model::GalleryCreationDate;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GalleryImage;
+use tukosmo_domain::core::gallery::model::GalleryUpdateDate;
+use tukosmo_domain::core::language::model::I18nText;
+use uuid::Uuid;
+
+use crate::core::shared::diesel_orm::schema::gallery;
+
+#[derive(
+    AsChangeset,
+    Clone,
+    Debug,
+    Identifiable,
+    Insertable,
+    PartialEq,
+    Queryable,
+    Selectable
+)]
+#[diesel(table_name = gallery)]
+#[diesel(check_for_backend(Pg))]
+pub struct DbGallery {
+    pub creation_date: DateTime<Utc>,
+    pub i18n_text_id_name: Uuid,
+    pub id: Uuid,
+    pub update_date: DateTime<Utc>,
+}
+
+impl DbGallery {
+    pub fn from_domain(gallery: Gallery) -> Self {
+        Self {
+            creation_date: gallery.creation_date.value().clone(),
+            i18n_text_id_name: gallery.name.id.value().clone(),
+            id: gallery.id.value().clone(),
+            update_date: gallery.update_date.value().clone(),
+        }
+    }
+
+    pub fn to_domain(
+        self,
+        gallery_name: I18nText,
+        gallery_images: Vec<GalleryImage>
+    ) -> Gallery {
+        Gallery {
+            creation_date: GalleryCreationDate::from(
+                self.creation_date.clone()
+            ),
+            id: GalleryId::from(self.id.clone()),
+            images: gallery_images,
+            name: gallery_name,
+            update_date: GalleryUpdateDate::from(self.update_date.clone()),
+        }
+    }
+}
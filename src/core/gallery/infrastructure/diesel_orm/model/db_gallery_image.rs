@@ -0,0 +1,77 @@
+use chrono::DateTime;
+use chrono::Utc;
+use diesel::pg::Pg;
+use diesel::prelude::AsChangeset;
+use diesel::prelude::Associations;
+use diesel::prelude::Identifiable;
+use diesel::prelude::Insertable;
+use diesel::prelude::Queryable;
+use diesel::prelude::Selectable;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GalleryImage;
+use tukosmo_domain::core::gallery::model::GalleryImageCreationDate;
+use tukosmo_domain::core::gallery::model::GalleryImageFileName;
+use tukosmo_domain::core::gallery::model::GalleryImageId;
+use tukosmo_domain::core::gallery::model::GalleryImagePosition;
+use tukosmo_domain::core::gallery::model::GalleryImageUpdateDate;
+use tukosmo_domain::core::language::model::I18nText;
+use uuid::Uuid;
+
+use crate::core::shared::diesel_orm::schema::gallery_image;
+use super::db_gallery::DbGallery;
+
+#[derive(
+    AsChangeset,
+    Associations,
+    Clone,
+    Debug,
+    Identifiable,
+    Insertable,
+    PartialEq,
+    Queryable,
+    Selectable
+)]
+#[diesel(belongs_to(DbGallery, foreign_key = gallery_id))]
+#[diesel(table_name = gallery_image)]
+#[diesel(check_for_backend(Pg))]
+pub struct DbGalleryImage {
+    pub creation_date: DateTime<Utc>,
+    pub file_name: String,
+    pub gallery_id: Uuid,
+    pub i18n_text_id_caption: Uuid,
+    pub id: Uuid,
+    pub position: i32,
+    pub update_date: DateTime<Utc>,
+}
+
+impl DbGalleryImage {
+    pub fn from_domain(
+        gallery_image: GalleryImage,
+        gallery_id: GalleryId
+    ) -> Self {
+        Self {
+            creation_date: gallery_image.creation_date.value().clone(),
+            file_name: gallery_image.file_name.value().to_string(),
+            gallery_id: gallery_id.value().clone(),
+            i18n_text_id_caption: gallery_image.caption.id.value().clone(),
+            id: gallery_image.id.value().clone(),
+            position: gallery_image.position.value().clone(),
+            update_date: gallery_image.update_date.value().clone(),
+        }
+    }
+
+    pub fn to_domain(self, caption: I18nText) -> GalleryImage {
+        GalleryImage {
+            caption,
+            creation_date: GalleryImageCreationDate::from(
+                self.creation_date.clone()
+            ),
+            file_name: GalleryImageFileName::from(self.file_name.clone()),
+            id: GalleryImageId::from(self.id.clone()),
+            position: GalleryImagePosition::from(self.position.clone()),
+            update_date: GalleryImageUpdateDate::from(
+                self.update_date.clone()
+            ),
+        }
+    }
+}
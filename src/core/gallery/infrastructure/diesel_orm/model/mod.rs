@@ -0,0 +1,5 @@
+mod db_gallery;
+pub use db_gallery::*;
+
+mod db_gallery_image;
+pub use db_gallery_image::*;
@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GallerySearchCriteria;
+use tukosmo_domain::core::gallery::model::GallerySearchFilterCriteria;
+use tukosmo_domain::core::gallery::repository::GalleryRepository;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use super::super::service::GalleryManager;
+
+pub struct DbGalleryRepository {
+    gallery: GalleryManager,
+}
+
+impl DbGalleryRepository {
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { gallery: GalleryManager::init(connection) }
+    }
+}
+
+impl GalleryRepository for DbGalleryRepository {
+    fn add(&mut self, gallery: Gallery) -> Result<(), DomainError> {
+        self.gallery.add(gallery)?;
+        Ok(())
+    }
+
+    fn count(
+        &mut self,
+        filter_criteria: GallerySearchFilterCriteria
+    ) -> Result<i64, DomainError> {
+        let total = self.gallery.count(filter_criteria)?;
+        Ok(total)
+    }
+
+    fn delete(&mut self, gallery_id: GalleryId) -> Result<(), DomainError> {
+        self.gallery.delete(gallery_id)?;
+        Ok(())
+    }
+
+    fn find(
+        &mut self,
+        search_criteria: GallerySearchCriteria
+    ) -> Result<Vec<Gallery>, DomainError> {
+        let galleries = self.gallery.find(search_criteria)?;
+        Ok(galleries)
+    }
+
+    fn get(&mut self, gallery_id: GalleryId) -> Result<Gallery, DomainError> {
+        let gallery = self.gallery.get(gallery_id)?;
+        Ok(gallery)
+    }
+
+    fn update(&mut self, gallery: Gallery) -> Result<(), DomainError> {
+        self.gallery.update(gallery)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+mod db_gallery_repository;
+pub use db_gallery_repository::*;
@@ -0,0 +1,239 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::gallery::error;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GalleryImage;
+use tukosmo_domain::core::gallery::model::GalleryImageId;
+use tukosmo_domain::core::gallery::model::GalleryImageSearchCriteria;
+use tukosmo_domain::core::gallery::model::GallerySearchCriteria;
+use tukosmo_domain::core::gallery::model::GallerySearchFilterCriteria;
+use tukosmo_domain::core::language::model::I18nText;
+use tukosmo_domain::core::language::model::I18nTextId;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::language::diesel_orm::service::I18nTextManager;
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbGallery;
+use super::super::model::DbGalleryImage;
+use super::super::statement::GalleryImageSqlExecutor;
+use super::super::statement::GallerySqlExecutor;
+
+pub struct GalleryManager {
+    gallery: GallerySqlExecutor,
+    gallery_image: GalleryImageSqlExecutor,
+    i18n_text_manager: I18nTextManager,
+}
+
+impl GalleryManager {
+    pub fn add(&mut self, gallery: Gallery) -> Result<(), DomainError> {
+        self.i18n_text_manager.add(gallery.name.clone())?;
+
+        let db_gallery = DbGallery::from_domain(gallery.clone());
+        self.gallery.insert(db_gallery)?;
+
+        let mut db_gallery_images: Vec<DbGalleryImage> = vec![];
+        for image in gallery.images {
+            self.i18n_text_manager.add(image.caption.clone())?;
+            db_gallery_images.push(
+                DbGalleryImage::from_domain(image, gallery.id.clone())
+            );
+        }
+        self.gallery_image.insert_in_bulk(db_gallery_images)?;
+
+        Ok(())
+    }
+
+    pub fn count(
+        &mut self,
+        filter_criteria: GallerySearchFilterCriteria
+    ) -> Result<i64, DomainError> {
+        let total = self.gallery.select_count(filter_criteria)?;
+        Ok(total)
+    }
+
+    pub fn delete(&mut self, gallery_id: GalleryId) -> Result<(), DomainError> {
+        let gallery = self.get(gallery_id)?;
+
+        // Images are removed by the database cascade, but not their captions
+        self.gallery.delete(GallerySearchCriteria::has_id(gallery.id).filter)?;
+        self.i18n_text_manager.delete(gallery.name.id)?;
+        for image in gallery.images {
+            self.i18n_text_manager.delete(image.caption.id)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn find(
+        &mut self,
+        search_criteria: GallerySearchCriteria
+    ) -> Result<Vec<Gallery>, DomainError> {
+        let db_galleries = self.gallery.select(search_criteria)?;
+        let gallery_ids = db_galleries
+            .iter()
+            .map(|g| GalleryId::from(g.id.clone()))
+            .collect();
+
+        let db_gallery_images = self.gallery_image.select(
+            GalleryImageSearchCriteria::has_gallery_id_in(gallery_ids)
+        )?;
+
+        let mut i18n_text_ids: Vec<I18nTextId> = db_galleries
+            .iter()
+            .map(|g| I18nTextId::from(g.i18n_text_id_name.clone()))
+            .collect();
+        i18n_text_ids.extend(
+            db_gallery_images
+                .iter()
+                .map(|i| I18nTextId::from(i.i18n_text_id_caption.clone()))
+        );
+        let i18n_texts = self.i18n_text_manager.get_in_bulk(i18n_text_ids)?;
+
+        let mut galleries: Vec<Gallery> = vec![];
+        for db_gallery in db_galleries {
+            let gallery_images = Self::images_to_domain(
+                db_gallery_images
+                    .iter()
+                    .filter(|i| i.gallery_id == db_gallery.id)
+                    .cloned()
+                    .collect(),
+                &i18n_texts
+            );
+            let gallery_name = i18n_texts
+                .iter()
+                .find(|t| t.id.value() == &db_gallery.i18n_text_id_name)
+                .unwrap()
+                .clone();
+
+            let gallery = db_gallery.to_domain(gallery_name, gallery_images);
+            galleries.push(gallery);
+        }
+
+        Ok(galleries)
+    }
+
+    pub fn get(
+        &mut self,
+        gallery_id: GalleryId
+    ) -> Result<Gallery, DomainError> {
+        let db_galleries = self.gallery.select(
+            GallerySearchCriteria::has_id(gallery_id.clone())
+        )?;
+        let db_gallery = db_galleries
+            .first()
+            .cloned()
+            .ok_or(error::GALLERY_NOT_FOUND)?;
+
+        let db_gallery_images = self.gallery_image.select(
+            GalleryImageSearchCriteria::has_gallery_id(gallery_id)
+        )?;
+
+        let mut i18n_text_ids: Vec<I18nTextId> = vec![
+            I18nTextId::from(db_gallery.i18n_text_id_name.clone())
+        ];
+        i18n_text_ids.extend(
+            db_gallery_images
+                .iter()
+                .map(|i| I18nTextId::from(i.i18n_text_id_caption.clone()))
+        );
+        let i18n_texts = self.i18n_text_manager.get_in_bulk(i18n_text_ids)?;
+
+        let gallery_images = Self::images_to_domain(
+            db_gallery_images,
+            &i18n_texts
+        );
+        let gallery_name = i18n_texts
+            .iter()
+            .find(|t| t.id.value() == &db_gallery.i18n_text_id_name)
+            .unwrap()
+            .clone();
+
+        let gallery = db_gallery.to_domain(gallery_name, gallery_images);
+
+        Ok(gallery)
+    }
+
+    fn images_to_domain(
+        db_gallery_images: Vec<DbGalleryImage>,
+        i18n_texts: &Vec<I18nText>
+    ) -> Vec<GalleryImage> {
+        db_gallery_images
+            .into_iter()
+            .map(|db_gallery_image| {
+                let caption = i18n_texts
+                    .iter()
+                    .find(|t|
+                        t.id.value() == &db_gallery_image.i18n_text_id_caption
+                    )
+                    .unwrap()
+                    .clone();
+                db_gallery_image.to_domain(caption)
+            })
+            .collect()
+    }
+
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self {
+            gallery: GallerySqlExecutor::init(Rc::clone(&connection)),
+            gallery_image: GalleryImageSqlExecutor::init(
+                Rc::clone(&connection)
+            ),
+            i18n_text_manager: I18nTextManager::init(connection),
+        }
+    }
+
+    pub fn update(&mut self, gallery: Gallery) -> Result<(), DomainError> {
+        let db_gallery = DbGallery::from_domain(gallery.clone());
+        self.gallery.update(&db_gallery)?;
+
+        self.i18n_text_manager.update(gallery.name)?;
+
+        let db_current_images = self.gallery_image.select(
+            GalleryImageSearchCriteria::has_gallery_id(gallery.id.clone())
+        )?;
+
+        let gallery_image_ids_to_retain: Vec<GalleryImageId> = gallery.images
+            .iter()
+            .map(|i| i.id.clone())
+            .collect();
+        self.gallery_image.delete(
+            GalleryImageSearchCriteria::has_gallery_id_and_id_not_in(
+                gallery.id.clone(),
+                gallery_image_ids_to_retain
+            ).filter
+        )?;
+
+        for db_current_image in db_current_images.iter() {
+            let is_retained = gallery.images
+                .iter()
+                .any(|i| i.id.value() == &db_current_image.id);
+            if !is_retained {
+                self.i18n_text_manager.delete(
+                    I18nTextId::from(
+                        db_current_image.i18n_text_id_caption.clone()
+                    )
+                )?;
+            }
+        }
+
+        let mut db_gallery_images: Vec<DbGalleryImage> = vec![];
+        for image in gallery.images {
+            let is_new = !db_current_images
+                .iter()
+                .any(|i| &i.id == image.id.value());
+            if is_new {
+                self.i18n_text_manager.add(image.caption.clone())?;
+            } else {
+                self.i18n_text_manager.update(image.caption.clone())?;
+            }
+            db_gallery_images.push(
+                DbGalleryImage::from_domain(image, gallery.id.clone())
+            );
+        }
+        self.gallery_image.upsert_in_bulk(db_gallery_images)?;
+
+        Ok(())
+    }
+}
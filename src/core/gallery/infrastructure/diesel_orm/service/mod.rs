@@ -0,0 +1,2 @@
+mod gallery_manager;
+pub use gallery_manager::*;
@@ -0,0 +1,210 @@
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::RunQueryDsl;
+use diesel::SelectableHelper;
+use diesel::pg::Pg;
+use diesel::pg::PgConnection;
+use diesel::query_builder::BoxedSelectStatement;
+use diesel::query_builder::FromClause;
+use diesel;
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use tukosmo_domain::core::gallery::model::GalleryImageSearchCriteria;
+use tukosmo_domain::core::gallery::model::GalleryImageSearchFilterCriteria;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use uuid::Uuid;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::schema::gallery_image;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbGalleryImage;
+
+pub struct GalleryImageSqlExecutor {
+    connection: Rc<RefCell<DbConnection>>,
+}
+
+impl ModelSqlExecutor<
+    DbGalleryImage,
+    (
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Text,
+        diesel::sql_types::Integer,
+        diesel::sql_types::Timestamptz,
+        diesel::sql_types::Timestamptz,
+    ),
+    GalleryImageSearchCriteria,
+    GalleryImageSearchFilterCriteria,
+    gallery_image::table
+>
+for GalleryImageSqlExecutor {
+    fn delete(
+        &mut self,
+        filter_criteria: GalleryImageSearchFilterCriteria
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut statement = diesel::delete(gallery_image::table).into_boxed();
+
+        if let Some(gallery_id) = filter_criteria.gallery_id {
+            statement = statement.filter(
+                gallery_image::gallery_id.eq(gallery_id.value().clone())
+            );
+        }
+        if let Some(gallery_ids) = filter_criteria.gallery_id_in {
+            statement = statement.filter(
+                gallery_image::gallery_id.eq_any(
+                    gallery_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+        if let Some(gallery_image_ids) = filter_criteria.id_not_in {
+            statement = statement.filter(
+                gallery_image::id.ne_all(
+                    gallery_image_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+
+        let result = statement.execute(connection);
+
+        match result {
+            Ok(_affected_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_DELETE_ON_DATABASE),
+        }
+    }
+
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
+        Rc::clone(&self.connection)
+    }
+
+    fn get_table() -> gallery_image::table {
+        gallery_image::table
+    }
+
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { connection }
+    }
+
+    fn select(
+        &mut self,
+        search_criteria: GalleryImageSearchCriteria
+    ) -> Result<Vec<DbGalleryImage>, DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let query = Self::select_query(search_criteria.filter);
+        let results = query
+            .order(gallery_image::position.asc())
+            .select(DbGalleryImage::as_select())
+            .load(connection)
+            .map_err(|_e| error::CANNOT_EXECUTE_SELECT_ON_DATABASE)?;
+
+        Ok(results)
+    }
+
+    fn select_query<'a>(
+        filter_criteria: GalleryImageSearchFilterCriteria
+    ) -> BoxedSelectStatement<
+        'a,
+        (
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Text,
+            diesel::sql_types::Integer,
+            diesel::sql_types::Timestamptz,
+            diesel::sql_types::Timestamptz,
+        ),
+        FromClause<gallery_image::table>,
+        Pg
+    > {
+        let mut query = gallery_image::table.into_boxed();
+
+        if let Some(gallery_id) = filter_criteria.gallery_id {
+            query = query.filter(
+                gallery_image::gallery_id.eq(gallery_id.value().clone())
+            );
+        }
+        if let Some(gallery_ids) = filter_criteria.gallery_id_in {
+            query = query.filter(
+                gallery_image::gallery_id.eq_any(
+                    gallery_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+        if let Some(gallery_image_ids) = filter_criteria.id_not_in {
+            query = query.filter(
+                gallery_image::id.ne_all(
+                    gallery_image_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+
+        query
+    }
+
+    fn update(
+        &mut self,
+        db_gallery_image: &DbGalleryImage
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::update(gallery_image::table.find(db_gallery_image.id))
+            .set(db_gallery_image)
+            .execute(connection);
+
+        match result {
+            Ok(0) => Err(error::NOTHING_TO_UPDATE_ON_DATABASE),
+            Ok(1) => Ok(()),
+            Ok(_) => Err(error::UNDESIRED_UPDATES_ON_DATABASE),
+            Err(_e) => Err(error::CANNOT_EXECUTE_UPDATE_ON_DATABASE),
+        }
+    }
+
+    fn upsert_in_bulk(
+        &mut self,
+        db_gallery_images: Vec<DbGalleryImage>
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::insert_into(gallery_image::table)
+            .values(db_gallery_images)
+            .on_conflict(gallery_image::id)
+            .do_update()
+            .set((
+                gallery_image::position.eq(
+                    diesel::pg::upsert::excluded(gallery_image::position)
+                ),
+                gallery_image::update_date.eq(
+                    diesel::pg::upsert::excluded(gallery_image::update_date)
+                ),
+            ))
+            .execute(connection);
+
+        match result {
+            Ok(_inserted_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_INSERT_ON_DATABASE),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::RunQueryDsl;
+use diesel::SelectableHelper;
+use diesel::pg::Pg;
+use diesel::pg::PgConnection;
+use diesel::query_builder::BoxedSelectStatement;
+use diesel::query_builder::FromClause;
+use diesel;
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use tukosmo_domain::core::gallery::model::GallerySearchCriteria;
+use tukosmo_domain::core::gallery::model::GallerySearchCriteriaOrderBy;
+use tukosmo_domain::core::gallery::model::GallerySearchFilterCriteria;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::schema::gallery;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbGallery;
+
+pub struct GallerySqlExecutor {
+    connection: Rc<RefCell<DbConnection>>,
+}
+
+impl ModelSqlExecutor<
+    DbGallery,
+    (
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Timestamptz,
+        diesel::sql_types::Timestamptz,
+    ),
+    GallerySearchCriteria,
+    GallerySearchFilterCriteria,
+    gallery::table
+>
+for GallerySqlExecutor {
+    fn delete(
+        &mut self,
+        filter_criteria: GallerySearchFilterCriteria
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut statement = diesel::delete(gallery::table).into_boxed();
+
+        if let Some(gallery_id) = filter_criteria.id {
+            statement = statement.filter(
+                gallery::id.eq(gallery_id.value().clone())
+            );
+        }
+
+        let result = statement.execute(connection);
+
+        match result {
+            Ok(_affected_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_DELETE_ON_DATABASE),
+        }
+    }
+
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
+        Rc::clone(&self.connection)
+    }
+
+    fn get_table() -> gallery::table {
+        gallery::table
+    }
+
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { connection }
+    }
+
+    fn select(
+        &mut self,
+        search_criteria: GallerySearchCriteria
+    ) -> Result<Vec<DbGallery>, DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut query = Self::select_query(search_criteria.filter);
+        if let Some(order_by) = search_criteria.order_by {
+            match order_by {
+                GallerySearchCriteriaOrderBy::CreationDate => {
+                    query = query.order(gallery::creation_date.desc());
+                }
+            }
+        }
+
+        let select = query.select(DbGallery::as_select());
+
+        let results = (
+            if let Some(pagination) = search_criteria.pagination {
+                select
+                    .offset((pagination.page - 1) * pagination.results_per_page)
+                    .limit(pagination.results_per_page)
+            } else {
+                select
+            }
+        )
+            .load(connection)
+            .map_err(|_e| error::CANNOT_EXECUTE_SELECT_ON_DATABASE)?;
+
+        Ok(results)
+    }
+
+    fn select_query<'a>(
+        filter_criteria: GallerySearchFilterCriteria
+    ) -> BoxedSelectStatement<
+        'a,
+        (
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Timestamptz,
+            diesel::sql_types::Timestamptz,
+        ),
+        FromClause<gallery::table>,
+        Pg
+    > {
+        let mut query = gallery::table.into_boxed();
+
+        if let Some(gallery_id) = filter_criteria.id {
+            query = query.filter(gallery::id.eq(gallery_id.value().clone()));
+        }
+
+        query
+    }
+
+    fn update(&mut self, db_gallery: &DbGallery) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::update(gallery::table.find(db_gallery.id))
+            .set(db_gallery)
+            .execute(connection);
+
+        match result {
+            Ok(0) => Err(error::NOTHING_TO_UPDATE_ON_DATABASE),
+            Ok(1) => Ok(()),
+            Ok(_) => Err(error::UNDESIRED_UPDATES_ON_DATABASE),
+            Err(_e) => Err(error::CANNOT_EXECUTE_UPDATE_ON_DATABASE),
+        }
+    }
+
+    fn upsert_in_bulk(
+        &mut self,
+        db_galleries: Vec<DbGallery>
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::insert_into(gallery::table)
+            .values(db_galleries)
+            .on_conflict(gallery::id)
+            .do_update()
+            .set(
+                gallery::update_date.eq(
+                    diesel::pg::upsert::excluded(gallery::update_date)
+                )
+            )
+            .execute(connection);
+
+        match result {
+            Ok(_inserted_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_INSERT_ON_DATABASE),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod gallery_image_sql_executor;
+pub use gallery_image_sql_executor::*;
+
+mod gallery_sql_executor;
+pub use gallery_sql_executor::*;
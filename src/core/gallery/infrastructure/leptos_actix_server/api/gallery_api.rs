@@ -0,0 +1,166 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::gallery::dto::DtoAddGallery;
+use tukosmo_application::core::gallery::dto::DtoDeleteGallery;
+use tukosmo_application::core::gallery::dto::DtoDeleteGalleryImage;
+use tukosmo_application::core::gallery::dto::DtoEditGallery;
+use tukosmo_application::core::gallery::dto::DtoEditGalleryImage;
+use tukosmo_application::core::gallery::dto::DtoGalleriesPaginated;
+use tukosmo_application::core::gallery::dto::DtoGetGalleriesPaginated;
+use tukosmo_application::core::gallery::dto::DtoGetGallery;
+use tukosmo_application::core::gallery::dto::DtoReorderGalleryImages;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::gallery::use_case::GalleryUseCase;
+use tukosmo_domain::core::gallery::model::Gallery;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+#[server(ApiCoreGalleryGalleryAdd)]
+pub async fn add(
+    dto: DtoAddGallery
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.add(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryDelete)]
+pub async fn delete(
+    dto: DtoDeleteGallery
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.delete(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryDeleteImage)]
+pub async fn delete_image(
+    dto: DtoDeleteGalleryImage
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.delete_image(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryEdit)]
+pub async fn edit(
+    dto: DtoEditGallery
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.edit(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryEditImage)]
+pub async fn edit_image(
+    dto: DtoEditGalleryImage
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.edit_image(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryGet)]
+pub async fn get(
+    dto: DtoGetGallery
+) -> Result<ServerResponse<Gallery>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.get(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryGetAllGalleries)]
+pub async fn get_all_galleries() -> Result<
+    ServerResponse<Vec<Gallery>>,
+    ServerFnError
+> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.get_all_galleries()
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryListPaginated)]
+pub async fn list_paginated(
+    dto: DtoGetGalleriesPaginated
+) -> Result<ServerResponse<DtoGalleriesPaginated>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.get_galleries_paginated(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreGalleryGalleryReorderImages)]
+pub async fn reorder_images(
+    dto: DtoReorderGalleryImages
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let gallery_use_case = common()?;
+        gallery_use_case.reorder_images(dto)
+    });
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn common() -> Result<GalleryUseCase, DomainError> {
+    use crate::core::shared::leptos_actix_server::service::database;
+    use crate::core::shared::leptos_actix_server::service::page_cache;
+
+    let db_pool = database::get_db_pool()?;
+    common_with_db_pool(&db_pool, page_cache::get_page_cache())
+}
+
+// Used by gallery_handler::upload_images(): it isn't a server function, so
+// there's no Leptos context to get the pool and the page cache from
+#[cfg(feature = "ssr")]
+pub(crate) fn common_with_db_pool(
+    db_pool: &crate::core::shared::diesel_orm::model::DbPool,
+    page_cache: Option<
+        actix_web::web::Data<
+            crate::core::shared::leptos_actix_server::service::page_cache::PageCache
+        >
+    >
+) -> Result<GalleryUseCase, DomainError> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+    use crate::core::shared::leptos_actix_server::repository::MemoryPageCacheRepository;
+    use crate::core::shared::diesel_orm::model::DbTransactionExecutor;
+
+    let data_repository = FsDataRepository::init()?;
+    let page_cache_repository = MemoryPageCacheRepository::init(page_cache);
+
+    let transaction_executor = DbTransactionExecutor::init(db_pool)?;
+    let gallery_use_case = GalleryUseCase::init(
+        Rc::new(RefCell::new(data_repository)),
+        Rc::new(RefCell::new(page_cache_repository)),
+        Rc::new(RefCell::new(transaction_executor))
+    );
+
+    Ok(gallery_use_case)
+}
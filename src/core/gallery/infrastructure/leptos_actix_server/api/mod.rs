@@ -0,0 +1 @@
+pub mod gallery_api;
@@ -0,0 +1,67 @@
+use actix_multipart::Multipart;
+use actix_web::HttpResponse;
+use actix_web::http;
+use actix_web::post;
+use actix_web::web;
+use serde::Deserialize;
+use tukosmo_application::core::gallery::dto::DtoUploadGalleryImages;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::language::model::LanguageCode;
+
+use crate::core::shared::diesel_orm::model::DbPool;
+use crate::core::shared::leptos_actix_server::service::multipart;
+use crate::core::shared::leptos_actix_server::service::page_cache::PageCache;
+use crate::core::shared::leptos_ui::service::navigation;
+use super::super::api::gallery_api;
+
+#[derive(Deserialize)]
+pub struct UploadImagesQuery {
+    language_code: String,
+}
+
+// Files can't be sent through Leptos server functions, so images are
+// uploaded with a regular multipart form and the browser is redirected back
+// to the TAP (with the error code in the query string if anything failed).
+#[post("/api/upload/gallery/{gallery_id}")]
+pub async fn upload_images(
+    path: web::Path<String>,
+    query: web::Query<UploadImagesQuery>,
+    db_pool: web::Data<DbPool>,
+    page_cache: web::Data<PageCache>,
+    payload: Multipart
+) -> HttpResponse {
+    let gallery_id = path.into_inner();
+    let language_code = LanguageCode::from(query.language_code.clone());
+
+    let result = match multipart::read_uploaded_files(payload).await {
+        Ok(files) => {
+            let dto = DtoUploadGalleryImages {
+                files,
+                gallery_id: gallery_id.clone(),
+            };
+            gallery_api
+                ::common_with_db_pool(&db_pool, Some(page_cache))
+                .and_then(|gallery_use_case| {
+                    gallery_use_case.upload_images(dto)
+                })
+        }
+        Err(e) => Err(e),
+    };
+
+    let edit_path = match GalleryId::from_string(&gallery_id) {
+        Ok(gallery_id) =>
+            navigation::path_admin_galleries_edit(&language_code, &gallery_id),
+        Err(_e) => navigation::path_admin_galleries(&language_code),
+    };
+    let location = match result {
+        Ok(()) => edit_path,
+        Err(domain_error) => {
+            domain_error.log();
+            format!("{}?error={}", edit_path, domain_error.get_full_code())
+        }
+    };
+
+    HttpResponse::SeeOther()
+        .append_header((http::header::LOCATION, location))
+        .finish()
+}
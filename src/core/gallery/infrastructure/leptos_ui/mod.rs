@@ -0,0 +1,8 @@
+mod model;
+pub use model::*;
+
+mod view;
+pub use view::*;
+
+mod widget;
+pub use widget::*;
@@ -0,0 +1,55 @@
+use leptos::StoredValue;
+use leptos::store_value;
+use tukosmo_application::core::gallery::dto::DtoGalleryForm;
+use tukosmo_domain::core::gallery::model::Gallery;
+
+use crate::core::language::leptos_ui::I18nTextFormFieldInitialValue;
+use crate::core::language::leptos_ui::I18nTextFormFieldValue;
+
+#[derive(Clone)]
+pub struct GalleryForm {
+    pub name: StoredValue<I18nTextFormFieldValue>,
+}
+
+#[derive(Clone)]
+struct GalleryFormInitialValues {
+    pub name: I18nTextFormFieldInitialValue,
+}
+
+impl GalleryForm {
+    pub fn get_dto(&self) -> DtoGalleryForm {
+        DtoGalleryForm {
+            name: self.name.get_value().get_i18n_text_value(),
+        }
+    }
+
+    fn init(initial_values: GalleryFormInitialValues) -> StoredValue<Self> {
+        let name = I18nTextFormFieldValue::init(
+            initial_values.name,
+            Gallery::validate_name_default_value,
+            Gallery::validate_name_translation_value
+        );
+
+        store_value(Self { name })
+    }
+
+    pub fn init_empty() -> StoredValue<Self> {
+        let initial_values = GalleryFormInitialValues {
+            name: I18nTextFormFieldInitialValue::empty(),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn init_filled(gallery: Gallery) -> StoredValue<Self> {
+        let initial_values = GalleryFormInitialValues {
+            name: I18nTextFormFieldInitialValue::filled(gallery.name),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn validate(&self) {
+        self.name.get_value().validate();
+    }
+}
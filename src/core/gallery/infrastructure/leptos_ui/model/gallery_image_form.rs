@@ -0,0 +1,32 @@
+use leptos::StoredValue;
+use leptos::store_value;
+use tukosmo_domain::core::gallery::model::GalleryImage;
+use tukosmo_domain::core::language::model::I18nTextValue;
+
+use crate::core::language::leptos_ui::I18nTextFormFieldInitialValue;
+use crate::core::language::leptos_ui::I18nTextFormFieldValue;
+
+#[derive(Clone)]
+pub struct GalleryImageForm {
+    pub caption: StoredValue<I18nTextFormFieldValue>,
+}
+
+impl GalleryImageForm {
+    pub fn get_caption_value(&self) -> I18nTextValue {
+        self.caption.get_value().get_i18n_text_value()
+    }
+
+    pub fn init_filled(gallery_image: GalleryImage) -> StoredValue<Self> {
+        let caption = I18nTextFormFieldValue::init(
+            I18nTextFormFieldInitialValue::filled(gallery_image.caption),
+            GalleryImage::validate_caption_value,
+            GalleryImage::validate_caption_value
+        );
+
+        store_value(Self { caption })
+    }
+
+    pub fn validate(&self) {
+        self.caption.get_value().validate();
+    }
+}
@@ -0,0 +1,5 @@
+mod gallery_form;
+pub use gallery_form::*;
+
+mod gallery_image_form;
+pub use gallery_image_form::*;
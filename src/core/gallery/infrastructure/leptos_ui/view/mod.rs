@@ -0,0 +1,14 @@
+mod tap_add_gallery_view;
+pub use tap_add_gallery_view::*;
+
+mod tap_delete_gallery_view;
+pub use tap_delete_gallery_view::*;
+
+mod tap_edit_gallery_view;
+pub use tap_edit_gallery_view::*;
+
+mod tap_galleries_view;
+pub use tap_galleries_view::*;
+
+mod web_gallery_view;
+pub use web_gallery_view::*;
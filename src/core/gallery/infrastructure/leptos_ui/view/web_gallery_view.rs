@@ -0,0 +1,76 @@
+use leptos::IntoView;
+use leptos::SignalGet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::use_params_map;
+use tukosmo_application::core::gallery::dto::DtoGetGallery;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+use crate::core::gallery::leptos_actix_server::api::gallery_api;
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::WebLayout;
+use crate::core::shared::leptos_ui::WebNotFoundView;
+use crate::core::shared::leptos_ui::use_global_context;
+use super::super::WebGallery;
+
+#[component]
+pub fn WebGalleryView() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+    let params = use_params_map();
+
+    let response_data = create_resource(
+        move || params.get(),
+        move |params| async move {
+            let gallery_id = params.get("id").cloned().unwrap_or_default();
+
+            let dto = DtoGetGallery { gallery_id };
+            let result = gallery_api::get(dto).await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(ServerResponse::Response(gallery)) => {
+                    // The route is rendered with SsrMode::Async, so the
+                    // headers haven't been sent yet
+                    #[cfg(feature = "ssr")]
+                    {
+                        use crate::core::shared::leptos_actix_server::service::page_cache;
+
+                        page_cache::allow_caching();
+                    }
+
+                    let gallery = store_value(gallery);
+                    view! {
+                        <div>
+                            <WebLayout>
+                                <h1>
+                                    {move || gallery.get_value().name.translate(current_language_reader.get().id)}
+                                </h1>
+                                <WebGallery gallery=gallery.get_value() />
+                            </WebLayout>
+                        </div>
+                    }
+                }
+                _ => {
+                    view! {
+                        <div>
+                            <WebNotFoundView />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <Transition fallback=move || ()>
+            {content}
+        </Transition>
+    }
+}
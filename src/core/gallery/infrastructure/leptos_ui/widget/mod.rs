@@ -0,0 +1,8 @@
+mod tap_gallery_images;
+pub use tap_gallery_images::*;
+
+mod web_content_body;
+pub use web_content_body::*;
+
+mod web_gallery;
+pub use web_gallery::*;
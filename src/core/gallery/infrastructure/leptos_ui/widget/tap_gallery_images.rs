@@ -0,0 +1,323 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+#[cfg(feature = "ssr")]
+use leptos::IntoClass;
+use leptos::IntoView;
+use leptos::RwSignal;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalGetUntracked;
+use leptos::SignalSet;
+use leptos::SignalUpdate;
+use leptos::component;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::use_query_map;
+use tukosmo_application::core::gallery::dto::DtoDeleteGalleryImage;
+use tukosmo_application::core::gallery::dto::DtoEditGalleryImage;
+use tukosmo_application::core::gallery::dto::DtoReorderGalleryImages;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GalleryImage;
+use tukosmo_domain::core::gallery::model::GalleryImageId;
+use tukosmo_domain::core::gallery::model::GalleryShortcode;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::gallery::leptos_actix_server::api::gallery_api;
+use crate::core::language::leptos_ui::TapFormI18nTextField;
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::t;
+use crate::t_error;
+use super::super::GalleryImageForm;
+
+#[component]
+pub fn TapGalleryImages(gallery: Gallery) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+    let url_query_map = use_query_map();
+
+    let images_signal = create_rw_signal(gallery.images.clone());
+    let dragged_image_id_signal =
+        create_rw_signal::<Option<GalleryImageId>>(None);
+    let waiting_response_signal = create_rw_signal(false);
+
+    // Uploads are regular form submissions, so their errors come back from
+    // the server as a query parameter
+    let server_error_signal = create_rw_signal::<Option<ServerResponseError>>(
+        url_query_map
+            .get_untracked()
+            .get("error")
+            .map(|error_code| ServerResponseError {
+                error_code: error_code.to_string(),
+                context: vec![],
+            })
+    );
+
+    let gallery_id = store_value(gallery.id.clone());
+    let shortcode = GalleryShortcode::from_gallery_id(&gallery.id);
+
+    let on_drop_image = move |target_image_id: GalleryImageId| {
+        let dragged_image_id = match dragged_image_id_signal.get_untracked() {
+            Some(dragged_image_id) => dragged_image_id,
+            None => {
+                return;
+            }
+        };
+        dragged_image_id_signal.set(None);
+        // Only one reordering request at a time
+        if
+            dragged_image_id == target_image_id ||
+            waiting_response_signal.get_untracked()
+        {
+            return;
+        }
+
+        let mut images = images_signal.get_untracked();
+        let from_index = images
+            .iter()
+            .position(|i| i.id == dragged_image_id)
+            .unwrap();
+        let to_index = images
+            .iter()
+            .position(|i| i.id == target_image_id)
+            .unwrap();
+        let dragged_image = images.remove(from_index);
+        images.insert(to_index, dragged_image);
+
+        waiting_response_signal.set(true);
+        spawn_local(async move {
+            let dto = DtoReorderGalleryImages {
+                gallery_id: gallery_id.get_value().value().to_string(),
+                image_ids: images
+                    .iter()
+                    .map(|i| i.id.value().to_string())
+                    .collect(),
+            };
+            let server_response_reorder = gallery_api::reorder_images(
+                dto
+            ).await;
+
+            manage_response(
+                server_response_reorder,
+                move |_response| {
+                    images_signal.set(images.clone());
+                    waiting_response_signal.set(false);
+                },
+                move |server_error| {
+                    server_error_signal.set(Some(server_error));
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <div class="box is-marginless mb-6">
+            <h2 class="title is-4">
+                {t!(main.images)}
+            </h2>
+
+            <p class="mb-4">
+                {t!(main.to_embed_this_gallery_add_this_shortcode_to_any_content)}
+                " "
+                <code>{shortcode}</code>
+            </p>
+
+            <form
+                action=move || navigation::path_api_upload_gallery_images(
+                    &current_language_reader.get().code,
+                    &gallery_id.get_value()
+                )
+                class="field has-addons mb-5"
+                enctype="multipart/form-data"
+                method="post"
+            >
+                <div class="control">
+                    <input
+                        accept="image/gif,image/jpeg,image/png,image/webp"
+                        class="input"
+                        multiple=true
+                        name="images"
+                        type="file"
+                    />
+                </div>
+                <div class="control">
+                    <button class="button is-link" type="submit">
+                        {t!(main.upload_images)}
+                    </button>
+                </div>
+            </form>
+
+            <Show when=move || server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <Show
+                when=move || !images_signal.get().is_empty()
+                fallback=|| view! {
+                    <p>{t!(main.this_gallery_has_no_images_yet)}</p>
+                }
+            >
+                <p class="help mb-3">
+                    {t!(main.drag_and_drop_the_images_to_reorder_them)}
+                </p>
+
+                <div class="columns is-multiline">
+                    <For
+                        children=move |image: GalleryImage| {
+                            view! {
+                                <TapGalleryImagesElement
+                                    dragged_image_id_signal=dragged_image_id_signal
+                                    gallery_id=gallery_id.get_value()
+                                    image=image
+                                    images_signal=images_signal
+                                    on_drop=Box::new(on_drop_image)
+                                    server_error_signal=server_error_signal
+                                />
+                            }
+                        }
+                        each=move || images_signal.get()
+                        key=|image| image.id.value().to_string()
+                    />
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn TapGalleryImagesElement(
+    dragged_image_id_signal: RwSignal<Option<GalleryImageId>>,
+    gallery_id: GalleryId,
+    image: GalleryImage,
+    images_signal: RwSignal<Vec<GalleryImage>>,
+    on_drop: Box<dyn Fn(GalleryImageId)>,
+    server_error_signal: RwSignal<Option<ServerResponseError>>
+) -> impl IntoView {
+    let waiting_response_signal = create_rw_signal(false);
+
+    let form = GalleryImageForm::init_filled(image.clone());
+    let gallery_id = store_value(gallery_id);
+    let image_id = store_value(image.id.clone());
+    let image_src = navigation::path_uploaded_file(image.file_name.value());
+
+    let on_click_save_caption_button = move |_| {
+        waiting_response_signal.set(true);
+
+        spawn_local(async move {
+            let dto = DtoEditGalleryImage {
+                caption: form.get_value().get_caption_value(),
+                gallery_id: gallery_id.get_value().value().to_string(),
+                image_id: image_id.get_value().value().to_string(),
+            };
+            let server_response_edit = gallery_api::edit_image(dto).await;
+
+            manage_response(
+                server_response_edit,
+                move |_response| {
+                    waiting_response_signal.set(false);
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    server_error_signal.set(Some(server_error));
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    let on_click_delete_button = move |_| {
+        waiting_response_signal.set(true);
+
+        spawn_local(async move {
+            let dto = DtoDeleteGalleryImage {
+                gallery_id: gallery_id.get_value().value().to_string(),
+                image_id: image_id.get_value().value().to_string(),
+            };
+            let server_response_delete = gallery_api::delete_image(dto).await;
+
+            manage_response(
+                server_response_delete,
+                move |_response| {
+                    images_signal.update(|draft| {
+                        draft.retain(|i| i.id != image_id.get_value());
+                    });
+                },
+                move |server_error| {
+                    server_error_signal.set(Some(server_error));
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <div
+            class="column is-one-third"
+            draggable="true"
+            on:dragover=move |event| event.prevent_default()
+            on:dragstart=move |_event| {
+                dragged_image_id_signal.set(Some(image_id.get_value()));
+            }
+            on:drop=move |event| {
+                event.prevent_default();
+                on_drop(image_id.get_value());
+            }
+        >
+            <div class="card">
+                <div class="card-image">
+                    <figure class="image">
+                        <img src=image_src />
+                    </figure>
+                </div>
+                <div class="card-content">
+                    <TapFormI18nTextField
+                        name=Box::new(|| t!(main.caption)())
+                        required=false
+                        value=form.get_value().caption
+                    />
+
+                    <div class="field is-grouped">
+                        <div class="control">
+                            <button
+                                class="button is-link is-small"
+                                class=("is-loading", waiting_response_signal)
+                                on:click=on_click_save_caption_button
+                            >
+                                {t!(main.save_caption)}
+                            </button>
+                        </div>
+                        <div class="control">
+                            <button
+                                class="button is-danger is-light is-small"
+                                on:click=on_click_delete_button
+                            >
+                                {t!(main.delete_image)}
+                            </button>
+                        </div>
+                    </div>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,76 @@
+use leptos::IntoView;
+use leptos::SignalGet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::gallery::dto::DtoGetGallery;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GalleryShortcode;
+use tukosmo_domain::core::gallery::model::GalleryShortcodeSegment;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+use crate::core::gallery::leptos_actix_server::api::gallery_api;
+use super::WebGallery;
+
+// Renders the HTML body of any content, replacing its gallery shortcodes
+// with the embedded galleries.
+#[component]
+pub fn WebContentBody(body: String) -> impl IntoView {
+    GalleryShortcode::split(&body)
+        .into_iter()
+        .map(|segment| {
+            match segment {
+                GalleryShortcodeSegment::Gallery(gallery_id) => {
+                    view! {
+                        <div>
+                            <WebGalleryEmbed gallery_id=gallery_id />
+                        </div>
+                    }
+                }
+                GalleryShortcodeSegment::Html(html) => {
+                    view! { <div inner_html=html></div> }
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+}
+
+#[component]
+fn WebGalleryEmbed(gallery_id: GalleryId) -> impl IntoView {
+    let gallery_id = gallery_id.value().to_string();
+
+    let response_data = create_resource(
+        || (),
+        move |_| {
+            let gallery_id = gallery_id.clone();
+            async move {
+                let dto = DtoGetGallery { gallery_id };
+                let result = gallery_api::get(dto).await;
+                result
+            }
+        }
+    );
+
+    // A missing gallery shouldn't break the content that embeds it
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(ServerResponse::Response(gallery)) => {
+                    view! {
+                        <div>
+                            <WebGallery gallery=gallery />
+                        </div>
+                    }
+                }
+                _ => view! { <div></div> },
+            }
+        });
+
+    view! {
+        <Transition fallback=move || ()>
+            {content}
+        </Transition>
+    }
+}
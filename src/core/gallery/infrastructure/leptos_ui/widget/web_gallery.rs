@@ -0,0 +1,132 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+#[cfg(feature = "ssr")]
+use leptos::IntoClass;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::SignalUpdate;
+use leptos::component;
+use leptos::create_rw_signal;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::gallery::model::GalleryImage;
+
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::t;
+
+#[component]
+pub fn WebGallery(gallery: Gallery) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let total_images = gallery.images.len();
+    let images = store_value(gallery.images);
+    let lightbox_index_signal = create_rw_signal::<Option<usize>>(None);
+
+    let lightbox_image = move || {
+        lightbox_index_signal
+            .get()
+            .and_then(|index| images.get_value().get(index).cloned())
+    };
+
+    let on_click_previous = move |_| {
+        lightbox_index_signal.update(|draft| {
+            if let Some(index) = draft {
+                *index = (*index + total_images - 1) % total_images;
+            }
+        });
+    };
+    let on_click_next = move |_| {
+        lightbox_index_signal.update(|draft| {
+            if let Some(index) = draft {
+                *index = (*index + 1) % total_images;
+            }
+        });
+    };
+    let on_click_close = move |_| lightbox_index_signal.set(None);
+
+    view! {
+        <div class="core-gallery-web_gallery">
+            <div class="core-gallery-web_gallery-grid">
+                <For
+                    children=move |(index, image): (usize, GalleryImage)| {
+                        let image_src = navigation::path_uploaded_file(
+                            image.file_name.value()
+                        );
+                        let caption = store_value(image.caption);
+                        view! {
+                            <figure class="core-gallery-web_gallery-item">
+                                <a on:click=move |_| lightbox_index_signal.set(Some(index))>
+                                    <img
+                                        alt=move || caption.get_value().translate(current_language_reader.get().id)
+                                        loading="lazy"
+                                        src=image_src
+                                    />
+                                </a>
+                            </figure>
+                        }
+                    }
+                    each=move || images.get_value().into_iter().enumerate()
+                    key=|(_, image)| image.id.value().to_string()
+                />
+            </div>
+
+            <div
+                class="core-gallery-web_gallery-lightbox animated-03"
+                class=("animated-hidden", move || lightbox_image().is_none())
+            >
+                <div
+                    class="core-gallery-web_gallery-lightbox-bg"
+                    on:click=on_click_close
+                ></div>
+
+                <Show when=move || lightbox_image().is_some()>
+                    <figure class="core-gallery-web_gallery-lightbox-content">
+                        <img
+                            src=move || lightbox_image()
+                                .map(|image| navigation::path_uploaded_file(
+                                    image.file_name.value()
+                                ))
+                                .unwrap_or_default()
+                        />
+                        <figcaption>
+                            {move || lightbox_image()
+                                .map(|image| image.caption.translate(
+                                    current_language_reader.get().id
+                                ))
+                                .unwrap_or_default()}
+                        </figcaption>
+                    </figure>
+                </Show>
+
+                <button
+                    class="core-gallery-web_gallery-lightbox-close"
+                    on:click=on_click_close
+                    title={t!(main.close)}
+                ></button>
+                <Show when=move || { total_images > 1 }>
+                    <button
+                        class="core-gallery-web_gallery-lightbox-previous"
+                        on:click=on_click_previous
+                        title={t!(main.previous_w_image)}
+                    >
+                        "‹"
+                    </button>
+                    <button
+                        class="core-gallery-web_gallery-lightbox-next"
+                        on:click=on_click_next
+                        title={t!(main.next_w_image)}
+                    >
+                        "›"
+                    </button>
+                </Show>
+            </div>
+        </div>
+    }
+}
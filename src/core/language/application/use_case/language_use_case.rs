@@ -6,8 +6,10 @@ use tukosmo_domain::core::language::model::LanguageCode;
 use tukosmo_domain::core::language::model::LanguageId;
 use tukosmo_domain::core::language::model::LanguageSearchCriteria;
 use tukosmo_domain::core::shared::error as error_shared;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
 use tukosmo_domain::core::shared::model::DomainError;
 use tukosmo_domain::core::shared::model::TransactionExecutor;
+use tukosmo_domain::core::shared::repository::PageCacheRepository;
 
 use crate::core::shared::service::run_transaction;
 use super::super::dto::DtoAddLanguage;
@@ -18,6 +20,7 @@ use super::super::dto::DtoGetLanguagesPaginated;
 use super::super::dto::DtoLanguagesPaginated;
 
 pub struct LanguageUseCase {
+    page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
     transaction_executor: Rc<RefCell<dyn TransactionExecutor>>,
 }
 
@@ -45,7 +48,13 @@ impl LanguageUseCase {
 
             language_repository.add(language)?;
             Ok(())
-        })
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
     }
 
     pub fn delete(&self, dto: DtoDeleteLanguage) -> Result<(), DomainError> {
@@ -61,11 +70,18 @@ impl LanguageUseCase {
             language_repository.delete(language_id)?;
 
             Ok(())
-        })
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
     }
 
     pub fn edit(&self, dto: DtoEditLanguage) -> Result<(), DomainError> {
-        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+        let transaction_executor = self.transaction_executor.borrow_mut();
+        let event = run_transaction(transaction_executor, |transaction| {
             let language_id = LanguageId::from_string(&dto.language_id)?;
 
             let mut language_repository =
@@ -83,6 +99,7 @@ impl LanguageUseCase {
             }
 
             let mut language = language_repository.get(language_id)?;
+            let previous_language = language.clone();
             language.modify(
                 dto.form.code,
                 dto.form.name,
@@ -91,10 +108,26 @@ impl LanguageUseCase {
                 dto.form.website_subtitle
             )?;
 
+            // The code and the names are in the language list of every page
+            // (and the pages of a previous code don't exist anymore)
+            let event = if
+                language.code == previous_language.code &&
+                language.name == previous_language.name &&
+                language.original_name == previous_language.original_name
+            {
+                ContentChangeEvent::LanguagePages(language.code.clone())
+            } else {
+                ContentChangeEvent::AllPages
+            };
+
             language_repository.update(language)?;
 
-            Ok(())
-        })
+            Ok(event)
+        })?;
+
+        self.page_cache_repository.borrow_mut().invalidate(event);
+
+        Ok(())
     }
 
     pub fn get(&self, dto: DtoGetLanguage) -> Result<Language, DomainError> {
@@ -145,8 +178,9 @@ impl LanguageUseCase {
     }
 
     pub fn init(
+        page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
         transaction_executor: Rc<RefCell<dyn TransactionExecutor>>
     ) -> Self {
-        Self { transaction_executor }
+        Self { page_cache_repository, transaction_executor }
     }
 }
@@ -1,4 +1,3 @@
-use diesel::pg::PgConnection;
 use std::cell::RefCell;
 use std::rc::Rc;
 use tukosmo_domain::core::language::model::Language;
@@ -8,6 +7,7 @@ use tukosmo_domain::core::language::model::LanguageSearchFilterCriteria;
 use tukosmo_domain::core::language::repository::LanguageRepository;
 use tukosmo_domain::core::shared::model::DomainError;
 
+use crate::core::shared::diesel_orm::model::DbConnection;
 use super::super::service::LanguageManager;
 
 pub struct DbLanguageRepository {
@@ -15,7 +15,7 @@ pub struct DbLanguageRepository {
 }
 
 impl DbLanguageRepository {
-    pub fn init(connection: Rc<RefCell<PgConnection>>) -> Self {
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
         Self { language: LanguageManager::init(connection) }
     }
 }
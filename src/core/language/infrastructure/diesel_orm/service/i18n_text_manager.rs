@@ -1,4 +1,3 @@
-use diesel::pg::PgConnection;
 use std::cell::RefCell;
 use std::rc::Rc;
 use tukosmo_domain::core::language::error;
@@ -9,6 +8,7 @@ use tukosmo_domain::core::language::model::I18nTranslationId;
 use tukosmo_domain::core::language::model::I18nTranslationSearchCriteria;
 use tukosmo_domain::core::shared::model::DomainError;
 
+use crate::core::shared::diesel_orm::model::DbConnection;
 use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
 use super::super::model::DbI18nText;
 use super::super::model::DbI18nTranslation;
@@ -102,7 +102,7 @@ impl I18nTextManager {
         Ok(i18n_texts)
     }
 
-    pub fn init(connection: Rc<RefCell<PgConnection>>) -> Self {
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
         Self {
             i18n_text: I18nTextSqlExecutor::init(Rc::clone(&connection)),
             i18n_translation: I18nTranslationSqlExecutor::init(connection),
@@ -1,4 +1,3 @@
-use diesel::pg::PgConnection;
 use std::cell::RefCell;
 use std::rc::Rc;
 use tukosmo_domain::core::language::error;
@@ -11,6 +10,7 @@ use tukosmo_domain::core::language::model::LanguageSearchCriteria;
 use tukosmo_domain::core::language::model::LanguageSearchFilterCriteria;
 use tukosmo_domain::core::shared::model::DomainError;
 
+use crate::core::shared::diesel_orm::model::DbConnection;
 use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
 use super::I18nTextManager;
 use super::super::model::DbI18nTranslation;
@@ -130,7 +130,7 @@ impl LanguageManager {
         Ok(language)
     }
 
-    pub fn init(connection: Rc<RefCell<PgConnection>>) -> Self {
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
         Self {
             i18n_text_manager: I18nTextManager::init(Rc::clone(&connection)),
             i18n_translation: I18nTranslationSqlExecutor::init(
@@ -16,12 +16,13 @@ use tukosmo_domain::core::shared::error;
 use tukosmo_domain::core::shared::model::DomainError;
 use uuid::Uuid;
 
+use crate::core::shared::diesel_orm::model::DbConnection;
 use crate::core::shared::diesel_orm::schema::i18n_text;
 use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
 use super::super::model::DbI18nText;
 
 pub struct I18nTextSqlExecutor {
-    connection: Rc<RefCell<PgConnection>>,
+    connection: Rc<RefCell<DbConnection>>,
 }
 
 impl ModelSqlExecutor<
@@ -42,7 +43,7 @@ for I18nTextSqlExecutor {
         filter_criteria: I18nTextSearchFilterCriteria
     ) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let mut statement = diesel::delete(i18n_text::table).into_boxed();
 
@@ -70,7 +71,7 @@ for I18nTextSqlExecutor {
         }
     }
 
-    fn get_connection(&mut self) -> Rc<RefCell<PgConnection>> {
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
         Rc::clone(&self.connection)
     }
 
@@ -78,7 +79,7 @@ for I18nTextSqlExecutor {
         i18n_text::table
     }
 
-    fn init(connection: Rc<RefCell<PgConnection>>) -> Self {
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
         Self { connection }
     }
 
@@ -87,7 +88,7 @@ for I18nTextSqlExecutor {
         search_criteria: I18nTextSearchCriteria
     ) -> Result<Vec<DbI18nText>, DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let query = Self::select_query(search_criteria.filter);
         let results = query
@@ -134,7 +135,7 @@ for I18nTextSqlExecutor {
 
     fn update(&mut self, db_i18n_text: &DbI18nText) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let result = diesel
             ::update(i18n_text::table.find(db_i18n_text.id))
@@ -154,7 +155,7 @@ for I18nTextSqlExecutor {
         db_i18n_texts: Vec<DbI18nText>
     ) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let result = diesel
             ::insert_into(i18n_text::table)
@@ -16,12 +16,13 @@ use tukosmo_domain::core::shared::error;
 use tukosmo_domain::core::shared::model::DomainError;
 use uuid::Uuid;
 
+use crate::core::shared::diesel_orm::model::DbConnection;
 use crate::core::shared::diesel_orm::schema::i18n_translation;
 use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
 use super::super::model::DbI18nTranslation;
 
 pub struct I18nTranslationSqlExecutor {
-    connection: Rc<RefCell<PgConnection>>,
+    connection: Rc<RefCell<DbConnection>>,
 }
 
 impl ModelSqlExecutor<
@@ -44,7 +45,7 @@ for I18nTranslationSqlExecutor {
         filter_criteria: I18nTranslationSearchFilterCriteria
     ) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let mut statement = diesel
             ::delete(i18n_translation::table)
@@ -99,7 +100,7 @@ for I18nTranslationSqlExecutor {
         }
     }
 
-    fn get_connection(&mut self) -> Rc<RefCell<PgConnection>> {
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
         Rc::clone(&self.connection)
     }
 
@@ -107,7 +108,7 @@ for I18nTranslationSqlExecutor {
         i18n_translation::table
     }
 
-    fn init(connection: Rc<RefCell<PgConnection>>) -> Self {
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
         Self { connection }
     }
 
@@ -116,7 +117,7 @@ for I18nTranslationSqlExecutor {
         search_criteria: I18nTranslationSearchCriteria
     ) -> Result<Vec<DbI18nTranslation>, DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let query = Self::select_query(search_criteria.filter);
         let results = query
@@ -193,7 +194,7 @@ for I18nTranslationSqlExecutor {
         db_i18n_translation: &DbI18nTranslation
     ) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let result = diesel
             ::update(i18n_translation::table.find(db_i18n_translation.id))
@@ -213,7 +214,7 @@ for I18nTranslationSqlExecutor {
         db_i18n_translations: Vec<DbI18nTranslation>
     ) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         use diesel::query_dsl::methods::FilterDsl;
         let result = diesel
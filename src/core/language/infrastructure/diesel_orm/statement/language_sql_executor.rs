@@ -16,12 +16,13 @@ use tukosmo_domain::core::language::model::LanguageSearchFilterCriteria;
 use tukosmo_domain::core::shared::error;
 use tukosmo_domain::core::shared::model::DomainError;
 
+use crate::core::shared::diesel_orm::model::DbConnection;
 use crate::core::shared::diesel_orm::schema::language;
 use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
 use super::super::model::DbLanguage;
 
 pub struct LanguageSqlExecutor {
-    connection: Rc<RefCell<PgConnection>>,
+    connection: Rc<RefCell<DbConnection>>,
 }
 
 impl ModelSqlExecutor<
@@ -46,7 +47,7 @@ for LanguageSqlExecutor {
         filter_criteria: LanguageSearchFilterCriteria
     ) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let mut statement = diesel::delete(language::table).into_boxed();
 
@@ -74,7 +75,7 @@ for LanguageSqlExecutor {
         }
     }
 
-    fn get_connection(&mut self) -> Rc<RefCell<PgConnection>> {
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
         Rc::clone(&self.connection)
     }
 
@@ -82,7 +83,7 @@ for LanguageSqlExecutor {
         language::table
     }
 
-    fn init(connection: Rc<RefCell<PgConnection>>) -> Self {
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
         Self { connection }
     }
 
@@ -91,7 +92,7 @@ for LanguageSqlExecutor {
         search_criteria: LanguageSearchCriteria
     ) -> Result<Vec<DbLanguage>, DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let mut query = Self::select_query(search_criteria.filter);
         if let Some(order_by) = search_criteria.order_by {
@@ -158,7 +159,7 @@ for LanguageSqlExecutor {
 
     fn update(&mut self, db_language: &DbLanguage) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let result = diesel
             ::update(language::table.find(db_language.id))
@@ -178,7 +179,7 @@ for LanguageSqlExecutor {
         db_languages: Vec<DbLanguage>
     ) -> Result<(), DomainError> {
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let result = diesel
             ::insert_into(language::table)
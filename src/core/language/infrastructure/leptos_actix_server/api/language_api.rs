@@ -92,9 +92,17 @@ fn common() -> Result<LanguageUseCase, DomainError> {
     use std::rc::Rc;
 
     use crate::core::shared::diesel_orm::model::DbTransactionExecutor;
+    use crate::core::shared::leptos_actix_server::repository::MemoryPageCacheRepository;
+    use crate::core::shared::leptos_actix_server::service::database;
+    use crate::core::shared::leptos_actix_server::service::page_cache;
 
-    let transaction_executor = DbTransactionExecutor::init()?;
+    let db_pool = database::get_db_pool()?;
+    let page_cache_repository = MemoryPageCacheRepository::init(
+        page_cache::get_page_cache()
+    );
+    let transaction_executor = DbTransactionExecutor::init(&db_pool)?;
     let language_use_case = LanguageUseCase::init(
+        Rc::new(RefCell::new(page_cache_repository)),
         Rc::new(RefCell::new(transaction_executor))
     );
 
@@ -0,0 +1,86 @@
+use serde::Deserialize;
+use serde::Serialize;
+use tukosmo_domain::core::language::model::I18nTextValue;
+use tukosmo_domain::core::menu::model::Menu;
+
+use crate::core::shared::dto::DtoDeleteForm;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoAddMenu {
+    pub form: DtoMenuForm,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoAddMenuItem {
+    pub form: DtoMenuItemForm,
+    pub menu_id: String,
+    pub parent_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoDeleteMenu {
+    pub form: DtoDeleteForm,
+    pub menu_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoDeleteMenuItem {
+    pub item_id: String,
+    pub menu_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoEditMenu {
+    pub form: DtoMenuForm,
+    pub menu_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoEditMenuItem {
+    pub form: DtoMenuItemForm,
+    pub item_id: String,
+    pub menu_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetMenu {
+    pub menu_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetMenuByLocation {
+    pub location: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetMenusPaginated {
+    pub current_page: i64,
+    pub results_per_page: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoMenuForm {
+    // An empty location means the menu isn't shown anywhere
+    pub location: String,
+    pub name: I18nTextValue,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoMenuItemForm {
+    pub label: I18nTextValue,
+    pub link_type: String,
+    pub link_value: String,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DtoMenusPaginated {
+    pub menus: Vec<Menu>,
+    pub total_results: i64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoReorderMenuItems {
+    pub item_ids: Vec<String>,
+    pub menu_id: String,
+    pub parent_id: Option<String>,
+}
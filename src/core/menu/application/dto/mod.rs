@@ -0,0 +1,2 @@
+mod dto_menu;
+pub use dto_menu::*;
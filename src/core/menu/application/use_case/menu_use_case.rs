@@ -0,0 +1,298 @@
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use tukosmo_domain::core::menu::error;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuItemId;
+use tukosmo_domain::core::menu::model::MenuItemLink;
+use tukosmo_domain::core::menu::model::MenuLocation;
+use tukosmo_domain::core::menu::model::MenuSearchCriteria;
+use tukosmo_domain::core::menu::repository::MenuRepository;
+use tukosmo_domain::core::shared::error as error_shared;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::TransactionExecutor;
+use tukosmo_domain::core::shared::repository::PageCacheRepository;
+
+use crate::core::shared::service::run_transaction;
+use super::super::dto::DtoAddMenu;
+use super::super::dto::DtoAddMenuItem;
+use super::super::dto::DtoDeleteMenu;
+use super::super::dto::DtoDeleteMenuItem;
+use super::super::dto::DtoEditMenu;
+use super::super::dto::DtoEditMenuItem;
+use super::super::dto::DtoGetMenu;
+use super::super::dto::DtoGetMenuByLocation;
+use super::super::dto::DtoGetMenusPaginated;
+use super::super::dto::DtoMenusPaginated;
+use super::super::dto::DtoReorderMenuItems;
+
+pub struct MenuUseCase {
+    page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+    transaction_executor: Rc<RefCell<dyn TransactionExecutor>>,
+}
+
+impl MenuUseCase {
+    pub fn add(&self, dto: DtoAddMenu) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let location = Self::parse_location(&dto.form.location)?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            let menu = Menu::new(dto.form.name, location)?;
+            Self::check_location_is_free(menu_repository.deref_mut(), &menu)?;
+
+            menu_repository.add(menu)?;
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn add_item(&self, dto: DtoAddMenuItem) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let menu_id = MenuId::from_string(&dto.menu_id)?;
+            let parent_id = Self::parse_parent_id(&dto.parent_id)?;
+            let link = MenuItemLink::new(
+                &dto.form.link_type,
+                dto.form.link_value
+            )?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            let mut menu = menu_repository.get(menu_id)?;
+            menu.add_item(parent_id.as_ref(), dto.form.label, link)?;
+
+            menu_repository.update(menu)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    fn check_location_is_free(
+        menu_repository: &mut dyn MenuRepository,
+        menu: &Menu
+    ) -> Result<(), DomainError> {
+        if let Some(location) = &menu.location {
+            let menus_in_location = menu_repository.count(
+                MenuSearchCriteria::has_location_and_not_id(
+                    location.clone(),
+                    menu.id.clone()
+                ).filter
+            )?;
+            if menus_in_location > 0 {
+                return Err(error::MENU_LOCATION_IS_ALREADY_IN_USE);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn delete(&self, dto: DtoDeleteMenu) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let menu_id = MenuId::from_string(&dto.menu_id)?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            if !dto.form.requested {
+                return Err(error_shared::FIELD_CANNOT_BE_EMPTY);
+            }
+            menu_repository.delete(menu_id)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn delete_item(
+        &self,
+        dto: DtoDeleteMenuItem
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let menu_id = MenuId::from_string(&dto.menu_id)?;
+            let item_id = MenuItemId::from_string(&dto.item_id)?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            let mut menu = menu_repository.get(menu_id)?;
+            menu.remove_item(&item_id)?;
+
+            menu_repository.update(menu)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn edit(&self, dto: DtoEditMenu) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let menu_id = MenuId::from_string(&dto.menu_id)?;
+            let location = Self::parse_location(&dto.form.location)?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            let mut menu = menu_repository.get(menu_id)?;
+            menu.modify(dto.form.name, location)?;
+            Self::check_location_is_free(menu_repository.deref_mut(), &menu)?;
+
+            menu_repository.update(menu)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn edit_item(&self, dto: DtoEditMenuItem) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let menu_id = MenuId::from_string(&dto.menu_id)?;
+            let item_id = MenuItemId::from_string(&dto.item_id)?;
+            let link = MenuItemLink::new(
+                &dto.form.link_type,
+                dto.form.link_value
+            )?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            let mut menu = menu_repository.get(menu_id)?;
+            menu.modify_item(&item_id, dto.form.label, link)?;
+
+            menu_repository.update(menu)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn get(&self, dto: DtoGetMenu) -> Result<Menu, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let menu_id = MenuId::from_string(&dto.menu_id)?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+            let menu = menu_repository.get(menu_id)?;
+
+            Ok(menu)
+        })
+    }
+
+    pub fn get_menu_by_location(
+        &self,
+        dto: DtoGetMenuByLocation
+    ) -> Result<Option<Menu>, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let location = MenuLocation::from_code(&dto.location)?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+            let menus = menu_repository.find(
+                MenuSearchCriteria::has_location(location)
+            )?;
+
+            Ok(menus.into_iter().next())
+        })
+    }
+
+    pub fn get_menus_paginated(
+        &self,
+        dto: DtoGetMenusPaginated
+    ) -> Result<DtoMenusPaginated, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            let menus = menu_repository.find(
+                MenuSearchCriteria::paginated(
+                    dto.current_page,
+                    dto.results_per_page
+                )
+            )?;
+            let total_results = menu_repository.count(
+                MenuSearchCriteria::all().filter
+            )?;
+
+            Ok(DtoMenusPaginated { menus, total_results })
+        })
+    }
+
+    pub fn init(
+        page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+        transaction_executor: Rc<RefCell<dyn TransactionExecutor>>
+    ) -> Self {
+        Self { page_cache_repository, transaction_executor }
+    }
+
+    fn parse_location(
+        location: &str
+    ) -> Result<Option<MenuLocation>, DomainError> {
+        if location.is_empty() {
+            return Ok(None);
+        }
+        let location = MenuLocation::from_code(location)?;
+        Ok(Some(location))
+    }
+
+    fn parse_parent_id(
+        parent_id: &Option<String>
+    ) -> Result<Option<MenuItemId>, DomainError> {
+        match parent_id {
+            Some(parent_id) => Ok(Some(MenuItemId::from_string(parent_id)?)),
+            None => Ok(None),
+        }
+    }
+
+    pub fn reorder_items(
+        &self,
+        dto: DtoReorderMenuItems
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let menu_id = MenuId::from_string(&dto.menu_id)?;
+            let parent_id = Self::parse_parent_id(&dto.parent_id)?;
+            let item_ids = dto.item_ids
+                .iter()
+                .map(|item_id| MenuItemId::from_string(item_id))
+                .collect::<Result<Vec<MenuItemId>, DomainError>>()?;
+
+            let mut menu_repository = transaction.menu_repository.borrow_mut();
+
+            let mut menu = menu_repository.get(menu_id)?;
+            menu.reorder_items(parent_id.as_ref(), item_ids)?;
+
+            menu_repository.update(menu)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,4 @@
+#[cfg(feature = "ssr")]
+mod menu_use_case;
+#[cfg(feature = "ssr")]
+pub use menu_use_case::*;
@@ -0,0 +1,96 @@
+use crate::core::shared::model::CoreSubmoduleName::Menu;
+use crate::core::shared::model::DomainError;
+use crate::core::shared::model::DomainErrorId;
+use crate::core::shared::model::DomainErrorVisibility;
+use crate::core::shared::model::ModuleName::Core;
+use super::model::MenuItemId;
+
+pub const INVALID_MENU_ITEM_EXTERNAL_URL: DomainError = get_domain_error(
+    "INVALID_MENU_ITEM_EXTERNAL_URL",
+    "The external URL must start with http:// or https://.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_MENU_ITEM_LINK_TYPE: DomainError = get_domain_error(
+    "INVALID_MENU_ITEM_LINK_TYPE",
+    "Invalid menu item link type.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_MENU_ITEM_ROUTE: DomainError = get_domain_error(
+    "INVALID_MENU_ITEM_ROUTE",
+    "The route must start with / and cannot contain spaces.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_MENU_LOCATION: DomainError = get_domain_error(
+    "INVALID_MENU_LOCATION",
+    "Invalid menu location.",
+    DomainErrorVisibility::Public
+);
+
+pub const MENU_ITEMS_DONT_MATCH: DomainError = get_domain_error(
+    "MENU_ITEMS_DONT_MATCH",
+    "The given items don't match the items of the menu.",
+    DomainErrorVisibility::Public
+);
+
+pub const MENU_ITEM_MAX_DEPTH_EXCEEDED: DomainError = get_domain_error(
+    "MENU_ITEM_MAX_DEPTH_EXCEEDED",
+    "Menu items cannot be nested any deeper.",
+    DomainErrorVisibility::Public
+);
+
+pub const MENU_LOCATION_IS_ALREADY_IN_USE: DomainError = get_domain_error(
+    "MENU_LOCATION_IS_ALREADY_IN_USE",
+    "Another menu is already shown in this location.",
+    DomainErrorVisibility::Public
+);
+
+pub const MENU_NOT_FOUND: DomainError = get_domain_error(
+    "MENU_NOT_FOUND",
+    "Menu not found.",
+    DomainErrorVisibility::Public
+);
+
+const fn get_domain_error(
+    error_code: &'static str,
+    message: &'static str,
+    visibility: DomainErrorVisibility
+) -> DomainError {
+    DomainError {
+        context: vec![],
+        id: DomainErrorId {
+            error_code,
+            module: Core(Menu),
+        },
+        message,
+        visibility,
+    }
+}
+
+fn get_domain_error_with_context(
+    error_code: &'static str,
+    message: &'static str,
+    visibility: DomainErrorVisibility,
+    context: Vec<(String, String)>
+) -> DomainError {
+    DomainError {
+        context,
+        id: DomainErrorId {
+            error_code,
+            module: Core(Menu),
+        },
+        message,
+        visibility,
+    }
+}
+
+pub fn menu_item_not_found(id: &MenuItemId) -> DomainError {
+    get_domain_error_with_context(
+        "MENU_ITEM_NOT_FOUND",
+        "Menu item not found.",
+        DomainErrorVisibility::Public,
+        vec![("id".to_string(), id.value().to_string())]
+    )
+}
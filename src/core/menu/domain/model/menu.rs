@@ -0,0 +1,390 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::core::language::model::I18nText;
+use crate::core::language::model::I18nTextValue;
+use crate::core::shared::error;
+use crate::core::shared::model::DomainError;
+use crate::core::shared::model::PaginationCriteria;
+use super::super::error as error_menu;
+use super::MenuItem;
+use super::MenuItemId;
+use super::MenuItemLink;
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct Menu {
+    pub creation_date: MenuCreationDate,
+    pub id: MenuId,
+    pub items: Vec<MenuItem>,
+    pub location: Option<MenuLocation>,
+    pub name: I18nText,
+    pub update_date: MenuUpdateDate,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct MenuCreationDate(DateTime<Utc>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MenuId(Uuid);
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum MenuLocation {
+    Footer,
+    Header,
+}
+
+#[derive(Clone)]
+pub struct MenuSearchCriteria {
+    pub filter: MenuSearchFilterCriteria,
+    pub order_by: Option<MenuSearchCriteriaOrderBy>,
+    pub pagination: Option<PaginationCriteria>,
+}
+
+#[derive(Clone)]
+pub enum MenuSearchCriteriaOrderBy {
+    CreationDate,
+}
+
+#[derive(Clone)]
+pub struct MenuSearchFilterCriteria {
+    pub id: Option<MenuId>,
+    pub location: Option<MenuLocation>,
+    pub not_id: Option<MenuId>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct MenuUpdateDate(DateTime<Utc>);
+
+// Deeper levels don't fit in a navigation bar's dropdowns
+pub const MENU_ITEM_MAX_DEPTH: usize = 3;
+
+impl Menu {
+    pub fn add_item(
+        &mut self,
+        parent_id: Option<&MenuItemId>,
+        label_value: I18nTextValue,
+        link: MenuItemLink
+    ) -> Result<(), DomainError> {
+        if let Some(parent_id) = parent_id {
+            let parent_depth = Self::find_item_depth(&self.items, parent_id, 1)
+                .ok_or(error_menu::menu_item_not_found(parent_id))?;
+            if parent_depth >= MENU_ITEM_MAX_DEPTH {
+                return Err(error_menu::MENU_ITEM_MAX_DEPTH_EXCEEDED);
+            }
+        }
+
+        let siblings = self.get_siblings_mut(parent_id)?;
+        let position = siblings.len() as i32;
+        let item = MenuItem::new(label_value, link, position)?;
+        siblings.push(item);
+        self.update_date = MenuUpdateDate::new();
+        Ok(())
+    }
+
+    fn find_item_depth(
+        items: &Vec<MenuItem>,
+        item_id: &MenuItemId,
+        depth: usize
+    ) -> Option<usize> {
+        items.iter().find_map(|item| {
+            if &item.id == item_id {
+                Some(depth)
+            } else {
+                Self::find_item_depth(&item.children, item_id, depth + 1)
+            }
+        })
+    }
+
+    fn find_item_mut<'a>(
+        items: &'a mut Vec<MenuItem>,
+        item_id: &MenuItemId
+    ) -> Option<&'a mut MenuItem> {
+        for item in items.iter_mut() {
+            if &item.id == item_id {
+                return Some(item);
+            }
+            if let Some(found_item) = Self::find_item_mut(
+                &mut item.children,
+                item_id
+            ) {
+                return Some(found_item);
+            }
+        }
+        None
+    }
+
+    fn find_parent_id(
+        items: &Vec<MenuItem>,
+        item_id: &MenuItemId,
+        parent_id: Option<&MenuItemId>
+    ) -> Option<Option<MenuItemId>> {
+        items.iter().find_map(|item| {
+            if &item.id == item_id {
+                Some(parent_id.cloned())
+            } else {
+                Self::find_parent_id(&item.children, item_id, Some(&item.id))
+            }
+        })
+    }
+
+    fn get_siblings_mut(
+        &mut self,
+        parent_id: Option<&MenuItemId>
+    ) -> Result<&mut Vec<MenuItem>, DomainError> {
+        match parent_id {
+            Some(parent_id) => {
+                let parent = Self::find_item_mut(&mut self.items, parent_id)
+                    .ok_or(error_menu::menu_item_not_found(parent_id))?;
+                Ok(&mut parent.children)
+            }
+            None => Ok(&mut self.items),
+        }
+    }
+
+    pub fn modify(
+        &mut self,
+        name_value: I18nTextValue,
+        location: Option<MenuLocation>
+    ) -> Result<(), DomainError> {
+        self.name.modify(
+            name_value,
+            Self::validate_name_default_value,
+            Self::validate_name_translation_value
+        )?;
+        self.location = location;
+        self.update_date = MenuUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn modify_item(
+        &mut self,
+        item_id: &MenuItemId,
+        label_value: I18nTextValue,
+        link: MenuItemLink
+    ) -> Result<(), DomainError> {
+        let item = Self::find_item_mut(&mut self.items, item_id).ok_or(
+            error_menu::menu_item_not_found(item_id)
+        )?;
+        item.modify(label_value, link)?;
+        self.update_date = MenuUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn new(
+        name_value: I18nTextValue,
+        location: Option<MenuLocation>
+    ) -> Result<Self, DomainError> {
+        let id = MenuId::new();
+        let name = I18nText::new(
+            name_value,
+            Self::validate_name_default_value,
+            Self::validate_name_translation_value
+        )?;
+        let creation_date = MenuCreationDate::new();
+        let update_date = MenuUpdateDate::new();
+
+        Ok(Menu {
+            creation_date,
+            id,
+            items: vec![],
+            location,
+            name,
+            update_date,
+        })
+    }
+
+    pub fn remove_item(
+        &mut self,
+        item_id: &MenuItemId
+    ) -> Result<MenuItem, DomainError> {
+        let parent_id = Self::find_parent_id(&self.items, item_id, None).ok_or(
+            error_menu::menu_item_not_found(item_id)
+        )?;
+        let siblings = self.get_siblings_mut(parent_id.as_ref())?;
+        let index = siblings
+            .iter()
+            .position(|i| &i.id == item_id)
+            .ok_or(error_menu::menu_item_not_found(item_id))?;
+        // Nested items go away with their parent
+        let removed_item = siblings.remove(index);
+        Self::renumber_items(siblings);
+        self.update_date = MenuUpdateDate::new();
+        Ok(removed_item)
+    }
+
+    fn renumber_items(items: &mut Vec<MenuItem>) {
+        for (position, item) in items.iter_mut().enumerate() {
+            item.move_to(position as i32);
+        }
+    }
+
+    // Only siblings (items with the same parent) can be reordered together
+    pub fn reorder_items(
+        &mut self,
+        parent_id: Option<&MenuItemId>,
+        item_ids: Vec<MenuItemId>
+    ) -> Result<(), DomainError> {
+        let siblings = self.get_siblings_mut(parent_id)?;
+
+        let same_length = item_ids.len() == siblings.len();
+        let same_items = item_ids
+            .iter()
+            .all(|item_id| siblings.iter().any(|i| &i.id == item_id));
+        if !same_length || !same_items {
+            return Err(error_menu::MENU_ITEMS_DONT_MATCH);
+        }
+
+        let mut reordered_items: Vec<MenuItem> = vec![];
+        for item_id in item_ids {
+            let index = siblings
+                .iter()
+                .position(|i| i.id == item_id)
+                .ok_or(error_menu::MENU_ITEMS_DONT_MATCH)?;
+            reordered_items.push(siblings.remove(index));
+        }
+        *siblings = reordered_items;
+        Self::renumber_items(siblings);
+        self.update_date = MenuUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn validate_name_default_value(value: &String) -> Option<DomainError> {
+        if 0 == value.len() {
+            return Some(error::FIELD_CANNOT_BE_EMPTY);
+        }
+        Self::validate_name_value(value)
+    }
+
+    pub fn validate_name_translation_value(
+        value: &String
+    ) -> Option<DomainError> {
+        Self::validate_name_value(value)
+    }
+
+    pub fn validate_name_value(_value: &String) -> Option<DomainError> {
+        None
+    }
+}
+
+impl MenuCreationDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl MenuId {
+    pub fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+
+    pub fn from_string(value: &str) -> Result<Self, DomainError> {
+        match Uuid::parse_str(value) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(error::INVALID_UUID),
+        }
+    }
+
+    fn new() -> Self {
+        let value = Uuid::new_v4();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl MenuLocation {
+    pub fn from_code(code: &str) -> Result<Self, DomainError> {
+        match code {
+            "footer" => Ok(Self::Footer),
+            "header" => Ok(Self::Header),
+            _ => Err(error_menu::INVALID_MENU_LOCATION),
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Footer => "footer",
+            Self::Header => "header",
+        }
+    }
+}
+
+impl MenuSearchCriteria {
+    pub fn all() -> Self {
+        let criteria = Self::default();
+        criteria
+    }
+
+    fn default() -> Self {
+        Self {
+            filter: MenuSearchFilterCriteria {
+                id: None,
+                location: None,
+                not_id: None,
+            },
+            order_by: None,
+            pagination: None,
+        }
+    }
+
+    pub fn has_id(menu_id: MenuId) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.id = Some(menu_id);
+        criteria
+    }
+
+    pub fn has_location(location: MenuLocation) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.location = Some(location);
+        criteria
+    }
+
+    pub fn has_location_and_not_id(
+        location: MenuLocation,
+        menu_id: MenuId
+    ) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.location = Some(location);
+        criteria.filter.not_id = Some(menu_id);
+        criteria
+    }
+
+    pub fn paginated(page: i64, results_per_page: i64) -> Self {
+        let mut criteria = Self::default();
+        criteria.pagination = Some(PaginationCriteria {
+            page,
+            results_per_page,
+        });
+        criteria.order_by = Some(MenuSearchCriteriaOrderBy::CreationDate);
+        criteria
+    }
+}
+
+impl MenuUpdateDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
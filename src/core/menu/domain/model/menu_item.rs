@@ -0,0 +1,307 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::core::gallery::model::GalleryId;
+use crate::core::language::model::I18nText;
+use crate::core::language::model::I18nTextValue;
+use crate::core::shared::error;
+use crate::core::shared::model::DomainError;
+use super::super::error as error_menu;
+use super::MenuId;
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct MenuItem {
+    pub children: Vec<MenuItem>,
+    pub creation_date: MenuItemCreationDate,
+    pub id: MenuItemId,
+    pub label: I18nText,
+    pub link: MenuItemLink,
+    pub position: MenuItemPosition,
+    pub update_date: MenuItemUpdateDate,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct MenuItemCreationDate(DateTime<Utc>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MenuItemId(Uuid);
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub enum MenuItemLink {
+    ExternalUrl(String),
+    Gallery(GalleryId),
+    Route(String),
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct MenuItemPosition(i32);
+
+#[derive(Clone)]
+pub struct MenuItemSearchCriteria {
+    pub filter: MenuItemSearchFilterCriteria,
+}
+
+#[derive(Clone)]
+pub struct MenuItemSearchFilterCriteria {
+    pub id_not_in: Option<Vec<MenuItemId>>,
+    pub menu_id: Option<MenuId>,
+    pub menu_id_in: Option<Vec<MenuId>>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct MenuItemUpdateDate(DateTime<Utc>);
+
+pub const MENU_ITEM_LINK_TYPE_EXTERNAL_URL: &'static str = "external_url";
+pub const MENU_ITEM_LINK_TYPE_GALLERY: &'static str = "gallery";
+pub const MENU_ITEM_LINK_TYPE_ROUTE: &'static str = "route";
+
+impl MenuItem {
+    pub fn modify(
+        &mut self,
+        label_value: I18nTextValue,
+        link: MenuItemLink
+    ) -> Result<(), DomainError> {
+        self.label.modify(
+            label_value,
+            Self::validate_label_default_value,
+            Self::validate_label_translation_value
+        )?;
+        self.link = link;
+        self.update_date = MenuItemUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn move_to(&mut self, position_value: i32) {
+        if self.position.value() != &position_value {
+            self.position = MenuItemPosition::new(position_value);
+            self.update_date = MenuItemUpdateDate::new();
+        }
+    }
+
+    pub fn new(
+        label_value: I18nTextValue,
+        link: MenuItemLink,
+        position_value: i32
+    ) -> Result<Self, DomainError> {
+        let id = MenuItemId::new();
+        let label = I18nText::new(
+            label_value,
+            Self::validate_label_default_value,
+            Self::validate_label_translation_value
+        )?;
+        let position = MenuItemPosition::new(position_value);
+        let creation_date = MenuItemCreationDate::new();
+        let update_date = MenuItemUpdateDate::new();
+
+        Ok(MenuItem {
+            children: vec![],
+            creation_date,
+            id,
+            label,
+            link,
+            position,
+            update_date,
+        })
+    }
+
+    pub fn validate_label_default_value(value: &String) -> Option<DomainError> {
+        if 0 == value.len() {
+            return Some(error::FIELD_CANNOT_BE_EMPTY);
+        }
+        Self::validate_label_value(value)
+    }
+
+    pub fn validate_label_translation_value(
+        value: &String
+    ) -> Option<DomainError> {
+        Self::validate_label_value(value)
+    }
+
+    pub fn validate_label_value(_value: &String) -> Option<DomainError> {
+        None
+    }
+}
+
+impl MenuItemCreationDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl MenuItemId {
+    pub fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+
+    pub fn from_string(value: &str) -> Result<Self, DomainError> {
+        match Uuid::parse_str(value) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(error::INVALID_UUID),
+        }
+    }
+
+    fn new() -> Self {
+        let value = Uuid::new_v4();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl MenuItemLink {
+    pub fn from(link_type: &str, link_value: &str) -> Self {
+        match link_type {
+            MENU_ITEM_LINK_TYPE_EXTERNAL_URL =>
+                Self::ExternalUrl(link_value.to_string()),
+            MENU_ITEM_LINK_TYPE_GALLERY =>
+                Self::Gallery(
+                    GalleryId::from(
+                        Uuid::parse_str(link_value).unwrap_or_default()
+                    )
+                ),
+            _ => Self::Route(link_value.to_string()),
+        }
+    }
+
+    pub fn link_type(&self) -> &'static str {
+        match self {
+            Self::ExternalUrl(_) => MENU_ITEM_LINK_TYPE_EXTERNAL_URL,
+            Self::Gallery(_) => MENU_ITEM_LINK_TYPE_GALLERY,
+            Self::Route(_) => MENU_ITEM_LINK_TYPE_ROUTE,
+        }
+    }
+
+    pub fn link_value(&self) -> String {
+        match self {
+            Self::ExternalUrl(url) => url.clone(),
+            Self::Gallery(gallery_id) => gallery_id.value().to_string(),
+            Self::Route(route) => route.clone(),
+        }
+    }
+
+    pub fn new(link_type: &str, link_value: String) -> Result<Self, DomainError> {
+        match link_type {
+            MENU_ITEM_LINK_TYPE_EXTERNAL_URL => {
+                match Self::validate_external_url(&link_value) {
+                    Some(validation_error) => Err(validation_error),
+                    None => Ok(Self::ExternalUrl(link_value)),
+                }
+            }
+            MENU_ITEM_LINK_TYPE_GALLERY => {
+                let gallery_id = GalleryId::from_string(&link_value)?;
+                Ok(Self::Gallery(gallery_id))
+            }
+            MENU_ITEM_LINK_TYPE_ROUTE => {
+                match Self::validate_route(&link_value) {
+                    Some(validation_error) => Err(validation_error),
+                    None => Ok(Self::Route(link_value)),
+                }
+            }
+            _ => Err(error_menu::INVALID_MENU_ITEM_LINK_TYPE),
+        }
+    }
+
+    pub fn validate_external_url(value: &String) -> Option<DomainError> {
+        if 0 == value.len() {
+            return Some(error::FIELD_CANNOT_BE_EMPTY);
+        }
+        let has_valid_scheme =
+            value.starts_with("http://") || value.starts_with("https://");
+        if !has_valid_scheme || value.contains(char::is_whitespace) {
+            return Some(error_menu::INVALID_MENU_ITEM_EXTERNAL_URL);
+        }
+        None
+    }
+
+    pub fn validate_route(value: &String) -> Option<DomainError> {
+        if 0 == value.len() {
+            return Some(error::FIELD_CANNOT_BE_EMPTY);
+        }
+        // Routes are relative to the current language (e.g. "/login")
+        if
+            !value.starts_with('/') ||
+            value.starts_with("//") ||
+            value.contains(char::is_whitespace)
+        {
+            return Some(error_menu::INVALID_MENU_ITEM_ROUTE);
+        }
+        None
+    }
+}
+
+impl MenuItemPosition {
+    pub fn from(value: i32) -> Self {
+        Self(value)
+    }
+
+    fn new(value: i32) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> &i32 {
+        &self.0
+    }
+}
+
+impl MenuItemSearchCriteria {
+    fn default() -> Self {
+        Self {
+            filter: MenuItemSearchFilterCriteria {
+                id_not_in: None,
+                menu_id: None,
+                menu_id_in: None,
+            },
+        }
+    }
+
+    pub fn has_menu_id(menu_id: MenuId) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.menu_id = Some(menu_id);
+        criteria
+    }
+
+    pub fn has_menu_id_and_id_not_in(
+        menu_id: MenuId,
+        menu_item_ids: Vec<MenuItemId>
+    ) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.menu_id = Some(menu_id);
+        criteria.filter.id_not_in = Some(menu_item_ids);
+        criteria
+    }
+
+    pub fn has_menu_id_in(menu_ids: Vec<MenuId>) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.menu_id_in = Some(menu_ids);
+        criteria
+    }
+}
+
+impl MenuItemUpdateDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
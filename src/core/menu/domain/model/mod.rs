@@ -0,0 +1,5 @@
+mod menu;
+pub use menu::*;
+
+mod menu_item;
+pub use menu_item::*;
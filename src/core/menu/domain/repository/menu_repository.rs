@@ -0,0 +1,25 @@
+use crate::core::shared::model::DomainError;
+use super::super::model::Menu;
+use super::super::model::MenuId;
+use super::super::model::MenuSearchCriteria;
+use super::super::model::MenuSearchFilterCriteria;
+
+pub trait MenuRepository {
+    fn add(&mut self, menu: Menu) -> Result<(), DomainError>;
+
+    fn count(
+        &mut self,
+        criteria: MenuSearchFilterCriteria
+    ) -> Result<i64, DomainError>;
+
+    fn delete(&mut self, menu_id: MenuId) -> Result<(), DomainError>;
+
+    fn find(
+        &mut self,
+        criteria: MenuSearchCriteria
+    ) -> Result<Vec<Menu>, DomainError>;
+
+    fn get(&mut self, menu_id: MenuId) -> Result<Menu, DomainError>;
+
+    fn update(&mut self, menu: Menu) -> Result<(), DomainError>;
+}
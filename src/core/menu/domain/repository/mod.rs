@@ -0,0 +1,2 @@
+mod menu_repository;
+pub use menu_repository::*;
@@ -0,0 +1,66 @@
+use chrono::DateTime;
+use chrono::Utc;
+use diesel::pg::Pg;
+use diesel::prelude::AsChangeset;
+use diesel::prelude::Identifiable;
+use diesel::prelude::Insertable;
+use diesel::prelude::Queryable;
+use diesel::prelude::Selectable;
+use tukosmo_domain::core::language::model::I18nText;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::menu::model::MenuCreationDate;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuItem;
+use tukosmo_domain::core::menu::model::MenuLocation;
+use tukosmo_domain::core::menu::model::MenuUpdateDate;
+use uuid::Uuid;
+
+use crate::core::shared::diesel_orm::schema::menu;
+
+#[derive(
+    AsChangeset,
+    Clone,
+    Debug,
+    Identifiable,
+    Insertable,
+    PartialEq,
+    Queryable,
+    Selectable
+)]
+// Otherwise, removing the location of a menu wouldn't be saved
+#[diesel(treat_none_as_null = true)]
+#[diesel(table_name = menu)]
+#[diesel(check_for_backend(Pg))]
+pub struct DbMenu {
+    pub creation_date: DateTime<Utc>,
+    pub i18n_text_id_name: Uuid,
+    pub id: Uuid,
+    pub location: Option<String>,
+    pub update_date: DateTime<Utc>,
+}
+
+impl DbMenu {
+    pub fn from_domain(menu: Menu) -> Self {
+        Self {
+            creation_date: menu.creation_date.value().clone(),
+            i18n_text_id_name: menu.name.id.value().clone(),
+            id: menu.id.value().clone(),
+            location: menu.location.map(|l| l.value().to_string()),
+            update_date: menu.update_date.value().clone(),
+        }
+    }
+
+    pub fn to_domain(self, menu_name: I18nText, menu_items: Vec<MenuItem>) -> Menu {
+        Menu {
+            creation_date: MenuCreationDate::from(self.creation_date.clone()),
+            id: MenuId::from(self.id.clone()),
+            items: menu_items,
+            // Locations are validated before being stored
+            location: self.location
+                .as_ref()
+                .and_then(|l| MenuLocation::from_code(l).ok()),
+            name: menu_name,
+            update_date: MenuUpdateDate::from(self.update_date.clone()),
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use chrono::DateTime;
+use chrono::Utc;
+use diesel::pg::Pg;
+use diesel::prelude::AsChangeset;
+use diesel::prelude::Associations;
+use diesel::prelude::Identifiable;
+use diesel::prelude::Insertable;
+use diesel::prelude::Queryable;
+use diesel::prelude::Selectable;
+use tukosmo_domain::core::language::model::I18nText;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuItem;
+use tukosmo_domain::core::menu::model::MenuItemCreationDate;
+use tukosmo_domain::core::menu::model::MenuItemId;
+use tukosmo_domain::core::menu::model::MenuItemLink;
+use tukosmo_domain::core::menu::model::MenuItemPosition;
+use tukosmo_domain::core::menu::model::MenuItemUpdateDate;
+use uuid::Uuid;
+
+use crate::core::shared::diesel_orm::schema::menu_item;
+use super::db_menu::DbMenu;
+
+#[derive(
+    AsChangeset,
+    Associations,
+    Clone,
+    Debug,
+    Identifiable,
+    Insertable,
+    PartialEq,
+    Queryable,
+    Selectable
+)]
+#[diesel(belongs_to(DbMenu, foreign_key = menu_id))]
+#[diesel(table_name = menu_item)]
+#[diesel(check_for_backend(Pg))]
+pub struct DbMenuItem {
+    pub creation_date: DateTime<Utc>,
+    pub i18n_text_id_label: Uuid,
+    pub id: Uuid,
+    pub link_type: String,
+    pub link_value: String,
+    pub menu_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub position: i32,
+    pub update_date: DateTime<Utc>,
+}
+
+impl DbMenuItem {
+    pub fn from_domain(
+        menu_item: MenuItem,
+        menu_id: MenuId,
+        parent_id: Option<MenuItemId>
+    ) -> Self {
+        Self {
+            creation_date: menu_item.creation_date.value().clone(),
+            i18n_text_id_label: menu_item.label.id.value().clone(),
+            id: menu_item.id.value().clone(),
+            link_type: menu_item.link.link_type().to_string(),
+            link_value: menu_item.link.link_value(),
+            menu_id: menu_id.value().clone(),
+            parent_id: parent_id.map(|p| p.value().clone()),
+            position: menu_item.position.value().clone(),
+            update_date: menu_item.update_date.value().clone(),
+        }
+    }
+
+    pub fn to_domain(self, label: I18nText, children: Vec<MenuItem>) -> MenuItem {
+        MenuItem {
+            children,
+            creation_date: MenuItemCreationDate::from(
+                self.creation_date.clone()
+            ),
+            id: MenuItemId::from(self.id.clone()),
+            label,
+            link: MenuItemLink::from(&self.link_type, &self.link_value),
+            position: MenuItemPosition::from(self.position.clone()),
+            update_date: MenuItemUpdateDate::from(self.update_date.clone()),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod db_menu;
+pub use db_menu::*;
+
+mod db_menu_item;
+pub use db_menu_item::*;
@@ -0,0 +1,59 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuSearchCriteria;
+use tukosmo_domain::core::menu::model::MenuSearchFilterCriteria;
+use tukosmo_domain::core::menu::repository::MenuRepository;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use super::super::service::MenuManager;
+
+pub struct DbMenuRepository {
+    menu: MenuManager,
+}
+
+impl DbMenuRepository {
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { menu: MenuManager::init(connection) }
+    }
+}
+
+impl MenuRepository for DbMenuRepository {
+    fn add(&mut self, menu: Menu) -> Result<(), DomainError> {
+        self.menu.add(menu)?;
+        Ok(())
+    }
+
+    fn count(
+        &mut self,
+        filter_criteria: MenuSearchFilterCriteria
+    ) -> Result<i64, DomainError> {
+        let total = self.menu.count(filter_criteria)?;
+        Ok(total)
+    }
+
+    fn delete(&mut self, menu_id: MenuId) -> Result<(), DomainError> {
+        self.menu.delete(menu_id)?;
+        Ok(())
+    }
+
+    fn find(
+        &mut self,
+        search_criteria: MenuSearchCriteria
+    ) -> Result<Vec<Menu>, DomainError> {
+        let menus = self.menu.find(search_criteria)?;
+        Ok(menus)
+    }
+
+    fn get(&mut self, menu_id: MenuId) -> Result<Menu, DomainError> {
+        let menu = self.menu.get(menu_id)?;
+        Ok(menu)
+    }
+
+    fn update(&mut self, menu: Menu) -> Result<(), DomainError> {
+        self.menu.update(menu)?;
+        Ok(())
+    }
+}
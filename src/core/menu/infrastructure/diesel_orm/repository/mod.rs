@@ -0,0 +1,2 @@
+mod db_menu_repository;
+pub use db_menu_repository::*;
@@ -0,0 +1,245 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::language::model::I18nText;
+use tukosmo_domain::core::language::model::I18nTextId;
+use tukosmo_domain::core::menu::error;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuItem;
+use tukosmo_domain::core::menu::model::MenuItemId;
+use tukosmo_domain::core::menu::model::MenuItemSearchCriteria;
+use tukosmo_domain::core::menu::model::MenuSearchCriteria;
+use tukosmo_domain::core::menu::model::MenuSearchFilterCriteria;
+use tukosmo_domain::core::shared::model::DomainError;
+use uuid::Uuid;
+
+use crate::core::language::diesel_orm::service::I18nTextManager;
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbMenu;
+use super::super::model::DbMenuItem;
+use super::super::statement::MenuItemSqlExecutor;
+use super::super::statement::MenuSqlExecutor;
+
+pub struct MenuManager {
+    i18n_text_manager: I18nTextManager,
+    menu: MenuSqlExecutor,
+    menu_item: MenuItemSqlExecutor,
+}
+
+impl MenuManager {
+    pub fn add(&mut self, menu: Menu) -> Result<(), DomainError> {
+        self.i18n_text_manager.add(menu.name.clone())?;
+
+        let db_menu = DbMenu::from_domain(menu.clone());
+        self.menu.insert(db_menu)?;
+
+        let mut db_menu_items: Vec<DbMenuItem> = vec![];
+        for (db_menu_item, label) in Self::items_to_db(
+            menu.items,
+            &menu.id,
+            None
+        ) {
+            self.i18n_text_manager.add(label)?;
+            db_menu_items.push(db_menu_item);
+        }
+        self.menu_item.insert_in_bulk(db_menu_items)?;
+
+        Ok(())
+    }
+
+    pub fn count(
+        &mut self,
+        filter_criteria: MenuSearchFilterCriteria
+    ) -> Result<i64, DomainError> {
+        let total = self.menu.select_count(filter_criteria)?;
+        Ok(total)
+    }
+
+    pub fn delete(&mut self, menu_id: MenuId) -> Result<(), DomainError> {
+        let menu = self.get(menu_id)?;
+
+        // Items are removed by the database cascade, but not their labels
+        self.menu.delete(MenuSearchCriteria::has_id(menu.id.clone()).filter)?;
+        self.i18n_text_manager.delete(menu.name.id)?;
+        for (_db_menu_item, label) in Self::items_to_db(
+            menu.items,
+            &menu.id,
+            None
+        ) {
+            self.i18n_text_manager.delete(label.id)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn find(
+        &mut self,
+        search_criteria: MenuSearchCriteria
+    ) -> Result<Vec<Menu>, DomainError> {
+        let db_menus = self.menu.select(search_criteria)?;
+        let menu_ids = db_menus
+            .iter()
+            .map(|m| MenuId::from(m.id.clone()))
+            .collect();
+
+        let db_menu_items = self.menu_item.select(
+            MenuItemSearchCriteria::has_menu_id_in(menu_ids)
+        )?;
+
+        let mut i18n_text_ids: Vec<I18nTextId> = db_menus
+            .iter()
+            .map(|m| I18nTextId::from(m.i18n_text_id_name.clone()))
+            .collect();
+        i18n_text_ids.extend(
+            db_menu_items
+                .iter()
+                .map(|i| I18nTextId::from(i.i18n_text_id_label.clone()))
+        );
+        let i18n_texts = self.i18n_text_manager.get_in_bulk(i18n_text_ids)?;
+
+        let mut menus: Vec<Menu> = vec![];
+        for db_menu in db_menus {
+            let menu_items = Self::items_to_domain(
+                &db_menu_items
+                    .iter()
+                    .filter(|i| i.menu_id == db_menu.id)
+                    .cloned()
+                    .collect(),
+                None,
+                &i18n_texts
+            );
+            let menu_name = i18n_texts
+                .iter()
+                .find(|t| t.id.value() == &db_menu.i18n_text_id_name)
+                .unwrap()
+                .clone();
+
+            let menu = db_menu.to_domain(menu_name, menu_items);
+            menus.push(menu);
+        }
+
+        Ok(menus)
+    }
+
+    pub fn get(&mut self, menu_id: MenuId) -> Result<Menu, DomainError> {
+        let menus = self.find(MenuSearchCriteria::has_id(menu_id))?;
+        let menu = menus.into_iter().next().ok_or(error::MENU_NOT_FOUND)?;
+        Ok(menu)
+    }
+
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self {
+            i18n_text_manager: I18nTextManager::init(Rc::clone(&connection)),
+            menu: MenuSqlExecutor::init(Rc::clone(&connection)),
+            menu_item: MenuItemSqlExecutor::init(connection),
+        }
+    }
+
+    // The tree is stored flat, each item pointing to its parent
+    fn items_to_db(
+        menu_items: Vec<MenuItem>,
+        menu_id: &MenuId,
+        parent_id: Option<MenuItemId>
+    ) -> Vec<(DbMenuItem, I18nText)> {
+        let mut db_menu_items: Vec<(DbMenuItem, I18nText)> = vec![];
+        for menu_item in menu_items {
+            let item_id = menu_item.id.clone();
+            let children = menu_item.children.clone();
+            let label = menu_item.label.clone();
+            db_menu_items.push((
+                DbMenuItem::from_domain(
+                    menu_item,
+                    menu_id.clone(),
+                    parent_id.clone()
+                ),
+                label,
+            ));
+            db_menu_items.extend(
+                Self::items_to_db(children, menu_id, Some(item_id))
+            );
+        }
+        db_menu_items
+    }
+
+    fn items_to_domain(
+        db_menu_items: &Vec<DbMenuItem>,
+        parent_id: Option<Uuid>,
+        i18n_texts: &Vec<I18nText>
+    ) -> Vec<MenuItem> {
+        db_menu_items
+            .iter()
+            .filter(|i| i.parent_id == parent_id)
+            .map(|db_menu_item| {
+                let label = i18n_texts
+                    .iter()
+                    .find(|t| t.id.value() == &db_menu_item.i18n_text_id_label)
+                    .unwrap()
+                    .clone();
+                let children = Self::items_to_domain(
+                    db_menu_items,
+                    Some(db_menu_item.id.clone()),
+                    i18n_texts
+                );
+                db_menu_item.clone().to_domain(label, children)
+            })
+            .collect()
+    }
+
+    pub fn update(&mut self, menu: Menu) -> Result<(), DomainError> {
+        let db_menu = DbMenu::from_domain(menu.clone());
+        self.menu.update(&db_menu)?;
+
+        self.i18n_text_manager.update(menu.name)?;
+
+        let db_current_items = self.menu_item.select(
+            MenuItemSearchCriteria::has_menu_id(menu.id.clone())
+        )?;
+
+        let db_menu_items_with_labels = Self::items_to_db(
+            menu.items,
+            &menu.id,
+            None
+        );
+
+        // Nested items of the removed ones are deleted by the cascade, but
+        // they aren't retained either, so their labels are removed below
+        let menu_item_ids_to_retain: Vec<MenuItemId> = db_menu_items_with_labels
+            .iter()
+            .map(|(i, _label)| MenuItemId::from(i.id.clone()))
+            .collect();
+        self.menu_item.delete(
+            MenuItemSearchCriteria::has_menu_id_and_id_not_in(
+                menu.id.clone(),
+                menu_item_ids_to_retain
+            ).filter
+        )?;
+
+        for db_current_item in db_current_items.iter() {
+            let is_retained = db_menu_items_with_labels
+                .iter()
+                .any(|(i, _label)| i.id == db_current_item.id);
+            if !is_retained {
+                self.i18n_text_manager.delete(
+                    I18nTextId::from(db_current_item.i18n_text_id_label.clone())
+                )?;
+            }
+        }
+
+        let mut db_menu_items: Vec<DbMenuItem> = vec![];
+        for (db_menu_item, label) in db_menu_items_with_labels {
+            let is_new = !db_current_items
+                .iter()
+                .any(|i| i.id == db_menu_item.id);
+            if is_new {
+                self.i18n_text_manager.add(label)?;
+            } else {
+                self.i18n_text_manager.update(label)?;
+            }
+            db_menu_items.push(db_menu_item);
+        }
+        self.menu_item.upsert_in_bulk(db_menu_items)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,2 @@
+mod menu_manager;
+pub use menu_manager::*;
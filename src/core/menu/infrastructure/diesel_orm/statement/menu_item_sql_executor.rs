@@ -0,0 +1,220 @@
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::RunQueryDsl;
+use diesel::SelectableHelper;
+use diesel::pg::Pg;
+use diesel::pg::PgConnection;
+use diesel::query_builder::BoxedSelectStatement;
+use diesel::query_builder::FromClause;
+use diesel;
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use tukosmo_domain::core::menu::model::MenuItemSearchCriteria;
+use tukosmo_domain::core::menu::model::MenuItemSearchFilterCriteria;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use uuid::Uuid;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::schema::menu_item;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbMenuItem;
+
+pub struct MenuItemSqlExecutor {
+    connection: Rc<RefCell<DbConnection>>,
+}
+
+impl ModelSqlExecutor<
+    DbMenuItem,
+    (
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Nullable<diesel::sql_types::Uuid>,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Integer,
+        diesel::sql_types::Timestamptz,
+        diesel::sql_types::Timestamptz,
+    ),
+    MenuItemSearchCriteria,
+    MenuItemSearchFilterCriteria,
+    menu_item::table
+>
+for MenuItemSqlExecutor {
+    fn delete(
+        &mut self,
+        filter_criteria: MenuItemSearchFilterCriteria
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut statement = diesel::delete(menu_item::table).into_boxed();
+
+        if let Some(menu_item_ids) = filter_criteria.id_not_in {
+            statement = statement.filter(
+                menu_item::id.ne_all(
+                    menu_item_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+        if let Some(menu_id) = filter_criteria.menu_id {
+            statement = statement.filter(
+                menu_item::menu_id.eq(menu_id.value().clone())
+            );
+        }
+        if let Some(menu_ids) = filter_criteria.menu_id_in {
+            statement = statement.filter(
+                menu_item::menu_id.eq_any(
+                    menu_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+
+        let result = statement.execute(connection);
+
+        match result {
+            Ok(_affected_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_DELETE_ON_DATABASE),
+        }
+    }
+
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
+        Rc::clone(&self.connection)
+    }
+
+    fn get_table() -> menu_item::table {
+        menu_item::table
+    }
+
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { connection }
+    }
+
+    fn select(
+        &mut self,
+        search_criteria: MenuItemSearchCriteria
+    ) -> Result<Vec<DbMenuItem>, DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let query = Self::select_query(search_criteria.filter);
+        let results = query
+            .order(menu_item::position.asc())
+            .select(DbMenuItem::as_select())
+            .load(connection)
+            .map_err(|_e| error::CANNOT_EXECUTE_SELECT_ON_DATABASE)?;
+
+        Ok(results)
+    }
+
+    fn select_query<'a>(
+        filter_criteria: MenuItemSearchFilterCriteria
+    ) -> BoxedSelectStatement<
+        'a,
+        (
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Nullable<diesel::sql_types::Uuid>,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Text,
+            diesel::sql_types::Text,
+            diesel::sql_types::Integer,
+            diesel::sql_types::Timestamptz,
+            diesel::sql_types::Timestamptz,
+        ),
+        FromClause<menu_item::table>,
+        Pg
+    > {
+        let mut query = menu_item::table.into_boxed();
+
+        if let Some(menu_item_ids) = filter_criteria.id_not_in {
+            query = query.filter(
+                menu_item::id.ne_all(
+                    menu_item_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+        if let Some(menu_id) = filter_criteria.menu_id {
+            query = query.filter(
+                menu_item::menu_id.eq(menu_id.value().clone())
+            );
+        }
+        if let Some(menu_ids) = filter_criteria.menu_id_in {
+            query = query.filter(
+                menu_item::menu_id.eq_any(
+                    menu_ids
+                        .iter()
+                        .map(|v| v.value().clone())
+                        .collect::<Vec<Uuid>>()
+                )
+            );
+        }
+
+        query
+    }
+
+    fn update(
+        &mut self,
+        db_menu_item: &DbMenuItem
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::update(menu_item::table.find(db_menu_item.id))
+            .set(db_menu_item)
+            .execute(connection);
+
+        match result {
+            Ok(0) => Err(error::NOTHING_TO_UPDATE_ON_DATABASE),
+            Ok(1) => Ok(()),
+            Ok(_) => Err(error::UNDESIRED_UPDATES_ON_DATABASE),
+            Err(_e) => Err(error::CANNOT_EXECUTE_UPDATE_ON_DATABASE),
+        }
+    }
+
+    fn upsert_in_bulk(
+        &mut self,
+        db_menu_items: Vec<DbMenuItem>
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::insert_into(menu_item::table)
+            .values(db_menu_items)
+            .on_conflict(menu_item::id)
+            .do_update()
+            .set((
+                menu_item::link_type.eq(
+                    diesel::pg::upsert::excluded(menu_item::link_type)
+                ),
+                menu_item::link_value.eq(
+                    diesel::pg::upsert::excluded(menu_item::link_value)
+                ),
+                menu_item::position.eq(
+                    diesel::pg::upsert::excluded(menu_item::position)
+                ),
+                menu_item::update_date.eq(
+                    diesel::pg::upsert::excluded(menu_item::update_date)
+                ),
+            ))
+            .execute(connection);
+
+        match result {
+            Ok(_inserted_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_INSERT_ON_DATABASE),
+        }
+    }
+}
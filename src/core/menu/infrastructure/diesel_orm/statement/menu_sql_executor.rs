@@ -0,0 +1,185 @@
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::RunQueryDsl;
+use diesel::SelectableHelper;
+use diesel::pg::Pg;
+use diesel::pg::PgConnection;
+use diesel::query_builder::BoxedSelectStatement;
+use diesel::query_builder::FromClause;
+use diesel;
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use tukosmo_domain::core::menu::model::MenuSearchCriteria;
+use tukosmo_domain::core::menu::model::MenuSearchCriteriaOrderBy;
+use tukosmo_domain::core::menu::model::MenuSearchFilterCriteria;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::schema::menu;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbMenu;
+
+pub struct MenuSqlExecutor {
+    connection: Rc<RefCell<DbConnection>>,
+}
+
+impl ModelSqlExecutor<
+    DbMenu,
+    (
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Nullable<diesel::sql_types::Text>,
+        diesel::sql_types::Timestamptz,
+        diesel::sql_types::Timestamptz,
+    ),
+    MenuSearchCriteria,
+    MenuSearchFilterCriteria,
+    menu::table
+>
+for MenuSqlExecutor {
+    fn delete(
+        &mut self,
+        filter_criteria: MenuSearchFilterCriteria
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut statement = diesel::delete(menu::table).into_boxed();
+
+        if let Some(menu_id) = filter_criteria.id {
+            statement = statement.filter(menu::id.eq(menu_id.value().clone()));
+        }
+        if let Some(menu_id) = filter_criteria.not_id {
+            statement = statement.filter(menu::id.ne(menu_id.value().clone()));
+        }
+        if let Some(location) = filter_criteria.location {
+            statement = statement.filter(menu::location.eq(location.value()));
+        }
+
+        let result = statement.execute(connection);
+
+        match result {
+            Ok(_affected_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_DELETE_ON_DATABASE),
+        }
+    }
+
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
+        Rc::clone(&self.connection)
+    }
+
+    fn get_table() -> menu::table {
+        menu::table
+    }
+
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { connection }
+    }
+
+    fn select(
+        &mut self,
+        search_criteria: MenuSearchCriteria
+    ) -> Result<Vec<DbMenu>, DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut query = Self::select_query(search_criteria.filter);
+        if let Some(order_by) = search_criteria.order_by {
+            match order_by {
+                MenuSearchCriteriaOrderBy::CreationDate => {
+                    query = query.order(menu::creation_date.desc());
+                }
+            }
+        }
+
+        let select = query.select(DbMenu::as_select());
+
+        let results = (
+            if let Some(pagination) = search_criteria.pagination {
+                select
+                    .offset((pagination.page - 1) * pagination.results_per_page)
+                    .limit(pagination.results_per_page)
+            } else {
+                select
+            }
+        )
+            .load(connection)
+            .map_err(|_e| error::CANNOT_EXECUTE_SELECT_ON_DATABASE)?;
+
+        Ok(results)
+    }
+
+    fn select_query<'a>(
+        filter_criteria: MenuSearchFilterCriteria
+    ) -> BoxedSelectStatement<
+        'a,
+        (
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Nullable<diesel::sql_types::Text>,
+            diesel::sql_types::Timestamptz,
+            diesel::sql_types::Timestamptz,
+        ),
+        FromClause<menu::table>,
+        Pg
+    > {
+        let mut query = menu::table.into_boxed();
+
+        if let Some(menu_id) = filter_criteria.id {
+            query = query.filter(menu::id.eq(menu_id.value().clone()));
+        }
+        if let Some(menu_id) = filter_criteria.not_id {
+            query = query.filter(menu::id.ne(menu_id.value().clone()));
+        }
+        if let Some(location) = filter_criteria.location {
+            query = query.filter(menu::location.eq(location.value()));
+        }
+
+        query
+    }
+
+    fn update(&mut self, db_menu: &DbMenu) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::update(menu::table.find(db_menu.id))
+            .set(db_menu)
+            .execute(connection);
+
+        match result {
+            Ok(0) => Err(error::NOTHING_TO_UPDATE_ON_DATABASE),
+            Ok(1) => Ok(()),
+            Ok(_) => Err(error::UNDESIRED_UPDATES_ON_DATABASE),
+            Err(_e) => Err(error::CANNOT_EXECUTE_UPDATE_ON_DATABASE),
+        }
+    }
+
+    fn upsert_in_bulk(
+        &mut self,
+        db_menus: Vec<DbMenu>
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::insert_into(menu::table)
+            .values(db_menus)
+            .on_conflict(menu::id)
+            .do_update()
+            .set((
+                menu::location.eq(diesel::pg::upsert::excluded(menu::location)),
+                menu::update_date.eq(
+                    diesel::pg::upsert::excluded(menu::update_date)
+                ),
+            ))
+            .execute(connection);
+
+        match result {
+            Ok(_inserted_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_INSERT_ON_DATABASE),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+mod menu_item_sql_executor;
+pub use menu_item_sql_executor::*;
+
+mod menu_sql_executor;
+pub use menu_sql_executor::*;
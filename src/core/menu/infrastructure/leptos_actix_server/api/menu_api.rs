@@ -0,0 +1,162 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::menu::dto::DtoAddMenu;
+use tukosmo_application::core::menu::dto::DtoAddMenuItem;
+use tukosmo_application::core::menu::dto::DtoDeleteMenu;
+use tukosmo_application::core::menu::dto::DtoDeleteMenuItem;
+use tukosmo_application::core::menu::dto::DtoEditMenu;
+use tukosmo_application::core::menu::dto::DtoEditMenuItem;
+use tukosmo_application::core::menu::dto::DtoGetMenu;
+use tukosmo_application::core::menu::dto::DtoGetMenuByLocation;
+use tukosmo_application::core::menu::dto::DtoGetMenusPaginated;
+use tukosmo_application::core::menu::dto::DtoMenusPaginated;
+use tukosmo_application::core::menu::dto::DtoReorderMenuItems;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::menu::use_case::MenuUseCase;
+use tukosmo_domain::core::menu::model::Menu;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+#[server(ApiCoreMenuMenuAdd)]
+pub async fn add(
+    dto: DtoAddMenu
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.add(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuAddItem)]
+pub async fn add_item(
+    dto: DtoAddMenuItem
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.add_item(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuDelete)]
+pub async fn delete(
+    dto: DtoDeleteMenu
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.delete(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuDeleteItem)]
+pub async fn delete_item(
+    dto: DtoDeleteMenuItem
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.delete_item(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuEdit)]
+pub async fn edit(
+    dto: DtoEditMenu
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.edit(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuEditItem)]
+pub async fn edit_item(
+    dto: DtoEditMenuItem
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.edit_item(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuGet)]
+pub async fn get(
+    dto: DtoGetMenu
+) -> Result<ServerResponse<Menu>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.get(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuGetByLocation)]
+pub async fn get_by_location(
+    dto: DtoGetMenuByLocation
+) -> Result<ServerResponse<Option<Menu>>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.get_menu_by_location(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuListPaginated)]
+pub async fn list_paginated(
+    dto: DtoGetMenusPaginated
+) -> Result<ServerResponse<DtoMenusPaginated>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.get_menus_paginated(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreMenuMenuReorderItems)]
+pub async fn reorder_items(
+    dto: DtoReorderMenuItems
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let menu_use_case = common()?;
+        menu_use_case.reorder_items(dto)
+    });
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn common() -> Result<MenuUseCase, DomainError> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::core::shared::diesel_orm::model::DbTransactionExecutor;
+    use crate::core::shared::leptos_actix_server::repository::MemoryPageCacheRepository;
+    use crate::core::shared::leptos_actix_server::service::database;
+    use crate::core::shared::leptos_actix_server::service::page_cache;
+
+    let db_pool = database::get_db_pool()?;
+    let page_cache_repository = MemoryPageCacheRepository::init(
+        page_cache::get_page_cache()
+    );
+    let transaction_executor = DbTransactionExecutor::init(&db_pool)?;
+    let menu_use_case = MenuUseCase::init(
+        Rc::new(RefCell::new(page_cache_repository)),
+        Rc::new(RefCell::new(transaction_executor))
+    );
+
+    Ok(menu_use_case)
+}
@@ -0,0 +1,78 @@
+use leptos::SignalGetUntracked;
+use leptos::StoredValue;
+use leptos::store_value;
+use tukosmo_application::core::menu::dto::DtoMenuForm;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::menu::model::MenuLocation;
+
+use crate::core::language::leptos_ui::I18nTextFormFieldInitialValue;
+use crate::core::language::leptos_ui::I18nTextFormFieldValue;
+use crate::core::shared::leptos_ui::FormFieldValue;
+use crate::t;
+
+#[derive(Clone)]
+pub struct MenuForm {
+    pub location: StoredValue<FormFieldValue<String>>,
+    pub name: StoredValue<I18nTextFormFieldValue>,
+}
+
+#[derive(Clone)]
+struct MenuFormInitialValues {
+    pub location: String,
+    pub name: I18nTextFormFieldInitialValue,
+}
+
+impl MenuForm {
+    pub fn get_dto(&self) -> DtoMenuForm {
+        DtoMenuForm {
+            location: self.location.get_value().signal.get_untracked(),
+            name: self.name.get_value().get_i18n_text_value(),
+        }
+    }
+
+    fn init(initial_values: MenuFormInitialValues) -> StoredValue<Self> {
+        let location = FormFieldValue::init(initial_values.location, |_| None);
+        let name = I18nTextFormFieldValue::init(
+            initial_values.name,
+            Menu::validate_name_default_value,
+            Menu::validate_name_translation_value
+        );
+
+        store_value(Self { location, name })
+    }
+
+    pub fn init_empty() -> StoredValue<Self> {
+        let initial_values = MenuFormInitialValues {
+            location: "".to_string(),
+            name: I18nTextFormFieldInitialValue::empty(),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn init_filled(menu: Menu) -> StoredValue<Self> {
+        let initial_values = MenuFormInitialValues {
+            location: match menu.location {
+                Some(location) => location.value().to_string(),
+                None => "".to_string(),
+            },
+            name: I18nTextFormFieldInitialValue::filled(menu.name),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn validate(&self) {
+        self.location.get_value().validate();
+        self.name.get_value().validate();
+    }
+}
+
+// The empty value means that the menu isn't shown anywhere
+pub fn menu_location_options() -> Vec<(String, String)> {
+    vec![
+        ("".to_string(), t!(main.none)()),
+        (MenuLocation::Header.value().to_string(), t!(main.header)()),
+        (MenuLocation::Footer.value().to_string(), t!(main.footer)())
+    ]
+}
@@ -0,0 +1,80 @@
+use leptos::SignalGetUntracked;
+use leptos::StoredValue;
+use leptos::store_value;
+use tukosmo_application::core::menu::dto::DtoMenuItemForm;
+use tukosmo_domain::core::menu::model::MENU_ITEM_LINK_TYPE_ROUTE;
+use tukosmo_domain::core::menu::model::MenuItem;
+
+use crate::core::language::leptos_ui::I18nTextFormFieldInitialValue;
+use crate::core::language::leptos_ui::I18nTextFormFieldValue;
+use crate::core::shared::leptos_ui::FormFieldValue;
+
+#[derive(Clone)]
+pub struct MenuItemForm {
+    pub label: StoredValue<I18nTextFormFieldValue>,
+    pub link_type: StoredValue<FormFieldValue<String>>,
+    // The link value is validated by the server, since it depends on the
+    // selected link type
+    pub link_value: StoredValue<FormFieldValue<String>>,
+}
+
+#[derive(Clone)]
+struct MenuItemFormInitialValues {
+    pub label: I18nTextFormFieldInitialValue,
+    pub link_type: String,
+    pub link_value: String,
+}
+
+impl MenuItemForm {
+    pub fn get_dto(&self) -> DtoMenuItemForm {
+        DtoMenuItemForm {
+            label: self.label.get_value().get_i18n_text_value(),
+            link_type: self.link_type.get_value().signal.get_untracked(),
+            link_value: self.link_value.get_value().signal.get_untracked(),
+        }
+    }
+
+    fn init(initial_values: MenuItemFormInitialValues) -> StoredValue<Self> {
+        let label = I18nTextFormFieldValue::init(
+            initial_values.label,
+            MenuItem::validate_label_default_value,
+            MenuItem::validate_label_translation_value
+        );
+        let link_type = FormFieldValue::init(
+            initial_values.link_type,
+            |_| None
+        );
+        let link_value = FormFieldValue::init(
+            initial_values.link_value,
+            |_| None
+        );
+
+        store_value(Self { label, link_type, link_value })
+    }
+
+    pub fn init_empty() -> StoredValue<Self> {
+        let initial_values = MenuItemFormInitialValues {
+            label: I18nTextFormFieldInitialValue::empty(),
+            link_type: MENU_ITEM_LINK_TYPE_ROUTE.to_string(),
+            link_value: "".to_string(),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn init_filled(menu_item: MenuItem) -> StoredValue<Self> {
+        let initial_values = MenuItemFormInitialValues {
+            label: I18nTextFormFieldInitialValue::filled(menu_item.label),
+            link_type: menu_item.link.link_type().to_string(),
+            link_value: menu_item.link.link_value(),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn validate(&self) {
+        self.label.get_value().validate();
+        self.link_type.get_value().validate();
+        self.link_value.get_value().validate();
+    }
+}
@@ -0,0 +1,5 @@
+mod menu_form;
+pub use menu_form::*;
+
+mod menu_item_form;
+pub use menu_item_form::*;
@@ -0,0 +1,11 @@
+mod tap_add_menu_view;
+pub use tap_add_menu_view::*;
+
+mod tap_delete_menu_view;
+pub use tap_delete_menu_view::*;
+
+mod tap_edit_menu_view;
+pub use tap_edit_menu_view::*;
+
+mod tap_menus_view;
+pub use tap_menus_view::*;
@@ -0,0 +1,94 @@
+use leptos::IntoView;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::component;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::NavigateOptions;
+use leptos_router::use_navigate;
+use tukosmo_application::core::menu::dto::DtoAddMenu;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::menu::leptos_actix_server::api::menu_api;
+use crate::core::language::leptos_ui::TapFormI18nTextField;
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapFormPage;
+use crate::core::shared::leptos_ui::TapFormSelectField;
+use crate::core::shared::leptos_ui::TapLayout;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::t;
+use super::super::MenuForm;
+use super::super::menu_location_options;
+
+#[component]
+pub fn TapAddMenuView() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+
+    let form = MenuForm::init_empty();
+
+    let on_click_submit_button = move |_| {
+        waiting_response_signal.set(true);
+        let current_language_code = current_language_reader.get().code;
+
+        spawn_local(async move {
+            let dto_form = form.get_value().get_dto();
+            let dto = DtoAddMenu { form: dto_form };
+            let server_response_add = menu_api::add(dto).await;
+
+            manage_response(
+                server_response_add,
+                move |_response| {
+                    let navigate = use_navigate();
+                    let path = navigation::path_admin_menus(
+                        &current_language_code
+                    );
+                    navigate(&path, NavigateOptions::default());
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    server_error_signal.set(Some(server_error));
+
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <TapLayout>
+            <TapFormPage
+                cancel_route_path=Box::new(move || navigation::path_admin_menus(
+                    &current_language_reader.get().code
+                ))
+                on_click_submit_button=Box::new(on_click_submit_button)
+                server_error_signal=server_error_signal
+                title=Box::new(|| t!(main.add_menu)())
+                waiting_response_signal=waiting_response_signal
+            >
+                // TODO
+                //<input name="csrf_token" type="hidden" value=csrf_token />
+
+                <TapFormI18nTextField
+                    name=Box::new(|| t!(main.name)())
+                    required=true
+                    value=form.get_value().name
+                />
+
+                <TapFormSelectField
+                    name=Box::new(|| t!(main.location)())
+                    options=Box::new(menu_location_options)
+                    required=false
+                    value=form.get_value().location
+                />
+            </TapFormPage>
+        </TapLayout>
+    }
+}
@@ -0,0 +1,152 @@
+use leptos::IntoView;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::NavigateOptions;
+use leptos_router::use_navigate;
+use leptos_router::use_params_map;
+use tukosmo_application::core::menu::dto::DtoDeleteMenu;
+use tukosmo_application::core::menu::dto::DtoGetMenu;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::menu::leptos_actix_server::api::menu_api;
+use crate::core::shared::leptos_ui::DeleteForm;
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapFormCheckboxField;
+use crate::core::shared::leptos_ui::TapFormPage;
+use crate::core::shared::leptos_ui::TapLayout;
+use crate::core::shared::leptos_ui::TapLoadingError;
+use crate::core::shared::leptos_ui::TapLoadingLeptosError;
+use crate::core::shared::leptos_ui::TapLoadingResource;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::t;
+
+#[component]
+pub fn TapDeleteMenuView() -> impl IntoView {
+    let params = use_params_map();
+
+    let response_data = create_resource(
+        move || params.get(),
+        move |params| async move {
+            let menu_id = params.get("id").unwrap();
+
+            let dto = DtoGetMenu { menu_id: menu_id.to_string() };
+            let result = menu_api::get(dto).await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(menu) => {
+                            view! {
+                                <div>
+                                    <TapDeleteMenuViewContent menu=menu />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapDeleteMenuViewContent(menu: Menu) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+
+    let form = DeleteForm::init_empty();
+
+    let stored_menu_id = store_value(menu.id.clone());
+    let on_click_submit_button = move |_| {
+        waiting_response_signal.set(true);
+        let current_language_code = current_language_reader.get().code;
+
+        spawn_local(async move {
+            let dto_form = form.get_value().get_dto();
+            let dto = DtoDeleteMenu {
+                form: dto_form,
+                menu_id: stored_menu_id.get_value().value().to_string(),
+            };
+            let server_response_delete = menu_api::delete(dto).await;
+
+            manage_response(
+                server_response_delete,
+                move |_response| {
+                    let navigate = use_navigate();
+                    let path = navigation::path_admin_menus(
+                        &current_language_code
+                    );
+                    navigate(&path, NavigateOptions::default());
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    server_error_signal.set(Some(server_error));
+
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <TapFormPage
+            cancel_route_path=Box::new(move || navigation::path_admin_menus_edit(
+                &current_language_reader.get().code,
+                &menu.id
+            ))
+            on_click_submit_button=Box::new(on_click_submit_button)
+            server_error_signal=server_error_signal
+            title=Box::new(|| t!(main.delete_menu)())
+            waiting_response_signal=waiting_response_signal
+        >
+            // TODO
+            //<input name="csrf_token" type="hidden" value=csrf_token />
+
+            <TapFormCheckboxField
+                required=true
+                text=Box::new(|| t!(main.i_understand_the_consequences_of_performing_this_action)())
+                value=form.get_value().requested
+            />
+        </TapFormPage>
+    }
+}
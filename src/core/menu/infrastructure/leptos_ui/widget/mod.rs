@@ -0,0 +1,5 @@
+mod tap_menu_items;
+pub use tap_menu_items::*;
+
+mod web_menu;
+pub use web_menu::*;
@@ -0,0 +1,567 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+#[cfg(feature = "ssr")]
+use leptos::IntoClass;
+use leptos::IntoView;
+use leptos::Resource;
+use leptos::RwSignal;
+use leptos::ServerFnError;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalGetUntracked;
+use leptos::SignalSet;
+use leptos::SignalUpdate;
+use leptos::StoredValue;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::menu::dto::DtoAddMenuItem;
+use tukosmo_application::core::menu::dto::DtoDeleteMenuItem;
+use tukosmo_application::core::menu::dto::DtoEditMenuItem;
+use tukosmo_application::core::menu::dto::DtoGetMenu;
+use tukosmo_application::core::menu::dto::DtoReorderMenuItems;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::menu::model::MENU_ITEM_LINK_TYPE_EXTERNAL_URL;
+use tukosmo_domain::core::menu::model::MENU_ITEM_LINK_TYPE_GALLERY;
+use tukosmo_domain::core::menu::model::MENU_ITEM_LINK_TYPE_ROUTE;
+use tukosmo_domain::core::menu::model::MENU_ITEM_MAX_DEPTH;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuItem;
+use tukosmo_domain::core::menu::model::MenuItemId;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+use web_sys::MouseEvent;
+
+use crate::core::gallery::leptos_actix_server::api::gallery_api;
+use crate::core::language::leptos_ui::TapFormI18nTextField;
+use crate::core::menu::leptos_actix_server::api::menu_api;
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapFormSelectField;
+use crate::core::shared::leptos_ui::TapFormTextField;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::t;
+use crate::t_error;
+use super::super::MenuItemForm;
+
+// Everything the items of the tree need to talk to the server and refresh
+// the whole menu afterwards
+#[derive(Clone, Copy)]
+struct TapMenuItemsState {
+    galleries_resource: Resource<
+        (),
+        Result<ServerResponse<Vec<Gallery>>, ServerFnError>
+    >,
+    menu_id: StoredValue<MenuId>,
+    menu_signal: RwSignal<Menu>,
+    server_error_signal: RwSignal<Option<ServerResponseError>>,
+    waiting_response_signal: RwSignal<bool>,
+}
+
+impl TapMenuItemsState {
+    fn reload_menu(&self) {
+        let state = *self;
+
+        spawn_local(async move {
+            let dto = DtoGetMenu {
+                menu_id: state.menu_id.get_value().value().to_string(),
+            };
+            let server_response_get = menu_api::get(dto).await;
+
+            manage_response(
+                server_response_get,
+                move |menu| {
+                    state.menu_signal.set(menu);
+                    state.waiting_response_signal.set(false);
+                },
+                move |server_error| {
+                    state.server_error_signal.set(Some(server_error));
+                    state.waiting_response_signal.set(false);
+                }
+            );
+        });
+    }
+}
+
+#[component]
+pub fn TapMenuItems(menu: Menu) -> impl IntoView {
+    let galleries_resource = create_resource(
+        || (),
+        |_| async move { gallery_api::get_all_galleries().await }
+    );
+
+    let state = TapMenuItemsState {
+        galleries_resource,
+        menu_id: store_value(menu.id.clone()),
+        menu_signal: create_rw_signal(menu),
+        server_error_signal: create_rw_signal(None),
+        waiting_response_signal: create_rw_signal(false),
+    };
+
+    let add_item_form_is_visible_signal = create_rw_signal(false);
+
+    view! {
+        <div class="box is-marginless mb-6">
+            <h2 class="title is-4">
+                {t!(main.items)}
+
+                <button
+                    class="button is-link is-pulled-right has-text-weight-normal"
+                    on:click=move |_| add_item_form_is_visible_signal.update(
+                        |draft| *draft = !*draft
+                    )
+                >
+                    {t!(main.add_item)}
+                </button>
+            </h2>
+
+            <Show when=move || state.server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| state.server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = state.server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <Show when=move || add_item_form_is_visible_signal.get()>
+                <TapMenuItemsAddForm
+                    form_is_visible_signal=add_item_form_is_visible_signal
+                    parent_id=None
+                    state=state
+                />
+            </Show>
+
+            <Show
+                when=move || !state.menu_signal.get().items.is_empty()
+                fallback=|| view! {
+                    <p>{t!(main.this_menu_has_no_items_yet)}</p>
+                }
+            >
+                {move || view! {
+                    <TapMenuItemsList
+                        depth=1
+                        items=state.menu_signal.get().items
+                        parent_id=None
+                        state=state
+                    />
+                }}
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn TapMenuItemsAddForm(
+    form_is_visible_signal: RwSignal<bool>,
+    parent_id: Option<MenuItemId>,
+    state: TapMenuItemsState
+) -> impl IntoView {
+    let form = MenuItemForm::init_empty();
+    let parent_id = store_value(parent_id);
+
+    let on_click_submit_button = move |_| {
+        state.waiting_response_signal.set(true);
+
+        spawn_local(async move {
+            let dto = DtoAddMenuItem {
+                form: form.get_value().get_dto(),
+                menu_id: state.menu_id.get_value().value().to_string(),
+                parent_id: parent_id
+                    .get_value()
+                    .map(|parent_id| parent_id.value().to_string()),
+            };
+            let server_response_add = menu_api::add_item(dto).await;
+
+            manage_response(
+                server_response_add,
+                move |_response| {
+                    form_is_visible_signal.set(false);
+                    state.server_error_signal.set(None);
+                    state.reload_menu();
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    state.server_error_signal.set(Some(server_error));
+                    state.waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <TapMenuItemsForm
+            form=form
+            on_click_cancel_button=Box::new(move |_| form_is_visible_signal.set(false))
+            on_click_submit_button=Box::new(on_click_submit_button)
+            state=state
+        />
+    }
+}
+
+#[component]
+fn TapMenuItemsEditForm(
+    form_is_visible_signal: RwSignal<bool>,
+    item: MenuItem,
+    state: TapMenuItemsState
+) -> impl IntoView {
+    let item_id = store_value(item.id.clone());
+    let form = MenuItemForm::init_filled(item);
+
+    let on_click_submit_button = move |_| {
+        state.waiting_response_signal.set(true);
+
+        spawn_local(async move {
+            let dto = DtoEditMenuItem {
+                form: form.get_value().get_dto(),
+                item_id: item_id.get_value().value().to_string(),
+                menu_id: state.menu_id.get_value().value().to_string(),
+            };
+            let server_response_edit = menu_api::edit_item(dto).await;
+
+            manage_response(
+                server_response_edit,
+                move |_response| {
+                    form_is_visible_signal.set(false);
+                    state.server_error_signal.set(None);
+                    state.reload_menu();
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    state.server_error_signal.set(Some(server_error));
+                    state.waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <TapMenuItemsForm
+            form=form
+            on_click_cancel_button=Box::new(move |_| form_is_visible_signal.set(false))
+            on_click_submit_button=Box::new(on_click_submit_button)
+            state=state
+        />
+    }
+}
+
+#[component]
+fn TapMenuItemsElement(
+    depth: usize,
+    item: MenuItem,
+    parent_id: Option<MenuItemId>,
+    sibling_ids: Vec<MenuItemId>,
+    state: TapMenuItemsState
+) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let add_subitem_form_is_visible_signal = create_rw_signal(false);
+    let edit_form_is_visible_signal = create_rw_signal(false);
+
+    let stored_item = store_value(item.clone());
+    let parent_id = store_value(parent_id);
+    let sibling_ids = store_value(sibling_ids);
+    let item_index = sibling_ids
+        .get_value()
+        .iter()
+        .position(|sibling_id| sibling_id == &item.id)
+        .unwrap_or_default();
+    let is_first = item_index == 0;
+    let is_last = item_index + 1 == sibling_ids.get_value().len();
+    let subitems_are_allowed = depth < MENU_ITEM_MAX_DEPTH;
+
+    let move_item = move |new_index: usize| {
+        // Only one request at a time
+        if state.waiting_response_signal.get_untracked() {
+            return;
+        }
+        state.waiting_response_signal.set(true);
+
+        let mut item_ids = sibling_ids.get_value();
+        let moved_item_id = item_ids.remove(item_index);
+        item_ids.insert(new_index, moved_item_id);
+
+        spawn_local(async move {
+            let dto = DtoReorderMenuItems {
+                item_ids: item_ids
+                    .iter()
+                    .map(|item_id| item_id.value().to_string())
+                    .collect(),
+                menu_id: state.menu_id.get_value().value().to_string(),
+                parent_id: parent_id
+                    .get_value()
+                    .map(|parent_id| parent_id.value().to_string()),
+            };
+            let server_response_reorder = menu_api::reorder_items(dto).await;
+
+            manage_response(
+                server_response_reorder,
+                move |_response| {
+                    state.reload_menu();
+                },
+                move |server_error| {
+                    state.server_error_signal.set(Some(server_error));
+                    state.waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    let on_click_delete_button = move |_| {
+        state.waiting_response_signal.set(true);
+
+        spawn_local(async move {
+            let dto = DtoDeleteMenuItem {
+                item_id: stored_item.get_value().id.value().to_string(),
+                menu_id: state.menu_id.get_value().value().to_string(),
+            };
+            let server_response_delete = menu_api::delete_item(dto).await;
+
+            manage_response(
+                server_response_delete,
+                move |_response| {
+                    state.reload_menu();
+                },
+                move |server_error| {
+                    state.server_error_signal.set(Some(server_error));
+                    state.waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <li class="core-menu-tap_menu_items-element">
+            <div class="core-menu-tap_menu_items-element-row">
+                <div class="core-menu-tap_menu_items-element-info">
+                    <strong>
+                        {move || stored_item.get_value().label.translate(
+                            current_language_reader.get().id
+                        )}
+                    </strong>
+                    " "
+                    <span class="tag">
+                        {move || stored_item.get_value().link.link_value()}
+                    </span>
+                </div>
+
+                <div class="buttons are-small">
+                    <button
+                        class="button"
+                        disabled=is_first
+                        on:click=move |_| move_item(item_index - 1)
+                        title=t!(main.move_up)
+                    >
+                        "↑"
+                    </button>
+                    <button
+                        class="button"
+                        disabled=is_last
+                        on:click=move |_| move_item(item_index + 1)
+                        title=t!(main.move_down)
+                    >
+                        "↓"
+                    </button>
+                    <button
+                        class="button is-link is-light"
+                        on:click=move |_| edit_form_is_visible_signal.update(
+                            |draft| *draft = !*draft
+                        )
+                    >
+                        {t!(main.edit)}
+                    </button>
+                    <button
+                        class="button is-link is-light"
+                        class=("is-hidden", !subitems_are_allowed)
+                        on:click=move |_| add_subitem_form_is_visible_signal.update(
+                            |draft| *draft = !*draft
+                        )
+                    >
+                        {t!(main.add_subitem)}
+                    </button>
+                    <button
+                        class="button is-danger is-light"
+                        on:click=on_click_delete_button
+                    >
+                        {t!(main.delete)}
+                    </button>
+                </div>
+            </div>
+
+            <Show when=move || edit_form_is_visible_signal.get()>
+                <TapMenuItemsEditForm
+                    form_is_visible_signal=edit_form_is_visible_signal
+                    item=stored_item.get_value()
+                    state=state
+                />
+            </Show>
+
+            <Show when=move || add_subitem_form_is_visible_signal.get()>
+                <TapMenuItemsAddForm
+                    form_is_visible_signal=add_subitem_form_is_visible_signal
+                    parent_id=Some(stored_item.get_value().id)
+                    state=state
+                />
+            </Show>
+
+            <Show when=move || !stored_item.get_value().children.is_empty()>
+                {move || view! {
+                    <TapMenuItemsList
+                        depth=depth + 1
+                        items=stored_item.get_value().children
+                        parent_id=Some(stored_item.get_value().id)
+                        state=state
+                    />
+                }.into_view()}
+            </Show>
+        </li>
+    }
+}
+
+#[component]
+fn TapMenuItemsForm(
+    form: StoredValue<MenuItemForm>,
+    on_click_cancel_button: Box<dyn Fn(MouseEvent)>,
+    on_click_submit_button: Box<dyn Fn(MouseEvent)>,
+    state: TapMenuItemsState
+) -> impl IntoView {
+    let link_type_options = move || {
+        vec![
+            (MENU_ITEM_LINK_TYPE_ROUTE.to_string(), t!(main.route)()),
+            (MENU_ITEM_LINK_TYPE_GALLERY.to_string(), t!(main.gallery)()),
+            (
+                MENU_ITEM_LINK_TYPE_EXTERNAL_URL.to_string(),
+                t!(main.external_url)(),
+            )
+        ]
+    };
+
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+    let gallery_options = move || {
+        let mut options = vec![("".to_string(), "-".to_string())];
+        if
+            let Some(Ok(ServerResponse::Response(galleries))) =
+                state.galleries_resource.get()
+        {
+            options.extend(
+                galleries.into_iter().map(|gallery| {
+                    (
+                        gallery.id.value().to_string(),
+                        gallery.name.translate(current_language_reader.get().id),
+                    )
+                })
+            );
+        }
+        options
+    };
+
+    let link_is_gallery = move || {
+        form.get_value().link_type.get_value().signal.get() ==
+            MENU_ITEM_LINK_TYPE_GALLERY
+    };
+
+    view! {
+        <div class="core-menu-tap_menu_items-form">
+            <TapFormI18nTextField
+                name=Box::new(|| t!(main.label)())
+                required=true
+                value=form.get_value().label
+            />
+
+            <TapFormSelectField
+                name=Box::new(|| t!(main.link_type)())
+                options=Box::new(link_type_options)
+                required=true
+                value=form.get_value().link_type
+            />
+
+            <Show
+                when=link_is_gallery
+                fallback=move || view! {
+                    <TapFormTextField
+                        name=Box::new(|| t!(main.link)())
+                        required=true
+                        value=form.get_value().link_value
+                    />
+                }
+            >
+                <TapFormSelectField
+                    name=Box::new(|| t!(main.gallery)())
+                    options=Box::new(gallery_options)
+                    required=true
+                    value=form.get_value().link_value
+                />
+            </Show>
+
+            <div class="field is-grouped">
+                <div class="control">
+                    <button
+                        class="button is-link"
+                        class=("is-loading", state.waiting_response_signal)
+                        on:click=on_click_submit_button
+                    >
+                        {t!(main.submit)}
+                    </button>
+                </div>
+                <div class="control">
+                    <button
+                        class="button is-link is-light"
+                        on:click=on_click_cancel_button
+                    >
+                        {t!(main.cancel)}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+#[component]
+fn TapMenuItemsList(
+    depth: usize,
+    items: Vec<MenuItem>,
+    parent_id: Option<MenuItemId>,
+    state: TapMenuItemsState
+) -> impl IntoView {
+    let sibling_ids: Vec<MenuItemId> = items
+        .iter()
+        .map(|item| item.id.clone())
+        .collect();
+    let sibling_ids = store_value(sibling_ids);
+    let parent_id = store_value(parent_id);
+
+    view! {
+        <ul class="core-menu-tap_menu_items-list">
+            <For
+                children=move |item: MenuItem| {
+                    view! {
+                        <TapMenuItemsElement
+                            depth=depth
+                            item=item
+                            parent_id=parent_id.get_value()
+                            sibling_ids=sibling_ids.get_value()
+                            state=state
+                        />
+                    }
+                }
+                each=move || items.clone()
+                key=|item| item.id.value().to_string()
+            />
+        </ul>
+    }
+}
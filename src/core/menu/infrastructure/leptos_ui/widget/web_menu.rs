@@ -0,0 +1,59 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::component;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_domain::core::menu::model::MenuItem;
+
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::use_global_context;
+
+// Renders the items as <li> elements, so the parent decides the <ul> styles
+#[component]
+pub fn WebMenuItems(items: Vec<MenuItem>) -> impl IntoView {
+    view! {
+        <For
+            children=move |item: MenuItem| {
+                view! { <WebMenuItemsElement item=item /> }
+            }
+            each=move || items.clone()
+            key=|item| item.id.value().to_string()
+        />
+    }
+}
+
+#[component]
+fn WebMenuItemsElement(item: MenuItem) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let item = store_value(item);
+
+    view! {
+        <li class="core-menu-web_menu-item">
+            <a
+                href=move || navigation::path_menu_item_link(
+                    &current_language_reader.get().code,
+                    &item.get_value().link
+                )
+            >
+                {move || item.get_value().label.translate(
+                    current_language_reader.get().id
+                )}
+            </a>
+
+            <Show when=move || !item.get_value().children.is_empty()>
+                <ul class="core-menu-web_menu-submenu">
+                    {move || view! {
+                        <WebMenuItems items=item.get_value().children />
+                    }.into_view()}
+                </ul>
+            </Show>
+        </li>
+    }
+}
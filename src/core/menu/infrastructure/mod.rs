@@ -0,0 +1,6 @@
+pub mod leptos_actix_server;
+
+#[cfg(feature = "ssr")]
+pub mod diesel_orm;
+
+pub mod leptos_ui;
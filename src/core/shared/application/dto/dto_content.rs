@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+use tukosmo_domain::core::shared::model::ContentImportMode;
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum DtoContentImportAction {
+    Created,
+    Skipped,
+    Unchanged,
+    Updated,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoContentImportChange {
+    pub action: DtoContentImportAction,
+    pub kind: DtoContentKind,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoContentImportReport {
+    pub changes: Vec<DtoContentImportChange>,
+    // Nothing was written; the changes are the ones an import would make
+    pub dry_run: bool,
+    pub mode: ContentImportMode,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum DtoContentKind {
+    Gallery,
+    Language,
+    Menu,
+    Widget,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoImportContent {
+    pub content_json: String,
+    pub dry_run: bool,
+    pub mode: String,
+}
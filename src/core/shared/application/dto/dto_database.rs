@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use serde::Serialize;
+use tukosmo_domain::core::shared::model::DatabaseMigration;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoDatabaseStatus {
+    pub migrations: Vec<DatabaseMigration>,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::DtoUploadedFile;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoUploadFavicon {
+    pub files: Vec<DtoUploadedFile>,
+}
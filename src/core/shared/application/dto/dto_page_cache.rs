@@ -0,0 +1,12 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoPageCacheStats {
+    pub enabled: bool,
+    pub entries: usize,
+    pub hits: u64,
+    pub max_size_bytes: u64,
+    pub misses: u64,
+    pub size_bytes: u64,
+}
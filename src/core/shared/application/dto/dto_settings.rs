@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoChangeDomain {
+    pub domain: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoDomain {
+    pub domain: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoEditServerSettings {
+    pub form: DtoServerSettingsForm,
+}
+
+// The database password is never sent to the browser, so an empty one keeps
+// the current password
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoServerSettingsForm {
+    pub admin_email: String,
+    pub database_host: String,
+    pub database_name: String,
+    pub database_password: String,
+    pub database_port: String,
+    pub database_sslmode: String,
+    pub database_user: String,
+    pub http_port: String,
+    pub https_port: String,
+    pub mode: String,
+}
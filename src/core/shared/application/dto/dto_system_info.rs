@@ -0,0 +1,23 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoSystemInfo {
+    pub booted_at: DateTime<Utc>,
+    pub cpu_cores: u32,
+    pub cpu_model: String,
+    pub cpu_usage_percentage: f64,
+    pub database_size_bytes: u64,
+    pub database_version: String,
+    // Of the file system where the data dir is
+    pub disk_total_bytes: u64,
+    pub disk_used_bytes: u64,
+    pub kernel_version: String,
+    pub memory_total_bytes: u64,
+    pub memory_used_bytes: u64,
+    pub os_name: String,
+    pub tukosmo_version: String,
+    pub uptime_seconds: u64,
+}
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoTlsCertificate {
+    pub days_until_expiration: i32,
+    pub expires_at: String,
+    pub is_self_signed: bool,
+    pub issued_at: String,
+    pub issuer: String,
+    pub subject_alt_names: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoTlsStatus {
+    // None when TLS is off, or when the certificate can't be read
+    pub certificate: Option<DtoTlsCertificate>,
+    pub certificate_error_code: Option<String>,
+    pub expiration_warning_sent_at: Option<String>,
+    pub last_renewal_attempt_at: Option<String>,
+    pub last_renewal_error_code: Option<String>,
+    pub mode: String,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoUploadedFile {
+    pub content: Vec<u8>,
+    pub original_name: String,
+}
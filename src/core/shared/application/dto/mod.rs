@@ -1,8 +1,32 @@
+mod dto_content;
+pub use dto_content::*;
+
+mod dto_database;
+pub use dto_database::*;
+
 mod dto_delete_form;
 pub use dto_delete_form::*;
 
+mod dto_favicon;
+pub use dto_favicon::*;
+
 mod dto_initial_data;
 pub use dto_initial_data::*;
 
 mod dto_local_i18n;
 pub use dto_local_i18n::*;
+
+mod dto_page_cache;
+pub use dto_page_cache::*;
+
+mod dto_settings;
+pub use dto_settings::*;
+
+mod dto_system_info;
+pub use dto_system_info::*;
+
+mod dto_tls;
+pub use dto_tls::*;
+
+mod dto_uploaded_file;
+pub use dto_uploaded_file::*;
@@ -0,0 +1,739 @@
+use chrono::Utc;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use uuid::Uuid;
+use tukosmo_domain::core::gallery::model::Gallery;
+use tukosmo_domain::core::gallery::model::GalleryId;
+use tukosmo_domain::core::gallery::model::GalleryImage;
+use tukosmo_domain::core::gallery::model::GallerySearchCriteria;
+use tukosmo_domain::core::gallery::repository::GalleryRepository;
+use tukosmo_domain::core::language::model::I18nText;
+use tukosmo_domain::core::language::model::I18nTextValue;
+use tukosmo_domain::core::language::model::Language;
+use tukosmo_domain::core::language::model::LanguageId;
+use tukosmo_domain::core::language::model::LanguageSearchCriteria;
+use tukosmo_domain::core::language::repository::LanguageRepository;
+use tukosmo_domain::core::menu::error as error_menu;
+use tukosmo_domain::core::menu::model::MENU_ITEM_MAX_DEPTH;
+use tukosmo_domain::core::menu::model::Menu;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuItem;
+use tukosmo_domain::core::menu::model::MenuItemLink;
+use tukosmo_domain::core::menu::model::MenuLocation;
+use tukosmo_domain::core::menu::model::MenuSearchCriteria;
+use tukosmo_domain::core::menu::repository::MenuRepository;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::CONTENT_EXPORT_FORMAT_VERSION;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
+use tukosmo_domain::core::shared::model::ContentExport;
+use tukosmo_domain::core::shared::model::ContentExportGallery;
+use tukosmo_domain::core::shared::model::ContentExportGalleryImage;
+use tukosmo_domain::core::shared::model::ContentExportI18nText;
+use tukosmo_domain::core::shared::model::ContentExportLanguage;
+use tukosmo_domain::core::shared::model::ContentExportMenu;
+use tukosmo_domain::core::shared::model::ContentExportMenuItem;
+use tukosmo_domain::core::shared::model::ContentExportWidget;
+use tukosmo_domain::core::shared::model::ContentImportMode;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::TransactionExecutor;
+use tukosmo_domain::core::shared::repository::PageCacheRepository;
+use tukosmo_domain::core::widget::model::WidgetArea;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+use tukosmo_domain::core::widget::model::WidgetInstanceId;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchCriteria;
+use tukosmo_domain::core::widget::repository::WidgetInstanceRepository;
+
+use crate::core::shared::service::run_transaction;
+use super::super::dto::DtoContentImportAction;
+use super::super::dto::DtoContentImportChange;
+use super::super::dto::DtoContentImportReport;
+use super::super::dto::DtoContentKind;
+use super::super::dto::DtoImportContent;
+
+pub struct ContentUseCase {
+    page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+    transaction_executor: Rc<RefCell<dyn TransactionExecutor>>,
+}
+
+// Keeps the state shared by all the steps of an import
+struct ContentImport {
+    changes: Vec<DtoContentImportChange>,
+    dry_run: bool,
+    // Language code => ID in this website
+    language_ids: HashMap<String, Uuid>,
+    mode: ContentImportMode,
+}
+
+impl ContentUseCase {
+    pub fn export(&self) -> Result<ContentExport, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let languages = transaction.language_repository
+                .borrow_mut()
+                .find(LanguageSearchCriteria::all_ordered())?;
+            let galleries = transaction.gallery_repository
+                .borrow_mut()
+                .find(GallerySearchCriteria::all())?;
+            let menus = transaction.menu_repository
+                .borrow_mut()
+                .find(MenuSearchCriteria::all())?;
+            let widgets = transaction.widget_instance_repository
+                .borrow_mut()
+                .find(WidgetInstanceSearchCriteria::all())?;
+
+            let language_codes: HashMap<Uuid, String> = languages
+                .iter()
+                .map(|l| (l.id.value().clone(), l.code.value().to_string()))
+                .collect();
+
+            Ok(ContentExport {
+                exported_at: Utc::now(),
+                format_version: CONTENT_EXPORT_FORMAT_VERSION,
+                galleries: galleries
+                    .iter()
+                    .map(|g| Self::export_gallery(g, &language_codes))
+                    .collect(),
+                languages: languages
+                    .iter()
+                    .map(|l| Self::export_language(l, &language_codes))
+                    .collect(),
+                menus: menus
+                    .iter()
+                    .map(|m| Self::export_menu(m, &language_codes))
+                    .collect(),
+                widgets: widgets
+                    .iter()
+                    .map(|w| Self::export_widget(w, &language_codes))
+                    .collect(),
+            })
+        })
+    }
+
+    fn export_gallery(
+        gallery: &Gallery,
+        language_codes: &HashMap<Uuid, String>
+    ) -> ContentExportGallery {
+        let mut images: Vec<&GalleryImage> = gallery.images.iter().collect();
+        images.sort_by_key(|image| *image.position.value());
+
+        ContentExportGallery {
+            id: gallery.id.value().clone(),
+            images: images
+                .into_iter()
+                .map(|image| ContentExportGalleryImage {
+                    caption: Self::export_i18n_text(
+                        &image.caption,
+                        language_codes
+                    ),
+                    file_name: image.file_name.value().to_string(),
+                })
+                .collect(),
+            name: Self::export_i18n_text(&gallery.name, language_codes),
+        }
+    }
+
+    fn export_i18n_text(
+        i18n_text: &I18nText,
+        language_codes: &HashMap<Uuid, String>
+    ) -> ContentExportI18nText {
+        ContentExportI18nText {
+            default_text: i18n_text.default_text.value().to_string(),
+            translations: i18n_text.translations
+                .iter()
+                .filter_map(|t| {
+                    language_codes
+                        .get(t.language_id.value())
+                        .map(|code| (code.clone(), t.text.value().to_string()))
+                })
+                .collect(),
+        }
+    }
+
+    fn export_language(
+        language: &Language,
+        language_codes: &HashMap<Uuid, String>
+    ) -> ContentExportLanguage {
+        ContentExportLanguage {
+            code: language.code.value().to_string(),
+            name: Self::export_i18n_text(&language.name, language_codes),
+            original_name: language.original_name.value().to_string(),
+            website_subtitle: language.website_subtitle.value().to_string(),
+            website_title: language.website_title.value().to_string(),
+        }
+    }
+
+    fn export_menu(
+        menu: &Menu,
+        language_codes: &HashMap<Uuid, String>
+    ) -> ContentExportMenu {
+        ContentExportMenu {
+            id: menu.id.value().clone(),
+            items: Self::export_menu_items(&menu.items, language_codes),
+            location: menu.location
+                .as_ref()
+                .map(|location| location.value().to_string()),
+            name: Self::export_i18n_text(&menu.name, language_codes),
+        }
+    }
+
+    fn export_menu_items(
+        items: &Vec<MenuItem>,
+        language_codes: &HashMap<Uuid, String>
+    ) -> Vec<ContentExportMenuItem> {
+        let mut items: Vec<&MenuItem> = items.iter().collect();
+        items.sort_by_key(|item| *item.position.value());
+
+        items
+            .into_iter()
+            .map(|item| ContentExportMenuItem {
+                children: Self::export_menu_items(
+                    &item.children,
+                    language_codes
+                ),
+                label: Self::export_i18n_text(&item.label, language_codes),
+                link_type: item.link.link_type().to_string(),
+                link_value: item.link.link_value(),
+            })
+            .collect()
+    }
+
+    fn export_widget(
+        widget: &WidgetInstance,
+        language_codes: &HashMap<Uuid, String>
+    ) -> ContentExportWidget {
+        ContentExportWidget {
+            area: widget.area.value().to_string(),
+            code: widget.code.value().to_string(),
+            content: Self::export_i18n_text(&widget.content, language_codes),
+            id: widget.id.value().clone(),
+            language_codes: widget.language_ids
+                .iter()
+                .filter_map(|language_id| {
+                    language_codes.get(language_id.value()).cloned()
+                })
+                .collect(),
+            limit: *widget.limit.value(),
+            position: *widget.position.value(),
+            title: Self::export_i18n_text(&widget.title, language_codes),
+        }
+    }
+
+    // Languages are matched by code, and the rest of the content by ID, so
+    // importing the same file twice doesn't duplicate anything. Nothing is
+    // ever deleted, and a dry run goes through the same steps without writing
+    pub fn import(
+        &self,
+        dto: DtoImportContent
+    ) -> Result<DtoContentImportReport, DomainError> {
+        let mode = ContentImportMode::from_code(&dto.mode)?;
+        let content: ContentExport = serde_json
+            ::from_str(&dto.content_json)
+            .map_err(|_e| error::INVALID_CONTENT_EXPORT)?;
+
+        if content.format_version > CONTENT_EXPORT_FORMAT_VERSION {
+            return Err(error::CONTENT_EXPORT_IS_NEWER_THAN_BINARY);
+        }
+        if content.format_version < 1 {
+            return Err(error::INVALID_CONTENT_EXPORT);
+        }
+
+        let transaction_executor = self.transaction_executor.borrow_mut();
+        let report = run_transaction(transaction_executor, |transaction| {
+            let mut content_import = ContentImport {
+                changes: vec![],
+                dry_run: dto.dry_run,
+                language_ids: HashMap::new(),
+                mode,
+            };
+
+            content_import.import_languages(
+                transaction.language_repository.borrow_mut().deref_mut(),
+                &content.languages
+            )?;
+            content_import.import_galleries(
+                transaction.gallery_repository.borrow_mut().deref_mut(),
+                &content.galleries
+            )?;
+            content_import.import_menus(
+                transaction.menu_repository.borrow_mut().deref_mut(),
+                &content.menus
+            )?;
+            content_import.import_widgets(
+                transaction.widget_instance_repository
+                    .borrow_mut()
+                    .deref_mut(),
+                &content.widgets
+            )?;
+
+            Ok(DtoContentImportReport {
+                changes: content_import.changes,
+                dry_run: dto.dry_run,
+                mode,
+            })
+        })?;
+
+        if !report.dry_run && !report.changes.is_empty() {
+            self.page_cache_repository
+                .borrow_mut()
+                .invalidate(ContentChangeEvent::AllPages);
+        }
+
+        Ok(report)
+    }
+
+    pub fn init(
+        page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+        transaction_executor: Rc<RefCell<dyn TransactionExecutor>>
+    ) -> Self {
+        Self { page_cache_repository, transaction_executor }
+    }
+}
+
+impl ContentImport {
+    fn add_change(
+        &mut self,
+        kind: DtoContentKind,
+        name: String,
+        action: DtoContentImportAction
+    ) {
+        self.changes.push(DtoContentImportChange { action, kind, name });
+    }
+
+    fn check_menu_location_is_free(
+        menu_repository: &mut dyn MenuRepository,
+        menu: &Menu
+    ) -> Result<(), DomainError> {
+        if let Some(location) = &menu.location {
+            let menus_in_location = menu_repository.count(
+                MenuSearchCriteria::has_location_and_not_id(
+                    location.clone(),
+                    menu.id.clone()
+                ).filter
+            )?;
+            if menus_in_location > 0 {
+                return Err(error_menu::MENU_LOCATION_IS_ALREADY_IN_USE);
+            }
+        }
+        Ok(())
+    }
+
+    // Existing content that differs from the imported one is only replaced
+    // in the overwrite mode
+    fn get_existing_action(
+        &self,
+        is_unchanged: bool
+    ) -> DtoContentImportAction {
+        if is_unchanged {
+            DtoContentImportAction::Unchanged
+        } else {
+            match self.mode {
+                ContentImportMode::Merge => DtoContentImportAction::Skipped,
+                ContentImportMode::Overwrite => DtoContentImportAction::Updated,
+            }
+        }
+    }
+
+    fn get_i18n_text_value(
+        &self,
+        i18n_text: &ContentExportI18nText
+    ) -> Result<I18nTextValue, DomainError> {
+        let has_unknown_language = i18n_text.translations
+            .keys()
+            .any(|code| !self.language_ids.contains_key(code));
+        if has_unknown_language {
+            return Err(error::INVALID_CONTENT_EXPORT);
+        }
+
+        // Languages without a translation get an empty text, so translations
+        // that don't exist in the import are removed
+        Ok(I18nTextValue {
+            default_text: i18n_text.default_text.clone(),
+            translations: self.language_ids
+                .iter()
+                .map(|(code, language_id)| {
+                    (
+                        language_id.clone(),
+                        i18n_text.translations
+                            .get(code)
+                            .cloned()
+                            .unwrap_or_default(),
+                    )
+                })
+                .collect(),
+        })
+    }
+
+    fn get_language_codes(&self) -> HashMap<Uuid, String> {
+        self.language_ids
+            .iter()
+            .map(|(code, language_id)| (language_id.clone(), code.clone()))
+            .collect()
+    }
+
+    fn get_language_ids(
+        &self,
+        language_codes: &Vec<String>
+    ) -> Result<Vec<LanguageId>, DomainError> {
+        language_codes
+            .iter()
+            .map(|code| {
+                self.language_ids
+                    .get(code)
+                    .map(|language_id| LanguageId::from(language_id.clone()))
+                    .ok_or(error::INVALID_CONTENT_EXPORT)
+            })
+            .collect()
+    }
+
+    fn import_galleries(
+        &mut self,
+        gallery_repository: &mut dyn GalleryRepository,
+        galleries: &Vec<ContentExportGallery>
+    ) -> Result<(), DomainError> {
+        let language_codes = self.get_language_codes();
+
+        for exported_gallery in galleries {
+            let gallery_id = GalleryId::from(exported_gallery.id.clone());
+            let name_value = self.get_i18n_text_value(&exported_gallery.name)?;
+            let images = self.new_gallery_images(&exported_gallery.images)?;
+            let name = exported_gallery.name.default_text.clone();
+
+            let existing_gallery = gallery_repository
+                .find(GallerySearchCriteria::has_id(gallery_id.clone()))?
+                .into_iter()
+                .next();
+
+            let action = match existing_gallery {
+                Some(mut gallery) => {
+                    let is_unchanged =
+                        &ContentUseCase::export_gallery(
+                            &gallery,
+                            &language_codes
+                        ) == exported_gallery;
+                    let action = self.get_existing_action(is_unchanged);
+                    if action == DtoContentImportAction::Updated {
+                        gallery.modify(name_value)?;
+                        gallery.images = images;
+                        if !self.dry_run {
+                            gallery_repository.update(gallery)?;
+                        }
+                    }
+                    action
+                }
+                None => {
+                    let mut gallery = Gallery::new(name_value)?;
+                    gallery.id = gallery_id;
+                    gallery.images = images;
+                    if !self.dry_run {
+                        gallery_repository.add(gallery)?;
+                    }
+                    DtoContentImportAction::Created
+                }
+            };
+
+            self.add_change(DtoContentKind::Gallery, name, action);
+        }
+
+        Ok(())
+    }
+
+    // New languages are added before setting their names, since a name can
+    // be translated into another language of the same import
+    fn import_languages(
+        &mut self,
+        language_repository: &mut dyn LanguageRepository,
+        languages: &Vec<ContentExportLanguage>
+    ) -> Result<(), DomainError> {
+        for (index, exported_language) in languages.iter().enumerate() {
+            let is_duplicated = languages[..index]
+                .iter()
+                .any(|l| l.code == exported_language.code);
+            if is_duplicated {
+                return Err(error::INVALID_CONTENT_EXPORT);
+            }
+        }
+
+        let existing_languages = language_repository.find(
+            LanguageSearchCriteria::all()
+        )?;
+        for language in &existing_languages {
+            self.language_ids.insert(
+                language.code.value().to_string(),
+                language.id.value().clone()
+            );
+        }
+
+        let mut new_languages: Vec<Language> = vec![];
+        for exported_language in languages {
+            if self.language_ids.contains_key(&exported_language.code) {
+                continue;
+            }
+
+            let language = Language::new(
+                exported_language.code.clone(),
+                I18nTextValue {
+                    default_text: exported_language.name.default_text.clone(),
+                    translations: vec![],
+                },
+                exported_language.original_name.clone(),
+                exported_language.website_title.clone(),
+                exported_language.website_subtitle.clone()
+            )?;
+            self.language_ids.insert(
+                exported_language.code.clone(),
+                language.id.value().clone()
+            );
+            if !self.dry_run {
+                language_repository.add(language.clone())?;
+            }
+            new_languages.push(language);
+        }
+
+        let language_codes = self.get_language_codes();
+
+        for exported_language in languages {
+            let name_value = self.get_i18n_text_value(&exported_language.name)?;
+            let name = exported_language.original_name.clone();
+
+            let new_language = new_languages
+                .iter_mut()
+                .find(|l| l.code.value() == exported_language.code);
+
+            let action = match new_language {
+                Some(language) => {
+                    language.modify(
+                        exported_language.code.clone(),
+                        name_value,
+                        exported_language.original_name.clone(),
+                        exported_language.website_title.clone(),
+                        exported_language.website_subtitle.clone()
+                    )?;
+                    if !self.dry_run {
+                        language_repository.update(language.clone())?;
+                    }
+                    DtoContentImportAction::Created
+                }
+                None => {
+                    let mut language = existing_languages
+                        .iter()
+                        .find(|l| l.code.value() == exported_language.code)
+                        .cloned()
+                        .ok_or(error::INVALID_CONTENT_EXPORT)?;
+                    let is_unchanged =
+                        &ContentUseCase::export_language(
+                            &language,
+                            &language_codes
+                        ) == exported_language;
+                    let action = self.get_existing_action(is_unchanged);
+                    if action == DtoContentImportAction::Updated {
+                        language.modify(
+                            exported_language.code.clone(),
+                            name_value,
+                            exported_language.original_name.clone(),
+                            exported_language.website_title.clone(),
+                            exported_language.website_subtitle.clone()
+                        )?;
+                        if !self.dry_run {
+                            language_repository.update(language)?;
+                        }
+                    }
+                    action
+                }
+            };
+
+            self.add_change(DtoContentKind::Language, name, action);
+        }
+
+        Ok(())
+    }
+
+    fn import_menus(
+        &mut self,
+        menu_repository: &mut dyn MenuRepository,
+        menus: &Vec<ContentExportMenu>
+    ) -> Result<(), DomainError> {
+        let language_codes = self.get_language_codes();
+
+        for exported_menu in menus {
+            let menu_id = MenuId::from(exported_menu.id.clone());
+            let location = match &exported_menu.location {
+                Some(location_code) =>
+                    Some(MenuLocation::from_code(location_code)?),
+                None => None,
+            };
+            let name_value = self.get_i18n_text_value(&exported_menu.name)?;
+            let items = self.new_menu_items(&exported_menu.items, 1)?;
+            let name = exported_menu.name.default_text.clone();
+
+            let existing_menu = menu_repository
+                .find(MenuSearchCriteria::has_id(menu_id.clone()))?
+                .into_iter()
+                .next();
+
+            let action = match existing_menu {
+                Some(mut menu) => {
+                    let is_unchanged =
+                        &ContentUseCase::export_menu(&menu, &language_codes) ==
+                        exported_menu;
+                    let action = self.get_existing_action(is_unchanged);
+                    if action == DtoContentImportAction::Updated {
+                        menu.modify(name_value, location)?;
+                        menu.items = items;
+                        Self::check_menu_location_is_free(
+                            menu_repository,
+                            &menu
+                        )?;
+                        if !self.dry_run {
+                            menu_repository.update(menu)?;
+                        }
+                    }
+                    action
+                }
+                None => {
+                    let mut menu = Menu::new(name_value, location)?;
+                    menu.id = menu_id;
+                    menu.items = items;
+                    Self::check_menu_location_is_free(menu_repository, &menu)?;
+                    if !self.dry_run {
+                        menu_repository.add(menu)?;
+                    }
+                    DtoContentImportAction::Created
+                }
+            };
+
+            self.add_change(DtoContentKind::Menu, name, action);
+        }
+
+        Ok(())
+    }
+
+    fn import_widgets(
+        &mut self,
+        widget_instance_repository: &mut dyn WidgetInstanceRepository,
+        widgets: &Vec<ContentExportWidget>
+    ) -> Result<(), DomainError> {
+        let language_codes = self.get_language_codes();
+
+        for exported_widget in widgets {
+            let widget_instance_id = WidgetInstanceId::from(
+                exported_widget.id.clone()
+            );
+            let area = WidgetArea::from_code(&exported_widget.area)?;
+            let content_value = self.get_i18n_text_value(
+                &exported_widget.content
+            )?;
+            let title_value = self.get_i18n_text_value(&exported_widget.title)?;
+            let language_ids = self.get_language_ids(
+                &exported_widget.language_codes
+            )?;
+            let limit_value = exported_widget.limit.to_string();
+            let name = if exported_widget.title.default_text.is_empty() {
+                exported_widget.code.clone()
+            } else {
+                exported_widget.title.default_text.clone()
+            };
+
+            let existing_widget = widget_instance_repository
+                .find(
+                    WidgetInstanceSearchCriteria::has_id(
+                        widget_instance_id.clone()
+                    )
+                )?
+                .into_iter()
+                .next();
+
+            let action = match existing_widget {
+                Some(mut widget) => {
+                    let is_unchanged =
+                        &ContentUseCase::export_widget(
+                            &widget,
+                            &language_codes
+                        ) == exported_widget;
+                    let action = self.get_existing_action(is_unchanged);
+                    if action == DtoContentImportAction::Updated {
+                        widget.modify(
+                            area,
+                            exported_widget.code.clone(),
+                            content_value,
+                            language_ids,
+                            &limit_value,
+                            title_value
+                        )?;
+                        widget.move_to(exported_widget.position);
+                        if !self.dry_run {
+                            widget_instance_repository.update(widget)?;
+                        }
+                    }
+                    action
+                }
+                None => {
+                    let mut widget = WidgetInstance::new(
+                        area,
+                        exported_widget.code.clone(),
+                        content_value,
+                        language_ids,
+                        &limit_value,
+                        exported_widget.position,
+                        title_value
+                    )?;
+                    widget.id = widget_instance_id;
+                    if !self.dry_run {
+                        widget_instance_repository.add(widget)?;
+                    }
+                    DtoContentImportAction::Created
+                }
+            };
+
+            self.add_change(DtoContentKind::Widget, name, action);
+        }
+
+        Ok(())
+    }
+
+    fn new_gallery_images(
+        &self,
+        images: &Vec<ContentExportGalleryImage>
+    ) -> Result<Vec<GalleryImage>, DomainError> {
+        let mut gallery_images = vec![];
+        for (position, image) in images.iter().enumerate() {
+            gallery_images.push(
+                GalleryImage::new(
+                    image.file_name.clone(),
+                    self.get_i18n_text_value(&image.caption)?,
+                    position as i32
+                )?
+            );
+        }
+        Ok(gallery_images)
+    }
+
+    fn new_menu_items(
+        &self,
+        items: &Vec<ContentExportMenuItem>,
+        depth: usize
+    ) -> Result<Vec<MenuItem>, DomainError> {
+        if !items.is_empty() && depth > MENU_ITEM_MAX_DEPTH {
+            return Err(error_menu::MENU_ITEM_MAX_DEPTH_EXCEEDED);
+        }
+
+        let mut menu_items = vec![];
+        for (position, item) in items.iter().enumerate() {
+            let link = MenuItemLink::new(
+                &item.link_type,
+                item.link_value.clone()
+            )?;
+            let mut menu_item = MenuItem::new(
+                self.get_i18n_text_value(&item.label)?,
+                link,
+                position as i32
+            )?;
+            menu_item.children = self.new_menu_items(
+                &item.children,
+                depth + 1
+            )?;
+            menu_items.push(menu_item);
+        }
+        Ok(menu_items)
+    }
+}
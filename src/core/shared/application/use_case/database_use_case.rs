@@ -0,0 +1,24 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::repository::MigrationRepository;
+
+use super::super::dto::DtoDatabaseStatus;
+
+pub struct DatabaseUseCase {
+    migration_repository: Rc<RefCell<dyn MigrationRepository>>,
+}
+
+impl DatabaseUseCase {
+    pub fn get_status(&self) -> Result<DtoDatabaseStatus, DomainError> {
+        let migration_repository = self.migration_repository.borrow_mut();
+        let migrations = migration_repository.find_all()?;
+        Ok(DtoDatabaseStatus { migrations })
+    }
+
+    pub fn init(
+        migration_repository: Rc<RefCell<dyn MigrationRepository>>
+    ) -> Self {
+        Self { migration_repository }
+    }
+}
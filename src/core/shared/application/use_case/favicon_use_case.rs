@@ -0,0 +1,31 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::FaviconPngImage;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use super::super::dto::DtoUploadFavicon;
+
+pub struct FaviconUseCase {
+    data_repository: Rc<RefCell<dyn DataRepository>>,
+}
+
+impl FaviconUseCase {
+    pub fn init(data_repository: Rc<RefCell<dyn DataRepository>>) -> Self {
+        Self { data_repository }
+    }
+
+    pub fn upload(&self, dto: DtoUploadFavicon) -> Result<(), DomainError> {
+        let file = dto.files
+            .into_iter()
+            .next()
+            .ok_or(error::NO_FILE_UPLOADED)?;
+        let favicon_png_image = FaviconPngImage::new(file.content)?;
+
+        let data_repository = self.data_repository.borrow_mut();
+        data_repository.update_favicon(&favicon_png_image)?;
+
+        Ok(())
+    }
+}
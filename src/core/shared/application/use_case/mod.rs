@@ -1,4 +1,24 @@
+#[cfg(feature = "ssr")]
+mod content_use_case;
+#[cfg(feature = "ssr")]
+pub use content_use_case::*;
+
+#[cfg(feature = "ssr")]
+mod database_use_case;
+#[cfg(feature = "ssr")]
+pub use database_use_case::*;
+
+#[cfg(feature = "ssr")]
+mod favicon_use_case;
+#[cfg(feature = "ssr")]
+pub use favicon_use_case::*;
+
 #[cfg(feature = "ssr")]
 mod global_use_case;
 #[cfg(feature = "ssr")]
 pub use global_use_case::*;
+
+#[cfg(feature = "ssr")]
+mod settings_use_case;
+#[cfg(feature = "ssr")]
+pub use settings_use_case::*;
@@ -0,0 +1,99 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerMode;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use super::super::dto::DtoDomain;
+use super::super::dto::DtoEditServerSettings;
+use super::super::dto::DtoServerSettingsForm;
+
+pub struct SettingsUseCase {
+    data_repository: Rc<RefCell<dyn DataRepository>>,
+}
+
+impl SettingsUseCase {
+    pub fn edit_server_settings(
+        &self,
+        dto: DtoEditServerSettings
+    ) -> Result<(), DomainError> {
+        let data_repository = self.data_repository.borrow_mut();
+        let mut server_config = data_repository.get_server_config()?;
+        let form = dto.form;
+
+        server_config.admin_email = form.admin_email;
+        server_config.http_port = Self::parse_port(&form.http_port)?;
+        server_config.https_port = Self::parse_port(&form.https_port)?;
+        server_config.mode = ServerMode::from_code(&form.mode)?;
+
+        server_config.database.host = form.database_host;
+        server_config.database.name = form.database_name;
+        // password_file takes precedence over password, so it's removed to
+        // make the new password the one used
+        if !form.database_password.is_empty() {
+            server_config.database.password = form.database_password;
+            server_config.database.password_file = None;
+        }
+        server_config.database.port = form.database_port
+            .trim()
+            .parse()
+            .map_err(|_e| error::INVALID_DATABASE_PORT)?;
+        server_config.database.sslmode = form.database_sslmode;
+        server_config.database.user = form.database_user;
+
+        let database = &server_config.database;
+        let has_empty_field = [&database.host, &database.name, &database.user]
+            .iter()
+            .any(|value| value.trim().is_empty());
+        if has_empty_field {
+            return Err(error::FIELD_CANNOT_BE_EMPTY);
+        }
+
+        // The same validation as when the server starts, so the new config
+        // can't prevent it from starting again
+        let problems = server_config.validate();
+        if let Some((_key, domain_error)) = problems.into_iter().next() {
+            return Err(domain_error);
+        }
+
+        data_repository.set_server_config(&server_config)?;
+
+        Ok(())
+    }
+
+    pub fn get_domain(&self) -> Result<DtoDomain, DomainError> {
+        let data_repository = self.data_repository.borrow_mut();
+        let server_config = data_repository.get_server_config()?;
+
+        Ok(DtoDomain { domain: server_config.domain })
+    }
+
+    pub fn get_server_settings(
+        &self
+    ) -> Result<DtoServerSettingsForm, DomainError> {
+        let data_repository = self.data_repository.borrow_mut();
+        let server_config = data_repository.get_server_config()?;
+
+        Ok(DtoServerSettingsForm {
+            admin_email: server_config.admin_email,
+            database_host: server_config.database.host,
+            database_name: server_config.database.name,
+            database_password: "".to_string(),
+            database_port: server_config.database.port.to_string(),
+            database_sslmode: server_config.database.sslmode,
+            database_user: server_config.database.user,
+            http_port: server_config.http_port.to_string(),
+            https_port: server_config.https_port.to_string(),
+            mode: server_config.mode.value().to_string(),
+        })
+    }
+
+    pub fn init(data_repository: Rc<RefCell<dyn DataRepository>>) -> Self {
+        Self { data_repository }
+    }
+
+    fn parse_port(value: &str) -> Result<u16, DomainError> {
+        value.trim().parse().map_err(|_e| error::INVALID_PORT)
+    }
+}
@@ -4,6 +4,18 @@ use super::model::DomainErrorId;
 use super::model::DomainErrorVisibility;
 use super::model::ModuleName::Core;
 
+pub const BACKUP_IS_NEWER_THAN_BINARY: DomainError = get_domain_error(
+    "BACKUP_IS_NEWER_THAN_BINARY",
+    "The backup was created by a newer version of Tukosmo.",
+    DomainErrorVisibility::Admin
+);
+
+pub const CANNOT_ACQUIRE_DATABASE_MIGRATIONS_LOCK: DomainError = get_domain_error(
+    "CANNOT_ACQUIRE_DATABASE_MIGRATIONS_LOCK",
+    "Cannot acquire the lock to run the database migrations.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_BEGIN_TRANSACTION: DomainError = get_domain_error(
     "CANNOT_BEGIN_TRANSACTION",
     "Cannot begin transaction.",
@@ -16,12 +28,48 @@ pub const CANNOT_COMMIT_TRANSACTION: DomainError = get_domain_error(
     DomainErrorVisibility::Admin
 );
 
+pub const CANNOT_CONNECT_TO_ACME_DIRECTORY: DomainError = get_domain_error(
+    "CANNOT_CONNECT_TO_ACME_DIRECTORY",
+    "Cannot connect to the ACME directory.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_CREATE_ACME_ACCOUNT: DomainError = get_domain_error(
+    "CANNOT_CREATE_ACME_ACCOUNT",
+    "Cannot create the ACME account.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_CREATE_ACME_ORDER: DomainError = get_domain_error(
+    "CANNOT_CREATE_ACME_ORDER",
+    "Cannot create the ACME order.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_CREATE_BACKUP: DomainError = get_domain_error(
+    "CANNOT_CREATE_BACKUP",
+    "Cannot create the backup file.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_CREATE_DIRECTORY: DomainError = get_domain_error(
     "CANNOT_CREATE_DIRECTORY",
     "Cannot create directory.",
     DomainErrorVisibility::Server
 );
 
+pub const CANNOT_DOWNLOAD_TLS_CERTIFICATE: DomainError = get_domain_error(
+    "CANNOT_DOWNLOAD_TLS_CERTIFICATE",
+    "Cannot download the TLS certificate.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_DUMP_DATABASE: DomainError = get_domain_error(
+    "CANNOT_DUMP_DATABASE",
+    "Cannot dump the database tables.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_ESTABLISH_DATABASE_CONNECTION: DomainError = get_domain_error(
     "CANNOT_ESTABLISH_DATABASE_CONNECTION",
     "Cannot establish connection with the database.",
@@ -52,18 +100,37 @@ pub const CANNOT_EXECUTE_UPDATE_ON_DATABASE: DomainError = get_domain_error(
     DomainErrorVisibility::Server
 );
 
+pub const CANNOT_EXPORT_CONTENT: DomainError = get_domain_error(
+    "CANNOT_EXPORT_CONTENT",
+    "Cannot export content.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_FINALIZE_ACME_ORDER: DomainError = get_domain_error(
+    "CANNOT_FINALIZE_ACME_ORDER",
+    "Cannot finalize the ACME order.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_GENERATE_TLS_CERTIFICATE: DomainError = get_domain_error(
     "CANNOT_GENERATE_TLS_CERTIFICATE",
     "Cannot generate the TLS certificate.",
     DomainErrorVisibility::Server
 );
 
-pub const CANNOT_OBTAIN_DATABASE_CREDENTIALS: DomainError = get_domain_error(
-    "CANNOT_OBTAIN_DATABASE_CREDENTIALS",
-    "Cannot obtain database credentials from DATABASE_URL env var.",
+pub const CANNOT_GENERATE_FAVICON: DomainError = get_domain_error(
+    "CANNOT_GENERATE_FAVICON",
+    "Cannot generate favicon.",
     DomainErrorVisibility::Server
 );
 
+pub const CANNOT_OBTAIN_CERTIFICATE_FOR_NEW_DOMAIN: DomainError =
+    get_domain_error(
+        "CANNOT_OBTAIN_CERTIFICATE_FOR_NEW_DOMAIN",
+        "Cannot obtain a certificate for the new domain.",
+        DomainErrorVisibility::Admin
+    );
+
 pub const CANNOT_OBTAIN_TUKOSMO_DATA_DIR_ENV_VAR: DomainError =
     get_domain_error(
         "CANNOT_OBTAIN_TUKOSMO_DATA_DIR_ENV_VAR",
@@ -89,36 +156,138 @@ pub const CANNOT_PARSE_TOML_FILE: DomainError = get_domain_error(
     DomainErrorVisibility::Server
 );
 
+pub const CANNOT_READ_DATABASE_INFO: DomainError = get_domain_error(
+    "CANNOT_READ_DATABASE_INFO",
+    "Cannot read the version and size of the database.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_READ_DATABASE_MIGRATIONS: DomainError = get_domain_error(
+    "CANNOT_READ_DATABASE_MIGRATIONS",
+    "Cannot read the database migrations.",
+    DomainErrorVisibility::Admin
+);
+
+pub const CANNOT_READ_DATABASE_PASSWORD_FILE: DomainError = get_domain_error(
+    "CANNOT_READ_DATABASE_PASSWORD_FILE",
+    "Cannot read the database password file.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_READ_FILE: DomainError = get_domain_error(
     "CANNOT_READ_FILE",
     "Cannot read file.",
     DomainErrorVisibility::Server
 );
 
+pub const CANNOT_READ_MULTIPART_UPLOAD: DomainError = get_domain_error(
+    "CANNOT_READ_MULTIPART_UPLOAD",
+    "Cannot read multipart upload.",
+    DomainErrorVisibility::Public
+);
+
 pub const CANNOT_READ_PATH_FS_METADATA: DomainError = get_domain_error(
     "CANNOT_READ_PATH_FS_METADATA",
     "Cannot read path file system metadata.",
     DomainErrorVisibility::Server
 );
 
+pub const CANNOT_READ_SYSTEM_INFO: DomainError = get_domain_error(
+    "CANNOT_READ_SYSTEM_INFO",
+    "Cannot read the CPU, memory or disk usage of the server.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_RELOAD_TLS_CERTIFICATE: DomainError = get_domain_error(
+    "CANNOT_RELOAD_TLS_CERTIFICATE",
+    "Cannot reload the TLS certificate.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_REMOVE_DIRECTORY: DomainError = get_domain_error(
     "CANNOT_REMOVE_DIRECTORY",
     "Cannot remove directory.",
     DomainErrorVisibility::Server
 );
 
+pub const CANNOT_REMOVE_FILE: DomainError = get_domain_error(
+    "CANNOT_REMOVE_FILE",
+    "Cannot remove file.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_RESTART_SERVER: DomainError = get_domain_error(
+    "CANNOT_RESTART_SERVER",
+    "Cannot restart the server.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_RESTORE_BACKUP: DomainError = get_domain_error(
+    "CANNOT_RESTORE_BACKUP",
+    "Cannot restore the backup.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_ROLLBACK_TRANSACTION: DomainError = get_domain_error(
     "CANNOT_ROLLBACK_TRANSACTION",
     "Cannot rollback transaction.",
     DomainErrorVisibility::Admin
 );
 
+pub const CANNOT_RUN_DATABASE_MIGRATIONS: DomainError = get_domain_error(
+    "CANNOT_RUN_DATABASE_MIGRATIONS",
+    "Cannot run the database migrations.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_SEND_EMAIL: DomainError = get_domain_error(
+    "CANNOT_SEND_EMAIL",
+    "Cannot send the email.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_SERIALIZE_TOML: DomainError = get_domain_error(
+    "CANNOT_SERIALIZE_TOML",
+    "Cannot serialize TOML.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_START_ACME_CHALLENGE_SERVER: DomainError = get_domain_error(
+    "CANNOT_START_ACME_CHALLENGE_SERVER",
+    "Cannot start the server that answers the ACME challenge.",
+    DomainErrorVisibility::Server
+);
+
+pub const CANNOT_VALIDATE_ACME_CHALLENGE: DomainError = get_domain_error(
+    "CANNOT_VALIDATE_ACME_CHALLENGE",
+    "Cannot validate the ACME challenge.",
+    DomainErrorVisibility::Server
+);
+
 pub const CANNOT_WRITE_FILE: DomainError = get_domain_error(
     "CANNOT_WRITE_FILE",
     "Cannot write file.",
     DomainErrorVisibility::Server
 );
 
+pub const CONTENT_EXPORT_IS_NEWER_THAN_BINARY: DomainError = get_domain_error(
+    "CONTENT_EXPORT_IS_NEWER_THAN_BINARY",
+    "The content was exported by a newer version of Tukosmo.",
+    DomainErrorVisibility::Admin
+);
+
+pub const DATABASE_CONNECTION_POOL_EXHAUSTED: DomainError = get_domain_error(
+    "DATABASE_CONNECTION_POOL_EXHAUSTED",
+    "The server is too busy right now. Please try again in a few seconds.",
+    DomainErrorVisibility::Public
+);
+
+pub const DATABASE_SCHEMA_IS_NEWER_THAN_BINARY: DomainError = get_domain_error(
+    "DATABASE_SCHEMA_IS_NEWER_THAN_BINARY",
+    "The database was migrated by a newer version of Tukosmo.",
+    DomainErrorVisibility::Server
+);
+
 pub const DATA_DIR_DOES_NOT_EXIST: DomainError = get_domain_error(
     "DATA_DIR_DOES_NOT_EXIST",
     "The provided data directory does not exist.",
@@ -137,24 +306,183 @@ pub const DIRECTORY_DOES_NOT_EXIST: DomainError = get_domain_error(
     DomainErrorVisibility::Server
 );
 
+pub const DOMAIN_DOES_NOT_RESOLVE: DomainError = get_domain_error(
+    "DOMAIN_DOES_NOT_RESOLVE",
+    "The domain does not resolve to any address.",
+    DomainErrorVisibility::Admin
+);
+
+pub const DOMAIN_DOES_NOT_RESOLVE_TO_THIS_SERVER: DomainError =
+    get_domain_error(
+        "DOMAIN_DOES_NOT_RESOLVE_TO_THIS_SERVER",
+        "The domain does not resolve to this server.",
+        DomainErrorVisibility::Admin
+    );
+
+pub const FAVICON_PNG_IMAGE_IS_TOO_SMALL: DomainError = get_domain_error(
+    "FAVICON_PNG_IMAGE_IS_TOO_SMALL",
+    "Favicon PNG image must be at least 192x192 pixels.",
+    DomainErrorVisibility::Public
+);
+
 pub const FIELD_CANNOT_BE_EMPTY: DomainError = get_domain_error(
     "FIELD_CANNOT_BE_EMPTY",
     "This field cannot be empty.",
     DomainErrorVisibility::Public
 );
 
+pub const HSTS_PRELOAD_REQUIREMENTS_NOT_MET: DomainError = get_domain_error(
+    "HSTS_PRELOAD_REQUIREMENTS_NOT_MET",
+    "HSTS preload requirements not met.",
+    DomainErrorVisibility::Admin
+);
+
+pub const HTTP_AND_HTTPS_PORTS_MUST_BE_DIFFERENT: DomainError =
+    get_domain_error(
+        "HTTP_AND_HTTPS_PORTS_MUST_BE_DIFFERENT",
+        "The HTTP and HTTPS ports must be different.",
+        DomainErrorVisibility::Admin
+    );
+
+pub const INVALID_ACME_DIRECTORY_URL: DomainError = get_domain_error(
+    "INVALID_ACME_DIRECTORY_URL",
+    "Invalid ACME directory URL.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_ACME_ROOT_CA: DomainError = get_domain_error(
+    "INVALID_ACME_ROOT_CA",
+    "The root CA file of the ACME directory is not a valid PEM certificate.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_ADMIN_EMAIL: DomainError = get_domain_error(
+    "INVALID_ADMIN_EMAIL",
+    "The admin email is not valid.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_BACKUP_FILE: DomainError = get_domain_error(
+    "INVALID_BACKUP_FILE",
+    "The file isn't a valid Tukosmo backup.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_CONTENT_EXPORT: DomainError = get_domain_error(
+    "INVALID_CONTENT_EXPORT",
+    "The content isn't a valid Tukosmo content export.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_CONTENT_IMPORT_MODE: DomainError = get_domain_error(
+    "INVALID_CONTENT_IMPORT_MODE",
+    "Invalid content import mode.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_DATABASE_PORT: DomainError = get_domain_error(
+    "INVALID_DATABASE_PORT",
+    "The database port must be a number between 1 and 65535.",
+    DomainErrorVisibility::Server
+);
+
+pub const INVALID_DATABASE_SSLMODE: DomainError = get_domain_error(
+    "INVALID_DATABASE_SSLMODE",
+    "The database sslmode must be disable, allow, prefer, require, verify-ca or verify-full.",
+    DomainErrorVisibility::Server
+);
+
+pub const INVALID_DOMAIN: DomainError = get_domain_error(
+    "INVALID_DOMAIN",
+    "The domain is not valid (it must be a host name, without scheme, port or path).",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_FAVICON_PNG_IMAGE: DomainError = get_domain_error(
+    "INVALID_FAVICON_PNG_IMAGE",
+    "Favicon is not a valid PNG image.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_FRAME_OPTIONS: DomainError = get_domain_error(
+    "INVALID_FRAME_OPTIONS",
+    "Invalid X-Frame-Options value.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_LOG_FILE_SIZE: DomainError = get_domain_error(
+    "INVALID_LOG_FILE_SIZE",
+    "The maximum size of the log file must be at least 1 MB.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_MONITORING_ALLOWED_IP: DomainError = get_domain_error(
+    "INVALID_MONITORING_ALLOWED_IP",
+    "Invalid IP or CIDR range in the monitoring allow-list.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_MONITORING_TOKEN: DomainError = get_domain_error(
+    "INVALID_MONITORING_TOKEN",
+    "The monitoring token must have at least 16 characters, without spaces.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_PORT: DomainError = get_domain_error(
+    "INVALID_PORT",
+    "The port must be between 1 and 65535.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_SECURITY_HEADER_VALUE: DomainError = get_domain_error(
+    "INVALID_SECURITY_HEADER_VALUE",
+    "Invalid security header value.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_SERVER_MODE: DomainError = get_domain_error(
+    "INVALID_SERVER_MODE",
+    "The mode must be development or production.",
+    DomainErrorVisibility::Admin
+);
+
 pub const INVALID_TOML_EXTENSION: DomainError = get_domain_error(
     "INVALID_TOML_EXTENSION",
     "File doesn't have a valid TOML extension.",
     DomainErrorVisibility::Server
 );
 
+pub const INVALID_TRUSTED_PROXY: DomainError = get_domain_error(
+    "INVALID_TRUSTED_PROXY",
+    "Invalid trusted proxy.",
+    DomainErrorVisibility::Admin
+);
+
 pub const INVALID_UUID: DomainError = get_domain_error(
     "INVALID_UUID",
     "This text is not a valid UUID.",
     DomainErrorVisibility::Public
 );
 
+pub const MANUAL_TLS_MODE_REQUIRES_CERTIFICATE_FILES: DomainError =
+    get_domain_error(
+        "MANUAL_TLS_MODE_REQUIRES_CERTIFICATE_FILES",
+        "The manual TLS mode requires the certificate files.",
+        DomainErrorVisibility::Admin
+    );
+
+pub const NEW_DOMAIN_IS_THE_CURRENT_ONE: DomainError = get_domain_error(
+    "NEW_DOMAIN_IS_THE_CURRENT_ONE",
+    "The new domain is the current one.",
+    DomainErrorVisibility::Admin
+);
+
+pub const NO_FILE_UPLOADED: DomainError = get_domain_error(
+    "NO_FILE_UPLOADED",
+    "No file has been uploaded.",
+    DomainErrorVisibility::Public
+);
+
 pub const NOTHING_TO_DELETE_ON_DATABASE: DomainError = get_domain_error(
     "NOTHING_TO_DELETE_ON_DATABASE",
     "The executed DELETE didn't remove any row.",
@@ -167,6 +495,12 @@ pub const NOTHING_TO_UPDATE_ON_DATABASE: DomainError = get_domain_error(
     DomainErrorVisibility::Server
 );
 
+pub const PAGE_CACHE_NOT_AVAILABLE: DomainError = get_domain_error(
+    "PAGE_CACHE_NOT_AVAILABLE",
+    "The page cache is not available.",
+    DomainErrorVisibility::Server
+);
+
 pub const PATH_NOT_POINTING_TO_DIRECTORY: DomainError = get_domain_error(
     "PATH_NOT_POINTING_TO_DIRECTORY",
     "The path does not point to a directory.",
@@ -179,6 +513,36 @@ pub const PATH_NOT_POINTING_TO_FILE: DomainError = get_domain_error(
     DomainErrorVisibility::Server
 );
 
+pub const REQUEST_FROM_ANOTHER_WEBSITE: DomainError = get_domain_error(
+    "REQUEST_FROM_ANOTHER_WEBSITE",
+    "The request has been sent from another website.",
+    DomainErrorVisibility::Public
+);
+
+pub const SERVER_CONFIG_IS_NEWER_THAN_BINARY: DomainError = get_domain_error(
+    "SERVER_CONFIG_IS_NEWER_THAN_BINARY",
+    "Tukosmo.toml was written by a newer version of Tukosmo.",
+    DomainErrorVisibility::Server
+);
+
+pub const SERVER_IS_NOT_RUNNING: DomainError = get_domain_error(
+    "SERVER_IS_NOT_RUNNING",
+    "The server is not running.",
+    DomainErrorVisibility::Server
+);
+
+pub const SERVER_STOPPED_UNEXPECTEDLY: DomainError = get_domain_error(
+    "SERVER_STOPPED_UNEXPECTEDLY",
+    "The server stopped unexpectedly.",
+    DomainErrorVisibility::Server
+);
+
+pub const TAP_SESSION_REQUIRED: DomainError = get_domain_error(
+    "TAP_SESSION_REQUIRED",
+    "You must be signed in to the TAP.",
+    DomainErrorVisibility::Public
+);
+
 pub const TEXT_DOESNT_REACH_MIN_LENGTH: DomainError = get_domain_error(
     "TEXT_DOESNT_REACH_MIN_LENGTH",
     "The text doesn't reach the min length.",
@@ -191,6 +555,19 @@ pub const TEXT_EXCEEDS_MAX_LENGTH: DomainError = get_domain_error(
     DomainErrorVisibility::Public
 );
 
+pub const TLS_CERTIFICATE_IS_NOT_MANAGED_BY_TUKOSMO: DomainError =
+    get_domain_error(
+        "TLS_CERTIFICATE_IS_NOT_MANAGED_BY_TUKOSMO",
+        "The TLS certificate is not managed by Tukosmo.",
+        DomainErrorVisibility::Admin
+    );
+
+pub const TLS_IS_DISABLED: DomainError = get_domain_error(
+    "TLS_IS_DISABLED",
+    "TLS is disabled.",
+    DomainErrorVisibility::Admin
+);
+
 pub const UNDESIRED_DELETES_ON_DATABASE: DomainError = get_domain_error(
     "UNDESIRED_DELETES_ON_DATABASE",
     "The executed DELETE tried to remove more than one row.",
@@ -203,6 +580,12 @@ pub const UNDESIRED_UPDATES_ON_DATABASE: DomainError = get_domain_error(
     DomainErrorVisibility::Server
 );
 
+pub const UPLOADED_FILE_IS_TOO_LARGE: DomainError = get_domain_error(
+    "UPLOADED_FILE_IS_TOO_LARGE",
+    "The uploaded file is larger than the maximum size allowed.",
+    DomainErrorVisibility::Public
+);
+
 const fn get_domain_error(
     error_code: &'static str,
     message: &'static str,
@@ -218,3 +601,33 @@ const fn get_domain_error(
         visibility,
     }
 }
+
+fn get_domain_error_with_context(
+    error_code: &'static str,
+    message: &'static str,
+    visibility: DomainErrorVisibility,
+    context: Vec<(String, String)>
+) -> DomainError {
+    DomainError {
+        context,
+        id: DomainErrorId {
+            error_code,
+            module: Core(Shared),
+        },
+        message,
+        visibility,
+    }
+}
+
+// Each problem is already formatted with its file and line (when known)
+pub fn invalid_server_config(problems: Vec<String>) -> DomainError {
+    get_domain_error_with_context(
+        "INVALID_SERVER_CONFIG",
+        "The server configuration (Tukosmo.toml) is not valid.",
+        DomainErrorVisibility::Server,
+        problems
+            .into_iter()
+            .map(|problem| ("problem".to_string(), problem))
+            .collect()
+    )
+}
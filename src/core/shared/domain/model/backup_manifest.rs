@@ -0,0 +1,14 @@
+use serde::{ Deserialize, Serialize };
+
+// Stored as manifest.json at the root of every backup archive
+#[derive(Clone, Deserialize, Serialize)]
+pub struct BackupManifest {
+    pub created_at: String,
+    pub format_version: u32,
+    // Latest migration applied when the backup was created
+    pub migration_version: String,
+    pub tables: Vec<String>,
+    pub tukosmo_version: String,
+}
+
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
@@ -0,0 +1,12 @@
+use crate::core::language::model::LanguageCode;
+
+// Which rendered pages of the website are affected by a change, so only
+// those are rendered again
+#[derive(Clone, PartialEq)]
+pub enum ContentChangeEvent {
+    // Content shown in every page (e.g. menus, widgets or the language list)
+    AllPages,
+    // Content only shown in the pages of that language (e.g. its website
+    // title)
+    LanguagePages(LanguageCode),
+}
@@ -0,0 +1,114 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::core::shared::error;
+use super::DomainError;
+
+// Portable between Tukosmo sites: languages are referenced by their code
+// (not their ID), so they're remapped to the languages of the target site
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExport {
+    pub exported_at: DateTime<Utc>,
+    pub format_version: u32,
+    #[serde(default)]
+    pub galleries: Vec<ContentExportGallery>,
+    #[serde(default)]
+    pub languages: Vec<ContentExportLanguage>,
+    #[serde(default)]
+    pub menus: Vec<ContentExportMenu>,
+    #[serde(default)]
+    pub widgets: Vec<ContentExportWidget>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExportGallery {
+    pub id: Uuid,
+    pub images: Vec<ContentExportGalleryImage>,
+    pub name: ContentExportI18nText,
+}
+
+// Only the file name is exported; the files must be copied separately
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExportGalleryImage {
+    pub caption: ContentExportI18nText,
+    pub file_name: String,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExportI18nText {
+    pub default_text: String,
+    // Language code => text
+    #[serde(default)]
+    pub translations: BTreeMap<String, String>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExportLanguage {
+    pub code: String,
+    pub name: ContentExportI18nText,
+    pub original_name: String,
+    pub website_subtitle: String,
+    pub website_title: String,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExportMenu {
+    pub id: Uuid,
+    pub items: Vec<ContentExportMenuItem>,
+    pub location: Option<String>,
+    pub name: ContentExportI18nText,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExportMenuItem {
+    #[serde(default)]
+    pub children: Vec<ContentExportMenuItem>,
+    pub label: ContentExportI18nText,
+    pub link_type: String,
+    pub link_value: String,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ContentExportWidget {
+    pub area: String,
+    pub code: String,
+    pub content: ContentExportI18nText,
+    pub id: Uuid,
+    // Empty when the widget is visible in every language
+    #[serde(default)]
+    pub language_codes: Vec<String>,
+    pub limit: i32,
+    pub position: i32,
+    pub title: ContentExportI18nText,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ContentImportMode {
+    // Existing content (same ID, or same code for languages) is left as is
+    Merge,
+    // Existing content is replaced with the imported one
+    Overwrite,
+}
+
+pub const CONTENT_EXPORT_FORMAT_VERSION: u32 = 1;
+
+impl ContentImportMode {
+    pub fn from_code(code: &str) -> Result<Self, DomainError> {
+        match code {
+            "merge" => Ok(Self::Merge),
+            "overwrite" => Ok(Self::Overwrite),
+            _ => Err(error::INVALID_CONTENT_IMPORT_MODE),
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Merge => "merge",
+            Self::Overwrite => "overwrite",
+        }
+    }
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DatabaseMigration {
+    pub is_applied: bool,
+    pub name: String,
+    pub version: String,
+}
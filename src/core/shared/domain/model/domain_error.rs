@@ -5,8 +5,12 @@ use std::fmt;
 
 #[derive(Clone, Deserialize, PartialEq, Serialize)]
 pub enum CoreSubmoduleName {
+    Gallery,
     Language,
+    Menu,
     Shared,
+    Theme,
+    Widget,
 }
 
 #[derive(Clone, Deserialize, PartialEq, Serialize)]
@@ -35,15 +39,29 @@ pub enum ModuleName {
     Core(CoreSubmoduleName),
 }
 
+pub const DOMAIN_ERROR_LOG_TARGET: &'static str = "tukosmo::domain_error";
+
 impl DomainError {
     pub fn get_full_code(&self) -> String {
         let prefix = match self.id.module {
+            ModuleName::Core(CoreSubmoduleName::Gallery) => "CORE.GALLERY",
             ModuleName::Core(CoreSubmoduleName::Language) => "CORE.LANGUAGE",
+            ModuleName::Core(CoreSubmoduleName::Menu) => "CORE.MENU",
             ModuleName::Core(CoreSubmoduleName::Shared) => "CORE.SHARED",
+            ModuleName::Core(CoreSubmoduleName::Theme) => "CORE.THEME",
+            ModuleName::Core(CoreSubmoduleName::Widget) => "CORE.WIDGET",
         };
         let error_code = self.id.error_code.to_string();
         format!("{}.{}", prefix, error_code)
     }
+
+    // Errors of the server (e.g. the database isn't reachable) only show
+    // their code to the users, so they're logged with their context
+    pub fn log(&self) {
+        if DomainErrorVisibility::Server == self.visibility {
+            log::error!(target: DOMAIN_ERROR_LOG_TARGET, "{}", self);
+        }
+    }
 }
 
 impl Error for DomainError {}
@@ -60,14 +78,13 @@ impl fmt::Debug for DomainError {
     }
 }
 
+// E.g. "CORE.SHARED.CANNOT_READ_FILE (Cannot read the file.) path=/a.txt"
 impl fmt::Display for DomainError {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        // TODO: Show more info (module, layer, visibility?, etc.)
-        write!(
-            formatter,
-            "DomainError: {} ({})",
-            self.id.error_code,
-            self.message
-        )
+        write!(formatter, "{} ({})", self.get_full_code(), self.message)?;
+        for (key, value) in &self.context {
+            write!(formatter, " {}={}", key, value)?;
+        }
+        Ok(())
     }
 }
@@ -0,0 +1,65 @@
+use super::super::error;
+use super::DomainError;
+
+#[derive(Clone)]
+pub struct FaviconPngImage(Vec<u8>);
+
+pub const FAVICON_MIN_SIZE_IN_PIXELS: u32 = 192;
+
+// Every PNG file starts with this signature, followed by the IHDR chunk,
+// which has the image width and height as big-endian integers
+const PNG_SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+const PNG_IHDR_CHUNK_TYPE: &'static [u8] = b"IHDR";
+const PNG_IHDR_CHUNK_TYPE_START: usize = 12;
+const PNG_IHDR_HEIGHT_START: usize = 20;
+const PNG_IHDR_WIDTH_START: usize = 16;
+const PNG_MIN_LENGTH: usize = 24;
+
+impl FaviconPngImage {
+    fn get_dimensions(value: &[u8]) -> Option<(u32, u32)> {
+        if
+            value.len() < PNG_MIN_LENGTH ||
+            value[..PNG_SIGNATURE.len()] != PNG_SIGNATURE ||
+            &value[PNG_IHDR_CHUNK_TYPE_START..PNG_IHDR_WIDTH_START] !=
+                PNG_IHDR_CHUNK_TYPE
+        {
+            return None;
+        }
+
+        let read_u32 = |start: usize| {
+            u32::from_be_bytes([
+                value[start],
+                value[start + 1],
+                value[start + 2],
+                value[start + 3],
+            ])
+        };
+        Some((read_u32(PNG_IHDR_WIDTH_START), read_u32(PNG_IHDR_HEIGHT_START)))
+    }
+
+    pub fn new(value: Vec<u8>) -> Result<Self, DomainError> {
+        match Self::validate(&value) {
+            Some(validation_error) => Err(validation_error),
+            None => Ok(Self(value)),
+        }
+    }
+
+    pub fn validate(value: &Vec<u8>) -> Option<DomainError> {
+        match Self::get_dimensions(value) {
+            Some((width, height)) => {
+                if
+                    width < FAVICON_MIN_SIZE_IN_PIXELS ||
+                    height < FAVICON_MIN_SIZE_IN_PIXELS
+                {
+                    return Some(error::FAVICON_PNG_IMAGE_IS_TOO_SMALL);
+                }
+                None
+            }
+            None => Some(error::INVALID_FAVICON_PNG_IMAGE),
+        }
+    }
+
+    pub fn value(&self) -> &[u8] {
+        &self.0
+    }
+}
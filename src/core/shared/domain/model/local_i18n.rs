@@ -63,6 +63,12 @@ pub struct LocalI18nByteSize {
 
 impl LocalI18nByteSize {
     pub fn with(&self, nbytes: u64) -> String {
+        let (formatted_number, unit) = self.with_unit(nbytes);
+        format!("{} {}", formatted_number, unit)
+    }
+
+    // The number and the unit, for texts that place them separately
+    pub fn with_unit(&self, nbytes: u64) -> (String, String) {
         // TODO: Move number formatting to its own struct, and use it here
         let (full_number, unit): (f64, String) = if nbytes < 1024 {
             (nbytes as f64, "bytes".to_string())
@@ -74,9 +80,13 @@ impl LocalI18nByteSize {
             ((nbytes as f64) / 1073741824.0, "GiB".to_string())
         };
 
+        // Rounded to two decimals, which are only shown if they aren't zero
+        let rounded_number = format!("{:.2}", full_number);
+        let (integer_part_string, decimal_part_string) = rounded_number
+            .split_once('.')
+            .unwrap_or((rounded_number.as_str(), "00"));
+
         let mut formatted_number: String = "".to_owned();
-        let integer_part = full_number.floor() as u64;
-        let integer_part_string = integer_part.to_string();
         let number_of_integer_digits = integer_part_string.len();
         let digits_by_group = self.digits_by_group as usize;
         let total_reminder = number_of_integer_digits % digits_by_group;
@@ -91,14 +101,12 @@ impl LocalI18nByteSize {
             }
         }
 
-        let decimal_part = full_number - full_number.floor();
-        if 0.0 != decimal_part {
+        if "00" != decimal_part_string {
             formatted_number.push_str(&self.decimal_separator);
-            let decimal_part_str = &decimal_part.to_string();
-            formatted_number.push_str(&decimal_part_str[2..4]);
+            formatted_number.push_str(decimal_part_string);
         }
 
-        format!("{} {}", formatted_number, unit)
+        (formatted_number, unit)
     }
 }
 
@@ -150,76 +158,146 @@ pub struct LocalI18nMain {
     pub account: String,
     pub account_status: String,
     pub actions: String,
+    pub activate: String,
+    pub active: String,
     pub active_w_account: String,
     pub add: String,
+    pub add_gallery: String,
+    pub add_item: String,
     pub add_language: String,
+    pub add_menu: String,
+    pub add_subitem: String,
     pub add_tag: String,
+    pub add_widget: String,
     pub administration_panel: String,
     pub administrator: String,
     pub administrators_email: String,
+    pub all: String,
     pub all_stats_of_pages_in_this_language: String,
     pub all_translations_in_this_language: String,
     pub any_other_data_related_to_this_language: String,
     pub appearance: String,
+    pub applied: String,
+    pub area: String,
     pub author: String,
     pub blog: String,
     pub browser: String,
+    pub cached_pages: String,
     pub cancel: String,
+    pub caption: String,
     pub change_domain: String,
     pub choose_a_file: String,
+    pub choose_a_tukosmo_backup_file: String,
     pub close: String,
     pub code: String,
+    pub content_html: String,
     pub copyright: TStringCopyright,
     pub cpu: String,
     pub cpu_current_usage: String,
     pub create_tag: String,
     pub create_user: String,
+    pub created_w_content: String,
     pub current_favicon: String,
     pub current_password: String,
     pub current_size: String,
+    pub customize_theme: String,
     pub dashboard: String,
     pub data: String,
     pub database: String,
+    pub database_host: String,
+    pub database_name: String,
+    pub database_password: String,
+    pub database_port: String,
+    pub database_sslmode: String,
+    pub database_user: String,
+    pub days_until_expiration: String,
     pub default_name: String,
     pub delete: String,
     pub delete_file: String,
+    pub delete_gallery: String,
+    pub delete_image: String,
     pub delete_language: String,
     pub delete_language_name: TStringWithName,
+    pub delete_menu: String,
     pub delete_tag_name: TStringWithName,
+    pub delete_widget: String,
     pub description: String,
     pub desire_great_day: String,
+    pub development: String,
+    pub disabled: String,
     pub disk_at_percentage: TStringWithDecimalNumber,
     pub disk_used_info: TStringDiskUsedInfo,
     pub domain_successfully_updated: String,
     pub domain_w_web: String,
+    pub domains_w_certificate: String,
     pub download_w_verb: String,
     pub downloads: String,
     pub drafts: String,
+    pub drag_and_drop_the_images_to_reorder_them: String,
+    pub dry_run_no_changes_have_been_saved: String,
+    pub dry_run_only_show_the_changes: String,
+    pub edit: String,
     pub edit_file: String,
+    pub edit_gallery: String,
     pub edit_language: String,
+    pub edit_menu: String,
     pub edit_tag: String,
     pub edit_user: String,
+    pub edit_widget: String,
     pub email: String,
+    pub enabled: String,
     pub enabled_modules: String,
+    pub expiration_warning_sent_at: String,
+    pub expires_at: String,
     pub export_backup: String,
+    pub export_backup_note: String,
+    pub export_content: String,
+    pub export_content_note: String,
+    pub external_url: String,
     pub faq: String,
     pub favicon: String,
     pub favicon_note: String,
     pub file: String,
     pub files: String,
+    pub footer: String,
     pub forgotten_password_w_question: String,
+    pub galleries: String,
     pub gallery: String,
     pub go_back_to_website: TStringWithName,
+    pub header: String,
     pub hello_user: TStringWithName,
     pub help: String,
+    pub hit_rate: String,
+    pub hits: String,
+    pub http_port: String,
+    pub https_port: String,
     pub i_understand_the_consequences_of_performing_this_action: String,
+    pub if_no_language_is_selected_the_widget_is_visible_in_all_of_them: String,
     pub if_you_delete_this_language_you_will_lose: String,
+    pub images: String,
     pub import_backup: String,
+    pub import_backup_note: String,
+    pub import_content: String,
+    pub import_content_note: String,
+    pub import_mode: String,
+    pub import_mode_merge: String,
+    pub import_mode_overwrite: String,
     pub in_the_last_month: String,
+    pub issued_at: String,
+    pub issuer: String,
+    pub items: String,
     pub kernel_version: String,
+    pub label: String,
     pub language: String,
+    pub language_switcher: String,
     pub languages: String,
+    pub last_renewal_attempt: String,
+    pub last_renewal_error: String,
     pub last_update: String,
+    pub leave_empty_to_keep_the_current_password: String,
+    pub link: String,
+    pub link_type: String,
+    pub location: String,
     pub login_w_noun: String,
     pub login_w_verb: String,
     pub logout_w_verb: String,
@@ -227,71 +305,134 @@ pub struct LocalI18nMain {
     pub memory: String,
     pub menu: String,
     pub menus: String,
+    pub migrations: String,
+    pub misses: String,
+    pub mode_w_server: String,
     pub modules: String,
+    pub move_down: String,
+    pub move_up: String,
+    pub n_logical_cores: TStringWithIntegerNumber,
     pub n_results_of_m: TStringNResultsOfM,
     pub n_visitors: TStringWithIntegerNumber,
     pub name: String,
     pub name_in_each_language: String,
+    pub never: String,
+    pub new_domain: String,
     pub new_password: String,
     pub new_password_repeat: String,
+    pub next_w_image: String,
     pub next_w_page: String,
     pub no_file_uploaded: String,
+    pub no_results_found: String,
+    pub none: String,
+    pub number_of_elements: String,
+    pub obtaining_a_certificate_may_take_a_few_minutes: String,
     pub one_result_of_m: TStringWithIntegerNumber,
     pub operating_system: String,
     pub original_name: String,
+    pub page_cache: String,
     pub page_n: TStringWithIntegerNumber,
     pub pages: String,
     pub password: String,
     pub password_repeat: String,
+    pub paste_the_exported_content: String,
     pub payments: String,
+    pub pending: String,
     pub permalink_identifier: String,
     pub platform_w_os: String,
     pub posts: String,
+    pub previous_w_image: String,
     pub previous_w_page: String,
+    pub production: String,
     pub published_w_posts: String,
+    pub purge_cache: String,
+    pub recent_posts: String,
+    pub regenerate_self_signed_certificate: String,
     pub remove: String,
+    pub renew_now: String,
+    pub result: String,
+    pub route: String,
+    pub save_caption: String,
+    pub search: String,
+    pub search_box: String,
     pub see_languages: String,
     pub select_a_language: String,
+    pub self_signed: String,
     pub server: String,
     pub server_os: TStringWithName,
     pub sessions: String,
     pub settings: String,
     pub shop: String,
+    pub sidebar: String,
     pub since: String,
     pub size: String,
+    pub skipped_w_content: String,
     pub statistics: String,
     pub status: String,
     pub submit: String,
     pub subscriptions: String,
     pub suspended_account: String,
     pub suspended_w_account: String,
+    pub tag_cloud: String,
     pub tags: String,
     pub tasks: String,
+    pub text_html: String,
+    pub the_backup_has_been_successfully_imported: String,
+    pub the_certificate_expires_soon_and_couldnt_be_renewed: String,
+    pub the_certificate_files_are_managed_outside_tukosmo: String,
+    pub the_content_has_been_successfully_imported: String,
+    pub the_dashboard_is_refreshed_every_n_seconds: TStringWithIntegerNumber,
     pub the_file_has_been_successfully_updated: String,
     pub the_language_has_been_successfully_updated: String,
+    pub the_page_cache_has_been_successfully_purged: String,
+    pub the_page_cache_is_disabled_in_the_server_configuration: String,
+    pub the_pages_seen_by_anonymous_visitors_are_kept_in_memory: String,
     pub the_session_has_been_successfully_deleted: String,
     pub the_tag_has_been_successfully_deleted: String,
     pub the_tag_has_been_successfully_updated: String,
+    pub the_tls_certificate_has_been_successfully_renewed: String,
     pub the_user_has_been_successfully_updated: String,
     pub the_websites_favicon_has_been_successfully_updated: String,
     pub theme: String,
+    pub theme_color_help: String,
+    pub theme_font_help: String,
+    pub theme_image_help: String,
+    pub there_are_no_posts_yet: String,
+    pub there_are_no_tags_yet: String,
+    pub there_are_no_themes_in_the_data_directory: String,
+    pub there_are_pending_migrations_restart_the_server_to_apply_them: String,
     pub this_action_is_irreversible: String,
+    pub this_area_has_no_widgets_yet: String,
+    pub this_gallery_has_no_images_yet: String,
+    pub this_menu_has_no_items_yet: String,
+    pub this_theme_has_no_customizable_variables: String,
     pub title: String,
+    pub tls: String,
+    pub tls_certificate: String,
+    pub tls_is_disabled_it_is_terminated_by_a_reverse_proxy: String,
+    pub tls_mode: String,
+    pub to_embed_this_gallery_add_this_shortcode_to_any_content: String,
     pub trash_w_bin: String,
     pub tukosmo: String,
     pub tukosmo_admin_panel: String,
     pub tukosmo_settings_successfully_updated: String,
     pub tukosmo_version: TStringWithName,
     pub tukosmo_will_automatically_restart_itself_apply_changes: String,
+    pub type_w_content: String,
+    pub unchanged_w_content: String,
     pub untranslated: String,
     pub update_os: String,
     pub update_packages: String,
+    pub updated_w_content: String,
     pub updated_w_server: String,
     pub updated_w_tukosmo: String,
     pub upload_file: String,
+    pub upload_images: String,
     pub upload_new_favicon_png_image: String,
     pub uptime: String,
     pub users: String,
+    pub version: String,
+    pub visible_in_languages: String,
     pub visit_website: String,
     pub warning_domain_page: String,
     pub web_browsers_preview: String,
@@ -300,6 +441,7 @@ pub struct LocalI18nMain {
     pub website_subtitle_in_this_language: String,
     pub website_title: String,
     pub website_title_in_this_language: String,
+    pub widget: String,
     pub widgets: String,
     pub your_account_has_been_successfully_updated: String,
     pub your_current_domain_is: TStringWithName,
@@ -401,16 +543,18 @@ pub struct TStringDiskUsedInfo(String);
 impl TStringDiskUsedInfo {
     pub fn with(
         &self,
-        used: f64,
-        used_unit: &str,
-        total: f64,
-        total_unit: &str
+        t_bytesize: &LocalI18nByteSize,
+        used_bytes: u64,
+        total_bytes: u64
     ) -> String {
+        let (used, used_unit) = t_bytesize.with_unit(used_bytes);
+        let (total, total_unit) = t_bytesize.with_unit(total_bytes);
+
         self.0
-            .replace("{used}", &used.to_string())
-            .replace("{used_unit}", used_unit)
-            .replace("{total}", &total.to_string())
-            .replace("{total_unit}", total_unit)
+            .replace("{used}", &used)
+            .replace("{used_unit}", &used_unit)
+            .replace("{total}", &total)
+            .replace("{total_unit}", &total_unit)
     }
 }
 
@@ -1,6 +1,25 @@
+#[cfg(feature = "ssr")]
+mod backup_manifest;
+#[cfg(feature = "ssr")]
+pub use backup_manifest::*;
+
+#[cfg(feature = "ssr")]
+mod content_change_event;
+#[cfg(feature = "ssr")]
+pub use content_change_event::*;
+
+mod content_export;
+pub use content_export::*;
+
+mod database_migration;
+pub use database_migration::*;
+
 mod domain_error;
 pub use domain_error::*;
 
+mod favicon_png_image;
+pub use favicon_png_image::*;
+
 mod local_i18n;
 pub use local_i18n::*;
 
@@ -15,6 +34,11 @@ pub use server_config::*;
 mod server_response;
 pub use server_response::*;
 
+#[cfg(feature = "ssr")]
+mod tls_renewal_status;
+#[cfg(feature = "ssr")]
+pub use tls_renewal_status::*;
+
 #[cfg(feature = "ssr")]
 mod transaction;
 #[cfg(feature = "ssr")]
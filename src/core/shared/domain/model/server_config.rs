@@ -1,17 +1,108 @@
 use serde::{ Deserialize, Serialize };
+use std::net::IpAddr;
 
+use crate::core::shared::error;
+use super::DomainError;
+
+// Unknown keys are rejected, so typos don't silently fall back to defaults.
+// Tables (database, backup...) must come after the plain values, since TOML
+// is serialized in the order of the fields
 #[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct ServerConfig {
-    pub platform: String,
+    pub config_version: u32,
+    pub platform: ServerPlatform,
     pub domain: String,
     pub http_port: u16,
     pub https_port: u16,
-    pub mode: String,
+    pub mode: ServerMode,
     pub admin_email: String,
     pub database: ServerDatabaseConfig,
+    #[serde(default)]
+    pub backup: ServerBackupConfig,
+    #[serde(default)]
+    pub acme: ServerAcmeConfig,
+    pub tls: ServerTlsConfig,
+    #[serde(default)]
+    pub security_headers: ServerSecurityHeadersConfig,
+    #[serde(default)]
+    pub page_cache: ServerPageCacheConfig,
+    #[serde(default)]
+    pub monitoring: ServerMonitoringConfig,
+    #[serde(default)]
+    pub logging: ServerLoggingConfig,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerLogFormat {
+    Json,
+    Logfmt,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerLogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerMode {
+    Development,
+    Production,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerPlatform {
+    Arch,
+    Debian,
+    Dragonflybsd,
+    Fedora,
+    Freebsd,
+    Linux,
+    Netbsd,
+    Openbsd,
+    Opensuse,
+    Ubuntu,
+}
+
+#[derive(Clone, Copy, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServerTlsMode {
+    // Obtained (and renewed) from the ACME directory of [acme]
+    Acme,
+    // Certificate files of tls.cert_chain_file and tls.cert_pkey_file
+    Manual,
+    // Only HTTP, for reverse proxies that terminate TLS
+    Off,
+    SelfSigned,
 }
 
 impl ServerConfig {
+    // Names of the TLS certificate (the domain is the first one), without
+    // duplicates
+    pub fn get_certificate_domains(&self) -> Vec<String> {
+        let mut certificate_domains = vec![self.domain.clone()];
+
+        let is_www_domain = self.domain.starts_with("www.");
+        if self.acme.include_www && !is_www_domain {
+            certificate_domains.push(format!("www.{}", self.domain));
+        }
+        for domain_alias in &self.acme.domain_aliases {
+            if !certificate_domains.contains(domain_alias) {
+                certificate_domains.push(domain_alias.clone());
+            }
+        }
+
+        certificate_domains
+    }
+
     pub fn http_domain_and_port(&self) -> String {
         format!("{}:{}", self.domain, self.http_port)
     }
@@ -21,50 +112,788 @@ impl ServerConfig {
     }
 
     pub fn has_development_mode(&self) -> bool {
-        "development" == &self.mode
+        ServerMode::Development == self.mode
     }
 
     pub fn has_production_mode(&self) -> bool {
-        "production" == &self.mode
+        ServerMode::Production == self.mode
     }
 
     pub fn platform_name(&self) -> String {
         (
-            match self.platform.as_str() {
-                "arch" => "Arch Linux",
-                "debian" => "Debian",
-                "dragonflybsd" => "DragonFly BSD",
-                "fedora" => "Fedora",
-                "freebsd" => "FreeBSD",
-                "netbsd" => "NetBSD",
-                "openbsd" => "OpenBSD",
-                "opensuse" => "OpenSUSE",
-                "ubuntu" => "Ubuntu",
-                _ => "Linux",
+            match self.platform {
+                ServerPlatform::Arch => "Arch Linux",
+                ServerPlatform::Debian => "Debian",
+                ServerPlatform::Dragonflybsd => "DragonFly BSD",
+                ServerPlatform::Fedora => "Fedora",
+                ServerPlatform::Freebsd => "FreeBSD",
+                ServerPlatform::Linux => "Linux",
+                ServerPlatform::Netbsd => "NetBSD",
+                ServerPlatform::Openbsd => "OpenBSD",
+                ServerPlatform::Opensuse => "OpenSUSE",
+                ServerPlatform::Ubuntu => "Ubuntu",
             }
         ).to_string()
     }
+
+    // Returns every problem found (key => error), not only the first one
+    pub fn validate(&self) -> Vec<(&'static str, DomainError)> {
+        let mut problems = vec![];
+
+        let admin_email_error = Self::validate_admin_email(&self.admin_email);
+        if let Some(domain_error) = admin_email_error {
+            problems.push(("admin_email", domain_error));
+        }
+        if let Some(domain_error) = Self::validate_domain(&self.domain) {
+            problems.push(("domain", domain_error));
+        }
+        if let Some(domain_error) = Self::validate_port(&self.http_port) {
+            problems.push(("http_port", domain_error));
+        }
+        if let Some(domain_error) = Self::validate_port(&self.https_port) {
+            problems.push(("https_port", domain_error));
+        }
+        if self.http_port == self.https_port {
+            problems.push((
+                "https_port",
+                error::HTTP_AND_HTTPS_PORTS_MUST_BE_DIFFERENT,
+            ));
+        }
+
+        if self.database.port == 0 {
+            problems.push(("database.port", error::INVALID_DATABASE_PORT));
+        }
+        if !self.database.has_valid_sslmode() {
+            problems.push((
+                "database.sslmode",
+                error::INVALID_DATABASE_SSLMODE,
+            ));
+        }
+
+        if let Some(domain_error) = Self::validate_acme_directory_url(
+            &self.acme.directory_url
+        ) {
+            problems.push(("acme.directory_url", domain_error));
+        }
+        for domain_alias in &self.acme.domain_aliases {
+            if let Some(domain_error) = Self::validate_domain(domain_alias) {
+                problems.push(("acme.domain_aliases", domain_error));
+            }
+        }
+
+        if self.tls.mode == ServerTlsMode::Manual {
+            let certificate_files = [
+                ("tls.cert_chain_file", &self.tls.cert_chain_file),
+                ("tls.cert_pkey_file", &self.tls.cert_pkey_file),
+            ];
+            for (key, file_path) in certificate_files {
+                let file_path_is_empty = file_path
+                    .as_ref()
+                    .map_or(true, |file_path| file_path.trim().is_empty());
+                if file_path_is_empty {
+                    problems.push((
+                        key,
+                        error::MANUAL_TLS_MODE_REQUIRES_CERTIFICATE_FILES,
+                    ));
+                }
+            }
+        }
+        for trusted_proxy in &self.tls.trusted_proxies {
+            if let Some(domain_error) = Self::validate_trusted_proxy(
+                trusted_proxy
+            ) {
+                problems.push(("tls.trusted_proxies", domain_error));
+            }
+        }
+
+        let security_headers = &self.security_headers;
+        let header_values = [
+            (
+                "security_headers.admin_content_security_policy",
+                &security_headers.admin_content_security_policy,
+            ),
+            (
+                "security_headers.permissions_policy",
+                &security_headers.permissions_policy,
+            ),
+            (
+                "security_headers.public_content_security_policy",
+                &security_headers.public_content_security_policy,
+            ),
+            (
+                "security_headers.referrer_policy",
+                &security_headers.referrer_policy,
+            ),
+        ];
+        for (key, value) in header_values {
+            if let Some(domain_error) = Self::validate_security_header_value(
+                value
+            ) {
+                problems.push((key, domain_error));
+            }
+        }
+        if !FRAME_OPTIONS.contains(&security_headers.frame_options.as_str()) {
+            problems.push((
+                "security_headers.frame_options",
+                error::INVALID_FRAME_OPTIONS,
+            ));
+        }
+        // Requirements of https://hstspreload.org
+        let hsts_preload_is_valid = !security_headers.hsts_preload || (
+            security_headers.hsts_include_subdomains &&
+                security_headers.hsts_max_age_seconds >=
+                    MIN_HSTS_PRELOAD_MAX_AGE_SECONDS
+        );
+        if !hsts_preload_is_valid {
+            problems.push((
+                "security_headers.hsts_preload",
+                error::HSTS_PRELOAD_REQUIREMENTS_NOT_MET,
+            ));
+        }
+
+        if self.logging.max_file_size_mb == 0 {
+            problems.push((
+                "logging.max_file_size_mb",
+                error::INVALID_LOG_FILE_SIZE,
+            ));
+        }
+
+        for allowed_ip in &self.monitoring.allowed_ips {
+            if let Some(domain_error) = Self::validate_monitoring_allowed_ip(
+                allowed_ip
+            ) {
+                problems.push(("monitoring.allowed_ips", domain_error));
+            }
+        }
+        if let Some(domain_error) = Self::validate_monitoring_token(
+            &self.monitoring.token
+        ) {
+            problems.push(("monitoring.token", domain_error));
+        }
+
+        problems
+    }
+
+    pub fn validate_acme_directory_url(value: &String) -> Option<DomainError> {
+        let is_valid = match value.strip_prefix("https://") {
+            Some(rest) =>
+                !rest.is_empty() && !rest.contains(char::is_whitespace),
+            None => false,
+        };
+
+        if is_valid {
+            None
+        } else {
+            Some(error::INVALID_ACME_DIRECTORY_URL)
+        }
+    }
+
+    pub fn validate_admin_email(value: &String) -> Option<DomainError> {
+        let is_valid = match value.split_once('@') {
+            Some((local_part, domain)) =>
+                !local_part.is_empty() &&
+                    !domain.contains('@') &&
+                    !value.chars().any(|c| c.is_whitespace()) &&
+                    Self::validate_domain(&domain.to_string()).is_none(),
+            None => false,
+        };
+        if is_valid {
+            None
+        } else {
+            Some(error::INVALID_ADMIN_EMAIL)
+        }
+    }
+
+    // Only the host name: no scheme, port or path
+    pub fn validate_domain(value: &String) -> Option<DomainError> {
+        let is_valid_label = |label: &str| {
+            !label.is_empty() &&
+                label.len() <= 63 &&
+                !label.starts_with('-') &&
+                !label.ends_with('-') &&
+                label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        };
+        let is_valid =
+            value.len() <= 253 && value.split('.').all(is_valid_label);
+        if is_valid {
+            None
+        } else {
+            Some(error::INVALID_DOMAIN)
+        }
+    }
+
+    pub fn validate_monitoring_allowed_ip(
+        value: &String
+    ) -> Option<DomainError> {
+        if ServerTlsConfig::parse_trusted_proxy(value).is_some() {
+            None
+        } else {
+            Some(error::INVALID_MONITORING_ALLOWED_IP)
+        }
+    }
+
+    // Sent in the Authorization header (Bearer), so it can't have spaces
+    pub fn validate_monitoring_token(value: &String) -> Option<DomainError> {
+        let is_valid = value.is_empty() || (
+            value.len() >= MIN_MONITORING_TOKEN_LENGTH &&
+                value.chars().all(|c| c.is_ascii_graphic())
+        );
+        if is_valid {
+            None
+        } else {
+            Some(error::INVALID_MONITORING_TOKEN)
+        }
+    }
+
+    pub fn validate_port(value: &u16) -> Option<DomainError> {
+        if 0 == *value {
+            Some(error::INVALID_PORT)
+        } else {
+            None
+        }
+    }
+
+    pub fn validate_security_header_value(
+        value: &String
+    ) -> Option<DomainError> {
+        if value.chars().any(|c| c.is_control()) {
+            Some(error::INVALID_SECURITY_HEADER_VALUE)
+        } else {
+            None
+        }
+    }
+
+    pub fn validate_trusted_proxy(value: &String) -> Option<DomainError> {
+        if ServerTlsConfig::parse_trusted_proxy(value).is_some() {
+            None
+        } else {
+            Some(error::INVALID_TRUSTED_PROXY)
+        }
+    }
+}
+
+impl ServerLogFormat {
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Logfmt => "logfmt",
+        }
+    }
+}
+
+impl ServerLogLevel {
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+    }
+}
+
+impl ServerMode {
+    pub fn from_code(code: &str) -> Result<Self, DomainError> {
+        match code {
+            "development" => Ok(Self::Development),
+            "production" => Ok(Self::Production),
+            _ => Err(error::INVALID_SERVER_MODE),
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Development => "development",
+            Self::Production => "production",
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerAcmeConfig {
+    // Any ACME server can be used. A local one for testing (e.g. Pebble)
+    // also needs its root CA in root_ca_file
+    #[serde(default = "default_acme_directory_url")]
+    pub directory_url: String,
+    // Other domains of the certificate, which must point to this server
+    #[serde(default)]
+    pub domain_aliases: Vec<String>,
+    // Adds www.{domain} to the certificate
+    #[serde(default)]
+    pub include_www: bool,
+    // PEM file of a root CA trusted by the ACME client besides the ones of
+    // the system (empty for none). It's only read once per process
+    #[serde(default)]
+    pub root_ca_file: String,
+}
+
+impl Default for ServerAcmeConfig {
+    fn default() -> Self {
+        Self {
+            directory_url: default_acme_directory_url(),
+            domain_aliases: vec![],
+            include_www: false,
+            root_ca_file: String::new(),
+        }
+    }
 }
 
 #[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerBackupConfig {
+    // Scheduled backups are disabled when it's 0
+    #[serde(default)]
+    pub interval_hours: u64,
+    // Uploads from the TAP are written to disk as they're received, and
+    // rejected as soon as they're larger
+    #[serde(default = "default_backup_max_import_size_mb")]
+    pub max_import_size_mb: u64,
+    // Size of the files of a backup once decompressed, so a small backup
+    // can't fill the disk when it's restored
+    #[serde(default = "default_backup_max_restored_size_mb")]
+    pub max_restored_size_mb: u64,
+    // Older scheduled backups are removed (manual ones are always kept);
+    // 0 keeps all of them
+    #[serde(default = "default_backup_retention_count")]
+    pub retention_count: usize,
+}
+
+impl Default for ServerBackupConfig {
+    fn default() -> Self {
+        Self {
+            interval_hours: 0,
+            max_import_size_mb: default_backup_max_import_size_mb(),
+            max_restored_size_mb: default_backup_max_restored_size_mb(),
+            retention_count: default_backup_retention_count(),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
 pub struct ServerDatabaseConfig {
     pub host: String,
     pub name: String,
+    #[serde(default)]
     pub password: String,
+    // Read instead of password when set (e.g. Docker or Kubernetes secrets)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password_file: Option<String>,
+    #[serde(default = "default_pool_connection_timeout_seconds")]
+    pub pool_connection_timeout_seconds: u64,
+    #[serde(default = "default_pool_idle_timeout_seconds")]
+    pub pool_idle_timeout_seconds: u64,
+    #[serde(default = "default_pool_max_size")]
+    pub pool_max_size: u32,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    // Directory of the Unix socket; when set, host and port are ignored
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub socket: Option<String>,
+    #[serde(default = "default_sslmode")]
+    pub sslmode: String,
     pub user: String,
 }
 
 impl ServerDatabaseConfig {
-    // TODO: Replace all the other database_url and use only this one
     pub fn get_database_url(&self) -> String {
-        format!(
-            "postgres://{}:{}@{}/{}",
-            self.user,
-            self.password,
-            self.host,
-            self.name
-        )
+        let credentials = format!(
+            "{}:{}",
+            encode_url_component(&self.user),
+            encode_url_component(&self.password)
+        );
+        let name = encode_url_component(&self.name);
+        let sslmode = encode_url_component(&self.sslmode);
+
+        match &self.socket {
+            Some(socket) =>
+                format!(
+                    "postgres://{}@/{}?host={}&sslmode={}",
+                    credentials,
+                    name,
+                    encode_url_component(socket),
+                    sslmode
+                ),
+            None => {
+                // IPv6 addresses must be enclosed in brackets
+                let host = if self.host.contains(':') {
+                    format!("[{}]", self.host)
+                } else {
+                    self.host.clone()
+                };
+                format!(
+                    "postgres://{}@{}:{}/{}?sslmode={}",
+                    credentials,
+                    host,
+                    self.port,
+                    name,
+                    sslmode
+                )
+            }
+        }
+    }
+
+    pub fn has_valid_sslmode(&self) -> bool {
+        DATABASE_SSLMODES.contains(&self.sslmode.as_str())
+    }
+}
+
+// Written to the standard output and to logs/tukosmo.log (in the data dir),
+// which is rotated when it reaches max_file_size_mb
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerLoggingConfig {
+    #[serde(default = "default_logging_access_log")]
+    pub access_log: bool,
+    #[serde(default = "default_logging_format")]
+    pub format: ServerLogFormat,
+    #[serde(default = "default_logging_level")]
+    pub level: ServerLogLevel,
+    #[serde(default = "default_logging_max_file_size_mb")]
+    pub max_file_size_mb: u64,
+    // Rotated files kept (tukosmo.log.1 is the newest one). The log file is
+    // disabled when it's 0
+    #[serde(default = "default_logging_max_files")]
+    pub max_files: u32,
+}
+
+impl Default for ServerLoggingConfig {
+    fn default() -> Self {
+        Self {
+            access_log: default_logging_access_log(),
+            format: default_logging_format(),
+            level: default_logging_level(),
+            max_file_size_mb: default_logging_max_file_size_mb(),
+            max_files: default_logging_max_files(),
+        }
+    }
+}
+
+// Access to /healthz, /readyz and /metrics, which is allowed to the clients
+// of allowed_ips, and to the ones that send the token (if it isn't empty)
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerMonitoringConfig {
+    // IPs or CIDR ranges, like tls.trusted_proxies
+    #[serde(default = "default_monitoring_allowed_ips")]
+    pub allowed_ips: Vec<String>,
+    #[serde(default)]
+    pub token: String,
+}
+
+impl Default for ServerMonitoringConfig {
+    fn default() -> Self {
+        Self {
+            allowed_ips: default_monitoring_allowed_ips(),
+            token: String::new(),
+        }
+    }
+}
+
+// Rendered pages of the public website, which are served to anonymous
+// visitors without rendering them again
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerPageCacheConfig {
+    #[serde(default = "default_page_cache_enabled")]
+    pub enabled: bool,
+    // The least recently used pages are removed when it's full
+    #[serde(default = "default_page_cache_max_size_mb")]
+    pub max_size_mb: u64,
+}
+
+impl Default for ServerPageCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_page_cache_enabled(),
+            max_size_mb: default_page_cache_max_size_mb(),
+        }
+    }
+}
+
+// Empty values disable their header. The content security policies are
+// templates where {nonce} is replaced by the nonce of the request, which
+// Leptos adds to its hydration scripts
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerSecurityHeadersConfig {
+    #[serde(default = "default_admin_content_security_policy")]
+    pub admin_content_security_policy: String,
+    #[serde(default = "default_frame_options")]
+    pub frame_options: String,
+    #[serde(default)]
+    pub hsts_include_subdomains: bool,
+    // HSTS is disabled when it's 0
+    #[serde(default = "default_hsts_max_age_seconds")]
+    pub hsts_max_age_seconds: u64,
+    #[serde(default)]
+    pub hsts_preload: bool,
+    #[serde(default = "default_permissions_policy")]
+    pub permissions_policy: String,
+    #[serde(default = "default_public_content_security_policy")]
+    pub public_content_security_policy: String,
+    #[serde(default = "default_referrer_policy")]
+    pub referrer_policy: String,
+}
+
+impl ServerSecurityHeadersConfig {
+    pub fn get_content_security_policy(
+        &self,
+        is_admin_panel: bool,
+        nonce: &str
+    ) -> String {
+        let content_security_policy = if is_admin_panel {
+            &self.admin_content_security_policy
+        } else {
+            &self.public_content_security_policy
+        };
+        content_security_policy.replace("{nonce}", nonce)
+    }
+
+    pub fn get_hsts_header_value(&self) -> Option<String> {
+        if 0 == self.hsts_max_age_seconds {
+            return None;
+        }
+
+        let mut hsts_header_value = format!(
+            "max-age={}",
+            self.hsts_max_age_seconds
+        );
+        if self.hsts_include_subdomains {
+            hsts_header_value.push_str("; includeSubDomains");
+        }
+        if self.hsts_preload {
+            hsts_header_value.push_str("; preload");
+        }
+        Some(hsts_header_value)
     }
 }
 
+impl Default for ServerSecurityHeadersConfig {
+    fn default() -> Self {
+        Self {
+            admin_content_security_policy:
+                default_admin_content_security_policy(),
+            frame_options: default_frame_options(),
+            hsts_include_subdomains: false,
+            hsts_max_age_seconds: default_hsts_max_age_seconds(),
+            hsts_preload: false,
+            permissions_policy: default_permissions_policy(),
+            public_content_security_policy:
+                default_public_content_security_policy(),
+            referrer_policy: default_referrer_policy(),
+        }
+    }
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct ServerTlsConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cert_chain_file: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cert_pkey_file: Option<String>,
+    pub mode: ServerTlsMode,
+    // IPs or CIDR ranges (e.g. 10.0.0.0/8) of the reverse proxies whose
+    // X-Forwarded-* headers are honoured
+    #[serde(default)]
+    pub trusted_proxies: Vec<String>,
+}
+
+impl ServerTlsConfig {
+    // The address of the network and its prefix length (a single IP is a
+    // network with the full length)
+    pub fn parse_trusted_proxy(value: &str) -> Option<(IpAddr, u8)> {
+        let (address, prefix_length) = match value.split_once('/') {
+            Some((address, prefix_length)) =>
+                (address, Some(prefix_length.parse::<u8>().ok()?)),
+            None => (value, None),
+        };
+        let ip = address.parse::<IpAddr>().ok()?;
+        let max_prefix_length = match ip {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+        let prefix_length = prefix_length.unwrap_or(max_prefix_length);
+
+        if prefix_length > max_prefix_length {
+            None
+        } else {
+            Some((ip, prefix_length))
+        }
+    }
+}
+
+impl ServerTlsMode {
+    // Whether Tukosmo obtains (and renews) the certificate itself
+    pub fn is_managed(&self) -> bool {
+        match self {
+            Self::Acme | Self::SelfSigned => true,
+            Self::Manual | Self::Off => false,
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Acme => "acme",
+            Self::Manual => "manual",
+            Self::Off => "off",
+            Self::SelfSigned => "self_signed",
+        }
+    }
+}
+
+// Percent-encodes everything except RFC 3986 unreserved characters
+fn encode_url_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' =>
+                encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn default_acme_directory_url() -> String {
+    ACME_DIRECTORY_URL_LETS_ENCRYPT.to_string()
+}
+
+// The TAP is never embedded, and it only loads its own resources
+fn default_admin_content_security_policy() -> String {
+    [
+        "default-src 'self'",
+        "script-src 'self' 'nonce-{nonce}' 'wasm-unsafe-eval'",
+        "style-src 'self' 'unsafe-inline'",
+        "img-src 'self' data: blob:",
+        "object-src 'none'",
+        "base-uri 'self'",
+        "form-action 'self'",
+        "frame-ancestors 'none'",
+    ].join("; ")
+}
+
+fn default_backup_max_import_size_mb() -> u64 {
+    2048
+}
+
+fn default_backup_max_restored_size_mb() -> u64 {
+    10240
+}
+
+fn default_backup_retention_count() -> usize {
+    7
+}
+
+fn default_frame_options() -> String {
+    "SAMEORIGIN".to_string()
+}
+
+// 2 years
+fn default_hsts_max_age_seconds() -> u64 {
+    63072000
+}
+
+fn default_logging_access_log() -> bool {
+    true
+}
+
+fn default_logging_format() -> ServerLogFormat {
+    ServerLogFormat::Logfmt
+}
+
+fn default_logging_level() -> ServerLogLevel {
+    ServerLogLevel::Info
+}
+
+fn default_logging_max_file_size_mb() -> u64 {
+    10
+}
+
+fn default_logging_max_files() -> u32 {
+    5
+}
+
+// Only the server itself
+fn default_monitoring_allowed_ips() -> Vec<String> {
+    vec!["127.0.0.1".to_string(), "::1".to_string()]
+}
+
+fn default_page_cache_enabled() -> bool {
+    true
+}
+
+fn default_page_cache_max_size_mb() -> u64 {
+    32
+}
+
+fn default_permissions_policy() -> String {
+    "camera=(), geolocation=(), microphone=(), payment=(), usb=()".to_string()
+}
+
+fn default_pool_connection_timeout_seconds() -> u64 {
+    5
+}
+
+fn default_pool_idle_timeout_seconds() -> u64 {
+    600
+}
+
+fn default_pool_max_size() -> u32 {
+    10
+}
+
+fn default_port() -> u16 {
+    5432
+}
+
+// Themes and content may use images from other websites
+fn default_public_content_security_policy() -> String {
+    [
+        "default-src 'self'",
+        "script-src 'self' 'nonce-{nonce}' 'wasm-unsafe-eval'",
+        "style-src 'self' 'unsafe-inline'",
+        "img-src 'self' data: https:",
+        "font-src 'self' data:",
+        "object-src 'none'",
+        "base-uri 'self'",
+        "form-action 'self'",
+        "frame-ancestors 'self'",
+    ].join("; ")
+}
+
+fn default_referrer_policy() -> String {
+    "strict-origin-when-cross-origin".to_string()
+}
+
+fn default_sslmode() -> String {
+    "prefer".to_string()
+}
+
+pub const ACME_DIRECTORY_URL_LETS_ENCRYPT: &'static str =
+    "https://acme-v02.api.letsencrypt.org/directory";
+
+pub const DATABASE_SSLMODES: [&'static str; 6] = [
+    "disable",
+    "allow",
+    "prefer",
+    "require",
+    "verify-ca",
+    "verify-full",
+];
+
+// Empty disables the header
+pub const FRAME_OPTIONS: [&'static str; 3] = ["", "DENY", "SAMEORIGIN"];
+
+pub const MIN_HSTS_PRELOAD_MAX_AGE_SECONDS: u64 = 31536000; // 1 year
+
+pub const MIN_MONITORING_TOKEN_LENGTH: usize = 16;
+
+// Increase it when the format of Tukosmo.toml changes, and add the upgrade
+// from the previous version (older files are upgraded when they're read)
+pub const SERVER_CONFIG_VERSION: u32 = 3;
+
 pub const TUKOSMO_VERSION: &'static str = "0.1.0";
@@ -20,11 +20,13 @@ impl<T> ServerResponse<T> {
     {
         match server_function() {
             Ok(response) => Self::Response(response),
-            Err(domain_error) =>
+            Err(domain_error) => {
+                domain_error.log();
                 Self::Error(ServerResponseError {
                     error_code: domain_error.get_full_code(),
                     context: domain_error.context,
-                }),
+                })
+            }
         }
     }
 }
@@ -0,0 +1,14 @@
+use serde::{ Deserialize, Serialize };
+
+// Stored in the data dir, so the TAP can show how the last renewal of the
+// TLS certificate went (dates are RFC 3339)
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub struct TlsRenewalStatus {
+    #[serde(default)]
+    pub expiration_warning_sent_at: Option<String>,
+    #[serde(default)]
+    pub last_attempt_at: Option<String>,
+    // Full code of the DomainError; None when the last attempt succeeded
+    #[serde(default)]
+    pub last_error_code: Option<String>,
+}
@@ -1,11 +1,19 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use crate::core::gallery::repository::GalleryRepository;
 use crate::core::language::repository::LanguageRepository;
+use crate::core::menu::repository::MenuRepository;
 use crate::core::shared::model::DomainError;
+use crate::core::widget::repository::WidgetInstanceRepository;
 
 pub struct Transaction {
+    pub gallery_repository: Rc<RefCell<dyn GalleryRepository>>,
     pub language_repository: Rc<RefCell<dyn LanguageRepository>>,
+    pub menu_repository: Rc<RefCell<dyn MenuRepository>>,
+    pub widget_instance_repository: Rc<
+        RefCell<dyn WidgetInstanceRepository>
+    >,
 }
 
 pub trait TransactionExecutor {
@@ -1,16 +1,32 @@
 use std::fs::File;
 
 use super::super::model::DomainError;
+use super::super::model::FaviconPngImage;
 use super::super::model::LocalI18n;
 use super::super::model::ServerConfig;
+use super::super::model::TlsRenewalStatus;
 
 pub trait DataRepository {
+    // Keeps a copy of the current certificates, so they can be restored with
+    // restore_certs_dir() if a new one can't be obtained
+    fn backup_certs_dir(&self) -> Result<(), DomainError>;
+
     fn check_tls_certificate_file_exists(&self) -> Result<bool, DomainError>;
 
     fn clean_certs_dir(&self) -> Result<(), DomainError>;
 
     fn create_new_acme_challenge_dir(&self) -> Result<(), DomainError>;
 
+    // None until the first certificate is obtained (the same ACME account is
+    // used for every order)
+    fn get_acme_account_key(&self) -> Result<Option<String>, DomainError>;
+
+    // None when there's no pending challenge with that token
+    fn get_acme_challenge_proof(
+        &self,
+        token: &str
+    ) -> Result<Option<String>, DomainError>;
+
     fn get_local_i18n(
         &self,
         language_code: &str
@@ -18,6 +34,8 @@ pub trait DataRepository {
 
     fn get_server_config(&self) -> Result<ServerConfig, DomainError>;
 
+    fn get_session_key(&self) -> Result<Option<Vec<u8>>, DomainError>;
+
     fn get_tls_certificate_chain_file(&self) -> Result<File, DomainError>;
 
     fn get_tls_certificate_file(&self) -> Result<File, DomainError>;
@@ -32,14 +50,46 @@ pub trait DataRepository {
         &self
     ) -> Result<String, DomainError>;
 
+    // The default status when there hasn't been any renewal yet
+    fn get_tls_renewal_status(&self) -> Result<TlsRenewalStatus, DomainError>;
+
     fn remove_acme_challenge_dir(&self) -> Result<(), DomainError>;
 
+    fn remove_uploaded_file(&self, file_name: &str) -> Result<(), DomainError>;
+
+    fn restore_certs_dir(&self) -> Result<(), DomainError>;
+
+    // Replaces Tukosmo.toml (the server must be restarted to apply it)
+    fn set_server_config(
+        &self,
+        server_config: &ServerConfig
+    ) -> Result<(), DomainError>;
+
+    fn set_tls_renewal_status(
+        &self,
+        tls_renewal_status: &TlsRenewalStatus
+    ) -> Result<(), DomainError>;
+
+    fn update_favicon(
+        &self,
+        favicon_png_image: &FaviconPngImage
+    ) -> Result<(), DomainError>;
+
+    fn write_acme_account_key(&self, content: &str) -> Result<(), DomainError>;
+
     fn write_acme_challenge_token_file(
         &self,
         token: &str,
         proof: &str
     ) -> Result<(), DomainError>;
 
+    fn write_acme_root_cas_file(
+        &self,
+        content: &str
+    ) -> Result<(), DomainError>;
+
+    fn write_session_key(&self, content: &[u8]) -> Result<(), DomainError>;
+
     fn write_tls_certificate_chain_file(
         &self,
         content: &str
@@ -59,4 +109,10 @@ pub trait DataRepository {
         &self,
         content: &str
     ) -> Result<(), DomainError>;
+
+    fn write_uploaded_file(
+        &self,
+        file_name: &str,
+        content: &[u8]
+    ) -> Result<(), DomainError>;
 }
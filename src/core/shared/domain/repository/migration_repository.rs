@@ -0,0 +1,6 @@
+use super::super::model::DatabaseMigration;
+use super::super::model::DomainError;
+
+pub trait MigrationRepository {
+    fn find_all(&self) -> Result<Vec<DatabaseMigration>, DomainError>;
+}
@@ -2,3 +2,13 @@
 mod data_repository;
 #[cfg(feature = "ssr")]
 pub use data_repository::*;
+
+#[cfg(feature = "ssr")]
+mod migration_repository;
+#[cfg(feature = "ssr")]
+pub use migration_repository::*;
+
+#[cfg(feature = "ssr")]
+mod page_cache_repository;
+#[cfg(feature = "ssr")]
+pub use page_cache_repository::*;
@@ -0,0 +1,7 @@
+use super::super::model::ContentChangeEvent;
+
+// Rendered pages of the website, which become outdated when the content they
+// show changes
+pub trait PageCacheRepository {
+    fn invalidate(&mut self, event: ContentChangeEvent);
+}
@@ -0,0 +1,2 @@
+DROP TABLE gallery_image;
+DROP TABLE gallery;
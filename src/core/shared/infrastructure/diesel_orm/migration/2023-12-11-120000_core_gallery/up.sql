@@ -0,0 +1,20 @@
+CREATE TABLE gallery (
+    id                UUID        PRIMARY KEY,
+    i18n_text_id_name UUID        NOT NULL
+                                  REFERENCES i18n_text,
+    creation_date     TIMESTAMPTZ NOT NULL,
+    update_date       TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE gallery_image (
+    id                   UUID        PRIMARY KEY,
+    gallery_id           UUID        NOT NULL
+                                     REFERENCES gallery ON DELETE CASCADE,
+    i18n_text_id_caption UUID        NOT NULL
+                                     REFERENCES i18n_text,
+    file_name            TEXT        NOT NULL
+                                     UNIQUE,
+    position             INTEGER     NOT NULL,
+    creation_date        TIMESTAMPTZ NOT NULL,
+    update_date          TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,2 @@
+DROP TABLE menu_item;
+DROP TABLE menu;
@@ -0,0 +1,140 @@
+CREATE TABLE menu (
+    id                UUID        PRIMARY KEY,
+    i18n_text_id_name UUID        NOT NULL
+                                  REFERENCES i18n_text,
+    location          TEXT        UNIQUE,
+    creation_date     TIMESTAMPTZ NOT NULL,
+    update_date       TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE menu_item (
+    id                 UUID        PRIMARY KEY,
+    menu_id            UUID        NOT NULL
+                                   REFERENCES menu ON DELETE CASCADE,
+    parent_id          UUID        REFERENCES menu_item ON DELETE CASCADE,
+    i18n_text_id_label UUID        NOT NULL
+                                   REFERENCES i18n_text,
+    link_type          TEXT        NOT NULL,
+    link_value         TEXT        NOT NULL,
+    position           INTEGER     NOT NULL,
+    creation_date      TIMESTAMPTZ NOT NULL,
+    update_date        TIMESTAMPTZ NOT NULL
+);
+
+INSERT INTO i18n_text (id, default_text, creation_date, update_date)
+VALUES ('f9a6b10e-5f26-4f64-9ecd-18e3c2c1aabb'::UUID, 'Main menu', NOW(), NOW());
+
+INSERT INTO i18n_translation (
+    id,
+    i18n_text_id,
+    language_id,
+    text,
+    creation_date,
+    update_date
+)
+VALUES (
+    '30c40570-59bf-4a12-acc4-14687d0fcfe4'::UUID,
+    'f9a6b10e-5f26-4f64-9ecd-18e3c2c1aabb'::UUID,
+    'd24c014a-e58c-4fff-8f15-be4b69385a97'::UUID,
+    'Menú principal',
+    NOW(),
+    NOW()
+);
+
+INSERT INTO i18n_text (id, default_text, creation_date, update_date)
+VALUES ('af9e466a-253d-4f91-a54f-4f76c51d42ed'::UUID, 'Login', NOW(), NOW());
+
+INSERT INTO i18n_translation (
+    id,
+    i18n_text_id,
+    language_id,
+    text,
+    creation_date,
+    update_date
+)
+VALUES (
+    '032fb075-3996-4402-b706-30c29a1a4b01'::UUID,
+    'af9e466a-253d-4f91-a54f-4f76c51d42ed'::UUID,
+    'd24c014a-e58c-4fff-8f15-be4b69385a97'::UUID,
+    'Inicio de sesión',
+    NOW(),
+    NOW()
+);
+
+INSERT INTO i18n_text (id, default_text, creation_date, update_date)
+VALUES ('95b63cac-dddf-4807-934d-85277d29cba7'::UUID, 'Administration panel', NOW(), NOW());
+
+INSERT INTO i18n_translation (
+    id,
+    i18n_text_id,
+    language_id,
+    text,
+    creation_date,
+    update_date
+)
+VALUES (
+    '9a715e22-a970-429d-bb4d-e4d813141b37'::UUID,
+    '95b63cac-dddf-4807-934d-85277d29cba7'::UUID,
+    'd24c014a-e58c-4fff-8f15-be4b69385a97'::UUID,
+    'Panel de administración',
+    NOW(),
+    NOW()
+);
+
+INSERT INTO menu (
+    id,
+    i18n_text_id_name,
+    location,
+    creation_date,
+    update_date
+) VALUES (
+    'a04f5826-0e19-483c-91eb-42edc95094cf'::UUID,
+    'f9a6b10e-5f26-4f64-9ecd-18e3c2c1aabb'::UUID,
+    'header',
+    NOW(),
+    NOW()
+);
+
+INSERT INTO menu_item (
+    id,
+    menu_id,
+    parent_id,
+    i18n_text_id_label,
+    link_type,
+    link_value,
+    position,
+    creation_date,
+    update_date
+) VALUES (
+    'e241d5a8-fae3-4aaf-86a0-4e7279b5fcd9'::UUID,
+    'a04f5826-0e19-483c-91eb-42edc95094cf'::UUID,
+    NULL,
+    'af9e466a-253d-4f91-a54f-4f76c51d42ed'::UUID,
+    'route',
+    '/login',
+    0,
+    NOW(),
+    NOW()
+);
+
+INSERT INTO menu_item (
+    id,
+    menu_id,
+    parent_id,
+    i18n_text_id_label,
+    link_type,
+    link_value,
+    position,
+    creation_date,
+    update_date
+) VALUES (
+    '912d79b3-a490-4e68-9636-363c446838c3'::UUID,
+    'a04f5826-0e19-483c-91eb-42edc95094cf'::UUID,
+    NULL,
+    '95b63cac-dddf-4807-934d-85277d29cba7'::UUID,
+    'route',
+    '/admin/dashboard',
+    1,
+    NOW(),
+    NOW()
+);
@@ -0,0 +1,63 @@
+CREATE TABLE widget_instance (
+    id                   UUID        PRIMARY KEY,
+    code                 TEXT        NOT NULL,
+    area                 TEXT        NOT NULL,
+    position             INTEGER     NOT NULL,
+    i18n_text_id_title   UUID        NOT NULL
+                                     REFERENCES i18n_text,
+    i18n_text_id_content UUID        NOT NULL
+                                     REFERENCES i18n_text,
+    item_limit           INTEGER     NOT NULL,
+    -- An empty array means that the widget is visible in every language
+    language_ids         UUID[]      NOT NULL DEFAULT '{}',
+    creation_date        TIMESTAMPTZ NOT NULL,
+    update_date          TIMESTAMPTZ NOT NULL
+);
+
+INSERT INTO i18n_text (id, default_text, creation_date, update_date)
+VALUES ('5b0f2d4e-8c3a-4f7e-9a61-2d7c8e4b1f03'::UUID, 'Languages', NOW(), NOW());
+
+INSERT INTO i18n_translation (
+    id,
+    i18n_text_id,
+    language_id,
+    text,
+    creation_date,
+    update_date
+)
+VALUES (
+    'c3e81a57-0d4b-4b9f-8e2a-71f6d5a9c2b4'::UUID,
+    '5b0f2d4e-8c3a-4f7e-9a61-2d7c8e4b1f03'::UUID,
+    'd24c014a-e58c-4fff-8f15-be4b69385a97'::UUID,
+    'Idiomas',
+    NOW(),
+    NOW()
+);
+
+INSERT INTO i18n_text (id, default_text, creation_date, update_date)
+VALUES ('9e4a7c21-6b3f-4d85-a0e9-3f2b8d6c5a17'::UUID, '', NOW(), NOW());
+
+INSERT INTO widget_instance (
+    id,
+    code,
+    area,
+    position,
+    i18n_text_id_title,
+    i18n_text_id_content,
+    item_limit,
+    language_ids,
+    creation_date,
+    update_date
+)
+VALUES (
+    'e1d7b3a9-4c62-4f08-b5e3-8a9f0c2d6e41'::UUID,
+    'language_switcher',
+    'sidebar',
+    0,
+    '5b0f2d4e-8c3a-4f7e-9a61-2d7c8e4b1f03'::UUID,
+    '9e4a7c21-6b3f-4d85-a0e9-3f2b8d6c5a17'::UUID,
+    10,
+    '{}',
+    NOW(),
+    NOW()
+);
@@ -1,5 +1,9 @@
 pub mod model;
 
+pub mod repository;
+
 pub mod schema;
 
+pub mod service;
+
 pub mod statement;
@@ -0,0 +1,125 @@
+use diesel::pg::PgConnection;
+use diesel::r2d2::ConnectionManager;
+use diesel::r2d2::Pool;
+use diesel::r2d2::PooledConnection;
+use dotenvy::dotenv;
+use std::env;
+use std::fs;
+use std::time::Duration;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerDatabaseConfig;
+
+pub type DbConnection = PooledConnection<ConnectionManager<PgConnection>>;
+
+pub type DbPool = Pool<ConnectionManager<PgConnection>>;
+
+const ENV_VAR_PREFIX: &'static str = "TUKOSMO_DATABASE_";
+
+pub fn init_db_pool(
+    database_config: &ServerDatabaseConfig
+) -> Result<DbPool, DomainError> {
+    let database_config = resolve_database_config(database_config)?;
+    let connection_manager = ConnectionManager::<PgConnection>::new(
+        database_config.get_database_url()
+    );
+
+    // Connections are only opened when they're needed, so an unreachable
+    // database is reported by get_db_connection() with its own error (e.g.
+    // by the migrations, which stop the server at boot)
+    let db_pool = Pool::builder()
+        .max_size(database_config.pool_max_size)
+        .min_idle(Some(0))
+        .connection_timeout(
+            Duration::from_secs(database_config.pool_connection_timeout_seconds)
+        )
+        .idle_timeout(
+            Some(Duration::from_secs(database_config.pool_idle_timeout_seconds))
+        )
+        .test_on_check_out(true)
+        .build_unchecked(connection_manager);
+
+    Ok(db_pool)
+}
+
+pub fn get_db_connection(
+    db_pool: &DbPool
+) -> Result<DbConnection, DomainError> {
+    match db_pool.get() {
+        Ok(connection) => Ok(connection),
+        Err(_e) => {
+            // r2d2 only returns a timeout error, so the pool's state tells
+            // us if it was full or if the database didn't accept connections
+            let state = db_pool.state();
+            let pool_is_exhausted =
+                state.connections >= db_pool.max_size() &&
+                state.idle_connections == 0;
+            if pool_is_exhausted {
+                Err(error::DATABASE_CONNECTION_POOL_EXHAUSTED)
+            } else {
+                Err(error::CANNOT_ESTABLISH_DATABASE_CONNECTION)
+            }
+        }
+    }
+}
+
+// Tukosmo.toml values can be overridden with TUKOSMO_DATABASE_<KEY> env vars,
+// and password_file (from either source) takes precedence over password
+pub fn resolve_database_config(
+    database_config: &ServerDatabaseConfig
+) -> Result<ServerDatabaseConfig, DomainError> {
+    dotenv().ok();
+    let mut database_config = database_config.clone();
+
+    if let Some(host) = get_env_var("HOST") {
+        database_config.host = host;
+    }
+    if let Some(name) = get_env_var("NAME") {
+        database_config.name = name;
+    }
+    if let Some(password) = get_env_var("PASSWORD") {
+        database_config.password = password;
+    }
+    if let Some(password_file) = get_env_var("PASSWORD_FILE") {
+        database_config.password_file = Some(password_file);
+    }
+    if let Some(port) = get_env_var("PORT") {
+        database_config.port = port
+            .parse()
+            .map_err(|_e| error::INVALID_DATABASE_PORT)?;
+    }
+    if let Some(socket) = get_env_var("SOCKET") {
+        database_config.socket = Some(socket);
+    }
+    if let Some(sslmode) = get_env_var("SSLMODE") {
+        database_config.sslmode = sslmode;
+    }
+    if let Some(user) = get_env_var("USER") {
+        database_config.user = user;
+    }
+
+    if database_config.port == 0 {
+        return Err(error::INVALID_DATABASE_PORT);
+    }
+    if !database_config.has_valid_sslmode() {
+        return Err(error::INVALID_DATABASE_SSLMODE);
+    }
+
+    if let Some(password_file) = &database_config.password_file {
+        let password = fs
+            ::read_to_string(password_file)
+            .map_err(|_e| error::CANNOT_READ_DATABASE_PASSWORD_FILE)?;
+        // Secret files usually end with a newline that isn't part of it
+        database_config.password = password
+            .trim_end_matches(&['\r', '\n'][..])
+            .to_string();
+    }
+
+    Ok(database_config)
+}
+
+fn get_env_var(key: &str) -> Option<String> {
+    env::var(format!("{}{}", ENV_VAR_PREFIX, key))
+        .ok()
+        .filter(|value| !value.is_empty())
+}
@@ -1,52 +1,83 @@
-use diesel::connection::SimpleConnection;
+use diesel::connection::AnsiTransactionManager;
+use diesel::connection::TransactionManager;
 use diesel::pg::PgConnection;
-use dotenvy::dotenv;
 use std::cell::RefCell;
-use std::env;
-use std::ops::DerefMut;
 use std::rc::Rc;
+use std::time::Instant;
 use tukosmo_domain::core::shared::model::DomainError;
 use tukosmo_domain::core::shared::model::Transaction;
 use tukosmo_domain::core::shared::model::TransactionExecutor;
 use tukosmo_domain::core::shared::error;
 
+use crate::core::gallery::diesel_orm::repository::DbGalleryRepository;
 use crate::core::language::diesel_orm::repository::DbLanguageRepository;
+use crate::core::menu::diesel_orm::repository::DbMenuRepository;
+use crate::core::shared::leptos_actix_server::service::metrics;
+use crate::core::widget::diesel_orm::repository::DbWidgetInstanceRepository;
+use super::DbConnection;
+use super::DbPool;
+use super::get_db_connection;
 
 pub struct DbTransactionExecutor {
-    connection: Rc<RefCell<PgConnection>>,
+    // When the current transaction began, for its duration in the metrics
+    begun_at: Option<Instant>,
+    connection: Rc<RefCell<DbConnection>>,
 }
 
+const OUTCOME_COMMIT: &'static str = "commit";
+const OUTCOME_ROLLBACK: &'static str = "rollback";
+
 impl DbTransactionExecutor {
-    pub fn init() -> Result<Self, DomainError> {
-        dotenv().ok();
-        let database_url = env
-            // TODO: Use Tukosmo.toml instead of env var
-            ::var("DATABASE_URL")
-            .map_err(|_e| error::CANNOT_OBTAIN_DATABASE_CREDENTIALS)?;
+    // The connection goes back to the pool when the executor is dropped
+    pub fn init(db_pool: &DbPool) -> Result<Self, DomainError> {
+        let connection = get_db_connection(db_pool)?;
+        Ok(Self {
+            begun_at: None,
+            connection: Rc::new(RefCell::new(connection)),
+        })
+    }
 
-        use diesel::Connection;
-        let connection = (match PgConnection::establish(&database_url) {
-            Ok(connection) => Ok(Rc::new(RefCell::new(connection))),
-            Err(_e) => Err(error::CANNOT_ESTABLISH_DATABASE_CONNECTION),
-        })?;
-        Ok(Self { connection })
+    fn observe_duration(&mut self, outcome: &'static str) {
+        if let Some(begun_at) = self.begun_at.take() {
+            metrics::observe_db_transaction(outcome, begun_at.elapsed());
+        }
     }
 }
 
 impl TransactionExecutor for DbTransactionExecutor {
     fn begin(&mut self) -> Result<Transaction, DomainError> {
+        self.begun_at = Some(Instant::now());
         let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
-        match connection.batch_execute("BEGIN TRANSACTION;") {
+        let connection: &mut PgConnection = &mut connection;
+        // Diesel tracks the transaction state, so a transaction begun inside
+        // another one is a savepoint instead of an error
+        match AnsiTransactionManager::begin_transaction(connection) {
             Ok(_) => {
+                let gallery_repository = DbGalleryRepository::init(
+                    Rc::clone(&self.connection)
+                );
                 let language_repository = DbLanguageRepository::init(
                     Rc::clone(&self.connection)
                 );
+                let menu_repository = DbMenuRepository::init(
+                    Rc::clone(&self.connection)
+                );
+                let widget_instance_repository =
+                    DbWidgetInstanceRepository::init(
+                        Rc::clone(&self.connection)
+                    );
 
                 let transaction = Transaction {
+                    gallery_repository: Rc::new(
+                        RefCell::new(gallery_repository)
+                    ),
                     language_repository: Rc::new(
                         RefCell::new(language_repository)
                     ),
+                    menu_repository: Rc::new(RefCell::new(menu_repository)),
+                    widget_instance_repository: Rc::new(
+                        RefCell::new(widget_instance_repository)
+                    ),
                 };
                 Ok(transaction)
             }
@@ -55,20 +86,28 @@ impl TransactionExecutor for DbTransactionExecutor {
     }
 
     fn commit(&mut self) -> Result<(), DomainError> {
-        let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
-        match connection.batch_execute("COMMIT;") {
-            Ok(_) => Ok(()),
-            Err(_) => Err(error::CANNOT_COMMIT_TRANSACTION),
-        }
+        let result = {
+            let mut connection = self.connection.borrow_mut();
+            let connection: &mut PgConnection = &mut connection;
+            match AnsiTransactionManager::commit_transaction(connection) {
+                Ok(_) => Ok(()),
+                Err(_) => Err(error::CANNOT_COMMIT_TRANSACTION),
+            }
+        };
+        self.observe_duration(OUTCOME_COMMIT);
+        result
     }
 
     fn rollback(&mut self) -> Result<(), DomainError> {
-        let mut connection = self.connection.borrow_mut();
-        let connection = connection.deref_mut();
-        match connection.batch_execute("ROLLBACK;") {
-            Ok(_) => Ok(()),
-            Err(_) => Err(error::CANNOT_ROLLBACK_TRANSACTION),
-        }
+        let result = {
+            let mut connection = self.connection.borrow_mut();
+            let connection: &mut PgConnection = &mut connection;
+            match AnsiTransactionManager::rollback_transaction(connection) {
+                Ok(_) => Ok(()),
+                Err(_) => Err(error::CANNOT_ROLLBACK_TRANSACTION),
+            }
+        };
+        self.observe_duration(OUTCOME_ROLLBACK);
+        result
     }
 }
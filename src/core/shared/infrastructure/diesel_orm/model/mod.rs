@@ -1,2 +1,5 @@
+mod db_pool;
+pub use db_pool::*;
+
 mod db_transaction;
 pub use db_transaction::*;
@@ -0,0 +1,28 @@
+use diesel::pg::PgConnection;
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use tukosmo_domain::core::shared::model::DatabaseMigration;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::repository::MigrationRepository;
+
+use super::super::model::DbConnection;
+use super::super::service::migration_runner;
+
+pub struct DbMigrationRepository {
+    connection: Rc<RefCell<DbConnection>>,
+}
+
+impl DbMigrationRepository {
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { connection }
+    }
+}
+
+impl MigrationRepository for DbMigrationRepository {
+    fn find_all(&self) -> Result<Vec<DatabaseMigration>, DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+        migration_runner::find_migrations(connection)
+    }
+}
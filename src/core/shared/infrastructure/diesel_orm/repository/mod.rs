@@ -0,0 +1,2 @@
+mod db_migration_repository;
+pub use db_migration_repository::*;
@@ -1,5 +1,26 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    gallery (id) {
+        id -> Uuid,
+        i18n_text_id_name -> Uuid,
+        creation_date -> Timestamptz,
+        update_date -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    gallery_image (id) {
+        id -> Uuid,
+        gallery_id -> Uuid,
+        i18n_text_id_caption -> Uuid,
+        file_name -> Text,
+        position -> Int4,
+        creation_date -> Timestamptz,
+        update_date -> Timestamptz,
+    }
+}
+
 diesel::table! {
     i18n_text (id) {
         id -> Uuid,
@@ -33,12 +54,62 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    menu (id) {
+        id -> Uuid,
+        i18n_text_id_name -> Uuid,
+        location -> Nullable<Text>,
+        creation_date -> Timestamptz,
+        update_date -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    menu_item (id) {
+        id -> Uuid,
+        menu_id -> Uuid,
+        parent_id -> Nullable<Uuid>,
+        i18n_text_id_label -> Uuid,
+        link_type -> Text,
+        link_value -> Text,
+        position -> Int4,
+        creation_date -> Timestamptz,
+        update_date -> Timestamptz,
+    }
+}
+
+diesel::table! {
+    widget_instance (id) {
+        id -> Uuid,
+        code -> Text,
+        area -> Text,
+        position -> Int4,
+        i18n_text_id_title -> Uuid,
+        i18n_text_id_content -> Uuid,
+        item_limit -> Int4,
+        language_ids -> Array<Uuid>,
+        creation_date -> Timestamptz,
+        update_date -> Timestamptz,
+    }
+}
+
+diesel::joinable!(gallery -> i18n_text (i18n_text_id_name));
+diesel::joinable!(gallery_image -> gallery (gallery_id));
+diesel::joinable!(gallery_image -> i18n_text (i18n_text_id_caption));
 diesel::joinable!(i18n_translation -> i18n_text (i18n_text_id));
 diesel::joinable!(i18n_translation -> language (language_id));
 diesel::joinable!(language -> i18n_text (i18n_text_id_name));
+diesel::joinable!(menu -> i18n_text (i18n_text_id_name));
+diesel::joinable!(menu_item -> i18n_text (i18n_text_id_label));
+diesel::joinable!(menu_item -> menu (menu_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
+    gallery,
+    gallery_image,
     i18n_text,
     i18n_translation,
     language,
+    menu,
+    menu_item,
+    widget_instance,
 );
@@ -0,0 +1,187 @@
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::connection::SimpleConnection;
+use diesel::pg::PgConnection;
+use diesel::sql_query;
+use diesel::sql_types::Text;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+// Rows are exported as a JSON array, so the dump doesn't depend on the
+// column types of each table (PostgreSQL converts them back on restore)
+pub struct DatabaseTableDump {
+    pub name: String,
+    pub rows_json: String,
+}
+
+#[derive(QueryableByName)]
+struct TableName {
+    #[diesel(sql_type = Text)]
+    table_name: String,
+}
+
+#[derive(QueryableByName)]
+struct TableReference {
+    #[diesel(sql_type = Text)]
+    referenced_table_name: String,
+    #[diesel(sql_type = Text)]
+    table_name: String,
+}
+
+#[derive(QueryableByName)]
+struct TableRows {
+    #[diesel(sql_type = Text)]
+    rows_json: String,
+}
+
+// Must be called inside a transaction, so every table is read from the same
+// snapshot of the database
+pub fn dump_tables(
+    connection: &mut PgConnection
+) -> Result<Vec<DatabaseTableDump>, DomainError> {
+    let table_names = find_table_names(connection)?;
+
+    let mut table_dumps = vec![];
+    for table_name in table_names {
+        let table_rows = sql_query(
+            format!(
+                "SELECT COALESCE(json_agg(t), '[]')::text AS rows_json \
+                 FROM {} t",
+                quote_identifier(&table_name)
+            )
+        )
+            .get_result::<TableRows>(connection)
+            .map_err(|_e| error::CANNOT_DUMP_DATABASE)?;
+
+        table_dumps.push(DatabaseTableDump {
+            name: table_name,
+            rows_json: table_rows.rows_json,
+        });
+    }
+
+    Ok(table_dumps)
+}
+
+// Referenced tables come before the tables that reference them, so rows can
+// be inserted in this order without breaking foreign keys
+pub fn find_table_names(
+    connection: &mut PgConnection
+) -> Result<Vec<String>, DomainError> {
+    let table_names: Vec<String> = sql_query(
+        "SELECT table_name::text AS table_name \
+         FROM information_schema.tables \
+         WHERE table_schema = 'public' \
+         AND table_type = 'BASE TABLE' \
+         AND table_name <> '__diesel_schema_migrations' \
+         ORDER BY table_name"
+    )
+        .load::<TableName>(connection)
+        .map_err(|_e| error::CANNOT_DUMP_DATABASE)?
+        .into_iter()
+        .map(|table| table.table_name)
+        .collect();
+
+    let table_references = sql_query(
+        "SELECT tc.relname::text AS table_name, \
+         rc.relname::text AS referenced_table_name \
+         FROM pg_constraint con \
+         JOIN pg_class tc ON tc.oid = con.conrelid \
+         JOIN pg_class rc ON rc.oid = con.confrelid \
+         JOIN pg_namespace n ON n.oid = tc.relnamespace \
+         WHERE con.contype = 'f' AND n.nspname = 'public'"
+    )
+        .load::<TableReference>(connection)
+        .map_err(|_e| error::CANNOT_DUMP_DATABASE)?;
+
+    let mut pending_references: BTreeMap<String, BTreeSet<String>> =
+        table_names
+            .iter()
+            .map(|table_name| (table_name.clone(), BTreeSet::new()))
+            .collect();
+    for reference in table_references {
+        // Self-references are checked at the end of each INSERT
+        if reference.table_name == reference.referenced_table_name {
+            continue;
+        }
+        if let Some(references) = pending_references.get_mut(
+            &reference.table_name
+        ) {
+            references.insert(reference.referenced_table_name);
+        }
+    }
+
+    let mut sorted_table_names = vec![];
+    while !pending_references.is_empty() {
+        let ready_table_name = pending_references
+            .iter()
+            .find(|(_, references)| references.is_empty())
+            .map(|(table_name, _)| table_name.clone())
+            // A cycle can't be sorted, so the first table breaks it
+            .unwrap_or_else(|| {
+                pending_references.keys().next().unwrap().clone()
+            });
+
+        pending_references.remove(&ready_table_name);
+        for references in pending_references.values_mut() {
+            references.remove(&ready_table_name);
+        }
+        sorted_table_names.push(ready_table_name);
+    }
+
+    Ok(sorted_table_names)
+}
+
+// Must be called inside a transaction, and the schema must be the one that
+// the dump was created with
+pub fn restore_tables(
+    connection: &mut PgConnection,
+    table_dumps: &[DatabaseTableDump]
+) -> Result<(), DomainError> {
+    let table_names = find_table_names(connection)?;
+    let all_tables_exist = table_dumps
+        .iter()
+        .all(|table_dump| table_names.contains(&table_dump.name));
+    if !all_tables_exist {
+        return Err(error::INVALID_BACKUP_FILE);
+    }
+
+    // Migrations insert some rows (e.g. the default language), so the tables
+    // are emptied first
+    if !table_names.is_empty() {
+        let quoted_table_names: Vec<String> = table_names
+            .iter()
+            .map(|table_name| quote_identifier(table_name))
+            .collect();
+        connection
+            .batch_execute(
+                &format!(
+                    "TRUNCATE TABLE {} CASCADE;",
+                    quoted_table_names.join(", ")
+                )
+            )
+            .map_err(|_e| error::CANNOT_RESTORE_BACKUP)?;
+    }
+
+    for table_dump in table_dumps {
+        let quoted_table_name = quote_identifier(&table_dump.name);
+        sql_query(
+            format!(
+                "INSERT INTO {} \
+                 SELECT * FROM json_populate_recordset(NULL::{}, $1::json)",
+                quoted_table_name,
+                quoted_table_name
+            )
+        )
+            .bind::<Text, _>(&table_dump.rows_json)
+            .execute(connection)
+            .map_err(|_e| error::CANNOT_RESTORE_BACKUP)?;
+    }
+
+    Ok(())
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("\"{}\"", identifier.replace('"', "\"\""))
+}
@@ -0,0 +1,28 @@
+use diesel::QueryableByName;
+use diesel::RunQueryDsl;
+use diesel::pg::PgConnection;
+use diesel::sql_query;
+use diesel::sql_types::BigInt;
+use diesel::sql_types::Text;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+#[derive(QueryableByName)]
+pub struct DatabaseInfo {
+    #[diesel(sql_type = BigInt)]
+    pub size_bytes: i64,
+    #[diesel(sql_type = Text)]
+    pub version: String,
+}
+
+// Size of the database of Tukosmo (not of the whole PostgreSQL server)
+pub fn get_database_info(
+    connection: &mut PgConnection
+) -> Result<DatabaseInfo, DomainError> {
+    sql_query(
+        "SELECT pg_database_size(current_database()) AS size_bytes, \
+         current_setting('server_version') AS version"
+    )
+        .get_result::<DatabaseInfo>(connection)
+        .map_err(|_e| error::CANNOT_READ_DATABASE_INFO)
+}
@@ -0,0 +1,170 @@
+use diesel::connection::SimpleConnection;
+use diesel::migration::MigrationSource;
+use diesel::pg::Pg;
+use diesel::pg::PgConnection;
+use diesel_migrations::EmbeddedMigrations;
+use diesel_migrations::MigrationHarness;
+use diesel_migrations::embed_migrations;
+use std::collections::HashSet;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DatabaseMigration;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use super::super::model::DbPool;
+use super::super::model::get_db_connection;
+
+// The path is relative to crates/infrastructure
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!(
+    "../../src/core/shared/infrastructure/diesel_orm/migration"
+);
+
+// Any constant works, as long as every Tukosmo instance uses the same one
+const MIGRATIONS_ADVISORY_LOCK_KEY: i64 = 0x74756b6f736d6f;
+
+pub fn find_migrations(
+    connection: &mut PgConnection
+) -> Result<Vec<DatabaseMigration>, DomainError> {
+    let applied_versions: HashSet<String> = connection
+        .applied_migrations()
+        .map_err(|_e| error::CANNOT_READ_DATABASE_MIGRATIONS)?
+        .into_iter()
+        .map(|version| version.to_string())
+        .collect();
+
+    let embedded_migrations = MigrationSource::<Pg>
+        ::migrations(&MIGRATIONS)
+        .map_err(|_e| error::CANNOT_READ_DATABASE_MIGRATIONS)?;
+
+    let mut migrations: Vec<DatabaseMigration> = embedded_migrations
+        .iter()
+        .map(|migration| {
+            let version = migration.name().version().to_string();
+            DatabaseMigration {
+                is_applied: applied_versions.contains(&version),
+                name: migration.name().to_string(),
+                version,
+            }
+        })
+        .collect();
+    migrations.sort_by(|a, b| a.version.cmp(&b.version));
+
+    Ok(migrations)
+}
+
+pub fn find_embedded_versions() -> Result<Vec<String>, DomainError> {
+    let mut embedded_versions: Vec<String> = MigrationSource::<Pg>
+        ::migrations(&MIGRATIONS)
+        .map_err(|_e| error::CANNOT_READ_DATABASE_MIGRATIONS)?
+        .iter()
+        .map(|migration| migration.name().version().to_string())
+        .collect();
+    embedded_versions.sort();
+    Ok(embedded_versions)
+}
+
+pub fn find_latest_applied_version(
+    connection: &mut PgConnection
+) -> Result<Option<String>, DomainError> {
+    let latest_applied_version = connection
+        .applied_migrations()
+        .map_err(|_e| error::CANNOT_READ_DATABASE_MIGRATIONS)?
+        .into_iter()
+        .map(|version| version.to_string())
+        .max();
+    Ok(latest_applied_version)
+}
+
+// Must be called inside a transaction; the lock is released when it ends
+pub fn lock_migrations_in_transaction(
+    connection: &mut PgConnection
+) -> Result<(), DomainError> {
+    connection
+        .batch_execute(
+            &format!(
+                "SELECT pg_advisory_xact_lock({});",
+                MIGRATIONS_ADVISORY_LOCK_KEY
+            )
+        )
+        .map_err(|_e| error::CANNOT_ACQUIRE_DATABASE_MIGRATIONS_LOCK)
+}
+
+// Reverts or applies migrations until the schema is the one of the given
+// version (e.g. the one a backup was created with)
+pub fn migrate_to_version(
+    connection: &mut PgConnection,
+    version: &str
+) -> Result<(), DomainError> {
+    loop {
+        match find_latest_applied_version(connection)? {
+            Some(latest_version) if latest_version.as_str() > version => {
+                connection
+                    .revert_last_migration(MIGRATIONS)
+                    .map_err(|_e| error::CANNOT_RUN_DATABASE_MIGRATIONS)?;
+            }
+            Some(latest_version) if latest_version.as_str() == version => {
+                return Ok(());
+            }
+            _ => {
+                connection
+                    .run_next_migration(MIGRATIONS)
+                    .map_err(|_e| error::CANNOT_RUN_DATABASE_MIGRATIONS)?;
+            }
+        }
+    }
+}
+
+// Runs before the server binds its ports; the advisory lock makes other
+// instances starting at the same time wait until the migrations are done
+pub fn run_pending_migrations(
+    db_pool: &DbPool
+) -> Result<Vec<String>, DomainError> {
+    let mut connection = get_db_connection(db_pool)?;
+    let connection: &mut PgConnection = &mut connection;
+
+    connection
+        .batch_execute(
+            &format!(
+                "SELECT pg_advisory_lock({});",
+                MIGRATIONS_ADVISORY_LOCK_KEY
+            )
+        )
+        .map_err(|_e| error::CANNOT_ACQUIRE_DATABASE_MIGRATIONS_LOCK)?;
+
+    let result = run_pending_migrations_while_locked(connection);
+
+    // The lock is also released if the connection is closed
+    let _ = connection.batch_execute(
+        &format!("SELECT pg_advisory_unlock({});", MIGRATIONS_ADVISORY_LOCK_KEY)
+    );
+
+    result
+}
+
+pub fn run_pending_migrations_while_locked(
+    connection: &mut PgConnection
+) -> Result<Vec<String>, DomainError> {
+    let embedded_versions: HashSet<String> = find_embedded_versions()?
+        .into_iter()
+        .collect();
+
+    // A newer Tukosmo already migrated this database, so running this binary
+    // could corrupt data it doesn't know about
+    let applied_versions = connection
+        .applied_migrations()
+        .map_err(|_e| error::CANNOT_READ_DATABASE_MIGRATIONS)?;
+    let schema_is_newer = applied_versions
+        .iter()
+        .any(|version| !embedded_versions.contains(&version.to_string()));
+    if schema_is_newer {
+        return Err(error::DATABASE_SCHEMA_IS_NEWER_THAN_BINARY);
+    }
+
+    let applied_now = connection
+        .run_pending_migrations(MIGRATIONS)
+        .map_err(|_e| error::CANNOT_RUN_DATABASE_MIGRATIONS)?
+        .into_iter()
+        .map(|version| version.to_string())
+        .collect();
+
+    Ok(applied_now)
+}
@@ -0,0 +1,3 @@
+pub mod database_dump;
+pub mod database_info;
+pub mod migration_runner;
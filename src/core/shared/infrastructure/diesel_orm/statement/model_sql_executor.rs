@@ -16,6 +16,8 @@ use std::rc::Rc;
 use tukosmo_domain::core::shared::error;
 use tukosmo_domain::core::shared::model::DomainError;
 
+use crate::core::shared::diesel_orm::model::DbConnection;
+
 pub trait ModelSqlExecutor<DbModel, Rows, SearchCriteria, SearchFilterCriteria, Table>
     where
         DbModel: Insertable<Table> +
@@ -39,16 +41,16 @@ pub trait ModelSqlExecutor<DbModel, Rows, SearchCriteria, SearchFilterCriteria,
         search_criteria: SearchFilterCriteria
     ) -> Result<(), DomainError>;
 
-    fn get_connection(&mut self) -> Rc<RefCell<PgConnection>>;
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>>;
 
     fn get_table() -> Table;
 
-    fn init(connection: Rc<RefCell<PgConnection>>) -> Self;
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self;
 
     fn insert(&mut self, db_model: DbModel) -> Result<(), DomainError> {
         let connection = self.get_connection();
         let mut connection = connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let table = Self::get_table();
 
@@ -69,7 +71,7 @@ pub trait ModelSqlExecutor<DbModel, Rows, SearchCriteria, SearchFilterCriteria,
     ) -> Result<(), DomainError> {
         let connection = self.get_connection();
         let mut connection = connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let table = Self::get_table();
 
@@ -96,7 +98,7 @@ pub trait ModelSqlExecutor<DbModel, Rows, SearchCriteria, SearchFilterCriteria,
     ) -> Result<i64, DomainError> {
         let connection = self.get_connection();
         let mut connection = connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         use diesel::QueryDsl;
         let query = Self::select_query(filter_criteria);
@@ -113,7 +115,7 @@ pub trait ModelSqlExecutor<DbModel, Rows, SearchCriteria, SearchFilterCriteria,
     ) -> Result<bool, DomainError> {
         let connection = self.get_connection();
         let mut connection = connection.borrow_mut();
-        let connection = connection.deref_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
 
         let query = Self::select_query(filter_criteria);
         let exists = select(exists(query))
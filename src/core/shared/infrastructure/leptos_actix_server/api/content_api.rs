@@ -0,0 +1,60 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::shared::dto::DtoContentImportReport;
+use tukosmo_application::core::shared::dto::DtoImportContent;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::shared::use_case::ContentUseCase;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+#[server(ApiCoreSharedContentImport)]
+pub async fn import(
+    dto: DtoImportContent
+) -> Result<ServerResponse<DtoContentImportReport>, ServerFnError> {
+    use crate::core::shared::leptos_actix_server::service::session;
+
+    let response = ServerResponse::build(|| {
+        session::check_tap_change_request()?;
+        let content_use_case = common()?;
+        content_use_case.import(dto)
+    });
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+fn common() -> Result<ContentUseCase, DomainError> {
+    use crate::core::shared::leptos_actix_server::service::database;
+    use crate::core::shared::leptos_actix_server::service::page_cache;
+
+    let db_pool = database::get_db_pool()?;
+    common_with_db_pool(&db_pool, page_cache::get_page_cache())
+}
+
+// Used by content_handler::export_content(): it isn't a server function, so
+// there's no Leptos context to get the pool and the page cache from
+#[cfg(feature = "ssr")]
+pub(crate) fn common_with_db_pool(
+    db_pool: &crate::core::shared::diesel_orm::model::DbPool,
+    page_cache: Option<
+        actix_web::web::Data<
+            crate::core::shared::leptos_actix_server::service::page_cache::PageCache
+        >
+    >
+) -> Result<ContentUseCase, DomainError> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::core::shared::diesel_orm::model::DbTransactionExecutor;
+    use crate::core::shared::leptos_actix_server::repository::MemoryPageCacheRepository;
+
+    let page_cache_repository = MemoryPageCacheRepository::init(page_cache);
+    let transaction_executor = DbTransactionExecutor::init(db_pool)?;
+    let content_use_case = ContentUseCase::init(
+        Rc::new(RefCell::new(page_cache_repository)),
+        Rc::new(RefCell::new(transaction_executor))
+    );
+
+    Ok(content_use_case)
+}
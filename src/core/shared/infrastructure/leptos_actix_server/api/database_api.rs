@@ -0,0 +1,42 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::shared::dto::DtoDatabaseStatus;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::shared::use_case::DatabaseUseCase;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+#[server(ApiCoreSharedDatabaseStatus)]
+pub async fn status() -> Result<
+    ServerResponse<DtoDatabaseStatus>,
+    ServerFnError
+> {
+    let response = ServerResponse::build(|| {
+        let database_use_case = common()?;
+        database_use_case.get_status()
+    });
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+fn common() -> Result<DatabaseUseCase, DomainError> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::core::shared::diesel_orm::model::get_db_connection;
+    use crate::core::shared::diesel_orm::repository::DbMigrationRepository;
+    use crate::core::shared::leptos_actix_server::service::database;
+
+    let db_pool = database::get_db_pool()?;
+    let connection = get_db_connection(&db_pool)?;
+    let migration_repository = DbMigrationRepository::init(
+        Rc::new(RefCell::new(connection))
+    );
+    let database_use_case = DatabaseUseCase::init(
+        Rc::new(RefCell::new(migration_repository))
+    );
+
+    Ok(database_use_case)
+}
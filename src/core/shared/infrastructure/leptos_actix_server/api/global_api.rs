@@ -17,10 +17,12 @@ fn common() -> Result<GlobalUseCase, DomainError> {
 
     use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
     use crate::core::shared::diesel_orm::model::DbTransactionExecutor;
+    use crate::core::shared::leptos_actix_server::service::database;
 
     let data_repository = FsDataRepository::init()?;
 
-    let transaction_executor = DbTransactionExecutor::init()?;
+    let db_pool = database::get_db_pool()?;
+    let transaction_executor = DbTransactionExecutor::init(&db_pool)?;
     let global_use_case = GlobalUseCase::init(
         Rc::new(RefCell::new(data_repository)),
         Rc::new(RefCell::new(transaction_executor))
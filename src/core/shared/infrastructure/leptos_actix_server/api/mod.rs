@@ -1 +1,7 @@
+pub mod content_api;
+pub mod database_api;
 pub mod global_api;
+pub mod page_cache_api;
+pub mod settings_api;
+pub mod system_info_api;
+pub mod tls_api;
@@ -0,0 +1,50 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::shared::dto::DtoPageCacheStats;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+#[server(ApiCoreSharedPageCacheGetStats)]
+pub async fn get_stats() -> Result<
+    ServerResponse<DtoPageCacheStats>,
+    ServerFnError
+> {
+    use tukosmo_domain::core::shared::error;
+
+    use crate::core::shared::leptos_actix_server::service::page_cache;
+
+    let response = ServerResponse::build(|| {
+        let page_cache = page_cache::get_page_cache()
+            .ok_or(error::PAGE_CACHE_NOT_AVAILABLE)?;
+        let stats = page_cache.get_stats();
+
+        Ok(DtoPageCacheStats {
+            enabled: stats.enabled,
+            entries: stats.entries,
+            hits: stats.hits,
+            max_size_bytes: stats.max_size_bytes,
+            misses: stats.misses,
+            size_bytes: stats.size_bytes,
+        })
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreSharedPageCachePurge)]
+pub async fn purge() -> Result<ServerResponse<()>, ServerFnError> {
+    use tukosmo_domain::core::shared::error;
+
+    use crate::core::shared::leptos_actix_server::service::page_cache;
+    use crate::core::shared::leptos_actix_server::service::session;
+
+    let response = ServerResponse::build(|| {
+        session::check_tap_change_request()?;
+        let page_cache = page_cache::get_page_cache()
+            .ok_or(error::PAGE_CACHE_NOT_AVAILABLE)?;
+        page_cache.purge();
+
+        Ok(())
+    });
+
+    Ok(response)
+}
@@ -0,0 +1,90 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::shared::dto::DtoChangeDomain;
+use tukosmo_application::core::shared::dto::DtoDomain;
+use tukosmo_application::core::shared::dto::DtoEditServerSettings;
+use tukosmo_application::core::shared::dto::DtoServerSettingsForm;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::shared::use_case::SettingsUseCase;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+// Not a use case, since the new certificate is obtained asynchronously. The
+// server is restarted when the domain has been changed
+#[server(ApiCoreSharedSettingsChangeDomain)]
+pub async fn change_domain(
+    dto: DtoChangeDomain
+) -> Result<ServerResponse<()>, ServerFnError> {
+    use crate::core::shared::leptos_actix_server::service::domain;
+    use crate::core::shared::leptos_actix_server::service::session;
+
+    let result = match session::check_tap_change_request() {
+        Ok(()) => domain::change_domain(dto.domain.trim()).await,
+        Err(domain_error) => Err(domain_error),
+    };
+    let response = ServerResponse::build(|| result);
+
+    Ok(response)
+}
+
+// The server is restarted after saving Tukosmo.toml, since most of its
+// values (ports, database...) are only read when the server starts
+#[server(ApiCoreSharedSettingsEditServer)]
+pub async fn edit_server(
+    dto: DtoEditServerSettings
+) -> Result<ServerResponse<()>, ServerFnError> {
+    use crate::core::shared::leptos_actix_server::service::server;
+    use crate::core::shared::leptos_actix_server::service::session;
+
+    let response = ServerResponse::build(|| {
+        session::check_tap_change_request()?;
+        let settings_use_case = common()?;
+        settings_use_case.edit_server_settings(dto)?;
+        server::restart_server()
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreSharedSettingsGetDomain)]
+pub async fn get_domain() -> Result<ServerResponse<DtoDomain>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let settings_use_case = common()?;
+        settings_use_case.get_domain()
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreSharedSettingsGetServer)]
+pub async fn get_server() -> Result<
+    ServerResponse<DtoServerSettingsForm>,
+    ServerFnError
+> {
+    use crate::core::shared::leptos_actix_server::service::session;
+
+    // The database host and user are shown
+    let response = ServerResponse::build(|| {
+        session::check_tap_read_request()?;
+        let settings_use_case = common()?;
+        settings_use_case.get_server_settings()
+    });
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+fn common() -> Result<SettingsUseCase, DomainError> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+
+    let data_repository = FsDataRepository::init()?;
+    let settings_use_case = SettingsUseCase::init(
+        Rc::new(RefCell::new(data_repository))
+    );
+
+    Ok(settings_use_case)
+}
@@ -0,0 +1,54 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::shared::dto::DtoSystemInfo;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+// Not a use case, since the CPU usage is measured asynchronously
+#[server(ApiCoreSharedSystemInfoGet)]
+pub async fn get() -> Result<ServerResponse<DtoSystemInfo>, ServerFnError> {
+    let result = get_system_info().await;
+    let response = ServerResponse::build(|| result);
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+async fn get_system_info() -> Result<DtoSystemInfo, DomainError> {
+    use crate::core::shared::diesel_orm::model::get_db_connection;
+    use crate::core::shared::diesel_orm::service::database_info;
+    use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+    use crate::core::shared::leptos_actix_server::service::database;
+    use crate::core::shared::leptos_actix_server::service::system_info;
+
+    // Before waiting for the CPU usage, so the request context is still
+    // available and the connection goes back to the pool right away
+    let database_info = {
+        let db_pool = database::get_db_pool()?;
+        let mut connection = get_db_connection(&db_pool)?;
+        database_info::get_database_info(&mut connection)?
+    };
+
+    let fs_data_repository = FsDataRepository::init()?;
+    let system_info = system_info::get_system_info(
+        &fs_data_repository.data_dir_path
+    ).await?;
+
+    Ok(DtoSystemInfo {
+        booted_at: system_info.booted_at,
+        cpu_cores: system_info.cpu_cores,
+        cpu_model: system_info.cpu_model,
+        cpu_usage_percentage: system_info.cpu_usage_percentage,
+        database_size_bytes: database_info.size_bytes.max(0) as u64,
+        database_version: database_info.version,
+        disk_total_bytes: system_info.disk_total_bytes,
+        disk_used_bytes: system_info.disk_used_bytes,
+        kernel_version: system_info.kernel_version,
+        memory_total_bytes: system_info.memory_total_bytes,
+        memory_used_bytes: system_info.memory_used_bytes,
+        os_name: system_info.os_name,
+        tukosmo_version: env!("CARGO_PKG_VERSION").to_string(),
+        uptime_seconds: system_info.uptime_seconds,
+    })
+}
@@ -0,0 +1,86 @@
+use leptos::ServerFnError;
+use leptos::server;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::shared::dto::DtoTlsCertificate;
+use tukosmo_application::core::shared::dto::DtoTlsStatus;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+#[server(ApiCoreSharedTlsGetStatus)]
+pub async fn get_status() -> Result<
+    ServerResponse<DtoTlsStatus>,
+    ServerFnError
+> {
+    use tukosmo_domain::core::shared::model::ServerTlsMode;
+    use tukosmo_domain::core::shared::repository::DataRepository;
+
+    use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+
+    let response = ServerResponse::build(|| {
+        let fs_data_repository = FsDataRepository::init()?;
+        let server_config = fs_data_repository.get_server_config()?;
+        let renewal_status = fs_data_repository.get_tls_renewal_status()?;
+
+        // A missing or damaged certificate is shown in the page instead of
+        // failing, so it can be renewed from there
+        let certificate_result = match server_config.tls.mode {
+            ServerTlsMode::Off => Ok(None),
+            _ => get_certificate().map(Some),
+        };
+        let (certificate, certificate_error_code) = match certificate_result {
+            Ok(certificate) => (certificate, None),
+            Err(domain_error) => (None, Some(domain_error.get_full_code())),
+        };
+
+        Ok(DtoTlsStatus {
+            certificate,
+            certificate_error_code,
+            expiration_warning_sent_at:
+                renewal_status.expiration_warning_sent_at,
+            last_renewal_attempt_at: renewal_status.last_attempt_at,
+            last_renewal_error_code: renewal_status.last_error_code,
+            mode: server_config.tls.mode.value().to_string(),
+        })
+    });
+
+    Ok(response)
+}
+
+// Obtains a new certificate from the ACME directory (or a new self-signed
+// one), which the running server starts using right away
+#[server(ApiCoreSharedTlsRenewCertificate)]
+pub async fn renew_certificate() -> Result<
+    ServerResponse<()>,
+    ServerFnError
+> {
+    use crate::core::shared::leptos_actix_server::service::session;
+    use crate::core::shared::leptos_actix_server::service::tls;
+
+    // Each order counts against the rate limits of the ACME directory
+    let result = match session::check_tap_change_request() {
+        Ok(()) => tls::renew_certificate(tls::ACME_MAX_ATTEMPTS_FROM_TAP)
+            .await
+            .and_then(|()| tls::reload_certificate_of_running_server()),
+        Err(domain_error) => Err(domain_error),
+    };
+    let response = ServerResponse::build(|| result);
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+fn get_certificate() -> Result<DtoTlsCertificate, DomainError> {
+    use crate::core::shared::leptos_actix_server::service::tls;
+
+    let status = tls::get_certificate_status()?;
+
+    Ok(DtoTlsCertificate {
+        days_until_expiration: status.days_until_expiration,
+        expires_at: status.expires_at,
+        is_self_signed: status.is_self_signed,
+        issued_at: status.issued_at,
+        issuer: status.issuer,
+        subject_alt_names: status.subject_alt_names,
+    })
+}
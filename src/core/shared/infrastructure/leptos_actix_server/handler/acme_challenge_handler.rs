@@ -0,0 +1,202 @@
+use actix_web::HttpResponse;
+use actix_web::get;
+use actix_web::http;
+use actix_web::web;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use super::super::repository::FsDataRepository;
+
+pub const ACME_CHALLENGE_PATH_PREFIX: &'static str =
+    "/.well-known/acme-challenge/";
+
+// Served over plain HTTP (see RedirectHTTPS), since the CA validates the
+// challenge on port 80 of the domain, which must reach the HTTP port of the
+// server (e.g. with a port mapping). The proofs are written to the data dir
+// by the ACME client, so every worker can serve them
+#[get("/.well-known/acme-challenge/{token}")]
+pub async fn acme_challenge(
+    path: web::Path<String>,
+    fs_data_repository: web::Data<FsDataRepository>
+) -> HttpResponse {
+    let token = path.into_inner();
+
+    // Tokens are base64url, so anything else (e.g. "..") isn't a token
+    let is_valid_token = !token.is_empty() &&
+        token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if !is_valid_token {
+        return HttpResponse::NotFound().finish();
+    }
+
+    match fs_data_repository.get_acme_challenge_proof(&token) {
+        Ok(Some(proof)) =>
+            HttpResponse::Ok()
+                .content_type(http::header::ContentType::plaintext())
+                .body(proof),
+        Ok(None) => HttpResponse::NotFound().finish(),
+        Err(_domain_error) => HttpResponse::InternalServerError().finish(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::App;
+    use actix_web::http::StatusCode;
+    use actix_web::test;
+    use std::env;
+    use std::fs;
+    use std::process;
+    use tukosmo_domain::core::shared::model::DomainError;
+
+    use super::super::super::service::server::RedirectHTTPS;
+    use super::*;
+
+    const HTTP_PORT: &'static str = "8001";
+    const HTTPS_PORT: &'static str = "8443";
+
+    // Every test uses the same data dir (the environment variable is shared
+    // by the whole process), so each one writes its own token
+    fn init_fs_data_repository() -> FsDataRepository {
+        let data_dir_path = env::temp_dir().join(
+            format!("tukosmo-acme-challenge-handler-{}", process::id())
+        );
+        env::set_var("TUKOSMO_DATA_DIR", &data_dir_path);
+        let fs_data_repository = unwrap(FsDataRepository::init());
+        fs::create_dir_all(&fs_data_repository.acme_challenge_dir_path)
+            .unwrap();
+        fs_data_repository
+    }
+
+    async fn request_token(
+        fs_data_repository: FsDataRepository,
+        token_path: &str
+    ) -> (StatusCode, String) {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(fs_data_repository))
+                .service(acme_challenge)
+        ).await;
+        let request = test::TestRequest::get()
+            .uri(&format!("{}{}", ACME_CHALLENGE_PATH_PREFIX, token_path))
+            .to_request();
+        let response = test::call_service(&app, request).await;
+
+        let status = response.status();
+        let body = test::read_body(response).await;
+        (status, String::from_utf8(body.to_vec()).unwrap())
+    }
+
+    fn unwrap<T>(result: Result<T, DomainError>) -> T {
+        result.unwrap_or_else(|domain_error| {
+            panic!("{}: {}", domain_error.get_full_code(), domain_error.message)
+        })
+    }
+
+    #[actix_web::test]
+    async fn serves_the_proof_of_a_valid_token() {
+        let fs_data_repository = init_fs_data_repository();
+        unwrap(
+            fs_data_repository.write_acme_challenge_token_file(
+                "valid-Token_1",
+                "valid-Token_1.proof"
+            )
+        );
+
+        let (status, body) = request_token(
+            fs_data_repository,
+            "valid-Token_1"
+        ).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, "valid-Token_1.proof");
+    }
+
+    #[actix_web::test]
+    async fn rejects_tokens_that_leave_the_challenge_dir() {
+        let fs_data_repository = init_fs_data_repository();
+        // Readable from the challenge dir as "../traversal-proof"
+        fs::write(
+            format!(
+                "{}/../traversal-proof",
+                fs_data_repository.acme_challenge_dir_path
+            ),
+            "secret"
+        ).unwrap();
+
+        let token_paths = [
+            "..",
+            "..%2Ftraversal-proof",
+            "..%2f..%2FTukosmo.toml",
+        ];
+        for token_path in token_paths {
+            let (status, body) = request_token(
+                fs_data_repository.clone(),
+                token_path
+            ).await;
+
+            assert_eq!(status, StatusCode::NOT_FOUND, "{}", token_path);
+            assert!(body.is_empty(), "{}", token_path);
+        }
+    }
+
+    #[actix_web::test]
+    async fn returns_not_found_for_unknown_tokens() {
+        let fs_data_repository = init_fs_data_repository();
+
+        let (status, body) = request_token(
+            fs_data_repository,
+            "unknown-token"
+        ).await;
+
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert!(body.is_empty());
+    }
+
+    #[actix_web::test]
+    async fn is_not_redirected_to_https() {
+        let fs_data_repository = init_fs_data_repository();
+        unwrap(
+            fs_data_repository.write_acme_challenge_token_file(
+                "plain-http-token",
+                "plain-http-token.proof"
+            )
+        );
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(fs_data_repository))
+                .service(acme_challenge)
+                .wrap(
+                    RedirectHTTPS::init(
+                        &[(HTTP_PORT.to_string(), HTTPS_PORT.to_string())],
+                        true
+                    )
+                )
+        ).await;
+
+        // Test requests are plain HTTP, so anything else is redirected
+        let request = test::TestRequest::get()
+            .insert_header(("host", format!("tukosmo.test:{}", HTTP_PORT)))
+            .uri("/")
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            response.headers().get(http::header::LOCATION).unwrap(),
+            &format!("https://tukosmo.test:{}/", HTTPS_PORT)
+        );
+
+        let request = test::TestRequest::get()
+            .insert_header(("host", format!("tukosmo.test:{}", HTTP_PORT)))
+            .uri(
+                &format!("{}plain-http-token", ACME_CHALLENGE_PATH_PREFIX)
+            )
+            .to_request();
+        let response = test::call_service(&app, request).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            test::read_body(response).await,
+            "plain-http-token.proof"
+        );
+    }
+}
@@ -0,0 +1,201 @@
+use actix_files::NamedFile;
+use actix_identity::Identity;
+use actix_multipart::Multipart;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::http;
+use actix_web::http::header::ContentDisposition;
+use actix_web::http::header::DispositionParam;
+use actix_web::http::header::DispositionType;
+use actix_web::post;
+use actix_web::web;
+use chrono::Utc;
+use serde::Deserialize;
+use std::fs;
+use tukosmo_domain::core::language::model::LanguageCode;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerConfig;
+
+use crate::core::shared::diesel_orm::model::DbPool;
+use crate::core::shared::leptos_ui::service::navigation;
+use super::super::repository::FsDataRepository;
+use super::super::service::backup;
+use super::super::service::csrf;
+use super::super::service::file_system;
+use super::super::service::multipart;
+use super::super::service::page_cache::PageCache;
+use super::super::service::session;
+
+#[derive(Deserialize)]
+pub struct BackupQuery {
+    language_code: String,
+}
+
+const BYTES_IN_A_MEGABYTE: u64 = 1048576;
+
+// The backup is also kept in the backups dir of the server, so it's a POST
+// (only sent by the form of the TAP)
+#[post("/api/backup/export")]
+pub async fn export_backup(
+    request: HttpRequest,
+    identity: Option<Identity>,
+    query: web::Query<BackupQuery>,
+    db_pool: web::Data<DbPool>,
+    fs_data_repository: web::Data<FsDataRepository>
+) -> HttpResponse {
+    let language_code = LanguageCode::from(query.language_code.clone());
+
+    if let Err(domain_error) = check_request(&request, &identity) {
+        return redirect_to_data_page(&language_code, Err(domain_error));
+    }
+
+    let result = web
+        ::block(move || {
+            let backup_file_path = backup::new_backup_file_path(
+                &fs_data_repository,
+                false
+            )?;
+            backup::create_backup(
+                &db_pool,
+                &fs_data_repository,
+                &backup_file_path
+            )?;
+            Ok::<String, DomainError>(backup_file_path)
+        }).await
+        .unwrap_or(Err(error::CANNOT_CREATE_BACKUP))
+        .and_then(|backup_file_path| {
+            NamedFile::open(&backup_file_path)
+                .map(|named_file| (backup_file_path, named_file))
+                .map_err(|_e| error::CANNOT_READ_FILE)
+        });
+
+    match result {
+        Ok((backup_file_path, named_file)) => {
+            let file_name = backup_file_path
+                .rsplit('/')
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            named_file
+                .set_content_disposition(ContentDisposition {
+                    disposition: DispositionType::Attachment,
+                    parameters: vec![DispositionParam::Filename(file_name)],
+                })
+                .into_response(&request)
+        }
+        Err(domain_error) =>
+            redirect_to_data_page(&language_code, Err(domain_error)),
+    }
+}
+
+// Replaces all the data of the website, so the browser is redirected back to
+// the TAP with the result in the query string
+#[post("/api/backup/import")]
+pub async fn import_backup(
+    request: HttpRequest,
+    identity: Option<Identity>,
+    query: web::Query<BackupQuery>,
+    db_pool: web::Data<DbPool>,
+    fs_data_repository: web::Data<FsDataRepository>,
+    page_cache: web::Data<PageCache>,
+    server_config: web::Data<ServerConfig>,
+    payload: Multipart
+) -> HttpResponse {
+    let language_code = LanguageCode::from(query.language_code.clone());
+
+    // Before reading the upload, so it isn't written to disk
+    if let Err(domain_error) = check_request(&request, &identity) {
+        return redirect_to_data_page(&language_code, Err(domain_error));
+    }
+
+    let max_size_bytes =
+        server_config.backup.max_import_size_mb * BYTES_IN_A_MEGABYTE;
+    let result = restore_uploaded_backup(
+        db_pool,
+        fs_data_repository,
+        payload,
+        max_size_bytes
+    ).await;
+
+    // The restored content can differ in every page
+    if result.is_ok() {
+        page_cache.purge();
+    }
+
+    redirect_to_data_page(&language_code, result)
+}
+
+// The backups contain all the data of the website, so they require a TAP
+// session, and the requests must come from the TAP itself
+fn check_request(
+    request: &HttpRequest,
+    identity: &Option<Identity>
+) -> Result<(), DomainError> {
+    session::check_tap_session(identity)?;
+    csrf::check_same_origin(request)
+}
+
+fn redirect_to_data_page(
+    language_code: &LanguageCode,
+    result: Result<(), DomainError>
+) -> HttpResponse {
+    let data_path = navigation::path_admin_data(language_code);
+    let location = match result {
+        Ok(()) => format!("{}?success=1", data_path),
+        Err(domain_error) => {
+            domain_error.log();
+            format!("{}?error={}", data_path, domain_error.get_full_code())
+        }
+    };
+
+    HttpResponse::SeeOther()
+        .append_header((http::header::LOCATION, location))
+        .finish()
+}
+
+async fn restore_uploaded_backup(
+    db_pool: web::Data<DbPool>,
+    fs_data_repository: web::Data<FsDataRepository>,
+    payload: Multipart,
+    max_size_bytes: u64
+) -> Result<(), DomainError> {
+    let tmp_dir_path = format!("{}/tmp", fs_data_repository.data_dir_path);
+    fs::create_dir_all(&tmp_dir_path).map_err(
+        |_e| error::CANNOT_CREATE_DIRECTORY
+    )?;
+
+    let uploaded_file_path = format!(
+        "{}/upload-{}{}",
+        tmp_dir_path,
+        Utc::now().timestamp_millis(),
+        backup::BACKUP_FILE_EXTENSION
+    );
+
+    let result = match multipart::save_uploaded_file(
+        payload,
+        &uploaded_file_path,
+        max_size_bytes
+    ).await {
+        Ok(true) => {
+            let uploaded_file_path = uploaded_file_path.clone();
+            web
+                ::block(move || {
+                    backup::restore_backup(
+                        &db_pool,
+                        &fs_data_repository,
+                        &uploaded_file_path
+                    )
+                }).await
+                .unwrap_or(Err(error::CANNOT_RESTORE_BACKUP))
+                .map(|_manifest| ())
+        }
+        Ok(false) => Err(error::NO_FILE_UPLOADED),
+        Err(domain_error) => Err(domain_error),
+    };
+
+    // Also when the upload was too large or incomplete
+    let _ = file_system::remove_file(&uploaded_file_path);
+
+    result
+}
@@ -0,0 +1,70 @@
+use actix_web::HttpResponse;
+use actix_web::get;
+use actix_web::http;
+use actix_web::http::header::ContentDisposition;
+use actix_web::http::header::ContentType;
+use actix_web::http::header::DispositionParam;
+use actix_web::http::header::DispositionType;
+use actix_web::web;
+use serde::Deserialize;
+use tukosmo_domain::core::language::model::LanguageCode;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::diesel_orm::model::DbPool;
+use crate::core::shared::leptos_ui::service::navigation;
+use super::super::api::content_api;
+
+#[derive(Deserialize)]
+pub struct ContentQuery {
+    language_code: String,
+}
+
+#[get("/api/content/export")]
+pub async fn export_content(
+    query: web::Query<ContentQuery>,
+    db_pool: web::Data<DbPool>
+) -> HttpResponse {
+    let language_code = LanguageCode::from(query.language_code.clone());
+
+    let result = web
+        ::block(move || {
+            // Exporting doesn't change any page
+            let content_use_case = content_api::common_with_db_pool(
+                &db_pool,
+                None
+            )?;
+            let content = content_use_case.export()?;
+            let content_json = serde_json
+                ::to_string_pretty(&content)
+                .map_err(|_e| error::CANNOT_EXPORT_CONTENT)?;
+            let file_name = format!(
+                "tukosmo-content-{}.json",
+                content.exported_at.format("%Y%m%d-%H%M%S")
+            );
+            Ok::<(String, String), DomainError>((file_name, content_json))
+        }).await
+        .unwrap_or(Err(error::CANNOT_EXPORT_CONTENT));
+
+    match result {
+        Ok((file_name, content_json)) =>
+            HttpResponse::Ok()
+                .content_type(ContentType::json())
+                .insert_header(ContentDisposition {
+                    disposition: DispositionType::Attachment,
+                    parameters: vec![DispositionParam::Filename(file_name)],
+                })
+                .body(content_json),
+        Err(domain_error) => {
+            domain_error.log();
+            let location = format!(
+                "{}?error={}",
+                navigation::path_admin_data(&language_code),
+                domain_error.get_full_code()
+            );
+            HttpResponse::SeeOther()
+                .append_header((http::header::LOCATION, location))
+                .finish()
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use actix_multipart::Multipart;
+use actix_web::HttpResponse;
+use actix_web::http;
+use actix_web::post;
+use actix_web::web;
+use chrono::Utc;
+use serde::Deserialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_application::core::shared::dto::DtoUploadFavicon;
+use tukosmo_application::core::shared::use_case::FaviconUseCase;
+use tukosmo_domain::core::language::model::LanguageCode;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::leptos_ui::service::navigation;
+use super::super::repository::FsDataRepository;
+use super::super::service::multipart;
+
+#[derive(Deserialize)]
+pub struct UploadFaviconQuery {
+    language_code: String,
+}
+
+fn common() -> Result<FaviconUseCase, DomainError> {
+    let data_repository = FsDataRepository::init()?;
+
+    let favicon_use_case = FaviconUseCase::init(
+        Rc::new(RefCell::new(data_repository))
+    );
+
+    Ok(favicon_use_case)
+}
+
+#[post("/api/upload/favicon")]
+pub async fn upload_favicon(
+    query: web::Query<UploadFaviconQuery>,
+    payload: Multipart
+) -> HttpResponse {
+    let language_code = LanguageCode::from(query.language_code.clone());
+
+    let result = match multipart::read_uploaded_files(payload).await {
+        Ok(files) => {
+            let dto = DtoUploadFavicon { files };
+            common().and_then(|favicon_use_case| favicon_use_case.upload(dto))
+        }
+        Err(e) => Err(e),
+    };
+
+    let favicon_path = navigation::path_admin_favicon(&language_code);
+    let location = match result {
+        // The value lets the TAP bypass the browser's cached favicon
+        Ok(()) =>
+            format!(
+                "{}?success={:x}",
+                favicon_path,
+                Utc::now().timestamp()
+            ),
+        Err(domain_error) => {
+            domain_error.log();
+            format!("{}?error={}", favicon_path, domain_error.get_full_code())
+        }
+    };
+
+    HttpResponse::SeeOther()
+        .append_header((http::header::LOCATION, location))
+        .finish()
+}
@@ -0,0 +1,6 @@
+pub mod acme_challenge_handler;
+pub mod backup_handler;
+pub mod content_handler;
+pub mod favicon_handler;
+pub mod monitoring_handler;
+pub mod static_file_handler;
@@ -0,0 +1,263 @@
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::get;
+use actix_web::http;
+use actix_web::web;
+use diesel::pg::PgConnection;
+use serde_json::json;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerTlsConfig;
+use tukosmo_domain::core::shared::model::ServerTlsMode;
+
+use crate::core::shared::diesel_orm::model::DbPool;
+use crate::core::shared::diesel_orm::model::get_db_connection;
+use crate::core::shared::diesel_orm::service::migration_runner;
+use super::super::service::metrics as metrics_service;
+use super::super::service::metrics::Metric;
+use super::super::service::metrics::MetricKind;
+use super::super::service::page_cache::PageCache;
+use super::super::service::request_origin;
+use super::super::service::tls;
+use super::super::service::tls::TlsCertificateResolver;
+
+// Answered over plain HTTP too (see RedirectHTTPS), since health checks of
+// orchestrators and load balancers usually reach the HTTP port
+pub const MONITORING_PATHS: [&'static str; 3] = [
+    "/healthz",
+    "/metrics",
+    "/readyz",
+];
+
+const CHECK_DISABLED: &'static str = "disabled";
+const CHECK_INTERNAL_ERROR: &'static str = "internal_error";
+const CHECK_OK: &'static str = "ok";
+
+// The process is up and answering requests
+#[get("/healthz")]
+pub async fn healthz(
+    request: HttpRequest,
+    server_config: web::Data<ServerConfig>
+) -> HttpResponse {
+    if !is_authorized(&request, &server_config) {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    HttpResponse::Ok()
+        .content_type(http::header::ContentType::plaintext())
+        .body("ok\n")
+}
+
+#[get("/metrics")]
+pub async fn metrics(
+    request: HttpRequest,
+    server_config: web::Data<ServerConfig>,
+    db_pool: web::Data<DbPool>,
+    page_cache: web::Data<PageCache>
+) -> HttpResponse {
+    if !is_authorized(&request, &server_config) {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let db_pool_state = db_pool.state();
+    let page_cache_stats = page_cache.get_stats();
+    let mut metrics_values = vec![
+        Metric {
+            help: "Open connections of the database pool.",
+            kind: MetricKind::Gauge,
+            name: "tukosmo_db_pool_connections",
+            value: db_pool_state.connections as f64,
+        },
+        Metric {
+            help: "Idle connections of the database pool.",
+            kind: MetricKind::Gauge,
+            name: "tukosmo_db_pool_idle_connections",
+            value: db_pool_state.idle_connections as f64,
+        },
+        Metric {
+            help: "Maximum connections of the database pool.",
+            kind: MetricKind::Gauge,
+            name: "tukosmo_db_pool_max_connections",
+            value: db_pool.max_size() as f64,
+        },
+        Metric {
+            help: "Pages stored in the page cache.",
+            kind: MetricKind::Gauge,
+            name: "tukosmo_page_cache_entries",
+            value: page_cache_stats.entries as f64,
+        },
+        Metric {
+            help: "Requests answered from the page cache.",
+            kind: MetricKind::Counter,
+            name: "tukosmo_page_cache_hits_total",
+            value: page_cache_stats.hits as f64,
+        },
+        Metric {
+            help: "Cacheable requests that had to be rendered.",
+            kind: MetricKind::Counter,
+            name: "tukosmo_page_cache_misses_total",
+            value: page_cache_stats.misses as f64,
+        },
+        Metric {
+            help: "Size of the pages stored in the page cache.",
+            kind: MetricKind::Gauge,
+            name: "tukosmo_page_cache_size_bytes",
+            value: page_cache_stats.size_bytes as f64,
+        },
+    ];
+
+    // Without TLS (or without a readable certificate) there's no value, and
+    // alerts can check that the metric is missing
+    let certificate_status = if server_config.tls.mode == ServerTlsMode::Off {
+        None
+    } else {
+        web::block(tls::get_certificate_status).await.ok().and_then(Result::ok)
+    };
+    if let Some(certificate_status) = certificate_status {
+        metrics_values.push(Metric {
+            help: "Days until the TLS certificate expires.",
+            kind: MetricKind::Gauge,
+            name: "tukosmo_tls_certificate_days_until_expiration",
+            value: certificate_status.days_until_expiration as f64,
+        });
+    }
+
+    HttpResponse::Ok()
+        .content_type("text/plain; version=0.0.4; charset=utf-8")
+        .body(metrics_service::render_metrics(&metrics_values))
+}
+
+// The server can answer requests: the database is reachable, its schema is
+// the one of this binary, and the certificate (if any) is loaded and valid
+#[get("/readyz")]
+pub async fn readyz(
+    request: HttpRequest,
+    server_config: web::Data<ServerConfig>,
+    db_pool: web::Data<DbPool>,
+    certificate_resolver: web::Data<Option<TlsCertificateResolver>>
+) -> HttpResponse {
+    if !is_authorized(&request, &server_config) {
+        return HttpResponse::Forbidden().finish();
+    }
+
+    let (database_check, migrations_check) = web
+        ::block(move || check_database(&db_pool))
+        .await
+        .unwrap_or_else(|_e| {
+            let check = Err(CHECK_INTERNAL_ERROR.to_string());
+            (check.clone(), check)
+        });
+
+    let tls_is_enabled = server_config.tls.mode != ServerTlsMode::Off;
+    let certificate_check = if !tls_is_enabled {
+        Ok(CHECK_DISABLED)
+    } else if certificate_resolver.is_none() {
+        Err("certificate_not_loaded".to_string())
+    } else {
+        web::block(tls::get_certificate_status)
+            .await
+            .map_err(|_e| CHECK_INTERNAL_ERROR.to_string())
+            .and_then(|result| {
+                result.map_err(|domain_error| domain_error.get_full_code())
+            })
+            .and_then(|certificate_status| {
+                if certificate_status.days_until_expiration < 0 {
+                    Err("certificate_expired".to_string())
+                } else {
+                    Ok(CHECK_OK)
+                }
+            })
+    };
+
+    let checks = [
+        ("certificate", certificate_check),
+        ("database", database_check),
+        ("migrations", migrations_check),
+    ];
+    let is_ready = checks.iter().all(|(_name, check)| check.is_ok());
+    let checks_json: serde_json::Map<String, serde_json::Value> = checks
+        .into_iter()
+        .map(|(name, check)| {
+            let value = match check {
+                Ok(value) => value.to_string(),
+                Err(value) => value,
+            };
+            (name.to_string(), serde_json::Value::String(value))
+        })
+        .collect();
+    let body = json!({
+        "checks": checks_json,
+        "status": if is_ready { "ready" } else { "not_ready" },
+    });
+
+    if is_ready {
+        HttpResponse::Ok().json(body)
+    } else {
+        HttpResponse::ServiceUnavailable().json(body)
+    }
+}
+
+// The database check fails when no connection can be obtained, and the
+// migrations one when any embedded migration isn't applied
+fn check_database(
+    db_pool: &DbPool
+) -> (Result<&'static str, String>, Result<&'static str, String>) {
+    let mut connection = match get_db_connection(db_pool) {
+        Ok(connection) => connection,
+        Err(domain_error) => {
+            let check = Err(domain_error.get_full_code());
+            return (check.clone(), check);
+        }
+    };
+    let connection: &mut PgConnection = &mut connection;
+
+    let migrations_check = match migration_runner::find_migrations(connection) {
+        Ok(migrations) if migrations.iter().all(|m| m.is_applied) =>
+            Ok(CHECK_OK),
+        Ok(_migrations) => Err("pending_migrations".to_string()),
+        Err(domain_error) => Err(domain_error.get_full_code()),
+    };
+
+    (Ok(CHECK_OK), migrations_check)
+}
+
+// Allowed for the IPs of monitoring.allowed_ips, or with the token of
+// monitoring.token (Authorization: Bearer <token>)
+fn is_authorized(request: &HttpRequest, server_config: &ServerConfig) -> bool {
+    let monitoring = &server_config.monitoring;
+
+    let client_ip = request_origin::get_request_origin(request).client_ip;
+    let ip_is_allowed = client_ip.map_or(false, |client_ip| {
+        monitoring.allowed_ips
+            .iter()
+            .filter_map(|value| ServerTlsConfig::parse_trusted_proxy(value))
+            .any(|(network, prefix_length)| {
+                request_origin::ip_is_in_network(
+                    &client_ip,
+                    &network,
+                    prefix_length
+                )
+            })
+    });
+    if ip_is_allowed {
+        return true;
+    }
+
+    if monitoring.token.is_empty() {
+        return false;
+    }
+    request
+        .headers()
+        .get(http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .map_or(false, |token| {
+            is_same_token(token.trim().as_bytes(), monitoring.token.as_bytes())
+        })
+}
+
+// Compares every byte, so the time taken doesn't tell how much of the token
+// was right
+fn is_same_token(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() &&
+        a.iter().zip(b.iter()).fold(0, |result, (x, y)| result | (x ^ y)) == 0
+}
@@ -0,0 +1,65 @@
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::get;
+use actix_web::web;
+use leptos::LeptosOptions;
+
+use super::super::service::static_file;
+use super::super::service::static_file::CachePolicy;
+use super::super::service::static_file::PkgVersion;
+
+#[get("/assets/{file_path:.*}")]
+pub async fn asset(
+    request: HttpRequest,
+    path: web::Path<String>,
+    leptos_options: web::Data<LeptosOptions>
+) -> actix_web::Result<HttpResponse> {
+    let file_path = path.into_inner();
+    if !static_file::is_safe_relative_path(&file_path) {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    static_file::serve_file(
+        &request,
+        &format!("{}/{}", leptos_options.site_root, file_path),
+        CachePolicy::Revalidate
+    )
+}
+
+// The scripts of the pages load /pkg/{version}/..., so those files can be
+// cached forever. Versions from before a deploy (e.g. pages kept open) get
+// the current files, but revalidated
+#[get("/pkg/{file_path:.*}")]
+pub async fn pkg_file(
+    request: HttpRequest,
+    path: web::Path<String>,
+    leptos_options: web::Data<LeptosOptions>,
+    pkg_version: web::Data<PkgVersion>
+) -> actix_web::Result<HttpResponse> {
+    let file_path = path.into_inner();
+    let (version, file_path) = match file_path.split_once('/') {
+        Some((version, rest)) if static_file::is_pkg_version(version) =>
+            (Some(version), rest),
+        _ => (None, file_path.as_str()),
+    };
+    if !static_file::is_safe_relative_path(file_path) {
+        return Ok(HttpResponse::NotFound().finish());
+    }
+
+    let cache_policy = if version == Some(pkg_version.0.as_str()) {
+        CachePolicy::Immutable
+    } else {
+        CachePolicy::Revalidate
+    };
+
+    static_file::serve_file(
+        &request,
+        &format!(
+            "{}/{}/{}",
+            leptos_options.site_root,
+            leptos_options.site_pkg_dir,
+            file_path
+        ),
+        cache_policy
+    )
+}
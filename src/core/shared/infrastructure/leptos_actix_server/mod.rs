@@ -1,5 +1,7 @@
 pub mod api;
 #[cfg(feature = "ssr")]
+pub mod handler;
+#[cfg(feature = "ssr")]
 pub mod model;
 #[cfg(feature = "ssr")]
 pub mod repository;
@@ -2,33 +2,64 @@ use dotenvy::dotenv;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::from_str;
+use serde_json::to_string_pretty;
 use std::env;
 use std::fs;
 use tukosmo_domain::core::shared::error;
 use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::FaviconPngImage;
 use tukosmo_domain::core::shared::model::LocalI18n;
 use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::TlsRenewalStatus;
 use tukosmo_domain::core::shared::repository::DataRepository;
 
+use super::super::service::favicon;
 use super::super::service::file_system;
-use super::super::service::toml;
+use super::super::service::server_config;
 
 #[derive(Clone, Deserialize, Serialize)]
 pub struct FsDataRepository {
+    pub acme_account_key_file_path: String,
     pub acme_challenge_dir_path: String,
+    pub acme_root_cas_file_path: String,
+    pub backups_dir_path: String,
     pub cert_chain_file_path: String,
     pub cert_file_path: String,
     pub cert_pkey_file_path: String,
     pub certs_dir_path: String,
     pub data_dir_path: String,
+    pub favicon_dir_path: String,
     pub intermediate_cert_file_path: String,
     pub locale_dir_path: String,
+    pub logs_dir_path: String,
+    pub previous_certs_dir_path: String,
+    pub session_key_file_path: String,
+    pub themes_dir_path: String,
+    pub tls_renewal_status_file_path: String,
     pub tukosmo_toml_file_path: String,
+    pub uploads_dir_path: String,
 }
 
 const DATA_DIR_ENV_VAR: &'static str = "TUKOSMO_DATA_DIR";
 
 impl DataRepository for FsDataRepository {
+    fn backup_certs_dir(&self) -> Result<(), DomainError> {
+        let previous_certs_dir_exists = file_system::check_directory_exists(
+            &self.previous_certs_dir_path
+        )?;
+        if previous_certs_dir_exists {
+            file_system::remove_directory(&self.previous_certs_dir_path)?;
+        }
+        fs::create_dir_all(&self.previous_certs_dir_path).map_err(
+            |_e| error::CANNOT_CREATE_DIRECTORY
+        )?;
+        file_system::copy_directory_files(
+            &self.certs_dir_path,
+            &self.previous_certs_dir_path
+        )?;
+        Ok(())
+    }
+
     fn check_tls_certificate_file_exists(&self) -> Result<bool, DomainError> {
         let file_exists = file_system::check_file_exists(&self.cert_file_path)?;
         Ok(file_exists)
@@ -41,11 +72,52 @@ impl DataRepository for FsDataRepository {
     }
 
     fn create_new_acme_challenge_dir(&self) -> Result<(), DomainError> {
-        file_system::remove_directory(&self.acme_challenge_dir_path)?;
-        file_system::create_directory(&self.acme_challenge_dir_path)?;
+        let acme_challenge_dir_exists = file_system::check_directory_exists(
+            &self.acme_challenge_dir_path
+        )?;
+        if acme_challenge_dir_exists {
+            file_system::remove_directory(&self.acme_challenge_dir_path)?;
+        }
+        fs::create_dir_all(&self.acme_challenge_dir_path).map_err(
+            |_e| error::CANNOT_CREATE_DIRECTORY
+        )?;
         Ok(())
     }
 
+    fn get_acme_account_key(&self) -> Result<Option<String>, DomainError> {
+        let file_exists = file_system::check_file_exists(
+            &self.acme_account_key_file_path
+        )?;
+        if !file_exists {
+            return Ok(None);
+        }
+        let acme_account_key = file_system::read_file_as_string(
+            &self.acme_account_key_file_path
+        )?;
+        Ok(Some(acme_account_key))
+    }
+
+    fn get_acme_challenge_proof(
+        &self,
+        token: &str
+    ) -> Result<Option<String>, DomainError> {
+        let acme_challenge_token_file_path = format!(
+            "{}/{}",
+            self.acme_challenge_dir_path,
+            token
+        );
+        let file_exists = file_system::check_file_exists(
+            &acme_challenge_token_file_path
+        )?;
+        if !file_exists {
+            return Ok(None);
+        }
+        let proof = file_system::read_file_as_string(
+            &acme_challenge_token_file_path
+        )?;
+        Ok(Some(proof))
+    }
+
     fn get_local_i18n(
         &self,
         language_code: &str
@@ -66,12 +138,25 @@ impl DataRepository for FsDataRepository {
     }
 
     fn get_server_config(&self) -> Result<ServerConfig, DomainError> {
-        let server_config: ServerConfig = toml::parse_file(
+        let server_config = server_config::read_server_config(
             &self.tukosmo_toml_file_path
         )?;
         Ok(server_config)
     }
 
+    fn get_session_key(&self) -> Result<Option<Vec<u8>>, DomainError> {
+        let file_exists = file_system::check_file_exists(
+            &self.session_key_file_path
+        )?;
+        if !file_exists {
+            return Ok(None);
+        }
+        let session_key = file_system::read_file_as_vec_u8(
+            &self.session_key_file_path
+        )?;
+        Ok(Some(session_key))
+    }
+
     fn get_tls_certificate_chain_file(&self) -> Result<fs::File, DomainError> {
         let cert_chain_file = file_system::read_file(
             &self.cert_chain_file_path
@@ -112,11 +197,111 @@ impl DataRepository for FsDataRepository {
         Ok(intermediate_cert_file_string)
     }
 
+    fn get_tls_renewal_status(&self) -> Result<TlsRenewalStatus, DomainError> {
+        let file_exists = file_system::check_file_exists(
+            &self.tls_renewal_status_file_path
+        )?;
+        if !file_exists {
+            return Ok(TlsRenewalStatus::default());
+        }
+        let tls_renewal_status_json = file_system::read_file_as_string(
+            &self.tls_renewal_status_file_path
+        )?;
+        // It's only informative, so a damaged file is just replaced later
+        let tls_renewal_status = from_str(&tls_renewal_status_json)
+            .unwrap_or_default();
+        Ok(tls_renewal_status)
+    }
+
     fn remove_acme_challenge_dir(&self) -> Result<(), DomainError> {
         file_system::remove_directory(&self.acme_challenge_dir_path)?;
         Ok(())
     }
 
+    fn remove_uploaded_file(&self, file_name: &str) -> Result<(), DomainError> {
+        let uploaded_file_path = format!(
+            "{}/{}",
+            self.uploads_dir_path,
+            file_name
+        );
+        file_system::remove_file(&uploaded_file_path)?;
+        Ok(())
+    }
+
+    fn restore_certs_dir(&self) -> Result<(), DomainError> {
+        let certs_dir_exists = file_system::check_directory_exists(
+            &self.certs_dir_path
+        )?;
+        if certs_dir_exists {
+            file_system::remove_directory(&self.certs_dir_path)?;
+        }
+        file_system::create_directory(&self.certs_dir_path)?;
+        file_system::copy_directory_files(
+            &self.previous_certs_dir_path,
+            &self.certs_dir_path
+        )?;
+        Ok(())
+    }
+
+    fn set_server_config(
+        &self,
+        server_config: &ServerConfig
+    ) -> Result<(), DomainError> {
+        server_config::write_server_config(
+            &self.tukosmo_toml_file_path,
+            server_config
+        )?;
+        Ok(())
+    }
+
+    fn set_tls_renewal_status(
+        &self,
+        tls_renewal_status: &TlsRenewalStatus
+    ) -> Result<(), DomainError> {
+        let tls_renewal_status_json = to_string_pretty(
+            tls_renewal_status
+        ).map_err(|_e| error::CANNOT_WRITE_FILE)?;
+        file_system::write_file(
+            &self.tls_renewal_status_file_path,
+            &tls_renewal_status_json
+        )?;
+        Ok(())
+    }
+
+    fn update_favicon(
+        &self,
+        favicon_png_image: &FaviconPngImage
+    ) -> Result<(), DomainError> {
+        // Generate everything before touching the disk, so a failure doesn't
+        // leave a half-updated favicon behind
+        let favicon_files = favicon::generate_favicon_files(favicon_png_image)?;
+
+        let favicon_dir_exists = file_system::check_directory_exists(
+            &self.favicon_dir_path
+        )?;
+        if !favicon_dir_exists {
+            file_system::create_directory(&self.favicon_dir_path)?;
+        }
+
+        for favicon_file in favicon_files {
+            let favicon_file_path = format!(
+                "{}/{}",
+                self.favicon_dir_path,
+                favicon_file.file_name
+            );
+            file_system::write_file_bytes(
+                &favicon_file_path,
+                &favicon_file.content
+            )?;
+        }
+        Ok(())
+    }
+
+    fn write_acme_account_key(&self, content: &str) -> Result<(), DomainError> {
+        file_system::write_file(&self.acme_account_key_file_path, content)?;
+        Ok(())
+    }
+
     fn write_acme_challenge_token_file(
         &self,
         token: &str,
@@ -131,6 +316,19 @@ impl DataRepository for FsDataRepository {
         Ok(())
     }
 
+    fn write_acme_root_cas_file(
+        &self,
+        content: &str
+    ) -> Result<(), DomainError> {
+        file_system::write_file(&self.acme_root_cas_file_path, content)?;
+        Ok(())
+    }
+
+    fn write_session_key(&self, content: &[u8]) -> Result<(), DomainError> {
+        file_system::write_file_bytes(&self.session_key_file_path, content)?;
+        Ok(())
+    }
+
     fn write_tls_certificate_chain_file(
         &self,
         content: &str
@@ -162,6 +360,27 @@ impl DataRepository for FsDataRepository {
         file_system::write_file(&self.intermediate_cert_file_path, content)?;
         Ok(())
     }
+
+    fn write_uploaded_file(
+        &self,
+        file_name: &str,
+        content: &[u8]
+    ) -> Result<(), DomainError> {
+        let uploads_dir_exists = file_system::check_directory_exists(
+            &self.uploads_dir_path
+        )?;
+        if !uploads_dir_exists {
+            file_system::create_directory(&self.uploads_dir_path)?;
+        }
+
+        let uploaded_file_path = format!(
+            "{}/{}",
+            self.uploads_dir_path,
+            file_name
+        );
+        file_system::write_file_bytes(&uploaded_file_path, content)?;
+        Ok(())
+    }
 }
 
 impl FsDataRepository {
@@ -178,11 +397,24 @@ impl FsDataRepository {
             return Err(error::DATA_DIR_DOES_NOT_EXIST);
         }
 
+        // Outside the certs dir, since it's cleaned for every new certificate
+        let acme_account_key_file_path = format!(
+            "{}/acme-account-key.pem",
+            &data_dir_path
+        );
+
         let acme_challenge_dir_path = format!(
             "{}/tmp/acme-challenge",
             &data_dir_path
         );
 
+        let acme_root_cas_file_path = format!(
+            "{}/acme-root-cas.pem",
+            &data_dir_path
+        );
+
+        let backups_dir_path = format!("{}/backups", &data_dir_path);
+
         let certs_dir_path = format!("{}/certs", &data_dir_path);
         let cert_file_path = format!("{}/server-cert.pem", &certs_dir_path);
         let cert_chain_file_path = format!(
@@ -198,20 +430,50 @@ impl FsDataRepository {
             &certs_dir_path
         );
 
+        let previous_certs_dir_path = format!(
+            "{}/tmp/previous-certs",
+            &data_dir_path
+        );
+
+        let favicon_dir_path = format!("{}/favicon", &data_dir_path);
+
         let locale_dir_path = format!("{}/assets/locale", &data_dir_path);
 
+        let logs_dir_path = format!("{}/logs", &data_dir_path);
+
+        let session_key_file_path = format!("{}/session.key", &data_dir_path);
+
+        let themes_dir_path = format!("{}/themes", &data_dir_path);
+
+        let tls_renewal_status_file_path = format!(
+            "{}/tls-renewal-status.json",
+            &data_dir_path
+        );
+
         let tukosmo_toml_file_path = format!("{}/Tukosmo.toml", &data_dir_path);
 
+        let uploads_dir_path = format!("{}/uploads", &data_dir_path);
+
         Ok(FsDataRepository {
+            acme_account_key_file_path,
             acme_challenge_dir_path,
+            acme_root_cas_file_path,
+            backups_dir_path,
             cert_chain_file_path,
             cert_file_path,
             cert_pkey_file_path,
             certs_dir_path,
             data_dir_path,
+            favicon_dir_path,
             intermediate_cert_file_path,
             locale_dir_path,
+            logs_dir_path,
+            previous_certs_dir_path,
+            session_key_file_path,
+            themes_dir_path,
+            tls_renewal_status_file_path,
             tukosmo_toml_file_path,
+            uploads_dir_path,
         })
     }
 }
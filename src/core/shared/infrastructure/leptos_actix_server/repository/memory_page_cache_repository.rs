@@ -0,0 +1,24 @@
+use actix_web::web;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
+use tukosmo_domain::core::shared::repository::PageCacheRepository;
+
+use super::super::service::page_cache::PageCache;
+
+pub struct MemoryPageCacheRepository {
+    page_cache: Option<web::Data<PageCache>>,
+}
+
+impl MemoryPageCacheRepository {
+    // Without a cache (e.g. outside of the server), nothing is invalidated
+    pub fn init(page_cache: Option<web::Data<PageCache>>) -> Self {
+        Self { page_cache }
+    }
+}
+
+impl PageCacheRepository for MemoryPageCacheRepository {
+    fn invalidate(&mut self, event: ContentChangeEvent) {
+        if let Some(page_cache) = &self.page_cache {
+            page_cache.invalidate(&event);
+        }
+    }
+}
@@ -1,2 +1,5 @@
 mod fs_data_repository;
 pub use fs_data_repository::*;
+
+mod memory_page_cache_repository;
+pub use memory_page_cache_repository::*;
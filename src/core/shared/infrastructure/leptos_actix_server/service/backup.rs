@@ -0,0 +1,486 @@
+use chrono::Utc;
+use diesel::Connection;
+use diesel::pg::PgConnection;
+use std::fs;
+use std::io::Read;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::BACKUP_FORMAT_VERSION;
+use tukosmo_domain::core::shared::model::BackupManifest;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerBackupConfig;
+use tukosmo_domain::core::shared::model::TUKOSMO_VERSION;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use crate::core::shared::diesel_orm::model::DbPool;
+use crate::core::shared::diesel_orm::model::get_db_connection;
+use crate::core::shared::diesel_orm::service::database_dump;
+use crate::core::shared::diesel_orm::service::database_dump::DatabaseTableDump;
+use crate::core::shared::diesel_orm::service::migration_runner;
+use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+use super::file_system;
+
+// Archive layout:
+//   manifest.json
+//   database/<table>.json
+//   themes/...
+//   uploads/...
+const ARCHIVE_DATABASE_DIR: &'static str = "database";
+const ARCHIVE_MANIFEST_FILE: &'static str = "manifest.json";
+const ARCHIVE_THEMES_DIR: &'static str = "themes";
+const ARCHIVE_UPLOADS_DIR: &'static str = "uploads";
+
+pub const BACKUP_FILE_EXTENSION: &'static str = ".tar.zst";
+const BYTES_IN_A_MEGABYTE: u64 = 1048576;
+const MANUAL_BACKUP_FILE_PREFIX: &'static str = "tukosmo-backup-";
+const SCHEDULED_BACKUP_FILE_PREFIX: &'static str = "tukosmo-scheduled-backup-";
+const ZSTD_COMPRESSION_LEVEL: i32 = 3;
+
+pub fn create_backup(
+    db_pool: &DbPool,
+    fs_data_repository: &FsDataRepository,
+    backup_file_path: &str
+) -> Result<BackupManifest, DomainError> {
+    let mut connection = get_db_connection(db_pool)?;
+    let connection: &mut PgConnection = &mut connection;
+
+    // Every table is read from the same snapshot, so the dump is consistent
+    // even if the website is being edited meanwhile
+    let mut dump_error: Option<DomainError> = None;
+    let (migration_version, table_dumps) = connection
+        .build_transaction()
+        .repeatable_read()
+        .read_only()
+        .run(|connection| {
+            dump_database(connection).map_err(|e| {
+                dump_error = Some(e);
+                diesel::result::Error::RollbackTransaction
+            })
+        })
+        .map_err(|_e| {
+            dump_error.take().unwrap_or(error::CANNOT_DUMP_DATABASE)
+        })?;
+
+    let manifest = BackupManifest {
+        created_at: Utc::now().to_rfc3339(),
+        format_version: BACKUP_FORMAT_VERSION,
+        migration_version,
+        tables: table_dumps
+            .iter()
+            .map(|table_dump| table_dump.name.clone())
+            .collect(),
+        tukosmo_version: TUKOSMO_VERSION.to_string(),
+    };
+
+    // Written next to the final file, so an interrupted backup never looks
+    // like a valid one
+    let partial_file_path = format!("{}.partial", backup_file_path);
+    let result = write_archive(
+        &partial_file_path,
+        &manifest,
+        &table_dumps,
+        fs_data_repository
+    ).and_then(|_| {
+        fs::rename(&partial_file_path, backup_file_path).map_err(
+            |_e| error::CANNOT_CREATE_BACKUP
+        )
+    });
+    if result.is_err() {
+        let _ = fs::remove_file(&partial_file_path);
+    }
+    result?;
+
+    Ok(manifest)
+}
+
+pub fn new_backup_file_path(
+    fs_data_repository: &FsDataRepository,
+    is_scheduled: bool
+) -> Result<String, DomainError> {
+    fs::create_dir_all(&fs_data_repository.backups_dir_path).map_err(
+        |_e| error::CANNOT_CREATE_DIRECTORY
+    )?;
+
+    let file_prefix = if is_scheduled {
+        SCHEDULED_BACKUP_FILE_PREFIX
+    } else {
+        MANUAL_BACKUP_FILE_PREFIX
+    };
+    Ok(
+        format!(
+            "{}/{}{}{}",
+            fs_data_repository.backups_dir_path,
+            file_prefix,
+            Utc::now().format("%Y%m%d-%H%M%S"),
+            BACKUP_FILE_EXTENSION
+        )
+    )
+}
+
+// Removes the oldest scheduled backups (the file names sort by date)
+pub fn prune_scheduled_backups(
+    fs_data_repository: &FsDataRepository,
+    retention_count: usize
+) -> Result<(), DomainError> {
+    if retention_count == 0 {
+        return Ok(());
+    }
+
+    let entries = fs
+        ::read_dir(&fs_data_repository.backups_dir_path)
+        .map_err(|_e| error::CANNOT_READ_PATH_FS_METADATA)?;
+    let mut scheduled_backup_file_paths: Vec<String> = entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .filter(|file_name| {
+            file_name.starts_with(SCHEDULED_BACKUP_FILE_PREFIX) &&
+                file_name.ends_with(BACKUP_FILE_EXTENSION)
+        })
+        .map(|file_name| {
+            format!("{}/{}", fs_data_repository.backups_dir_path, file_name)
+        })
+        .collect();
+    scheduled_backup_file_paths.sort();
+
+    let surplus = scheduled_backup_file_paths
+        .len()
+        .saturating_sub(retention_count);
+    for file_path in scheduled_backup_file_paths.iter().take(surplus) {
+        file_system::remove_file(file_path)?;
+    }
+
+    Ok(())
+}
+
+// The database is restored inside one transaction: if anything fails, it's
+// left untouched. Files are only replaced after the transaction is committed.
+pub fn restore_backup(
+    db_pool: &DbPool,
+    fs_data_repository: &FsDataRepository,
+    backup_file_path: &str
+) -> Result<BackupManifest, DomainError> {
+    let tmp_dir_path = format!("{}/tmp", fs_data_repository.data_dir_path);
+    let staging_dir_path = format!(
+        "{}/restore-{}",
+        tmp_dir_path,
+        Utc::now().timestamp_millis()
+    );
+    fs::create_dir_all(&staging_dir_path).map_err(
+        |_e| error::CANNOT_CREATE_DIRECTORY
+    )?;
+
+    let result = restore_staged_backup(
+        db_pool,
+        fs_data_repository,
+        backup_file_path,
+        &staging_dir_path
+    );
+
+    let _ = fs::remove_dir_all(&staging_dir_path);
+
+    result
+}
+
+pub fn spawn_backup_thread(
+    db_pool: DbPool,
+    backup_config: ServerBackupConfig
+) -> Option<tokio::task::JoinHandle<()>> {
+    if backup_config.interval_hours == 0 {
+        return None;
+    }
+
+    let interval = Duration::from_secs(backup_config.interval_hours * 3600);
+
+    let backup_thread = tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(interval).await;
+
+            let db_pool = db_pool.clone();
+            let retention_count = backup_config.retention_count;
+            let result = tokio::task
+                ::spawn_blocking(move || {
+                    create_scheduled_backup(&db_pool, retention_count)
+                }).await
+                .unwrap_or(Err(error::CANNOT_CREATE_BACKUP));
+
+            match result {
+                Ok(backup_file_path) => {
+                    log::info!(
+                        "Scheduled backup created: {}",
+                        backup_file_path
+                    );
+                }
+                Err(domain_error) => {
+                    log::error!(
+                        "Cannot create scheduled backup: {}",
+                        domain_error.get_full_code()
+                    );
+                    // With the details of the errors only shown in the server
+                    domain_error.log();
+                }
+            }
+        }
+    });
+
+    Some(backup_thread)
+}
+
+fn append_archive_file(
+    archive_builder: &mut tar::Builder<impl Write>,
+    archive_path: &str,
+    content: &[u8]
+) -> Result<(), DomainError> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(content.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(Utc::now().timestamp() as u64);
+    header.set_cksum();
+
+    archive_builder
+        .append_data(&mut header, archive_path, content)
+        .map_err(|_e| error::CANNOT_CREATE_BACKUP)
+}
+
+fn create_scheduled_backup(
+    db_pool: &DbPool,
+    retention_count: usize
+) -> Result<String, DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+    let backup_file_path = new_backup_file_path(&fs_data_repository, true)?;
+
+    create_backup(db_pool, &fs_data_repository, &backup_file_path)?;
+    prune_scheduled_backups(&fs_data_repository, retention_count)?;
+
+    Ok(backup_file_path)
+}
+
+fn dump_database(
+    connection: &mut PgConnection
+) -> Result<(String, Vec<DatabaseTableDump>), DomainError> {
+    let migration_version = migration_runner
+        ::find_latest_applied_version(connection)?
+        .ok_or(error::CANNOT_DUMP_DATABASE)?;
+    let table_dumps = database_dump::dump_tables(connection)?;
+    Ok((migration_version, table_dumps))
+}
+
+fn read_staged_backup(
+    staging_dir_path: &str
+) -> Result<(BackupManifest, Vec<DatabaseTableDump>), DomainError> {
+    let manifest_str = fs
+        ::read_to_string(
+            format!("{}/{}", staging_dir_path, ARCHIVE_MANIFEST_FILE)
+        )
+        .map_err(|_e| error::INVALID_BACKUP_FILE)?;
+    let manifest: BackupManifest = serde_json
+        ::from_str(&manifest_str)
+        .map_err(|_e| error::INVALID_BACKUP_FILE)?;
+
+    if manifest.format_version > BACKUP_FORMAT_VERSION {
+        return Err(error::BACKUP_IS_NEWER_THAN_BINARY);
+    }
+    if manifest.format_version < BACKUP_FORMAT_VERSION {
+        return Err(error::INVALID_BACKUP_FILE);
+    }
+
+    let embedded_versions = migration_runner::find_embedded_versions()?;
+    if !embedded_versions.contains(&manifest.migration_version) {
+        let latest_embedded_version = embedded_versions
+            .last()
+            .cloned()
+            .unwrap_or_default();
+        return if manifest.migration_version > latest_embedded_version {
+            Err(error::BACKUP_IS_NEWER_THAN_BINARY)
+        } else {
+            Err(error::INVALID_BACKUP_FILE)
+        };
+    }
+
+    let mut table_dumps = vec![];
+    for table_name in &manifest.tables {
+        // Table names are part of the paths read below
+        let table_name_is_valid = !table_name.is_empty() &&
+            table_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !table_name_is_valid {
+            return Err(error::INVALID_BACKUP_FILE);
+        }
+
+        let rows_json = fs
+            ::read_to_string(
+                format!(
+                    "{}/{}/{}.json",
+                    staging_dir_path,
+                    ARCHIVE_DATABASE_DIR,
+                    table_name
+                )
+            )
+            .map_err(|_e| error::INVALID_BACKUP_FILE)?;
+        table_dumps.push(DatabaseTableDump {
+            name: table_name.clone(),
+            rows_json,
+        });
+    }
+
+    Ok((manifest, table_dumps))
+}
+
+fn replace_directory(
+    new_directory_path: &str,
+    directory_path: &str
+) -> Result<(), DomainError> {
+    if !Path::new(new_directory_path).is_dir() {
+        return Ok(());
+    }
+
+    let old_directory_path = format!("{}.old", directory_path);
+    if Path::new(&old_directory_path).exists() {
+        fs::remove_dir_all(&old_directory_path).map_err(
+            |_e| error::CANNOT_REMOVE_DIRECTORY
+        )?;
+    }
+    if Path::new(directory_path).exists() {
+        fs::rename(directory_path, &old_directory_path).map_err(
+            |_e| error::CANNOT_RESTORE_BACKUP
+        )?;
+    }
+    fs::rename(new_directory_path, directory_path).map_err(
+        |_e| error::CANNOT_RESTORE_BACKUP
+    )?;
+    let _ = fs::remove_dir_all(&old_directory_path);
+
+    Ok(())
+}
+
+fn restore_database(
+    connection: &mut PgConnection,
+    manifest: &BackupManifest,
+    table_dumps: &[DatabaseTableDump]
+) -> Result<(), DomainError> {
+    // Other instances can't run migrations while the schema is changing
+    migration_runner::lock_migrations_in_transaction(connection)?;
+
+    // The rows must be inserted with the schema they were dumped from, and
+    // then the pending migrations bring them up to date
+    migration_runner::migrate_to_version(
+        connection,
+        &manifest.migration_version
+    )?;
+    database_dump::restore_tables(connection, table_dumps)?;
+    migration_runner::run_pending_migrations_while_locked(connection)?;
+
+    Ok(())
+}
+
+fn restore_staged_backup(
+    db_pool: &DbPool,
+    fs_data_repository: &FsDataRepository,
+    backup_file_path: &str,
+    staging_dir_path: &str
+) -> Result<BackupManifest, DomainError> {
+    let server_config = fs_data_repository.get_server_config()?;
+    let max_restored_bytes =
+        server_config.backup.max_restored_size_mb * BYTES_IN_A_MEGABYTE;
+
+    let backup_file = file_system::read_file(backup_file_path)?;
+    let decoder = zstd::Decoder
+        ::new(backup_file)
+        .map_err(|_e| error::INVALID_BACKUP_FILE)?;
+    // Entries with absolute paths or ".." can't escape the staging dir
+    let mut archive = tar::Archive::new(decoder.take(max_restored_bytes));
+    let unpack_result = archive.unpack(staging_dir_path);
+    // An archive cut at the limit can still look complete, so reaching the
+    // limit is an error by itself
+    let limit_was_reached = archive.into_inner().limit() == 0;
+    if unpack_result.is_err() || limit_was_reached {
+        return Err(error::INVALID_BACKUP_FILE);
+    }
+
+    let (manifest, table_dumps) = read_staged_backup(staging_dir_path)?;
+
+    let mut connection = get_db_connection(db_pool)?;
+    let connection: &mut PgConnection = &mut connection;
+
+    let mut restore_error: Option<DomainError> = None;
+    connection
+        .transaction(|connection| {
+            restore_database(connection, &manifest, &table_dumps).map_err(
+                |e| {
+                    restore_error = Some(e);
+                    diesel::result::Error::RollbackTransaction
+                }
+            )
+        })
+        .map_err(|_e| {
+            restore_error.take().unwrap_or(error::CANNOT_RESTORE_BACKUP)
+        })?;
+
+    replace_directory(
+        &format!("{}/{}", staging_dir_path, ARCHIVE_UPLOADS_DIR),
+        &fs_data_repository.uploads_dir_path
+    )?;
+    replace_directory(
+        &format!("{}/{}", staging_dir_path, ARCHIVE_THEMES_DIR),
+        &fs_data_repository.themes_dir_path
+    )?;
+
+    Ok(manifest)
+}
+
+fn write_archive(
+    archive_file_path: &str,
+    manifest: &BackupManifest,
+    table_dumps: &[DatabaseTableDump],
+    fs_data_repository: &FsDataRepository
+) -> Result<(), DomainError> {
+    let archive_file = fs::File
+        ::create(archive_file_path)
+        .map_err(|_e| error::CANNOT_CREATE_BACKUP)?;
+    let encoder = zstd::Encoder
+        ::new(archive_file, ZSTD_COMPRESSION_LEVEL)
+        .map_err(|_e| error::CANNOT_CREATE_BACKUP)?;
+    let mut archive_builder = tar::Builder::new(encoder);
+
+    let manifest_json = serde_json
+        ::to_string_pretty(manifest)
+        .map_err(|_e| error::CANNOT_CREATE_BACKUP)?;
+    append_archive_file(
+        &mut archive_builder,
+        ARCHIVE_MANIFEST_FILE,
+        manifest_json.as_bytes()
+    )?;
+
+    for table_dump in table_dumps {
+        append_archive_file(
+            &mut archive_builder,
+            &format!("{}/{}.json", ARCHIVE_DATABASE_DIR, table_dump.name),
+            table_dump.rows_json.as_bytes()
+        )?;
+    }
+
+    let archive_dirs = [
+        (ARCHIVE_THEMES_DIR, &fs_data_repository.themes_dir_path),
+        (ARCHIVE_UPLOADS_DIR, &fs_data_repository.uploads_dir_path),
+    ];
+    for (archive_dir, directory_path) in archive_dirs {
+        let directory_exists = file_system::check_directory_exists(
+            directory_path
+        )?;
+        if directory_exists {
+            archive_builder
+                .append_dir_all(archive_dir, directory_path)
+                .map_err(|_e| error::CANNOT_CREATE_BACKUP)?;
+        }
+    }
+
+    archive_builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .and_then(|archive_file| archive_file.sync_all())
+        .map_err(|_e| error::CANNOT_CREATE_BACKUP)?;
+
+    Ok(())
+}
@@ -0,0 +1,394 @@
+use actix_web::App;
+use actix_web::HttpServer;
+use actix_web::dev::ServerHandle;
+use actix_web::web;
+use clap::Parser;
+use clap::Subcommand;
+use leptos::get_configuration;
+use std::io;
+use std::net::SocketAddr;
+use tokio;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::SERVER_CONFIG_VERSION;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerTlsMode;
+use tukosmo_domain::core::shared::model::TUKOSMO_VERSION;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use crate::core::shared::diesel_orm::model::init_db_pool;
+use crate::core::shared::diesel_orm::model::resolve_database_config;
+use crate::core::shared::diesel_orm::service::migration_runner;
+use crate::core::shared::leptos_actix_server::handler::acme_challenge_handler;
+use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+use crate::core::shared::leptos_actix_server::service::backup;
+use crate::core::shared::leptos_actix_server::service::server;
+use crate::core::shared::leptos_actix_server::service::server_config;
+use crate::core::shared::leptos_actix_server::service::session;
+use crate::core::shared::leptos_actix_server::service::tls;
+
+#[derive(Parser)]
+#[command(
+    about = "Tukosmo web server and maintenance tasks.",
+    name = "tukosmo_server",
+    version = TUKOSMO_VERSION
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+}
+
+#[derive(Subcommand)]
+enum CliCommand {
+    #[command(about = "Create a backup of the database and the data dir")]
+    Backup {
+        #[arg(
+            help = "Backup file to create (by default, a new file in the \
+                    backups dir)",
+            long
+        )]
+        output: Option<String>,
+    },
+    #[command(subcommand, about = "Manage the TLS certificate")]
+    Cert(CliCertCommand),
+    #[command(about = "Validate Tukosmo.toml and the data dir")]
+    CheckConfig,
+    #[command(
+        about = "Create an administrator account (not available yet: \
+                 Tukosmo doesn't have user accounts)"
+    )]
+    CreateAdmin {
+        #[arg(help = "Email of the administrator", long)]
+        email: String,
+    },
+    #[command(about = "Apply the pending database migrations")]
+    Migrate,
+    #[command(about = "Invalidate all the sessions (after the next restart)")]
+    ResetSessions,
+    #[command(
+        about = "Restore a backup created with the backup command (it \
+                 replaces all the current data)"
+    )]
+    Restore {
+        #[arg(help = "Backup file to restore")]
+        file: String,
+    },
+    #[command(about = "Run the web server (default)")]
+    Serve,
+}
+
+#[derive(Subcommand)]
+enum CliCertCommand {
+    #[command(
+        about = "Obtain a new certificate (the running server answers the \
+                 ACME challenge, or a temporary one if it's stopped)"
+    )]
+    Renew,
+    #[command(about = "Show the expiration of the current certificate")]
+    Status,
+}
+
+// Scripts can rely on these values, so don't change the existing ones
+pub const EXIT_CODE_SUCCESS: i32 = 0;
+pub const EXIT_CODE_ERROR: i32 = 1;
+pub const EXIT_CODE_USAGE: i32 = 2;
+pub const EXIT_CODE_INVALID_CONFIG: i32 = 3;
+pub const EXIT_CODE_DATABASE_UNAVAILABLE: i32 = 4;
+pub const EXIT_CODE_CERT_NEEDS_RENEWAL: i32 = 5;
+// Same value as EX_UNAVAILABLE in sysexits.h
+pub const EXIT_CODE_NOT_AVAILABLE_YET: i32 = 69;
+
+pub async fn run() -> i32 {
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            let _ = e.print();
+            return if e.use_stderr() {
+                EXIT_CODE_USAGE
+            } else {
+                EXIT_CODE_SUCCESS
+            };
+        }
+    };
+
+    let result = match cli.command.unwrap_or(CliCommand::Serve) {
+        CliCommand::Backup { output } => create_backup(output),
+        CliCommand::Cert(CliCertCommand::Renew) => renew_certificate().await,
+        CliCommand::Cert(CliCertCommand::Status) => certificate_status(),
+        CliCommand::CheckConfig => check_config(),
+        CliCommand::CreateAdmin { email } => create_admin(&email),
+        CliCommand::Migrate => migrate(),
+        CliCommand::ResetSessions => reset_sessions(),
+        CliCommand::Restore { file } => restore_backup(&file),
+        CliCommand::Serve => serve().await,
+    };
+
+    match result {
+        Ok(exit_code) => exit_code,
+        Err(domain_error) => {
+            eprintln!(
+                "Error {}: {}",
+                domain_error.get_full_code(),
+                domain_error.message
+            );
+            // E.g. every problem found in Tukosmo.toml
+            for (_key, value) in &domain_error.context {
+                eprintln!("  {}", value);
+            }
+            get_exit_code(&domain_error)
+        }
+    }
+}
+
+fn certificate_status() -> Result<i32, DomainError> {
+    let status = tls::get_certificate_status()?;
+
+    println!("Issuer: {}", status.issuer);
+    println!("Domains: {}", status.subject_alt_names.join(", "));
+    println!("Issued at: {}", status.issued_at);
+    println!("Expires at: {}", status.expires_at);
+    println!("Days until expiration: {}", status.days_until_expiration);
+    if status.needs_renewal {
+        println!("The certificate needs to be renewed.");
+        Ok(EXIT_CODE_CERT_NEEDS_RENEWAL)
+    } else {
+        println!("The certificate is valid.");
+        Ok(EXIT_CODE_SUCCESS)
+    }
+}
+
+// Read-only: an older Tukosmo.toml is only reported, and it's upgraded when
+// the server starts (or by the migrate command)
+fn check_config() -> Result<i32, DomainError> {
+    let fs_data_repository = FsDataRepository::init()
+        .map_err(|e| invalid_config(e))?;
+    let server_config = fs_data_repository
+        .get_server_config()
+        .map_err(|e| invalid_config(e))?;
+    let file_path = &fs_data_repository.tukosmo_toml_file_path;
+    let config_version = server_config::get_server_config_file_version(
+        file_path
+    ).map_err(|e| invalid_config(e))?;
+    resolve_database_config(&server_config.database)
+        .map_err(|e| invalid_config(e))?;
+
+    println!("Data dir: {}", fs_data_repository.data_dir_path);
+    println!("Domain: {}", server_config.domain);
+    println!("Mode: {}", server_config.mode.value());
+    println!("TLS mode: {}", server_config.tls.mode.value());
+    if config_version < SERVER_CONFIG_VERSION {
+        println!(
+            "{} would be upgraded from version {} to version {}.",
+            file_path,
+            config_version,
+            SERVER_CONFIG_VERSION
+        );
+    }
+    println!("The configuration is valid.");
+    Ok(EXIT_CODE_SUCCESS)
+}
+
+// The command is already part of the interface, so scripts can be written
+// against it, but there's nothing to create until user accounts exist
+fn create_admin(email: &str) -> Result<i32, DomainError> {
+    eprintln!(
+        "The administrator {} wasn't created: Tukosmo doesn't have user \
+        accounts yet, so create-admin isn't available in this version.",
+        email
+    );
+
+    Ok(EXIT_CODE_NOT_AVAILABLE_YET)
+}
+
+fn create_backup(output: Option<String>) -> Result<i32, DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
+    let db_pool = init_db_pool(&server_config.database)?;
+
+    let backup_file_path = match output {
+        Some(output) => output,
+        None => backup::new_backup_file_path(&fs_data_repository, false)?,
+    };
+    let manifest = backup::create_backup(
+        &db_pool,
+        &fs_data_repository,
+        &backup_file_path
+    )?;
+
+    println!("Backup created: {}", backup_file_path);
+    println!("Database migration: {}", manifest.migration_version);
+    Ok(EXIT_CODE_SUCCESS)
+}
+
+fn get_exit_code(domain_error: &DomainError) -> i32 {
+    let database_errors = [
+        error::CANNOT_ESTABLISH_DATABASE_CONNECTION.id,
+        error::DATABASE_CONNECTION_POOL_EXHAUSTED.id,
+    ];
+    let config_errors = [
+        error::CANNOT_OBTAIN_TUKOSMO_DATA_DIR_ENV_VAR.id,
+        error::CANNOT_PARSE_TOML_FILE.id,
+        error::CANNOT_READ_DATABASE_PASSWORD_FILE.id,
+        error::DATA_DIR_DOES_NOT_EXIST.id,
+        error::INVALID_DATABASE_PORT.id,
+        error::INVALID_DATABASE_SSLMODE.id,
+        error::invalid_server_config(vec![]).id,
+        error::SERVER_CONFIG_IS_NEWER_THAN_BINARY.id,
+    ];
+
+    if database_errors.contains(&domain_error.id) {
+        EXIT_CODE_DATABASE_UNAVAILABLE
+    } else if config_errors.contains(&domain_error.id) {
+        EXIT_CODE_INVALID_CONFIG
+    } else {
+        EXIT_CODE_ERROR
+    }
+}
+
+// Any failure while checking the config means that the config is invalid
+fn invalid_config(domain_error: DomainError) -> DomainError {
+    eprintln!("Invalid configuration.");
+    domain_error
+}
+
+fn migrate() -> Result<i32, DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
+    upgrade_server_config_file(&fs_data_repository);
+    let db_pool = init_db_pool(&server_config.database)?;
+
+    let applied_migrations = migration_runner::run_pending_migrations(
+        &db_pool
+    )?;
+    if applied_migrations.is_empty() {
+        println!("The database is up to date.");
+    }
+    for migration_version in applied_migrations {
+        println!("Applied database migration {}", migration_version);
+    }
+    Ok(EXIT_CODE_SUCCESS)
+}
+
+async fn renew_certificate() -> Result<i32, DomainError> {
+    let server_config = FsDataRepository::init()?.get_server_config()?;
+
+    let challenge_server_handle = match server_config.tls.mode {
+        ServerTlsMode::Acme => {
+            let handle = start_acme_challenge_server(&server_config).await?;
+            if handle.is_none() {
+                println!(
+                    "The HTTP port is in use, so the running server answers \
+                    the ACME challenge."
+                );
+            }
+            handle
+        }
+        ServerTlsMode::Manual
+        | ServerTlsMode::Off
+        | ServerTlsMode::SelfSigned => None,
+    };
+
+    let result = tls::renew_certificate(tls::ACME_MAX_ATTEMPTS).await;
+
+    if let Some(challenge_server_handle) = challenge_server_handle {
+        challenge_server_handle.stop(true).await;
+    }
+
+    result?;
+    println!("The certificate has been renewed.");
+    println!("Restart the server to start using it.");
+    Ok(EXIT_CODE_SUCCESS)
+}
+
+fn reset_sessions() -> Result<i32, DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+    session::reset_cookie_key(&fs_data_repository)?;
+    println!("The session key has been replaced.");
+    println!("Restart the server to sign everyone out.");
+    Ok(EXIT_CODE_SUCCESS)
+}
+
+fn restore_backup(file: &str) -> Result<i32, DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
+    let db_pool = init_db_pool(&server_config.database)?;
+
+    let manifest = backup::restore_backup(&db_pool, &fs_data_repository, file)?;
+
+    println!(
+        "Backup created at {} with Tukosmo {} restored.",
+        manifest.created_at,
+        manifest.tukosmo_version
+    );
+    println!("Restart the server if it's running.");
+    Ok(EXIT_CODE_SUCCESS)
+}
+
+async fn serve() -> Result<i32, DomainError> {
+    server::start_server().await?;
+    println!("Server thread finished.");
+    Ok(EXIT_CODE_SUCCESS)
+}
+
+// The CA validates the challenge on the HTTP port, so it's answered by a
+// temporary server when Tukosmo isn't running. Otherwise, the running server
+// answers it, since the proofs are written to the data dir (None is returned)
+async fn start_acme_challenge_server(
+    server_config: &ServerConfig
+) -> Result<Option<ServerHandle>, DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+
+    // The same address as start_server_thread()
+    let conf_file = get_configuration(None)
+        .await
+        .map_err(|_e| error::CANNOT_START_ACME_CHALLENGE_SERVER)?;
+    let http_socket_addr = SocketAddr::new(
+        conf_file.leptos_options.site_addr.ip(),
+        server_config.http_port
+    );
+
+    let server = HttpServer::new(move || {
+        App::new()
+            .app_data(web::Data::new(fs_data_repository.clone()))
+            .service(acme_challenge_handler::acme_challenge)
+    })
+        .disable_signals()
+        .workers(1);
+    let server = match server.bind(&http_socket_addr) {
+        Ok(server) => server.run(),
+        Err(e) if e.kind() == io::ErrorKind::AddrInUse => {
+            return Ok(None);
+        }
+        Err(_e) => {
+            return Err(error::CANNOT_START_ACME_CHALLENGE_SERVER);
+        }
+    };
+
+    let handle = server.handle();
+    tokio::spawn(server);
+
+    Ok(Some(handle))
+}
+
+// Like when the server starts, a failure isn't fatal, since the upgraded
+// configuration is used anyway
+fn upgrade_server_config_file(fs_data_repository: &FsDataRepository) {
+    let file_path = &fs_data_repository.tukosmo_toml_file_path;
+    match server_config::upgrade_server_config_file(file_path) {
+        Ok(Some(upgrade)) =>
+            println!(
+                "{} upgraded from version {} (the original file was kept in \
+                {}).",
+                file_path,
+                upgrade.previous_version,
+                upgrade.backup_file_path
+            ),
+        Ok(None) => {}
+        Err(domain_error) =>
+            eprintln!(
+                "{} couldn't be upgraded: {}",
+                file_path,
+                domain_error.message
+            ),
+    }
+}
@@ -0,0 +1,44 @@
+use actix_web::HttpRequest;
+use actix_web::http;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use super::request_origin;
+
+const SAME_ORIGIN: &'static str = "same-origin";
+const SEC_FETCH_SITE: &'static str = "sec-fetch-site";
+
+// Browsers send Sec-Fetch-Site with every request, and Origin with every
+// POST, so a form submitted from another website is detected without a
+// token. Requests without both headers (e.g. from very old browsers) are
+// rejected too
+pub fn check_same_origin(request: &HttpRequest) -> Result<(), DomainError> {
+    if let Some(fetch_site) = get_header(request, SEC_FETCH_SITE) {
+        return if fetch_site == SAME_ORIGIN {
+            Ok(())
+        } else {
+            Err(error::REQUEST_FROM_ANOTHER_WEBSITE)
+        };
+    }
+
+    // E.g. "https://example.com:8443" => "example.com:8443", like the host
+    let origin_host = get_header(request, http::header::ORIGIN.as_str())
+        .and_then(|origin| {
+            origin.split_once("://").map(|(_scheme, host)| host.to_string())
+        });
+    let request_host = request_origin::get_request_origin(request).host;
+
+    match origin_host {
+        Some(origin_host) if origin_host.eq_ignore_ascii_case(&request_host) =>
+            Ok(()),
+        _ => Err(error::REQUEST_FROM_ANOTHER_WEBSITE),
+    }
+}
+
+fn get_header(request: &HttpRequest, name: &str) -> Option<String> {
+    request
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
@@ -0,0 +1,18 @@
+use actix_web::HttpRequest;
+use actix_web::web;
+use leptos::use_context;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::diesel_orm::model::DbPool;
+
+// Leptos provides the Actix request to server functions and SSR rendering,
+// and the pool is registered as app data in start_server_thread()
+pub fn get_db_pool() -> Result<web::Data<DbPool>, DomainError> {
+    let request = use_context::<HttpRequest>()
+        .ok_or(error::CANNOT_ESTABLISH_DATABASE_CONNECTION)?;
+    let db_pool = request
+        .app_data::<web::Data<DbPool>>()
+        .ok_or(error::CANNOT_ESTABLISH_DATABASE_CONNECTION)?;
+    Ok(web::Data::clone(db_pool))
+}
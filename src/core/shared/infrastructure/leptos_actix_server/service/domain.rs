@@ -0,0 +1,95 @@
+use futures::FutureExt;
+use std::net::IpAddr;
+use std::panic::AssertUnwindSafe;
+use tokio;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use super::super::repository::FsDataRepository;
+use super::server;
+use super::tls;
+
+// The new certificate is obtained while the server keeps running with the
+// current one, and everything is restored if it fails, so a wrong domain never
+// leaves the website without a working certificate
+pub async fn change_domain(new_domain: &str) -> Result<(), DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+    let previous_server_config = fs_data_repository.get_server_config()?;
+
+    if let Some(domain_error) = ServerConfig::validate_domain(
+        &new_domain.to_string()
+    ) {
+        return Err(domain_error);
+    }
+    if new_domain == previous_server_config.domain {
+        return Err(error::NEW_DOMAIN_IS_THE_CURRENT_ONE);
+    }
+
+    check_domain_resolves_to_server(&previous_server_config, new_domain).await?;
+
+    fs_data_repository.backup_certs_dir()?;
+
+    let mut server_config = previous_server_config.clone();
+    server_config.domain = new_domain.to_string();
+    fs_data_repository.set_server_config(&server_config)?;
+
+    // generate_new_certificate() may replace the certs dir (and does nothing
+    // when the certificate isn't managed by Tukosmo). The ACME client may
+    // panic, which must also roll back the change
+    let result = AssertUnwindSafe(
+        tls::generate_new_certificate(tls::ACME_MAX_ATTEMPTS_FROM_TAP)
+    )
+        .catch_unwind()
+        .await
+        .unwrap_or(Err(error::CANNOT_GENERATE_TLS_CERTIFICATE));
+
+    if let Err(domain_error) = result {
+        log::error!(
+            "Cannot obtain a certificate for {}: {}",
+            new_domain,
+            domain_error.message
+        );
+        fs_data_repository.set_server_config(&previous_server_config)?;
+        fs_data_repository.restore_certs_dir()?;
+        return Err(error::CANNOT_OBTAIN_CERTIFICATE_FOR_NEW_DOMAIN);
+    }
+
+    server::restart_server()
+}
+
+// The public IP of the server isn't known, so the new domain must resolve to
+// (at least) one of the addresses of the current domain. In development mode,
+// local domains (e.g. from /etc/hosts) are also accepted
+async fn check_domain_resolves_to_server(
+    server_config: &ServerConfig,
+    new_domain: &str
+) -> Result<(), DomainError> {
+    let new_domain_ips = lookup_ips(new_domain, server_config.http_port).await;
+    if new_domain_ips.is_empty() {
+        return Err(error::DOMAIN_DOES_NOT_RESOLVE);
+    }
+
+    let current_domain_ips = lookup_ips(
+        &server_config.domain,
+        server_config.http_port
+    ).await;
+    let resolves_to_server = new_domain_ips.iter().any(|ip| {
+        current_domain_ips.contains(ip) ||
+            (server_config.has_development_mode() && ip.is_loopback())
+    });
+    if !resolves_to_server {
+        return Err(error::DOMAIN_DOES_NOT_RESOLVE_TO_THIS_SERVER);
+    }
+
+    Ok(())
+}
+
+async fn lookup_ips(domain: &str, port: u16) -> Vec<IpAddr> {
+    match tokio::net::lookup_host((domain, port)).await {
+        Ok(socket_addrs) =>
+            socket_addrs.map(|socket_addr| socket_addr.ip()).collect(),
+        Err(_e) => vec![],
+    }
+}
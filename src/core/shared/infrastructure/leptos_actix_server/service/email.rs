@@ -0,0 +1,51 @@
+use std::io::Write;
+use std::process::Command;
+use std::process::Stdio;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use super::super::repository::FsDataRepository;
+
+const SENDMAIL_PATH: &'static str = "/usr/sbin/sendmail";
+
+// There are no SMTP settings yet, so the email is handed to the mail transfer
+// agent of the system (sendmail, or the compatible command of Postfix, Exim,
+// msmtp...)
+pub fn send_email_to_admin(
+    subject: &str,
+    body: &str
+) -> Result<(), DomainError> {
+    let server_config = FsDataRepository::init()?.get_server_config()?;
+
+    let message = format!(
+        "From: Tukosmo <tukosmo@{}>\r\n\
+        To: {}\r\n\
+        Subject: {}\r\n\
+        Content-Type: text/plain; charset=utf-8\r\n\
+        \r\n\
+        {}\r\n",
+        server_config.domain,
+        server_config.admin_email,
+        subject,
+        body.replace('\n', "\r\n")
+    );
+
+    let mut sendmail = Command::new(SENDMAIL_PATH)
+        .args(["-t", "-i"])
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|_e| error::CANNOT_SEND_EMAIL)?;
+    sendmail.stdin
+        .take()
+        .ok_or(error::CANNOT_SEND_EMAIL)?
+        .write_all(message.as_bytes())
+        .map_err(|_e| error::CANNOT_SEND_EMAIL)?;
+
+    let exit_status = sendmail.wait().map_err(|_e| error::CANNOT_SEND_EMAIL)?;
+    if !exit_status.success() {
+        return Err(error::CANNOT_SEND_EMAIL);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use image::ColorType;
+use image::ImageFormat;
+use image::ImageOutputFormat;
+use image::codecs::ico::IcoEncoder;
+use image::codecs::ico::IcoFrame;
+use image::imageops::FilterType;
+use std::io::Cursor;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::FaviconPngImage;
+
+pub struct FaviconFile {
+    pub content: Vec<u8>,
+    pub file_name: &'static str,
+}
+
+pub const APPLE_TOUCH_ICON_FILE_NAME: &'static str = "apple-touch-icon.png";
+pub const FAVICON_FILE_NAME: &'static str = "favicon.ico";
+pub const MANIFEST_FILE_NAME: &'static str = "manifest.webmanifest";
+pub const MANIFEST_ICON_192_FILE_NAME: &'static str = "icon-192.png";
+pub const MANIFEST_ICON_512_FILE_NAME: &'static str = "icon-512.png";
+
+const APPLE_TOUCH_ICON_SIZE: u32 = 180;
+const FAVICON_ICO_SIZES: [u32; 3] = [16, 32, 48];
+
+pub fn generate_favicon_files(
+    favicon_png_image: &FaviconPngImage
+) -> Result<Vec<FaviconFile>, DomainError> {
+    let image = image
+        ::load_from_memory_with_format(
+            favicon_png_image.value(),
+            ImageFormat::Png
+        )
+        .map_err(|_e| error::INVALID_FAVICON_PNG_IMAGE)?;
+
+    let resize = |size: u32| {
+        image.resize_exact(size, size, FilterType::Lanczos3).to_rgba8()
+    };
+
+    let encode_png = |size: u32| -> Result<Vec<u8>, DomainError> {
+        let mut content = Cursor::new(vec![]);
+        image::DynamicImage::ImageRgba8(resize(size))
+            .write_to(&mut content, ImageOutputFormat::Png)
+            .map_err(|_e| error::CANNOT_GENERATE_FAVICON)?;
+        Ok(content.into_inner())
+    };
+
+    // The .ico file bundles all the small sizes, so browsers pick the best
+    let ico_images: Vec<_> = FAVICON_ICO_SIZES
+        .iter()
+        .map(|&size| (size, resize(size)))
+        .collect();
+    let ico_frames = ico_images
+        .iter()
+        .map(|(size, rgba_image)| {
+            IcoFrame::as_png(
+                rgba_image.as_raw(),
+                *size,
+                *size,
+                ColorType::Rgba8
+            ).map_err(|_e| error::CANNOT_GENERATE_FAVICON)
+        })
+        .collect::<Result<Vec<IcoFrame>, DomainError>>()?;
+    let mut favicon_ico = vec![];
+    IcoEncoder::new(&mut favicon_ico)
+        .encode_images(&ico_frames)
+        .map_err(|_e| error::CANNOT_GENERATE_FAVICON)?;
+
+    let manifest = format!(
+        r#"{{
+    "icons": [
+        {{ "src": "/{}", "sizes": "192x192", "type": "image/png" }},
+        {{ "src": "/{}", "sizes": "512x512", "type": "image/png" }}
+    ]
+}}
+"#,
+        MANIFEST_ICON_192_FILE_NAME,
+        MANIFEST_ICON_512_FILE_NAME
+    );
+
+    Ok(
+        vec![
+            FaviconFile {
+                content: encode_png(APPLE_TOUCH_ICON_SIZE)?,
+                file_name: APPLE_TOUCH_ICON_FILE_NAME,
+            },
+            FaviconFile {
+                content: favicon_ico,
+                file_name: FAVICON_FILE_NAME,
+            },
+            FaviconFile {
+                content: manifest.into_bytes(),
+                file_name: MANIFEST_FILE_NAME,
+            },
+            FaviconFile {
+                content: encode_png(192)?,
+                file_name: MANIFEST_ICON_192_FILE_NAME,
+            },
+            FaviconFile {
+                content: encode_png(512)?,
+                file_name: MANIFEST_ICON_512_FILE_NAME,
+            },
+        ]
+    )
+}
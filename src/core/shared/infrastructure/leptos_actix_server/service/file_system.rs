@@ -38,6 +38,33 @@ pub fn check_file_exists(file_path: &str) -> Result<bool, DomainError> {
     }
 }
 
+// Only the files at the top level of the directory are copied
+pub fn copy_directory_files(
+    from_directory_path: &str,
+    to_directory_path: &str
+) -> Result<(), DomainError> {
+    let entries = fs
+        ::read_dir(from_directory_path)
+        .map_err(|_e| error::CANNOT_READ_PATH_FS_METADATA)?;
+    for entry in entries.flatten() {
+        let is_file = entry
+            .file_type()
+            .map(|file_type| file_type.is_file())
+            .unwrap_or(false);
+        if is_file {
+            fs::copy(
+                entry.path(),
+                format!(
+                    "{}/{}",
+                    to_directory_path,
+                    entry.file_name().to_string_lossy()
+                )
+            ).map_err(|_e| error::CANNOT_WRITE_FILE)?;
+        }
+    }
+    Ok(())
+}
+
 pub fn create_directory(directory_path: &str) -> Result<(), DomainError> {
     let directory_exists = check_directory_exists(directory_path)?;
     if directory_exists {
@@ -50,6 +77,33 @@ pub fn create_directory(directory_path: &str) -> Result<(), DomainError> {
     Ok(())
 }
 
+pub fn list_directory_names(
+    directory_path: &str
+) -> Result<Vec<String>, DomainError> {
+    let directory_exists = check_directory_exists(directory_path)?;
+    if !directory_exists {
+        return Ok(vec![]);
+    }
+
+    let entries = fs
+        ::read_dir(directory_path)
+        .map_err(|_e| error::CANNOT_READ_PATH_FS_METADATA)?;
+    let mut directory_names = vec![];
+    for entry in entries.flatten() {
+        let is_dir = entry
+            .file_type()
+            .map(|file_type| file_type.is_dir())
+            .unwrap_or(false);
+        if is_dir {
+            if let Some(name) = entry.file_name().to_str() {
+                directory_names.push(name.to_string());
+            }
+        }
+    }
+    directory_names.sort();
+    Ok(directory_names)
+}
+
 pub fn read_file(file_path: &str) -> Result<fs::File, DomainError> {
     let file = fs::File::open(file_path).map_err(|_e| error::CANNOT_READ_FILE)?;
     Ok(file)
@@ -79,6 +133,16 @@ pub fn remove_directory(directory_path: &str) -> Result<(), DomainError> {
     Ok(())
 }
 
+pub fn remove_file(file_path: &str) -> Result<(), DomainError> {
+    let file_exists = check_file_exists(file_path)?;
+    if !file_exists {
+        return Ok(());
+    }
+
+    fs::remove_file(file_path).map_err(|_e| error::CANNOT_REMOVE_FILE)?;
+    Ok(())
+}
+
 pub fn write_file(
     file_path: &str,
     file_content: &str
@@ -86,3 +150,11 @@ pub fn write_file(
     fs::write(file_path, file_content).map_err(|_e| error::CANNOT_WRITE_FILE)?;
     Ok(())
 }
+
+pub fn write_file_bytes(
+    file_path: &str,
+    file_content: &[u8]
+) -> Result<(), DomainError> {
+    fs::write(file_path, file_content).map_err(|_e| error::CANNOT_WRITE_FILE)?;
+    Ok(())
+}
@@ -0,0 +1,282 @@
+use chrono::SecondsFormat;
+use chrono::Utc;
+use log::Level;
+use log::LevelFilter;
+use log::Log;
+use log::Metadata;
+use log::Record;
+use serde_json::Map;
+use serde_json::Value;
+use std::fs;
+use std::fs::File;
+use std::fs::OpenOptions;
+use std::future::Future;
+use std::io::Write;
+use std::sync::Mutex;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerLogFormat;
+use tukosmo_domain::core::shared::model::ServerLogLevel;
+use tukosmo_domain::core::shared::model::ServerLoggingConfig;
+
+// Writes the records of the log crate (from any module or dependency) with
+// the format of [logging] (Tukosmo.toml)
+pub struct Logger {
+    // None until the server config has been read
+    state: Mutex<Option<LoggerState>>,
+}
+
+struct LoggerState {
+    config: ServerLoggingConfig,
+    file: Option<File>,
+    file_path: String,
+    file_size_bytes: u64,
+}
+
+const ACCESS_LOG_TARGET: &'static str = "tukosmo::access";
+const BYTES_IN_A_MEGABYTE: u64 = 1048576;
+const LOG_FILE_NAME: &'static str = "tukosmo.log";
+
+static LOGGER: Logger = Logger { state: Mutex::new(None) };
+
+tokio::task_local! {
+    // Set by the RequestLogger middleware while the request is handled
+    static REQUEST_ID: String;
+}
+
+impl Log for Logger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn flush(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let file = state.as_mut().and_then(|state| state.file.as_mut());
+        if let Some(file) = file {
+            let _ = file.flush();
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        self.write(
+            record.level(),
+            record.target(),
+            &record.args().to_string(),
+            vec![]
+        );
+    }
+}
+
+impl Logger {
+    fn write(
+        &self,
+        level: Level,
+        target: &str,
+        message: &str,
+        mut fields: Vec<(&'static str, String)>
+    ) {
+        if let Some(request_id) = get_request_id() {
+            fields.push(("request_id", request_id));
+        }
+
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let format = state
+            .as_ref()
+            .map_or(ServerLogFormat::Logfmt, |state| state.config.format);
+        let line = match format {
+            ServerLogFormat::Json =>
+                format_json_line(level, target, message, fields),
+            ServerLogFormat::Logfmt =>
+                format_logfmt_line(level, target, message, fields),
+        };
+
+        // Orchestrators (e.g. systemd or Kubernetes) collect the standard
+        // output, so the file is an addition
+        println!("{}", line);
+        if let Some(state) = state.as_mut() {
+            state.write_to_file(&line);
+        }
+    }
+}
+
+impl LoggerState {
+    // tukosmo.log.{n} => tukosmo.log.{n + 1}, and the oldest one is replaced
+    fn rotate_file(&mut self) {
+        self.file = None;
+
+        let max_files = self.config.max_files;
+        for n in (1..max_files).rev() {
+            let _ = fs::rename(
+                format!("{}.{}", self.file_path, n),
+                format!("{}.{}", self.file_path, n + 1)
+            );
+        }
+        let _ = fs::rename(&self.file_path, format!("{}.1", self.file_path));
+
+        self.file = open_file(&self.file_path).ok();
+        self.file_size_bytes = 0;
+    }
+
+    fn write_to_file(&mut self, line: &str) {
+        if self.file.is_none() {
+            return;
+        }
+
+        let line_size_bytes = line.len() as u64 + 1;
+        let max_file_size_bytes =
+            self.config.max_file_size_mb * BYTES_IN_A_MEGABYTE;
+        let is_full = self.file_size_bytes > 0 &&
+            self.file_size_bytes + line_size_bytes > max_file_size_bytes;
+        if is_full {
+            self.rotate_file();
+        }
+
+        if let Some(file) = self.file.as_mut() {
+            if writeln!(file, "{}", line).is_ok() {
+                self.file_size_bytes += line_size_bytes;
+            }
+        }
+    }
+}
+
+pub fn get_request_id() -> Option<String> {
+    REQUEST_ID.try_with(|request_id| request_id.clone()).ok()
+}
+
+// Called every time the server starts, so a restart applies the changes of
+// [logging]. The logger is only installed the first time
+pub fn init(
+    config: &ServerLoggingConfig,
+    logs_dir_path: &str
+) -> Result<(), DomainError> {
+    let file_path = format!("{}/{}", logs_dir_path, LOG_FILE_NAME);
+    let file = if config.max_files > 0 {
+        fs::create_dir_all(logs_dir_path)
+            .map_err(|_e| error::CANNOT_CREATE_DIRECTORY)?;
+        Some(open_file(&file_path)?)
+    } else {
+        None
+    };
+    let file_size_bytes = file
+        .as_ref()
+        .and_then(|file| file.metadata().ok())
+        .map_or(0, |metadata| metadata.len());
+
+    let mut state = LOGGER.state.lock().unwrap_or_else(|e| e.into_inner());
+    *state = Some(LoggerState {
+        config: config.clone(),
+        file,
+        file_path,
+        file_size_bytes,
+    });
+    drop(state);
+
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(get_level_filter(config.level));
+
+    Ok(())
+}
+
+pub fn log_access(fields: Vec<(&'static str, String)>) {
+    let access_log_is_enabled = LOGGER.state
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .as_ref()
+        .map_or(false, |state| state.config.access_log);
+    if !access_log_is_enabled || Level::Info > log::max_level() {
+        return;
+    }
+
+    LOGGER.write(Level::Info, ACCESS_LOG_TARGET, "request", fields);
+}
+
+// Every log of the future (even from other modules) has the request ID
+pub async fn with_request_id<F>(request_id: String, future: F) -> F::Output
+    where F: Future
+{
+    REQUEST_ID.scope(request_id, future).await
+}
+
+fn escape_logfmt_value(value: &str) -> String {
+    let needs_quotes = value.is_empty() ||
+        value.chars().any(|c| c.is_whitespace() || c == '"' || c == '=');
+    if !needs_quotes {
+        return value.to_string();
+    }
+
+    format!(
+        "\"{}\"",
+        value
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+    )
+}
+
+fn format_json_line(
+    level: Level,
+    target: &str,
+    message: &str,
+    fields: Vec<(&'static str, String)>
+) -> String {
+    let mut object = Map::new();
+    object.insert("time".to_string(), Value::String(get_time()));
+    object.insert(
+        "level".to_string(),
+        Value::String(level.as_str().to_lowercase())
+    );
+    object.insert("target".to_string(), Value::String(target.to_string()));
+    object.insert("message".to_string(), Value::String(message.to_string()));
+    for (key, value) in fields {
+        object.insert(key.to_string(), Value::String(value));
+    }
+
+    Value::Object(object).to_string()
+}
+
+fn format_logfmt_line(
+    level: Level,
+    target: &str,
+    message: &str,
+    fields: Vec<(&'static str, String)>
+) -> String {
+    let mut line = format!(
+        "time={} level={} target={} message={}",
+        get_time(),
+        level.as_str().to_lowercase(),
+        escape_logfmt_value(target),
+        escape_logfmt_value(message)
+    );
+    for (key, value) in fields {
+        line.push_str(&format!(" {}={}", key, escape_logfmt_value(&value)));
+    }
+
+    line
+}
+
+fn get_level_filter(level: ServerLogLevel) -> LevelFilter {
+    match level {
+        ServerLogLevel::Error => LevelFilter::Error,
+        ServerLogLevel::Warn => LevelFilter::Warn,
+        ServerLogLevel::Info => LevelFilter::Info,
+        ServerLogLevel::Debug => LevelFilter::Debug,
+        ServerLogLevel::Trace => LevelFilter::Trace,
+    }
+}
+
+fn get_time() -> String {
+    Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true)
+}
+
+fn open_file(file_path: &str) -> Result<File, DomainError> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_path)
+        .map_err(|_e| error::CANNOT_WRITE_FILE)
+}
@@ -0,0 +1,333 @@
+use actix_web::dev::Service;
+use actix_web::dev::ServiceRequest;
+use actix_web::dev::ServiceResponse;
+use actix_web::dev::Transform;
+use actix_web::dev::forward_ready;
+use futures::FutureExt;
+use futures::future::LocalBoxFuture;
+use futures::future::Ready;
+use futures::future::ok;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum MetricKind {
+    Counter,
+    Gauge,
+}
+
+// Values that are read when the metrics are requested (e.g. the usage of the
+// database pool), instead of being recorded
+pub struct Metric {
+    pub help: &'static str,
+    pub kind: MetricKind,
+    pub name: &'static str,
+    pub value: f64,
+}
+
+// Records the requests answered by every route
+pub struct RequestMetrics;
+
+pub struct RequestMetricsService<S> {
+    service: S,
+}
+
+struct Histogram {
+    // Not cumulative; they're added up when rendered
+    bucket_counts: [u64; HISTOGRAM_BUCKETS_IN_SECONDS.len()],
+    count: u64,
+    sum_seconds: f64,
+}
+
+struct MetricsState {
+    // Outcome (commit or rollback) => durations
+    db_transaction_durations: BTreeMap<&'static str, Histogram>,
+    // (method, route) => durations
+    request_durations: BTreeMap<(String, String), Histogram>,
+    // (method, route, status) => requests
+    requests: BTreeMap<(String, String, u16), u64>,
+    server_restarts: u64,
+}
+
+const HISTOGRAM_BUCKETS_IN_SECONDS: [f64; 11] = [
+    0.005,
+    0.01,
+    0.025,
+    0.05,
+    0.1,
+    0.25,
+    0.5,
+    1.0,
+    2.5,
+    5.0,
+    10.0,
+];
+
+// Route of the server functions, which have their own paths
+const SERVER_FNS_ROUTE: &'static str = "/api/{tail:.*}";
+
+const UNMATCHED_ROUTE: &'static str = "unmatched";
+
+// Shared by the whole process, so they aren't reset when the server restarts,
+// and they can be recorded outside the requests (e.g. by the transactions)
+static METRICS: Mutex<MetricsState> = Mutex::new(MetricsState::new());
+
+impl Histogram {
+    const fn new() -> Self {
+        Self {
+            bucket_counts: [0; HISTOGRAM_BUCKETS_IN_SECONDS.len()],
+            count: 0,
+            sum_seconds: 0.0,
+        }
+    }
+
+    fn observe(&mut self, duration: Duration) {
+        let seconds = duration.as_secs_f64();
+        let bucket_index = HISTOGRAM_BUCKETS_IN_SECONDS
+            .iter()
+            .position(|upper_bound| seconds <= *upper_bound);
+        if let Some(bucket_index) = bucket_index {
+            self.bucket_counts[bucket_index] += 1;
+        }
+        self.count += 1;
+        self.sum_seconds += seconds;
+    }
+
+    fn render(&self, output: &mut String, name: &str, labels: &str) {
+        let mut cumulative_count = 0;
+        for (upper_bound, bucket_count) in HISTOGRAM_BUCKETS_IN_SECONDS
+            .iter()
+            .zip(self.bucket_counts.iter())
+        {
+            cumulative_count += bucket_count;
+            output.push_str(&format!(
+                "{}_bucket{{{},le=\"{}\"}} {}\n",
+                name,
+                labels,
+                upper_bound,
+                cumulative_count
+            ));
+        }
+        output.push_str(&format!(
+            "{}_bucket{{{},le=\"+Inf\"}} {}\n",
+            name,
+            labels,
+            self.count
+        ));
+        output.push_str(
+            &format!("{}_sum{{{}}} {}\n", name, labels, self.sum_seconds)
+        );
+        output.push_str(
+            &format!("{}_count{{{}}} {}\n", name, labels, self.count)
+        );
+    }
+}
+
+impl MetricKind {
+    fn value(&self) -> &'static str {
+        match self {
+            Self::Counter => "counter",
+            Self::Gauge => "gauge",
+        }
+    }
+}
+
+impl MetricsState {
+    const fn new() -> Self {
+        Self {
+            db_transaction_durations: BTreeMap::new(),
+            request_durations: BTreeMap::new(),
+            requests: BTreeMap::new(),
+            server_restarts: 0,
+        }
+    }
+}
+
+impl RequestMetrics {
+    pub fn init() -> Self {
+        RequestMetrics
+    }
+}
+
+impl<S> Service<ServiceRequest>
+    for RequestMetricsService<S>
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let started_at = Instant::now();
+        let method = req.method().to_string();
+
+        self.service
+            .call(req)
+            .map(move |result| {
+                let service_response = result?;
+                let status = service_response.status();
+                let request = service_response.request();
+
+                // Paths are only used for the server functions that exist,
+                // since anyone can request any path
+                let pattern = request.match_pattern();
+                let is_server_fn = pattern.as_deref() == Some(SERVER_FNS_ROUTE);
+                let route = if is_server_fn && status.is_success() {
+                    request.path().to_string()
+                } else {
+                    pattern.unwrap_or_else(|| UNMATCHED_ROUTE.to_string())
+                };
+                observe_request(method, route, status.as_u16(), started_at);
+
+                Ok(service_response)
+            })
+            .boxed_local()
+    }
+}
+
+impl<S> Transform<S, ServiceRequest>
+    for RequestMetrics
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = RequestMetricsService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(RequestMetricsService { service })
+    }
+}
+
+pub fn increment_server_restarts() {
+    let mut metrics = METRICS.lock().unwrap_or_else(|e| e.into_inner());
+    metrics.server_restarts += 1;
+}
+
+pub fn observe_db_transaction(outcome: &'static str, duration: Duration) {
+    let mut metrics = METRICS.lock().unwrap_or_else(|e| e.into_inner());
+    metrics.db_transaction_durations
+        .entry(outcome)
+        .or_insert_with(Histogram::new)
+        .observe(duration);
+}
+
+// Prometheus text format (version 0.0.4)
+pub fn render_metrics(metrics_values: &[Metric]) -> String {
+    let metrics = METRICS.lock().unwrap_or_else(|e| e.into_inner());
+    let mut output = String::new();
+
+    render_header(
+        &mut output,
+        "tukosmo_http_requests_total",
+        "Requests answered, by method, route and status.",
+        MetricKind::Counter.value()
+    );
+    for ((method, route, status), requests) in &metrics.requests {
+        output.push_str(&format!(
+            "tukosmo_http_requests_total{{method=\"{}\",route=\"{}\",\
+                status=\"{}\"}} {}\n",
+            escape_label_value(method),
+            escape_label_value(route),
+            status,
+            requests
+        ));
+    }
+
+    render_header(
+        &mut output,
+        "tukosmo_http_request_duration_seconds",
+        "Time taken to answer the requests, by method and route.",
+        "histogram"
+    );
+    for ((method, route), histogram) in &metrics.request_durations {
+        histogram.render(
+            &mut output,
+            "tukosmo_http_request_duration_seconds",
+            &format!(
+                "method=\"{}\",route=\"{}\"",
+                escape_label_value(method),
+                escape_label_value(route)
+            )
+        );
+    }
+
+    render_header(
+        &mut output,
+        "tukosmo_db_transaction_duration_seconds",
+        "Time taken by the database transactions, by outcome.",
+        "histogram"
+    );
+    for (outcome, histogram) in &metrics.db_transaction_durations {
+        histogram.render(
+            &mut output,
+            "tukosmo_db_transaction_duration_seconds",
+            &format!("outcome=\"{}\"", outcome)
+        );
+    }
+
+    render_header(
+        &mut output,
+        "tukosmo_server_restarts_total",
+        "Restarts of the server (e.g. after changing its settings).",
+        MetricKind::Counter.value()
+    );
+    output.push_str(
+        &format!("tukosmo_server_restarts_total {}\n", metrics.server_restarts)
+    );
+
+    for metric in metrics_values {
+        render_header(
+            &mut output,
+            metric.name,
+            metric.help,
+            metric.kind.value()
+        );
+        output.push_str(&format!("{} {}\n", metric.name, metric.value));
+    }
+
+    output
+}
+
+fn escape_label_value(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn observe_request(
+    method: String,
+    route: String,
+    status: u16,
+    started_at: Instant
+) {
+    let mut metrics = METRICS.lock().unwrap_or_else(|e| e.into_inner());
+    metrics.request_durations
+        .entry((method.clone(), route.clone()))
+        .or_insert_with(Histogram::new)
+        .observe(started_at.elapsed());
+    *metrics.requests.entry((method, route, status)).or_insert(0) += 1;
+}
+
+fn render_header(output: &mut String, name: &str, help: &str, kind: &str) {
+    output.push_str(&format!("# HELP {} {}\n", name, help));
+    output.push_str(&format!("# TYPE {} {}\n", name, kind));
+}
@@ -1,4 +1,22 @@
+pub mod backup;
+pub mod cli;
+pub mod csrf;
+pub mod database;
+pub mod domain;
+pub mod email;
+pub mod favicon;
 pub mod file_system;
+pub mod logger;
+pub mod metrics;
+pub mod multipart;
+pub mod page_cache;
+pub mod request_logger;
+pub mod request_origin;
+pub mod security_headers;
 pub mod server;
+pub mod server_config;
+pub mod session;
+pub mod static_file;
+pub mod system_info;
 pub mod tls;
 pub mod toml;
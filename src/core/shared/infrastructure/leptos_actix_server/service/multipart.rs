@@ -0,0 +1,84 @@
+use actix_multipart::Multipart;
+use futures_util::TryStreamExt;
+use tokio::io::AsyncWriteExt;
+use tukosmo_application::core::shared::dto::DtoUploadedFile;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+pub async fn read_uploaded_files(
+    mut payload: Multipart
+) -> Result<Vec<DtoUploadedFile>, DomainError> {
+    let mut uploaded_files: Vec<DtoUploadedFile> = vec![];
+
+    while
+        let Some(mut field) = payload
+            .try_next().await
+            .map_err(|_e| error::CANNOT_READ_MULTIPART_UPLOAD)?
+    {
+        let original_name = match field.content_disposition().get_filename() {
+            Some(file_name) => file_name.to_string(),
+            // Not a file field (or an empty file input), so it's skipped
+            None => {
+                continue;
+            }
+        };
+
+        let mut content: Vec<u8> = vec![];
+        while
+            let Some(chunk) = field
+                .try_next().await
+                .map_err(|_e| error::CANNOT_READ_MULTIPART_UPLOAD)?
+        {
+            content.extend_from_slice(&chunk);
+        }
+
+        if !original_name.is_empty() && !content.is_empty() {
+            uploaded_files.push(DtoUploadedFile { content, original_name });
+        }
+    }
+
+    Ok(uploaded_files)
+}
+
+// The first file of the upload is written to file_path as it's received
+// (instead of keeping it in memory), and the upload stops as soon as it's
+// larger than max_size_bytes. Returns false when no file has been uploaded
+pub async fn save_uploaded_file(
+    mut payload: Multipart,
+    file_path: &str,
+    max_size_bytes: u64
+) -> Result<bool, DomainError> {
+    while
+        let Some(mut field) = payload
+            .try_next().await
+            .map_err(|_e| error::CANNOT_READ_MULTIPART_UPLOAD)?
+    {
+        let file_name = field.content_disposition().get_filename();
+        if file_name.map_or(true, |file_name| file_name.is_empty()) {
+            continue;
+        }
+
+        let mut file = tokio::fs::File
+            ::create(file_path).await
+            .map_err(|_e| error::CANNOT_WRITE_FILE)?;
+        let mut size_bytes: u64 = 0;
+        while
+            let Some(chunk) = field
+                .try_next().await
+                .map_err(|_e| error::CANNOT_READ_MULTIPART_UPLOAD)?
+        {
+            size_bytes += chunk.len() as u64;
+            if size_bytes > max_size_bytes {
+                return Err(error::UPLOADED_FILE_IS_TOO_LARGE);
+            }
+            file
+                .write_all(&chunk).await
+                .map_err(|_e| error::CANNOT_WRITE_FILE)?;
+        }
+        file.flush().await.map_err(|_e| error::CANNOT_WRITE_FILE)?;
+
+        return Ok(size_bytes > 0);
+    }
+
+    Ok(false)
+}
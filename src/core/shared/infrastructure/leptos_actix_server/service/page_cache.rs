@@ -0,0 +1,521 @@
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::body::BoxBody;
+use actix_web::body::to_bytes;
+use actix_web::dev::Service;
+use actix_web::dev::ServiceRequest;
+use actix_web::dev::ServiceResponse;
+use actix_web::dev::Transform;
+use actix_web::dev::forward_ready;
+use actix_web::http;
+use actix_web::http::header;
+use actix_web::http::header::HeaderName;
+use actix_web::http::header::HeaderValue;
+use actix_web::web;
+use futures::future::LocalBoxFuture;
+use futures::future::Ready;
+use futures::future::ok;
+use leptos::use_context;
+use leptos_actix::ResponseOptions;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
+use tukosmo_domain::core::shared::model::ServerPageCacheConfig;
+
+use super::security_headers;
+use super::session;
+
+// Rendered pages of the public website (LRU, limited by the size of the
+// pages), shared by all the workers
+pub struct PageCache {
+    enabled: bool,
+    max_size_bytes: u64,
+    state: Mutex<PageCacheState>,
+}
+
+// Only serves (and stores) the pages of anonymous visitors
+pub struct PageCacheMiddleware {
+    page_cache: web::Data<PageCache>,
+}
+
+pub struct PageCacheMiddlewareService<S> {
+    page_cache: web::Data<PageCache>,
+    service: S,
+}
+
+pub struct PageCacheStats {
+    pub enabled: bool,
+    pub entries: usize,
+    pub hits: u64,
+    pub max_size_bytes: u64,
+    pub misses: u64,
+    pub size_bytes: u64,
+}
+
+#[derive(Clone, Eq, Hash, PartialEq)]
+struct PageCacheKey {
+    language_code: String,
+    path: String,
+}
+
+#[derive(Clone)]
+struct CachedPage {
+    body: String,
+    content_security_policy: Option<String>,
+    content_type: Option<String>,
+    // The nonce of the scripts, replaced by a new one in every response
+    nonce: Option<String>,
+}
+
+struct PageCacheEntry {
+    last_used: u64,
+    page: CachedPage,
+}
+
+#[derive(Default)]
+struct PageCacheState {
+    entries: HashMap<PageCacheKey, PageCacheEntry>,
+    hits: u64,
+    misses: u64,
+    // Last use => key, so the least recently used page is the first one
+    recency: BTreeMap<u64, PageCacheKey>,
+    size_bytes: u64,
+    use_counter: u64,
+}
+
+const BYTES_IN_A_MEGABYTE: u64 = 1048576;
+
+// Set by the pages that can be cached (see allow_caching()), and removed
+// before the response is sent
+const X_TUKOSMO_PAGE_CACHE: &'static str = "x-tukosmo-page-cache";
+
+// First segments of the paths that aren't pages of the public website
+const EXCLUDED_PATH_SEGMENTS: [&'static str; 6] = [
+    ".well-known",
+    "api",
+    "assets",
+    "pkg",
+    "theme",
+    "uploads",
+];
+
+impl PageCache {
+    fn get(&self, key: &PageCacheKey) -> Option<CachedPage> {
+        let mut state = self.state.lock().unwrap();
+        state.use_counter += 1;
+        let use_counter = state.use_counter;
+
+        let previous_use = match state.entries.get_mut(key) {
+            Some(entry) => {
+                let previous_use = entry.last_used;
+                entry.last_used = use_counter;
+                previous_use
+            }
+            None => return None,
+        };
+        state.recency.remove(&previous_use);
+        state.recency.insert(use_counter, key.clone());
+        state.hits += 1;
+
+        state.entries.get(key).map(|entry| entry.page.clone())
+    }
+
+    pub fn get_stats(&self) -> PageCacheStats {
+        let state = self.state.lock().unwrap();
+
+        PageCacheStats {
+            enabled: self.enabled,
+            entries: state.entries.len(),
+            hits: state.hits,
+            max_size_bytes: self.max_size_bytes,
+            misses: state.misses,
+            size_bytes: state.size_bytes,
+        }
+    }
+
+    pub fn init(config: &ServerPageCacheConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            max_size_bytes: config.max_size_mb * BYTES_IN_A_MEGABYTE,
+            state: Mutex::new(PageCacheState::default()),
+        }
+    }
+
+    fn insert(&self, key: PageCacheKey, page: CachedPage) {
+        let mut state = self.state.lock().unwrap();
+        state.misses += 1;
+
+        let page_size = page.body.len() as u64;
+        if page_size > self.max_size_bytes {
+            return;
+        }
+
+        state.remove(&key);
+        while state.size_bytes + page_size > self.max_size_bytes {
+            let least_recently_used_key = match state.recency.values().next() {
+                Some(key) => key.clone(),
+                None => break,
+            };
+            state.remove(&least_recently_used_key);
+        }
+
+        state.use_counter += 1;
+        let use_counter = state.use_counter;
+        state.recency.insert(use_counter, key.clone());
+        state.size_bytes += page_size;
+        state.entries.insert(key, PageCacheEntry {
+            last_used: use_counter,
+            page,
+        });
+    }
+
+    pub fn invalidate(&self, event: &ContentChangeEvent) {
+        match event {
+            ContentChangeEvent::AllPages => self.purge(),
+            ContentChangeEvent::LanguagePages(language_code) => {
+                let mut state = self.state.lock().unwrap();
+                let keys: Vec<PageCacheKey> = state.entries
+                    .keys()
+                    .filter(|key| key.language_code == language_code.value())
+                    .cloned()
+                    .collect();
+                for key in keys {
+                    state.remove(&key);
+                }
+            }
+        }
+    }
+
+    // The stats are kept, since they measure the cache since the server
+    // started
+    pub fn purge(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.recency.clear();
+        state.size_bytes = 0;
+    }
+}
+
+impl PageCacheMiddleware {
+    pub fn init(page_cache: &web::Data<PageCache>) -> Self {
+        PageCacheMiddleware {
+            page_cache: web::Data::clone(page_cache),
+        }
+    }
+}
+
+impl<S> Service<ServiceRequest>
+    for PageCacheMiddlewareService<S>
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let key = match get_cache_key(req.request()) {
+            Some(key) if self.page_cache.enabled => key,
+            _ => {
+                let future = self.service.call(req);
+                return Box::pin(async move {
+                    let mut service_response = future.await?;
+                    service_response.headers_mut().remove(X_TUKOSMO_PAGE_CACHE);
+                    Ok(service_response)
+                });
+            }
+        };
+
+        if let Some(page) = self.page_cache.get(&key) {
+            let response = build_cached_page_response(page);
+            return Box::pin(ok(req.into_response(response)));
+        }
+
+        let page_cache = web::Data::clone(&self.page_cache);
+        let future = self.service.call(req);
+
+        Box::pin(async move {
+            let mut service_response = future.await?;
+            let is_cacheable = is_cacheable_response(&service_response);
+            service_response.headers_mut().remove(X_TUKOSMO_PAGE_CACHE);
+            if !is_cacheable {
+                return Ok(service_response);
+            }
+
+            // The body must be complete to store it, so the rendering of the
+            // page isn't streamed to the browser
+            let (request, response) = service_response.into_parts();
+            let (response, body) = response.into_parts();
+            let body = to_bytes(body).await.map_err(|_e| {
+                actix_web::error::ErrorInternalServerError("Internal error")
+            })?;
+
+            if let Ok(html) = std::str::from_utf8(&body) {
+                let get_header = |name: header::HeaderName| {
+                    response.headers()
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(|value| value.to_string())
+                };
+                let content_security_policy = get_header(
+                    header::CONTENT_SECURITY_POLICY
+                );
+                let nonce = content_security_policy
+                    .as_ref()
+                    .and_then(|value| get_nonce(value));
+                page_cache.insert(key, CachedPage {
+                    body: html.to_string(),
+                    content_security_policy,
+                    content_type: get_header(header::CONTENT_TYPE),
+                    nonce,
+                });
+            }
+
+            let response = response.set_body(BoxBody::new(body));
+            Ok(ServiceResponse::new(request, response))
+        })
+    }
+}
+
+impl PageCacheState {
+    fn remove(&mut self, key: &PageCacheKey) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.recency.remove(&entry.last_used);
+            self.size_bytes -= entry.page.body.len() as u64;
+        }
+    }
+}
+
+impl<S> Transform<S, ServiceRequest>
+    for PageCacheMiddleware
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = PageCacheMiddlewareService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(PageCacheMiddlewareService {
+            page_cache: web::Data::clone(&self.page_cache),
+            service,
+        })
+    }
+}
+
+// Called while rendering the pages that are the same for every anonymous
+// visitor, since the other ones are never stored. It must be called before
+// the headers are sent: from the component of the route, or from a Suspense
+// or a Transition when the route is rendered with SsrMode::Async
+pub fn allow_caching() {
+    if let Some(response_options) = use_context::<ResponseOptions>() {
+        response_options.insert_header(
+            HeaderName::from_static(X_TUKOSMO_PAGE_CACHE),
+            HeaderValue::from_static("1")
+        );
+    }
+}
+
+// Registered as app data in start_server_thread(), and reached from server
+// functions through the Actix request that Leptos provides
+pub fn get_page_cache() -> Option<web::Data<PageCache>> {
+    use_context::<HttpRequest>().and_then(|request| {
+        request.app_data::<web::Data<PageCache>>().cloned()
+    })
+}
+
+fn build_cached_page_response(page: CachedPage) -> HttpResponse {
+    let mut response = HttpResponse::Ok();
+
+    let new_nonce = security_headers::generate_nonce();
+    let replace_nonce = |value: &str| match &page.nonce {
+        Some(nonce) => value.replace(nonce.as_str(), &new_nonce),
+        None => value.to_string(),
+    };
+
+    if let Some(content_type) = &page.content_type {
+        response.insert_header((header::CONTENT_TYPE, content_type.as_str()));
+    }
+    if let Some(content_security_policy) = &page.content_security_policy {
+        response.insert_header((
+            header::CONTENT_SECURITY_POLICY,
+            replace_nonce(content_security_policy),
+        ));
+    }
+
+    response.body(replace_nonce(&page.body))
+}
+
+// Only GET requests of anonymous visitors (without a session) to pages of the
+// public website. Queries (e.g. searches) aren't cached, since they're
+// unlimited
+fn get_cache_key(request: &HttpRequest) -> Option<PageCacheKey> {
+    let is_anonymous = request.cookie(session::SESSION_COOKIE_NAME).is_none();
+    if
+        request.method() != http::Method::GET ||
+        !request.query_string().is_empty() ||
+        !is_anonymous
+    {
+        return None;
+    }
+
+    let path = request.path();
+    let mut segments = path.trim_start_matches('/').split('/');
+    let language_code = segments.next().unwrap_or_default();
+    let is_admin_panel = segments.next() == Some("admin");
+    if is_admin_panel || EXCLUDED_PATH_SEGMENTS.contains(&language_code) {
+        return None;
+    }
+
+    Some(PageCacheKey {
+        language_code: language_code.to_string(),
+        path: path.to_string(),
+    })
+}
+
+fn get_nonce(content_security_policy: &str) -> Option<String> {
+    let (_before, after) = content_security_policy.split_once("'nonce-")?;
+    let (nonce, _after) = after.split_once('\'')?;
+
+    if nonce.is_empty() {
+        None
+    } else {
+        Some(nonce.to_string())
+    }
+}
+
+// Only the pages that have allowed it, unless they've also set a cookie or
+// their own Cache-Control
+fn is_cacheable_response(service_response: &ServiceResponse) -> bool {
+    let headers = service_response.headers();
+    let is_html = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map_or(false, |value| value.starts_with("text/html"));
+
+    service_response.status() == http::StatusCode::OK &&
+        is_html &&
+        headers.contains_key(X_TUKOSMO_PAGE_CACHE) &&
+        !headers.contains_key(header::SET_COOKIE) &&
+        !headers.contains_key(header::CACHE_CONTROL)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn init_page_cache(max_size_bytes: u64) -> PageCache {
+        PageCache {
+            enabled: true,
+            max_size_bytes,
+            state: Mutex::new(PageCacheState::default()),
+        }
+    }
+
+    fn key(language_code: &str, path: &str) -> PageCacheKey {
+        PageCacheKey {
+            language_code: language_code.to_string(),
+            path: path.to_string(),
+        }
+    }
+
+    fn page(size_bytes: usize) -> CachedPage {
+        CachedPage {
+            body: "a".repeat(size_bytes),
+            content_security_policy: None,
+            content_type: Some("text/html; charset=utf-8".to_string()),
+            nonce: None,
+        }
+    }
+
+    #[test]
+    fn stores_pages_after_a_miss() {
+        let page_cache = init_page_cache(100);
+        let home_key = key("en", "/en");
+
+        assert!(page_cache.get(&home_key).is_none());
+        page_cache.insert(home_key.clone(), page(10));
+        let cached_page = page_cache.get(&home_key);
+
+        assert_eq!(cached_page.map(|page| page.body.len()), Some(10));
+        let stats = page_cache.get_stats();
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.size_bytes, 10);
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_page() {
+        let page_cache = init_page_cache(100);
+        let first_key = key("en", "/en/first");
+        let second_key = key("en", "/en/second");
+        let third_key = key("en", "/en/third");
+
+        page_cache.insert(first_key.clone(), page(40));
+        page_cache.insert(second_key.clone(), page(40));
+        // The first page becomes the most recently used one
+        assert!(page_cache.get(&first_key).is_some());
+        page_cache.insert(third_key.clone(), page(40));
+
+        assert!(page_cache.get(&first_key).is_some());
+        assert!(page_cache.get(&second_key).is_none());
+        assert!(page_cache.get(&third_key).is_some());
+        let stats = page_cache.get_stats();
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.size_bytes, 80);
+    }
+
+    #[test]
+    fn doesnt_store_pages_larger_than_the_cache() {
+        let page_cache = init_page_cache(100);
+        let home_key = key("en", "/en");
+
+        page_cache.insert(home_key.clone(), page(101));
+
+        assert!(page_cache.get(&home_key).is_none());
+        assert_eq!(page_cache.get_stats().size_bytes, 0);
+    }
+
+    #[test]
+    fn keeps_the_size_when_pages_are_replaced_and_removed() {
+        let page_cache = init_page_cache(100);
+        let english_key = key("en", "/en");
+        let spanish_key = key("es", "/es");
+
+        page_cache.insert(english_key.clone(), page(40));
+        page_cache.insert(english_key.clone(), page(30));
+        page_cache.insert(spanish_key.clone(), page(20));
+        assert_eq!(page_cache.get_stats().size_bytes, 50);
+
+        {
+            let mut state = page_cache.state.lock().unwrap();
+            state.remove(&english_key);
+            // Removing a page that isn't stored changes nothing
+            state.remove(&english_key);
+            assert_eq!(state.size_bytes, 20);
+            assert_eq!(state.entries.len(), 1);
+            assert_eq!(state.recency.len(), 1);
+        }
+
+        page_cache.purge();
+        let stats = page_cache.get_stats();
+        assert_eq!(stats.entries, 0);
+        assert_eq!(stats.size_bytes, 0);
+    }
+}
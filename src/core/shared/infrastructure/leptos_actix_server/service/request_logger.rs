@@ -0,0 +1,150 @@
+use actix_web::HttpMessage;
+use actix_web::dev::Service;
+use actix_web::dev::ServiceRequest;
+use actix_web::dev::ServiceResponse;
+use actix_web::dev::Transform;
+use actix_web::dev::forward_ready;
+use actix_web::http::header;
+use actix_web::http::header::HeaderName;
+use actix_web::http::header::HeaderValue;
+use futures::future::LocalBoxFuture;
+use futures::future::Ready;
+use futures::future::ok;
+use std::time::Instant;
+use uuid::Uuid;
+
+use super::logger;
+use super::request_origin;
+
+// Gives every request an ID, which is in its logs and in the X-Request-Id
+// header of the response, and logs the request when it's answered
+pub struct RequestLogger;
+
+pub struct RequestLoggerService<S> {
+    service: S,
+}
+
+// Added to the extensions of the request, so handlers can read it
+#[derive(Clone)]
+pub struct RequestId(pub String);
+
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+pub const X_REQUEST_ID: &'static str = "x-request-id";
+
+impl RequestLogger {
+    pub fn init() -> Self {
+        RequestLogger
+    }
+}
+
+impl<S> Service<ServiceRequest>
+    for RequestLoggerService<S>
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let started_at = Instant::now();
+        let request_origin = request_origin::get_request_origin(req.request());
+
+        // The ID of a trusted proxy is kept, so the logs of both match
+        let forwarded_request_id = req
+            .headers()
+            .get(X_REQUEST_ID)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| is_valid_request_id(value))
+            .filter(|_value| request_origin.is_forwarded)
+            .map(|value| value.to_string());
+        let request_id = forwarded_request_id
+            .unwrap_or_else(|| Uuid::new_v4().to_string());
+        req.extensions_mut().insert(RequestId(request_id.clone()));
+
+        let mut fields = vec![
+            (
+                "client_ip",
+                request_origin.client_ip
+                    .map(|client_ip| client_ip.to_string())
+                    .unwrap_or_default(),
+            ),
+            ("method", req.method().to_string()),
+            ("path", req.path().to_string()),
+            (
+                "user_agent",
+                req.headers()
+                    .get(header::USER_AGENT)
+                    .and_then(|value| value.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+        ];
+
+        let future = self.service.call(req);
+
+        Box::pin(
+            logger::with_request_id(request_id.clone(), async move {
+                let result = future.await;
+
+                let status = match &result {
+                    Ok(service_response) => service_response.status(),
+                    Err(e) => e.as_response_error().status_code(),
+                };
+                fields.push(("status", status.as_u16().to_string()));
+                fields.push((
+                    "duration_ms",
+                    started_at.elapsed().as_millis().to_string(),
+                ));
+                logger::log_access(fields);
+
+                let mut service_response = result?;
+                if let Ok(value) = HeaderValue::from_str(&request_id) {
+                    service_response.headers_mut().insert(
+                        HeaderName::from_static(X_REQUEST_ID),
+                        value
+                    );
+                }
+                Ok(service_response)
+            })
+        )
+    }
+}
+
+impl<S> Transform<S, ServiceRequest>
+    for RequestLogger
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = RequestLoggerService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(RequestLoggerService { service })
+    }
+}
+
+// Only characters that are safe in headers and logs
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty() &&
+        value.len() <= MAX_REQUEST_ID_LENGTH &&
+        value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_.".contains(c))
+}
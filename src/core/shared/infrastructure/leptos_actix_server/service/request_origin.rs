@@ -0,0 +1,132 @@
+use actix_web::HttpRequest;
+use actix_web::http;
+use actix_web::web;
+use std::net::IpAddr;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerTlsConfig;
+
+// What the client used to reach the website, which differs from the
+// connection when a reverse proxy is in the middle
+pub struct RequestOrigin {
+    pub client_ip: Option<IpAddr>,
+    pub host: String,
+    pub is_forwarded: bool,
+    pub is_https: bool,
+}
+
+const X_FORWARDED_FOR: &'static str = "x-forwarded-for";
+const X_FORWARDED_HOST: &'static str = "x-forwarded-host";
+const X_FORWARDED_PROTO: &'static str = "x-forwarded-proto";
+
+// The X-Forwarded-* headers are only honoured when the connection comes from
+// a trusted proxy (tls.trusted_proxies), since anyone can send them. Unlike
+// connection_info() of Actix, which always trusts them
+pub fn get_request_origin(request: &HttpRequest) -> RequestOrigin {
+    let trusted_proxies: Vec<(IpAddr, u8)> = request
+        .app_data::<web::Data<ServerConfig>>()
+        .map(|server_config| {
+            server_config.tls.trusted_proxies
+                .iter()
+                .filter_map(|value| ServerTlsConfig::parse_trusted_proxy(value))
+                .collect()
+        })
+        .unwrap_or_default();
+    let is_trusted = |ip: &IpAddr| {
+        trusted_proxies
+            .iter()
+            .any(|(network, prefix_length)| {
+                ip_is_in_network(ip, network, *prefix_length)
+            })
+    };
+
+    let peer_ip = request.peer_addr().map(|addr| addr.ip().to_canonical());
+    let host = get_header(request, http::header::HOST.as_str())
+        .or_else(|| request.uri().authority().map(|a| a.to_string()))
+        .unwrap_or_else(|| request.app_config().host().to_string());
+    let is_https = request.app_config().secure();
+
+    let is_forwarded = peer_ip.as_ref().map_or(false, |ip| is_trusted(ip));
+    if !is_forwarded {
+        return RequestOrigin {
+            client_ip: peer_ip,
+            host,
+            is_forwarded,
+            is_https,
+        };
+    }
+
+    // Every proxy appends the address it received the request from, so the
+    // client is the last one that isn't a trusted proxy
+    let forwarded_ips: Vec<IpAddr> = get_header(request, X_FORWARDED_FOR)
+        .map(|value| {
+            value
+                .split(',')
+                .filter_map(|ip| ip.trim().parse::<IpAddr>().ok())
+                .map(|ip| ip.to_canonical())
+                .collect()
+        })
+        .unwrap_or_default();
+    let client_ip = forwarded_ips
+        .iter()
+        .rev()
+        .find(|ip| !is_trusted(ip))
+        .or(forwarded_ips.first())
+        .copied()
+        .or(peer_ip);
+
+    // The first proxy (the one the client reached) sets the original values
+    let forwarded_host = get_header(request, X_FORWARDED_HOST).and_then(
+        |value| get_first_value(&value)
+    );
+    let forwarded_proto = get_header(request, X_FORWARDED_PROTO).and_then(
+        |value| get_first_value(&value)
+    );
+
+    RequestOrigin {
+        client_ip,
+        host: forwarded_host.unwrap_or(host),
+        is_forwarded,
+        is_https: match forwarded_proto {
+            Some(proto) => proto.eq_ignore_ascii_case("https"),
+            None => is_https,
+        },
+    }
+}
+
+pub fn ip_is_in_network(
+    ip: &IpAddr,
+    network: &IpAddr,
+    prefix_length: u8
+) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(network)) => {
+            let mask = u32::MAX
+                .checked_shl(32 - (prefix_length as u32))
+                .unwrap_or(0);
+            (u32::from(*ip) & mask) == (u32::from(*network) & mask)
+        }
+        (IpAddr::V6(ip), IpAddr::V6(network)) => {
+            let mask = u128::MAX
+                .checked_shl(128 - (prefix_length as u32))
+                .unwrap_or(0);
+            (u128::from(*ip) & mask) == (u128::from(*network) & mask)
+        }
+        _ => false,
+    }
+}
+
+fn get_first_value(value: &str) -> Option<String> {
+    value
+        .split(',')
+        .next()
+        .map(|first_value| first_value.trim().to_string())
+        .filter(|first_value| !first_value.is_empty())
+}
+
+fn get_header(request: &HttpRequest, name: &str) -> Option<String> {
+    request
+        .headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string())
+}
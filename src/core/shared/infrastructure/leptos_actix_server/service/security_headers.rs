@@ -0,0 +1,192 @@
+use actix_web::HttpRequest;
+use actix_web::dev::Service;
+use actix_web::dev::ServiceRequest;
+use actix_web::dev::ServiceResponse;
+use actix_web::dev::Transform;
+use actix_web::dev::forward_ready;
+use actix_web::http::header;
+use actix_web::http::header::HeaderName;
+use actix_web::http::header::HeaderValue;
+use actix_web::web;
+use futures::FutureExt;
+use futures::future::LocalBoxFuture;
+use futures::future::Ready;
+use futures::future::ok;
+use leptos::nonce::use_nonce;
+use leptos::use_context;
+use leptos_actix::ResponseOptions;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerSecurityHeadersConfig;
+
+use super::request_origin;
+
+// Sets the headers of [security_headers] (Tukosmo.toml) in every response,
+// unless the response already has them
+#[derive(Clone)]
+pub struct SecurityHeaders {
+    config: ServerSecurityHeadersConfig,
+}
+
+pub struct SecurityHeadersService<S> {
+    config: ServerSecurityHeadersConfig,
+    service: S,
+}
+
+const X_CONTENT_TYPE_OPTIONS_VALUE: &'static str = "nosniff";
+
+impl SecurityHeaders {
+    pub fn init(config: &ServerSecurityHeadersConfig) -> Self {
+        SecurityHeaders {
+            config: config.clone(),
+        }
+    }
+}
+
+impl<S> Service<ServiceRequest>
+    for SecurityHeadersService<S>
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        // Browsers ignore HSTS received over plain HTTP
+        let is_https = request_origin
+            ::get_request_origin(req.request())
+            .is_https;
+
+        // Pages rendered by Leptos already have the policy with the nonce of
+        // their scripts, so this one is for the rest of responses (e.g. files)
+        let content_security_policy = self.config.get_content_security_policy(
+            is_admin_panel_path(req.path()),
+            &generate_nonce()
+        );
+        let hsts_header_value = if is_https {
+            self.config.get_hsts_header_value()
+        } else {
+            None
+        };
+        let headers = [
+            (header::CONTENT_SECURITY_POLICY, content_security_policy),
+            (
+                header::PERMISSIONS_POLICY,
+                self.config.permissions_policy.clone(),
+            ),
+            (header::REFERRER_POLICY, self.config.referrer_policy.clone()),
+            (
+                header::STRICT_TRANSPORT_SECURITY,
+                hsts_header_value.unwrap_or_default(),
+            ),
+            (
+                header::X_CONTENT_TYPE_OPTIONS,
+                X_CONTENT_TYPE_OPTIONS_VALUE.to_string(),
+            ),
+            (header::X_FRAME_OPTIONS, self.config.frame_options.clone()),
+        ];
+
+        self.service
+            .call(req)
+            .map(move |result| {
+                let mut service_response = result?;
+                for (name, value) in headers {
+                    insert_header_if_missing(
+                        &mut service_response,
+                        name,
+                        value
+                    );
+                }
+                Ok(service_response)
+            })
+            .boxed_local()
+    }
+}
+
+impl<S> Transform<S, ServiceRequest>
+    for SecurityHeaders
+    where
+        S: Service<
+            ServiceRequest,
+            Response = ServiceResponse,
+            Error = actix_web::Error
+        >,
+        S::Future: 'static
+{
+    type Response = ServiceResponse;
+    type Error = actix_web::Error;
+    type InitError = ();
+    type Transform = SecurityHeadersService<S>;
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ok(SecurityHeadersService {
+            config: self.config.clone(),
+            service,
+        })
+    }
+}
+
+// Called while rendering the app, since the nonce is generated by Leptos for
+// each request, and added to its hydration scripts
+pub fn insert_content_security_policy() {
+    let request = match use_context::<HttpRequest>() {
+        Some(request) => request,
+        None => return,
+    };
+    let response_options = match use_context::<ResponseOptions>() {
+        Some(response_options) => response_options,
+        None => return,
+    };
+    let server_config = match request.app_data::<web::Data<ServerConfig>>() {
+        Some(server_config) => server_config,
+        None => return,
+    };
+
+    let nonce = use_nonce()
+        .map(|nonce| nonce.to_string())
+        .unwrap_or_else(generate_nonce);
+    let content_security_policy = server_config.security_headers
+        .get_content_security_policy(
+            is_admin_panel_path(request.path()),
+            &nonce
+        );
+    if content_security_policy.is_empty() {
+        return;
+    }
+
+    if let Ok(value) = HeaderValue::from_str(&content_security_policy) {
+        response_options.insert_header(header::CONTENT_SECURITY_POLICY, value);
+    }
+}
+
+pub fn generate_nonce() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+fn insert_header_if_missing(
+    service_response: &mut ServiceResponse,
+    name: HeaderName,
+    value: String
+) {
+    // Empty values disable the header
+    if value.is_empty() || service_response.headers().contains_key(&name) {
+        return;
+    }
+
+    if let Ok(value) = HeaderValue::from_str(&value) {
+        service_response.headers_mut().insert(name, value);
+    }
+}
+
+// The TAP is under /{language_code}/admin
+fn is_admin_panel_path(path: &str) -> bool {
+    path.split('/').nth(2) == Some("admin")
+}
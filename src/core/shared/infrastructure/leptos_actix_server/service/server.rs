@@ -4,6 +4,7 @@ use actix_session::SessionMiddleware;
 use actix_session::config::CookieContentSecurity;
 use actix_session::config::PersistentSession;
 use actix_session::storage::CookieSessionStore;
+use actix_web::HttpRequest;
 use actix_web::HttpResponse;
 use actix_web::HttpServer;
 use actix_web::cookie;
@@ -14,49 +15,71 @@ use actix_web::dev::ServiceResponse;
 use actix_web::dev::Transform;
 use actix_web::dev::forward_ready;
 use actix_web::http;
+use actix_web::middleware;
 use actix_web::web;
 use chrono::Utc;
-use core::future::Future;
-use futures::FutureExt;
 use futures::future::Either;
 use futures::future::Ready;
 use futures::future::ok;
 use leptos::get_configuration;
+use leptos::use_context;
 use leptos::view;
 use leptos_actix::LeptosRoutes;
 use leptos_actix::generate_route_list;
-use rand::Rng;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 use tokio;
+use tukosmo_domain::core::shared::error;
 use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerTlsMode;
 use tukosmo_domain::core::shared::repository::DataRepository;
 
+use crate::core::gallery::leptos_actix_server::handler::gallery_handler;
+use crate::core::shared::diesel_orm::model::init_db_pool;
+use crate::core::shared::diesel_orm::service::migration_runner;
+use crate::core::shared::leptos_actix_server::handler::acme_challenge_handler;
+use crate::core::shared::leptos_actix_server::handler::backup_handler;
+use crate::core::shared::leptos_actix_server::handler::content_handler;
+use crate::core::shared::leptos_actix_server::handler::favicon_handler;
+use crate::core::shared::leptos_actix_server::handler::monitoring_handler;
+use crate::core::shared::leptos_actix_server::handler::static_file_handler;
 use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+use crate::core::shared::leptos_actix_server::service::backup;
+use crate::core::shared::leptos_actix_server::service::favicon as favicon_service;
+use crate::core::shared::leptos_actix_server::service::file_system;
+use crate::core::shared::leptos_actix_server::service::logger;
+use crate::core::shared::leptos_actix_server::service::metrics;
+use crate::core::shared::leptos_actix_server::service::page_cache;
+use crate::core::shared::leptos_actix_server::service::page_cache::PageCache;
+use crate::core::shared::leptos_actix_server::service::request_logger;
+use crate::core::shared::leptos_actix_server::service::request_origin;
+use crate::core::shared::leptos_actix_server::service::security_headers;
+use crate::core::shared::leptos_actix_server::service::server_config;
+use crate::core::shared::leptos_actix_server::service::session;
+use crate::core::shared::leptos_actix_server::service::static_file;
 use crate::core::shared::leptos_actix_server::service::tls;
 use crate::core::shared::leptos_ui::App;
+use crate::core::theme::leptos_actix_server::handler::theme_handler;
 
 /*
  * TODO: Manage errors with DomainError instead of .unwrap(), .except(), etc.
  */
 
-pub struct AcmeChallengeServer {
-    pub thread: tokio::task::JoinHandle<Result<(), std::io::Error>>,
-    pub handle: ServerHandle,
-}
-
 #[derive(Clone)]
 pub struct Handle(pub Arc<Mutex<Option<ServerHandle>>>);
 
 #[derive(Default, Clone)]
 pub struct RedirectHTTPS {
     replacements: Vec<(String, String)>,
+    tls_is_enabled: bool,
 }
 
 pub struct RedirectHTTPSService<S> {
     service: S,
     replacements: Vec<(String, String)>,
+    tls_is_enabled: bool,
 }
 
 pub struct TukosmoServer {
@@ -67,6 +90,9 @@ pub struct TukosmoServer {
 
 const COOKIE_DURATION_IN_DAYS: i64 = 7;
 
+// Gives the server function time to send its response before stopping
+const RESTART_DELAY_IN_SECONDS: u64 = 1;
+
 impl Handle {
     pub fn new() -> Self {
         Self(Arc::new(Mutex::new(None)))
@@ -76,21 +102,30 @@ impl Handle {
         self.0.lock().unwrap().replace(handle)
     }
 
-    pub async fn stop(&self, graceful: bool) -> impl Future<Output = ()> {
-        let o = self.0.lock().unwrap().take();
-        match o {
-            Some(server_handle) => { server_handle.stop(graceful) }
+    // The handle is taken by the first stop (e.g. a restart)
+    pub async fn stop(&self, graceful: bool) -> Result<(), DomainError> {
+        let server_handle = self.0.lock().unwrap().take();
+        match server_handle {
+            Some(server_handle) => {
+                server_handle.stop(graceful).await;
+                Ok(())
+            }
             None => {
-                panic!("None in Handle::stop()");
+                log::error!("The server can't be stopped: it isn't running");
+                Err(error::SERVER_IS_NOT_RUNNING)
             }
         }
     }
 }
 
 impl RedirectHTTPS {
-    pub fn with_replacements(replacements: &[(String, String)]) -> Self {
+    pub fn init(
+        replacements: &[(String, String)],
+        tls_is_enabled: bool
+    ) -> Self {
         RedirectHTTPS {
             replacements: replacements.to_vec(),
+            tls_is_enabled,
         }
     }
 }
@@ -112,15 +147,31 @@ impl<S> Service<ServiceRequest>
     forward_ready!(service);
 
     fn call(&self, req: ServiceRequest) -> Self::Future {
-        if req.connection_info().scheme() == "https" {
+        // ACME challenges must be answered over plain HTTP
+        let is_acme_challenge = req
+            .path()
+            .starts_with(acme_challenge_handler::ACME_CHALLENGE_PATH_PREFIX);
+        let is_monitoring = monitoring_handler::MONITORING_PATHS
+            .contains(&req.path());
+        // Without TLS, only a trusted proxy can tell that the client used HTTP
+        let request_origin = request_origin::get_request_origin(req.request());
+        let can_redirect = self.tls_is_enabled || request_origin.is_forwarded;
+        if
+            request_origin.is_https ||
+            is_acme_challenge ||
+            is_monitoring ||
+            !can_redirect
+        {
             Either::Left(self.service.call(req))
         } else {
-            let host = req.connection_info().host().to_owned();
             let uri = req.uri().to_owned();
 
-            let mut url = format!("https://{}{}", host, uri);
-            for (s1, s2) in self.replacements.iter() {
-                url = url.replace(s1, s2);
+            let mut url = format!("https://{}{}", request_origin.host, uri);
+            // The host of a proxy doesn't have the ports of Tukosmo.toml
+            if !request_origin.is_forwarded {
+                for (s1, s2) in self.replacements.iter() {
+                    url = url.replace(s1, s2);
+                }
             }
 
             Either::Right(
@@ -138,66 +189,68 @@ impl<S> Service<ServiceRequest>
     }
 }
 
-pub fn start_acme_challenge_server(
-    domain: &str
-) -> Result<AcmeChallengeServer, DomainError> {
-    let fs_data_repository = FsDataRepository::init()?;
-    let acme_challenge_dir_path = fs_data_repository.acme_challenge_dir_path;
-
-    // For HTTP, the challenge is a text file that needs to be accessible
-    // over the web for the domain we are trying to get a certificate for:
-    // http://mydomain.io/.well-known/acme-challenge/<token>
-    // (the token is the filename and the proof is the content of the file)
-    let server = HttpServer::new(move || {
-        actix_web::App
-            ::new()
-            .service(
-                Files::new(
-                    "/.well-known/acme-challenge",
-                    &acme_challenge_dir_path
-                ).show_files_listing()
-            )
-    })
-        .bind((domain, 80))
-        .unwrap()
-        .shutdown_timeout(0)
-        .run();
-
-    let handle = server.handle();
-    let server_thread = tokio::spawn(server);
-
-    return Ok(AcmeChallengeServer {
-        thread: server_thread,
-        handle,
+// Leptos provides the Actix request to server functions, and the handle is
+// registered as app data in start_server_thread(). The restart is graceful,
+// and start_server() starts it again (reading Tukosmo.toml) when it stops
+// without a handle
+pub fn restart_server() -> Result<(), DomainError> {
+    let request = use_context::<HttpRequest>()
+        .ok_or(error::CANNOT_RESTART_SERVER)?;
+    let handle = request
+        .app_data::<web::Data<Handle>>()
+        .ok_or(error::CANNOT_RESTART_SERVER)?
+        .get_ref()
+        .clone();
+
+    actix_web::rt::spawn(async move {
+        tokio::time::sleep(
+            Duration::from_secs(RESTART_DELAY_IN_SECONDS)
+        ).await;
+
+        // The stop command is sent right away, so there's no need to wait
+        // for the workers (including this one) to finish
+        let server_handle = handle.0.lock().unwrap().take();
+        if let Some(server_handle) = server_handle {
+            let _ = server_handle.stop(true);
+        }
     });
+
+    Ok(())
 }
 
 pub async fn start_server() -> Result<(), DomainError> {
     // TODO: Add option in TAP to reset cookies private key.
     // TODO: Add option in TAP to reset all sessions stored in PostgreSQL.
-    let cookie_pkey: actix_web::cookie::Key = actix_web::cookie::Key::derive_from(
-        &rand::thread_rng().gen::<[u8; 32]>()
-    );
+    let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
+    // Before anything is logged (e.g. by get_cookie_key())
+    logger::init(&server_config.logging, &fs_data_repository.logs_dir_path)?;
+    upgrade_server_config_file(&fs_data_repository);
+    let cookie_pkey = session::get_cookie_key(&fs_data_repository)?;
 
     loop {
         let server = start_server_thread(cookie_pkey.clone()).await?;
-        println!("SERVER ON");
+        log::info!("Server started");
 
-        match server.thread.await {
-            Ok(_) => {
-                for child_thread in server.child_threads {
-                    child_thread.abort();
-                }
+        let result = server.thread.await;
+        for child_thread in server.child_threads {
+            child_thread.abort();
+        }
 
+        match result {
+            Ok(_) => {
                 if let None = server.handle.0.lock().unwrap().take() {
-                    println!("Restarting server...");
+                    log::info!("Restarting the server");
+                    metrics::increment_server_restarts();
                     continue;
                 } else {
                     break;
                 }
             }
+            // The command exits with an error code (see cli::run())
             Err(e) => {
-                panic!("SERVER ERROR: {:?}", e);
+                log::error!("The server stopped unexpectedly: {:?}", e);
+                return Err(error::SERVER_STOPPED_UNEXPECTEDLY);
             }
         }
     }
@@ -212,31 +265,94 @@ async fn start_server_thread(
 
     let startup_code = format!("{:x}", Utc::now().timestamp());
 
-    let server_config = FsDataRepository::init()?.get_server_config()?;
+    let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
+    // Again, so a restart applies the changes of [logging]
+    logger::init(&server_config.logging, &fs_data_repository.logs_dir_path)?;
+    let uploads_dir_path = fs_data_repository.uploads_dir_path.clone();
+
+    // With the off mode, TLS is terminated by a reverse proxy, so there's no
+    // HTTPS listener
+    let tls_mode = server_config.tls.mode;
+    let tls_is_enabled = ServerTlsMode::Off != tls_mode;
+    let certificate_resolver = if tls_is_enabled {
+        Some(tls::load_certificate_resolver().await?)
+    } else {
+        None
+    };
+
+    let handle_server = web::Data::new(handle.clone());
+    // Reloaded when the certificate is renewed from the TAP
+    let certificate_resolver_data = web::Data::new(
+        certificate_resolver.clone()
+    );
 
-    let production_mode_is_enabled = server_config.has_production_mode();
+    // Shared by all the workers, and recreated when the server restarts so
+    // changes in the [database] section are applied
+    let db_pool = web::Data::new(init_db_pool(&server_config.database)?);
 
-    let rustls_config = tls::load_rustls_config().await?;
+    // The schema must be up to date before any request reaches the database
+    let applied_migrations = migration_runner::run_pending_migrations(
+        &db_pool
+    )?;
+    for migration_version in applied_migrations {
+        log::info!("Applied database migration {}", migration_version);
+    }
 
-    let handle_server = web::Data::new(handle.clone());
+    let backup_thread = backup::spawn_backup_thread(
+        db_pool.get_ref().clone(),
+        server_config.backup.clone()
+    );
 
     let conf_file = get_configuration(None).await.unwrap();
-    let http_socket_addr = conf_file.leptos_options.site_addr;
+    // Only the IP is taken from the Leptos config, so the ports of
+    // Tukosmo.toml (editable from the TAP) are the ones used
+    let http_socket_addr = SocketAddr::new(
+        conf_file.leptos_options.site_addr.ip(),
+        server_config.http_port
+    );
     let https_socket_addr = SocketAddr::new(
         http_socket_addr.ip(),
         server_config.https_port
     );
     let routes = generate_route_list(|| view! { <App/> });
 
+    // The hydration scripts of the pages are loaded from /pkg/{version}, so
+    // browsers can cache them until the next build
+    let pkg_version = web::Data::new(
+        static_file::get_pkg_version(
+            &format!(
+                "{}/{}",
+                conf_file.leptos_options.site_root,
+                conf_file.leptos_options.site_pkg_dir
+            )
+        )?
+    );
+    let mut routes_leptos_options = conf_file.leptos_options.clone();
+    routes_leptos_options.site_pkg_dir = format!(
+        "{}/{}",
+        routes_leptos_options.site_pkg_dir,
+        pkg_version.0
+    );
+
+    // Shared by all the workers, so invalidations reach every one of them
+    let page_cache = web::Data::new(
+        PageCache::init(&server_config.page_cache)
+    );
+
     let server = HttpServer::new(move || {
         let leptos_options = &conf_file.leptos_options;
-        let site_root = &leptos_options.site_root;
 
         actix_web::App
             ::new()
             .app_data(web::Data::new(startup_code.clone()))
             .app_data(web::Data::new(server_config.clone()))
             .app_data(web::Data::clone(&handle_server))
+            .app_data(web::Data::clone(&certificate_resolver_data))
+            .app_data(web::Data::clone(&db_pool))
+            .app_data(web::Data::new(fs_data_repository.clone()))
+            .app_data(web::Data::clone(&pkg_version))
+            .app_data(web::Data::clone(&page_cache))
             // wrap() and wrap_fn() execution order goes from last to first
             .wrap(IdentityMiddleware::default())
             .wrap(
@@ -252,26 +368,25 @@ async fn start_server_thread(
                         )
                     )
                     .cookie_content_security(CookieContentSecurity::Private)
+                    .cookie_name(session::SESSION_COOKIE_NAME.to_string())
                     .cookie_secure(true)
                     .build()
             )
-            .wrap_fn(|service_request, service| {
-                service.call(service_request).map(|result| {
-                    let mut service_response = result.unwrap();
-                    service_response
-                        .headers_mut()
-                        .insert(
-                            http::header::STRICT_TRANSPORT_SECURITY,
-                            http::header::HeaderValue::from_static(
-                                tls::HSTS_HEADER_VALUE
-                            )
-                        );
-                    Ok(service_response)
-                })
-            })
+            // Outside the session, so pages that set a cookie aren't cached,
+            // and inside the compression, so the cache stores plain HTML
+            .wrap(page_cache::PageCacheMiddleware::init(&page_cache))
+            .wrap(middleware::Compress::default())
+            .wrap(
+                security_headers::SecurityHeaders::init(
+                    &server_config.security_headers
+                )
+            )
+            // Only inside the HTTPS redirection, so the time of the other
+            // middlewares is measured too
+            .wrap(metrics::RequestMetrics::init())
             .wrap(
                 // TODO: Use official Actix solution when they have it.
-                RedirectHTTPS::with_replacements(
+                RedirectHTTPS::init(
                     &[
                         (
                             server_config.http_port
@@ -283,26 +398,50 @@ async fn start_server_thread(
                                 .to_string()
                                 .to_owned(),
                         ),
-                    ]
+                    ],
+                    tls_is_enabled
                 )
             )
+            // Outermost, so every log of the request (even the redirections)
+            // has its ID
+            .wrap(request_logger::RequestLogger::init())
+            .service(acme_challenge_handler::acme_challenge)
+            // Must be registered before the server functions' wildcard route
+            .service(backup_handler::export_backup)
+            .service(backup_handler::import_backup)
+            .service(content_handler::export_content)
+            .service(favicon_handler::upload_favicon)
+            .service(gallery_handler::upload_images)
+            .service(monitoring_handler::healthz)
+            .service(monitoring_handler::metrics)
+            .service(monitoring_handler::readyz)
             .route("/api/{tail:.*}", leptos_actix::handle_server_fns())
-            .service(Files::new("/pkg", format!("{site_root}/pkg")))
-            .service(Files::new("/assets", site_root))
+            .service(static_file_handler::pkg_file)
+            .service(static_file_handler::asset)
+            .service(Files::new("/uploads", &uploads_dir_path))
+            .service(theme_handler::asset)
+            .service(theme_handler::stylesheet)
             .service(favicon)
             .leptos_routes(
-                leptos_options.to_owned(),
+                routes_leptos_options.to_owned(),
                 routes.to_owned(),
                 || view! { <App/> }
             )
             .app_data(web::Data::new(leptos_options.to_owned()))
-        //.wrap(middleware::Compress::default())
     })
         .bind(&http_socket_addr)
-        .unwrap()
-        .bind_rustls(&https_socket_addr, rustls_config)
-        .unwrap()
-        .run();
+        .unwrap();
+    let server = match &certificate_resolver {
+        Some(certificate_resolver) =>
+            server
+                .bind_rustls(
+                    &https_socket_addr,
+                    tls::get_rustls_config(certificate_resolver)
+                )
+                .unwrap()
+                .run(),
+        None => server.run(),
+    };
 
     handle.replace(server.handle());
 
@@ -310,9 +449,26 @@ async fn start_server_thread(
 
     let mut child_threads: Vec<tokio::task::JoinHandle<()>> = Vec::new();
 
-    if production_mode_is_enabled {
-        let renewal_thread = tls::spawn_renewal_thread(handle.clone())?;
-        child_threads.push(renewal_thread);
+    if let Some(backup_thread) = backup_thread {
+        child_threads.push(backup_thread);
+    }
+
+    if let Some(certificate_resolver) = certificate_resolver {
+        match tls_mode {
+            ServerTlsMode::Acme => {
+                let renewal_thread = tls::spawn_renewal_thread(
+                    certificate_resolver
+                )?;
+                child_threads.push(renewal_thread);
+            }
+            ServerTlsMode::Manual => {
+                let reload_thread = tls::spawn_reload_thread(
+                    certificate_resolver
+                );
+                child_threads.push(reload_thread);
+            }
+            ServerTlsMode::Off | ServerTlsMode::SelfSigned => {}
+        }
     }
 
     Ok(TukosmoServer {
@@ -322,6 +478,29 @@ async fn start_server_thread(
     })
 }
 
+// A failure isn't fatal (e.g. the file may be read-only), since the file is
+// upgraded in memory every time it's read anyway
+fn upgrade_server_config_file(fs_data_repository: &FsDataRepository) {
+    let file_path = &fs_data_repository.tukosmo_toml_file_path;
+    match server_config::upgrade_server_config_file(file_path) {
+        Ok(Some(upgrade)) =>
+            log::info!(
+                "{} upgraded from version {} (the original file was kept in \
+                {})",
+                file_path,
+                upgrade.previous_version,
+                upgrade.backup_file_path
+            ),
+        Ok(None) => {}
+        Err(domain_error) =>
+            log::warn!(
+                "{} couldn't be upgraded: {}",
+                file_path,
+                domain_error.message
+            ),
+    }
+}
+
 impl<S> Transform<S, ServiceRequest>
     for RedirectHTTPS
     where
@@ -342,15 +521,37 @@ impl<S> Transform<S, ServiceRequest>
         ok(RedirectHTTPSService {
             service,
             replacements: self.replacements.clone(),
+            tls_is_enabled: self.tls_is_enabled,
         })
     }
 }
 
-#[actix_web::get("favicon.ico")]
+#[actix_web::get(
+    "/{file_name:favicon\\.ico|apple-touch-icon\\.png|icon-192\\.png|icon-512\\.png|manifest\\.webmanifest}"
+)]
 async fn favicon(
-    leptos_options: actix_web::web::Data<leptos::LeptosOptions>
+    path: web::Path<String>,
+    fs_data_repository: web::Data<FsDataRepository>,
+    leptos_options: web::Data<leptos::LeptosOptions>
 ) -> actix_web::Result<actix_files::NamedFile> {
-    let leptos_options = leptos_options.into_inner();
-    let site_root = &leptos_options.site_root;
-    Ok(actix_files::NamedFile::open(format!("{site_root}/favicon.ico"))?)
+    let file_name = path.into_inner();
+    let favicon_file_path = format!(
+        "{}/{}",
+        fs_data_repository.favicon_dir_path,
+        file_name
+    );
+
+    // Until a favicon is uploaded from the TAP, the one bundled with the site
+    // assets is used (the other icons don't exist yet, so they return 404)
+    let favicon_file_exists = file_system
+        ::check_file_exists(&favicon_file_path)
+        .unwrap_or(false);
+    if !favicon_file_exists && file_name == favicon_service::FAVICON_FILE_NAME {
+        let site_root = &leptos_options.site_root;
+        return Ok(
+            actix_files::NamedFile::open(format!("{site_root}/favicon.ico"))?
+        );
+    }
+
+    Ok(actix_files::NamedFile::open(favicon_file_path)?)
 }
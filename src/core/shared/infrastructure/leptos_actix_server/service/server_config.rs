@@ -0,0 +1,398 @@
+use std::fs;
+use std::io::Write;
+use toml::Table;
+use toml::Value;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::SERVER_CONFIG_VERSION;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerPlatform;
+use tukosmo_domain::core::shared::model::ServerTlsMode;
+
+use super::file_system;
+
+// Returned when the file was from an older version
+pub struct ServerConfigUpgrade {
+    pub backup_file_path: String,
+    pub previous_version: u32,
+}
+
+// Files written before config_version existed
+const UNVERSIONED_CONFIG_VERSION: u32 = 1;
+
+// Files from older versions are upgraded in memory (they're only saved by
+// upgrade_server_config_file()), and every problem of the file is reported at
+// once with its line, instead of stopping at the first one
+pub fn read_server_config(
+    file_path: &str
+) -> Result<ServerConfig, DomainError> {
+    let (server_config, _config_version) = parse_server_config(file_path)?;
+    Ok(server_config)
+}
+
+// The structs of the config reject unknown keys (deny_unknown_fields), but
+// deserializing stops at the first problem. So the key with the problem is
+// removed and the table is deserialized again, to report every problem at
+// once. A missing key can't be removed, so the search stops there
+fn deserialize_server_config(
+    file_path: &str,
+    toml_str: &str,
+    mut table: Table
+) -> Result<ServerConfig, Vec<String>> {
+    let mut problems = vec![];
+    let mut removed_keys: Vec<String> = vec![];
+
+    loop {
+        // Serialized again, since the table could have been upgraded, and the
+        // spans of the errors are only available when parsing a string
+        let table_str = toml::to_string(&table).map_err(|e| {
+            vec![format_problem(file_path, None, None, &e.to_string())]
+        })?;
+        let e = match toml::from_str::<ServerConfig>(&table_str) {
+            Ok(server_config) if problems.is_empty() =>
+                return Ok(server_config),
+            Ok(_) => return Err(problems),
+            Err(e) => e,
+        };
+        let byte_index = e.span().map_or(0, |span| span.start);
+
+        if let Some(name) = get_missing_field(e.message()) {
+            let section = table_str
+                .lines()
+                .nth(get_line(&table_str, byte_index) - 1)
+                .and_then(get_header);
+            let key = get_key_path(section, name);
+            if !removed_keys.contains(&key) {
+                let line = section.and_then(
+                    |section| find_key_line(toml_str, None, section)
+                );
+                problems.push(
+                    format_problem(file_path, line, Some(&key), "missing key")
+                );
+            }
+            return Err(problems);
+        }
+
+        let (section, name) = match find_key_at(&table_str, byte_index) {
+            Some(key) => key,
+            None => {
+                problems.push(
+                    format_problem(file_path, None, None, e.message())
+                );
+                return Err(problems);
+            }
+        };
+        let key = get_key_path(section, name);
+        problems.push(
+            format_problem(
+                file_path,
+                find_key_line(toml_str, section, name),
+                Some(&key),
+                e.message()
+            )
+        );
+
+        let section_table = match section {
+            Some(section) => match table.get_mut(section) {
+                Some(Value::Table(section_table)) => section_table,
+                _ => return Err(problems),
+            },
+            None => &mut table,
+        };
+        if section_table.remove(name).is_none() {
+            return Err(problems);
+        }
+        removed_keys.push(key);
+    }
+}
+
+// Section (None for the root) and name of the key at that position, or of
+// the section when it's a header
+fn find_key_at(
+    toml_str: &str,
+    byte_index: usize
+) -> Option<(Option<&str>, &str)> {
+    let line_index = get_line(toml_str, byte_index) - 1;
+    let mut current_section: Option<&str> = None;
+
+    for (index, line) in toml_str.lines().enumerate() {
+        let header = get_header(line);
+        if index == line_index {
+            return match header {
+                Some(header) => Some((None, header)),
+                None => line
+                    .split_once('=')
+                    .map(|(key, _value)| {
+                        (current_section, key.trim().trim_matches('"'))
+                    }),
+            };
+        }
+        if header.is_some() {
+            current_section = header;
+        }
+    }
+
+    None
+}
+
+// Line (starting at 1) of the key inside its section (None for the root),
+// or of the section header when the key is a table
+fn find_key_line(
+    toml_str: &str,
+    section: Option<&str>,
+    name: &str
+) -> Option<usize> {
+    let mut current_section: Option<&str> = None;
+
+    for (index, line) in toml_str.lines().enumerate() {
+        if let Some(header) = get_header(line) {
+            if section.is_none() && header == name {
+                return Some(index + 1);
+            }
+            current_section = Some(header);
+        } else if current_section == section {
+            if let Some((key, _value)) = line.split_once('=') {
+                if key.trim().trim_matches('"') == name {
+                    return Some(index + 1);
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn format_problem(
+    file_path: &str,
+    line: Option<usize>,
+    key: Option<&str>,
+    message: &str
+) -> String {
+    let location = match line {
+        Some(line) => format!("{}:{}", file_path, line),
+        None => file_path.to_string(),
+    };
+    match key {
+        Some(key) => format!("{}: {}: {}", location, key, message),
+        None => format!("{}: {}", location, message),
+    }
+}
+
+fn get_config_version(table: &Table) -> Result<u32, String> {
+    match table.get("config_version") {
+        Some(Value::Integer(version)) if *version >= 1 =>
+            u32::try_from(*version).map_err(|e| e.to_string()),
+        Some(_) => Err("must be a positive integer".to_string()),
+        None => Ok(UNVERSIONED_CONFIG_VERSION),
+    }
+}
+
+fn get_header(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if !line.starts_with('[') {
+        return None;
+    }
+    line
+        .trim_start_matches('[')
+        .split(']')
+        .next()
+        .map(|header| header.trim())
+}
+
+fn get_key_path(section: Option<&str>, name: &str) -> String {
+    match section {
+        Some(section) => format!("{}.{}", section, name),
+        None => name.to_string(),
+    }
+}
+
+fn get_line(toml_str: &str, byte_index: usize) -> usize {
+    let byte_index = byte_index.min(toml_str.len());
+    toml_str.as_bytes()[..byte_index]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count() + 1
+}
+
+// Serde reports a missing key as "missing field `name`"
+fn get_missing_field(message: &str) -> Option<&str> {
+    message
+        .strip_prefix("missing field `")
+        .and_then(|rest| rest.split('`').next())
+}
+
+// Version of the file before upgrading it, which is also validated
+pub fn get_server_config_file_version(
+    file_path: &str
+) -> Result<u32, DomainError> {
+    let (_server_config, config_version) = parse_server_config(file_path)?;
+    Ok(config_version)
+}
+
+// Also returns the version of the file (before upgrading it)
+fn parse_server_config(
+    file_path: &str
+) -> Result<(ServerConfig, u32), DomainError> {
+    let toml_str = file_system::read_file_as_string(file_path)?;
+
+    let mut table: Table = toml::from_str(&toml_str).map_err(|e| {
+        let line = e.span().map(|span| get_line(&toml_str, span.start));
+        error::invalid_server_config(
+            vec![format_problem(file_path, line, None, e.message())]
+        )
+    })?;
+
+    let config_version = get_config_version(&table).map_err(|message| {
+        let line = find_key_line(&toml_str, None, "config_version");
+        error::invalid_server_config(
+            vec![
+                format_problem(
+                    file_path,
+                    line,
+                    Some("config_version"),
+                    &message
+                )
+            ]
+        )
+    })?;
+    if config_version > SERVER_CONFIG_VERSION {
+        return Err(error::SERVER_CONFIG_IS_NEWER_THAN_BINARY);
+    }
+    if config_version < SERVER_CONFIG_VERSION {
+        upgrade_server_config(&mut table, config_version);
+    }
+
+    let server_config = deserialize_server_config(file_path, &toml_str, table)
+        .map_err(|problems| error::invalid_server_config(problems))?;
+
+    let mut problems = vec![];
+    for (key, domain_error) in server_config.validate() {
+        let (section, name) = match key.split_once('.') {
+            Some((section, name)) => (Some(section), name),
+            None => (None, key),
+        };
+        problems.push(
+            format_problem(
+                file_path,
+                find_key_line(&toml_str, section, name),
+                Some(key),
+                domain_error.message
+            )
+        );
+    }
+    if !problems.is_empty() {
+        return Err(error::invalid_server_config(problems));
+    }
+
+    Ok((server_config, config_version))
+}
+
+fn upgrade_server_config(table: &mut Table, config_version: u32) {
+    for version in config_version..SERVER_CONFIG_VERSION {
+        match version {
+            1 => upgrade_server_config_from_version_1(table),
+            2 => upgrade_server_config_from_version_2(table),
+            _ => {}
+        }
+    }
+    table.insert(
+        "config_version".to_string(),
+        Value::Integer(SERVER_CONFIG_VERSION as i64)
+    );
+}
+
+// Called once when the server starts (and by the migrate command), so the
+// upgrade is saved and reported only once. A copy of the original file is
+// kept
+pub fn upgrade_server_config_file(
+    file_path: &str
+) -> Result<Option<ServerConfigUpgrade>, DomainError> {
+    let (server_config, config_version) = parse_server_config(file_path)?;
+    if config_version == SERVER_CONFIG_VERSION {
+        return Ok(None);
+    }
+
+    let backup_file_path = format!("{}.v{}.bak", file_path, config_version);
+    fs::copy(file_path, &backup_file_path)
+        .map_err(|_e| error::CANNOT_WRITE_FILE)?;
+    write_server_config(file_path, &server_config)?;
+
+    Ok(Some(ServerConfigUpgrade {
+        backup_file_path,
+        previous_version: config_version,
+    }))
+}
+
+// Version 1 accepted any platform, and unknown ones were shown as Linux
+fn upgrade_server_config_from_version_1(table: &mut Table) {
+    if let Some(Value::String(platform)) = table.get("platform") {
+        let is_known_platform = Value::String(platform.clone())
+            .try_into::<ServerPlatform>()
+            .is_ok();
+        if !is_known_platform {
+            table.insert(
+                "platform".to_string(),
+                Value::String("linux".to_string())
+            );
+        }
+    }
+}
+
+// Version 2 had no [tls] section: the certificate was obtained with ACME in
+// production mode, and self-signed in development mode
+fn upgrade_server_config_from_version_2(table: &mut Table) {
+    if table.contains_key("tls") {
+        return;
+    }
+
+    let is_production_mode = matches!(
+        table.get("mode"),
+        Some(Value::String(mode)) if mode == "production"
+    );
+    let tls_mode = if is_production_mode {
+        ServerTlsMode::Acme
+    } else {
+        ServerTlsMode::SelfSigned
+    };
+
+    let mut tls_table = Table::new();
+    tls_table.insert(
+        "mode".to_string(),
+        Value::String(tls_mode.value().to_string())
+    );
+    table.insert("tls".to_string(), Value::Table(tls_table));
+}
+
+// The new file is fully written next to the current one before replacing it
+// (a rename is atomic), so a crash never leaves a half-written config. The
+// previous config is kept in a .bak file
+pub fn write_server_config(
+    file_path: &str,
+    server_config: &ServerConfig
+) -> Result<(), DomainError> {
+    let toml_str = toml
+        ::to_string(server_config)
+        .map_err(|_e| error::CANNOT_SERIALIZE_TOML)?;
+
+    let tmp_file_path = format!("{}.tmp", file_path);
+    let backup_file_path = format!("{}.bak", file_path);
+
+    let mut tmp_file = fs::File
+        ::create(&tmp_file_path)
+        .map_err(|_e| error::CANNOT_WRITE_FILE)?;
+    tmp_file
+        .write_all(toml_str.as_bytes())
+        .and_then(|()| tmp_file.sync_all())
+        .map_err(|_e| error::CANNOT_WRITE_FILE)?;
+
+    if file_system::check_file_exists(file_path)? {
+        fs::copy(file_path, &backup_file_path).map_err(
+            |_e| error::CANNOT_WRITE_FILE
+        )?;
+    }
+    fs::rename(&tmp_file_path, file_path).map_err(
+        |_e| error::CANNOT_WRITE_FILE
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use actix_identity::Identity;
+use actix_identity::IdentityExt;
+use actix_web::HttpRequest;
+use actix_web::cookie::Key;
+use leptos::use_context;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::repository::DataRepository;
+
+use super::super::repository::FsDataRepository;
+use super::csrf;
+
+// Requests without it come from anonymous visitors
+pub const SESSION_COOKIE_NAME: &'static str = "id";
+
+// For server functions that change the website or the server: like the
+// backup routes, they require a TAP session and reject requests from other
+// websites
+pub fn check_tap_change_request() -> Result<(), DomainError> {
+    let request = get_leptos_request()?;
+    check_tap_session(&request.get_identity().ok())?;
+    csrf::check_same_origin(&request)
+}
+
+// For server functions that only read (they're also called while rendering
+// the pages of the TAP, which aren't always requested from the TAP itself)
+pub fn check_tap_read_request() -> Result<(), DomainError> {
+    let request = get_leptos_request()?;
+    check_tap_session(&request.get_identity().ok())
+}
+
+// There are no user accounts yet, so nobody can sign in and the routes that
+// require it are only available from the CLI (e.g. the backups)
+pub fn check_tap_session(
+    identity: &Option<Identity>
+) -> Result<(), DomainError> {
+    match identity {
+        Some(identity) if identity.id().is_ok() => Ok(()),
+        _ => Err(error::TAP_SESSION_REQUIRED),
+    }
+}
+
+// Sessions are stored in private cookies, so they stay valid across restarts
+// as long as the key doesn't change
+pub fn get_cookie_key(
+    fs_data_repository: &FsDataRepository
+) -> Result<Key, DomainError> {
+    if let Some(session_key) = fs_data_repository.get_session_key()? {
+        if let Ok(cookie_key) = Key::try_from(session_key.as_slice()) {
+            return Ok(cookie_key);
+        }
+        log::warn!(
+            "The session key is invalid, so a new one will be generated"
+        );
+    }
+
+    reset_cookie_key(fs_data_repository)
+}
+
+// Every existing session cookie becomes unreadable with a new key
+pub fn reset_cookie_key(
+    fs_data_repository: &FsDataRepository
+) -> Result<Key, DomainError> {
+    let cookie_key = Key::generate();
+    fs_data_repository.write_session_key(cookie_key.master())?;
+    Ok(cookie_key)
+}
+
+// Leptos provides the Actix request to server functions
+fn get_leptos_request() -> Result<HttpRequest, DomainError> {
+    use_context::<HttpRequest>().ok_or(error::TAP_SESSION_REQUIRED)
+}
@@ -0,0 +1,175 @@
+use actix_files::NamedFile;
+use actix_web::HttpRequest;
+use actix_web::HttpResponse;
+use actix_web::http::header;
+use actix_web::http::header::ContentEncoding;
+use actix_web::http::header::HeaderValue;
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::Hasher;
+use std::path::Path;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use super::file_system;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum CachePolicy {
+    // For URLs that change with the content of the file
+    Immutable,
+    // The browser must check the ETag (or Last-Modified) before using its copy
+    Revalidate,
+}
+
+// Hash of the /pkg files (JS, WASM and CSS of the build), which is part of
+// the URLs of the scripts that hydrate the pages
+#[derive(Clone)]
+pub struct PkgVersion(pub String);
+
+const CACHE_CONTROL_IMMUTABLE: &'static str =
+    "public, max-age=31536000, immutable"; // 1 year
+const CACHE_CONTROL_REVALIDATE: &'static str = "no-cache";
+
+// In order of preference
+const PRECOMPRESSED_ENCODINGS: [(ContentEncoding, &'static str); 2] = [
+    (ContentEncoding::Brotli, "br"),
+    (ContentEncoding::Gzip, "gz"),
+];
+
+pub const PKG_VERSION_LENGTH: usize = 16;
+
+pub fn get_pkg_version(
+    pkg_dir_path: &str
+) -> Result<PkgVersion, DomainError> {
+    let mut hasher = DefaultHasher::new();
+    hash_directory(&mut hasher, Path::new(pkg_dir_path), "")?;
+
+    Ok(PkgVersion(format!("{:016x}", hasher.finish())))
+}
+
+pub fn is_pkg_version(value: &str) -> bool {
+    value.len() == PKG_VERSION_LENGTH &&
+        value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+// Relative paths from the URL can't leave the directory they're joined to
+pub fn is_safe_relative_path(path: &str) -> bool {
+    !path.is_empty() &&
+        !path.contains('\\') &&
+        path
+            .split('/')
+            .all(|segment| !segment.is_empty() && !segment.starts_with('.'))
+}
+
+// The .br or .gz file next to the requested one is sent instead, when the
+// browser accepts it (they can be generated when deploying, e.g. with
+// brotli -k). NamedFile answers the conditional requests with 304
+pub fn serve_file(
+    request: &HttpRequest,
+    file_path: &str,
+    cache_policy: CachePolicy
+) -> actix_web::Result<HttpResponse> {
+    let mut named_file = None;
+    for (content_encoding, extension) in PRECOMPRESSED_ENCODINGS {
+        if !accepts_encoding(request, content_encoding.as_str()) {
+            continue;
+        }
+
+        let precompressed_file_path = format!("{}.{}", file_path, extension);
+        let precompressed_file_exists = file_system
+            ::check_file_exists(&precompressed_file_path)
+            .unwrap_or(false);
+        if precompressed_file_exists {
+            let content_type = actix_files::file_extension_to_mime(
+                Path::new(file_path)
+                    .extension()
+                    .and_then(|extension| extension.to_str())
+                    .unwrap_or_default()
+            );
+            named_file = Some(
+                NamedFile::open(precompressed_file_path)?
+                    .disable_content_disposition()
+                    .set_content_encoding(content_encoding)
+                    .set_content_type(content_type)
+            );
+            break;
+        }
+    }
+    let named_file = match named_file {
+        Some(named_file) => named_file,
+        None => NamedFile::open(file_path)?,
+    };
+
+    let mut response = named_file.into_response(request);
+    let cache_control = match cache_policy {
+        CachePolicy::Immutable => CACHE_CONTROL_IMMUTABLE,
+        CachePolicy::Revalidate => CACHE_CONTROL_REVALIDATE,
+    };
+    let headers = response.headers_mut();
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(cache_control)
+    );
+    headers.insert(header::VARY, HeaderValue::from_static("accept-encoding"));
+
+    Ok(response)
+}
+
+fn accepts_encoding(request: &HttpRequest, encoding: &str) -> bool {
+    request
+        .headers()
+        .get_all(header::ACCEPT_ENCODING)
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|value| {
+            let mut parameters = value.split(';').map(|value| value.trim());
+            let name = parameters.next().unwrap_or_default();
+            // q=0 means "not acceptable"
+            let is_refused = parameters.any(|parameter| {
+                match parameter.strip_prefix("q=") {
+                    Some(quality) =>
+                        quality.parse::<f32>().map_or(false, |q| q == 0.0),
+                    None => false,
+                }
+            });
+            name.eq_ignore_ascii_case(encoding) && !is_refused
+        })
+}
+
+// Names and contents of every file, sorted, so the hash only changes when
+// the build does
+fn hash_directory(
+    hasher: &mut DefaultHasher,
+    directory_path: &Path,
+    relative_path: &str
+) -> Result<(), DomainError> {
+    let mut entries: Vec<fs::DirEntry> = fs
+        ::read_dir(directory_path)
+        .map_err(|_e| error::CANNOT_READ_PATH_FS_METADATA)?
+        .flatten()
+        .collect();
+    entries.sort_by_key(|entry| entry.file_name());
+
+    for entry in entries {
+        let entry_relative_path = format!(
+            "{}/{}",
+            relative_path,
+            entry.file_name().to_string_lossy()
+        );
+        let file_type = entry
+            .file_type()
+            .map_err(|_e| error::CANNOT_READ_PATH_FS_METADATA)?;
+
+        if file_type.is_dir() {
+            hash_directory(hasher, &entry.path(), &entry_relative_path)?;
+        } else if file_type.is_file() {
+            let content = fs
+                ::read(entry.path())
+                .map_err(|_e| error::CANNOT_READ_FILE)?;
+            hasher.write(entry_relative_path.as_bytes());
+            hasher.write(&content);
+        }
+    }
+
+    Ok(())
+}
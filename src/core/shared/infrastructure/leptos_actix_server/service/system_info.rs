@@ -0,0 +1,201 @@
+use chrono::DateTime;
+use chrono::Utc;
+use std::ffi::CString;
+use std::fs;
+use std::time::Duration;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+
+// Read from /proc, so only Linux servers are supported
+pub struct SystemInfo {
+    pub booted_at: DateTime<Utc>,
+    pub cpu_cores: u32,
+    pub cpu_model: String,
+    pub cpu_usage_percentage: f64,
+    pub disk_total_bytes: u64,
+    pub disk_used_bytes: u64,
+    pub kernel_version: String,
+    pub memory_total_bytes: u64,
+    pub memory_used_bytes: u64,
+    pub os_name: String,
+    pub uptime_seconds: u64,
+}
+
+struct CpuTimes {
+    busy: u64,
+    total: u64,
+}
+
+const BYTES_IN_A_KILOBYTE: u64 = 1024;
+
+// Time between the two readings of /proc/stat
+const CPU_USAGE_SAMPLE_IN_MILLISECONDS: u64 = 250;
+
+const DEFAULT_OS_NAME: &'static str = "Linux";
+
+// The disk is the one of the file system where the data dir is, since it's
+// where the uploads, backups and logs are stored
+pub async fn get_system_info(
+    data_dir_path: &str
+) -> Result<SystemInfo, DomainError> {
+    // /proc/stat only has the CPU time since boot, so the current usage is
+    // the difference between two readings
+    let first_cpu_times = read_cpu_times()?;
+    tokio::time::sleep(
+        Duration::from_millis(CPU_USAGE_SAMPLE_IN_MILLISECONDS)
+    ).await;
+    let second_cpu_times = read_cpu_times()?;
+    let busy_delta = second_cpu_times.busy.saturating_sub(
+        first_cpu_times.busy
+    );
+    let total_delta = second_cpu_times.total.saturating_sub(
+        first_cpu_times.total
+    );
+    let cpu_usage_percentage = if total_delta == 0 {
+        0.0
+    } else {
+        busy_delta as f64 * 100.0 / total_delta as f64
+    };
+
+    let (cpu_cores, cpu_model) = read_cpu_model()?;
+    let (disk_total_bytes, disk_used_bytes) = read_disk_usage(data_dir_path)?;
+    let (memory_total_bytes, memory_used_bytes) = read_memory_usage()?;
+    let uptime_seconds = read_uptime_seconds()?;
+    let booted_at =
+        Utc::now() - chrono::Duration::seconds(uptime_seconds as i64);
+
+    Ok(SystemInfo {
+        booted_at,
+        cpu_cores,
+        cpu_model,
+        cpu_usage_percentage,
+        disk_total_bytes,
+        disk_used_bytes,
+        kernel_version: read_proc_file("/proc/sys/kernel/osrelease")?
+            .trim()
+            .to_string(),
+        memory_total_bytes,
+        memory_used_bytes,
+        os_name: read_os_name(),
+        uptime_seconds,
+    })
+}
+
+// Logical cores, and the model of the first one
+fn read_cpu_model() -> Result<(u32, String), DomainError> {
+    let cpuinfo = read_proc_file("/proc/cpuinfo")?;
+
+    let mut cpu_cores = 0;
+    let mut cpu_model = None;
+    for line in cpuinfo.lines() {
+        let (key, value) = match line.split_once(':') {
+            Some((key, value)) => (key.trim(), value.trim()),
+            None => continue,
+        };
+        match key {
+            "processor" => cpu_cores += 1,
+            // "Hardware" is the only model some ARM boards have
+            "model name" | "Hardware" if cpu_model.is_none() =>
+                cpu_model = Some(value.to_string()),
+            _ => {}
+        }
+    }
+
+    Ok((cpu_cores, cpu_model.unwrap_or_default()))
+}
+
+// The aggregated "cpu" line: user, nice, system, idle, iowait, irq, softirq
+// and steal (guest times are already included in user and nice)
+fn read_cpu_times() -> Result<CpuTimes, DomainError> {
+    let stat = read_proc_file("/proc/stat")?;
+    let times: Vec<u64> = stat
+        .lines()
+        .find(|line| line.starts_with("cpu "))
+        .ok_or(error::CANNOT_READ_SYSTEM_INFO)?
+        .split_whitespace()
+        .skip(1)
+        .take(8)
+        .map(|value| value.parse::<u64>())
+        .collect::<Result<_, _>>()
+        .map_err(|_e| error::CANNOT_READ_SYSTEM_INFO)?;
+    if times.len() < 5 {
+        return Err(error::CANNOT_READ_SYSTEM_INFO);
+    }
+
+    let total: u64 = times.iter().sum();
+    let idle = times[3] + times[4];
+
+    Ok(CpuTimes {
+        busy: total.saturating_sub(idle),
+        total,
+    })
+}
+
+// Used space is counted like df does, so the blocks reserved for root are
+// neither used nor available
+fn read_disk_usage(path: &str) -> Result<(u64, u64), DomainError> {
+    let c_path = CString::new(path)
+        .map_err(|_e| error::CANNOT_READ_SYSTEM_INFO)?;
+    // Safe, since the path is a valid C string and stat is only written by
+    // statvfs
+    let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::statvfs(c_path.as_ptr(), &mut stat) };
+    if result != 0 {
+        return Err(error::CANNOT_READ_SYSTEM_INFO);
+    }
+
+    let block_size = stat.f_frsize as u64;
+    let total_bytes = stat.f_blocks as u64 * block_size;
+    let free_bytes = stat.f_bfree as u64 * block_size;
+
+    Ok((total_bytes, total_bytes.saturating_sub(free_bytes)))
+}
+
+// Memory used by the processes: the available memory (free, and caches that
+// can be dropped) isn't counted
+fn read_memory_usage() -> Result<(u64, u64), DomainError> {
+    let meminfo = read_proc_file("/proc/meminfo")?;
+    let read_value = |key: &str| -> Result<u64, DomainError> {
+        meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix(key))
+            .and_then(|value| {
+                value.trim().trim_end_matches("kB").trim().parse::<u64>().ok()
+            })
+            .map(|kilobytes| kilobytes * BYTES_IN_A_KILOBYTE)
+            .ok_or(error::CANNOT_READ_SYSTEM_INFO)
+    };
+
+    let total_bytes = read_value("MemTotal:")?;
+    let available_bytes = read_value("MemAvailable:")?;
+
+    Ok((total_bytes, total_bytes.saturating_sub(available_bytes)))
+}
+
+// Not every system has /etc/os-release, so it isn't an error
+fn read_os_name() -> String {
+    fs::read_to_string("/etc/os-release")
+        .ok()
+        .and_then(|os_release| {
+            os_release.lines().find_map(|line| {
+                line.strip_prefix("PRETTY_NAME=")
+                    .map(|value| value.trim_matches('"').to_string())
+            })
+        })
+        .filter(|os_name| !os_name.is_empty())
+        .unwrap_or_else(|| DEFAULT_OS_NAME.to_string())
+}
+
+fn read_proc_file(file_path: &str) -> Result<String, DomainError> {
+    fs::read_to_string(file_path).map_err(|_e| error::CANNOT_READ_SYSTEM_INFO)
+}
+
+fn read_uptime_seconds() -> Result<u64, DomainError> {
+    let uptime = read_proc_file("/proc/uptime")?;
+    uptime
+        .split_whitespace()
+        .next()
+        .and_then(|value| value.parse::<f64>().ok())
+        .map(|seconds| seconds as u64)
+        .ok_or(error::CANNOT_READ_SYSTEM_INFO)
+}
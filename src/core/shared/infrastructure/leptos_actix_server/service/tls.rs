@@ -1,99 +1,358 @@
+use acme_micro::Account;
 use acme_micro::Directory;
 use acme_micro::DirectoryUrl;
 use acme_micro::create_p384_key;
+use actix_web::HttpRequest;
+use actix_web::web;
+use chrono::DateTime;
+use chrono::Utc;
+use futures::FutureExt;
+use leptos::use_context;
 use openssl::asn1::Asn1Time;
-use openssl::asn1::Asn1TimeRef;
 use openssl::x509::X509;
+use openssl_probe;
 use rcgen;
-use reqwest;
 use rustls;
+use rustls::server::ClientHello;
+use rustls::server::ResolvesServerCert;
+use rustls::sign;
+use rustls::sign::CertifiedKey;
 use rustls_pemfile;
-use std::convert::TryInto;
+use std::env;
+use std::fs;
 use std::io::BufReader;
+use std::panic::AssertUnwindSafe;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::RwLock;
 use std::time::Duration;
 use tokio;
 use tukosmo_domain::core::shared::error;
 use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerTlsMode;
+use tukosmo_domain::core::shared::model::TlsRenewalStatus;
 use tukosmo_domain::core::shared::repository::DataRepository;
 
 use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
-use crate::core::shared::leptos_actix_server::service::server::Handle;
-use crate::core::shared::leptos_actix_server::service::server::start_acme_challenge_server;
+use super::email;
+use super::file_system;
 
-pub const HSTS_HEADER_VALUE: &'static str = "max-age=63072000"; // 2 years
-const INTERMEDIATE_CERTIFICATE_LETS_ENCRYPT_URL: &'static str =
-    "https://letsencrypt.org/certs/lets-encrypt-r3.pem";
+struct AcmeCertificate {
+    cert_chain: String,
+    cert_pkey: String,
+}
+
+// Shared by all the workers (cloning it doesn't clone the certificate), so
+// reloading it changes the certificate of new TLS connections
+#[derive(Clone)]
+pub struct TlsCertificateResolver {
+    certified_key: Arc<RwLock<Arc<CertifiedKey>>>,
+}
+
+pub struct TlsCertificateStatus {
+    pub days_until_expiration: i32,
+    pub expires_at: String,
+    pub is_self_signed: bool,
+    pub issued_at: String,
+    pub issuer: String,
+    pub needs_renewal: bool,
+    pub subject_alt_names: Vec<String>,
+}
+
+// Requests from the TAP only make one attempt, so they don't wait for the
+// retries (a failed attempt is saved in the renewal status anyway)
+pub const ACME_MAX_ATTEMPTS: u32 = 3;
+pub const ACME_MAX_ATTEMPTS_FROM_TAP: u32 = 1;
+const MAX_SECONDS_UNTIL_EXPIRATION_WARNING: i64 = 1209600; // 14 days
 const MILLISECONDS_UNTIL_ACME_CHALLENGE_VALIDATION: u64 = 5000;
 const MILLISECONDS_UNTIL_ACME_CSR_VALIDATION: u64 = 5000;
-const MIN_SECONDS_WITHOUT_RENEWAL: i32 = 2592000; // 30 days
+const MIN_SECONDS_WITHOUT_RENEWAL: i64 = 2592000; // 30 days
+const SECONDS_BETWEEN_EXPIRATION_WARNINGS: i64 = 86400; // 1 day
+const SECONDS_BETWEEN_MANUAL_CERTIFICATE_RELOADS: u64 = 3600; // 1 hour
+const SECONDS_UNTIL_FIRST_ACME_RETRY: u64 = 30;
+const SECONDS_UNTIL_RENEWAL_RETRY: u64 = 3600; // 1 hour
+const SSL_CERT_FILE_ENV_VAR: &'static str = "SSL_CERT_FILE";
+
+static ACME_ROOT_CA_IS_TRUSTED: Mutex<bool> = Mutex::new(false);
+
+impl ResolvesServerCert for TlsCertificateResolver {
+    fn resolve(&self, _client_hello: ClientHello) -> Option<Arc<CertifiedKey>> {
+        // A poisoned lock still holds a valid certificate
+        let certified_key = self.certified_key
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Some(Arc::clone(&certified_key))
+    }
+}
+
+impl TlsCertificateResolver {
+    // Connections already established keep the previous certificate
+    pub fn reload(&self) -> Result<(), DomainError> {
+        let fs_data_repository = FsDataRepository::init()?;
+        let server_config = fs_data_repository.get_server_config()?;
+        let certified_key = load_certified_key(
+            &fs_data_repository,
+            &server_config
+        )?;
+
+        let mut current_certified_key = self.certified_key
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        *current_certified_key = Arc::new(certified_key);
+
+        Ok(())
+    }
+}
+
+// In production mode, a self-signed certificate is the temporary one used
+// until the first certificate is obtained
+fn cert_is_self_signed(cert: &X509) -> Result<bool, DomainError> {
+    let issuer_der = cert
+        .issuer_name()
+        .to_der()
+        .map_err(|_e| error::CANNOT_PARSE_TLS_CERTIFICATE)?;
+    let subject_der = cert
+        .subject_name()
+        .to_der()
+        .map_err(|_e| error::CANNOT_PARSE_TLS_CERTIFICATE)?;
+
+    Ok(issuer_der == subject_der)
+}
 
 fn cert_needs_renewal(cert: &X509) -> Result<bool, DomainError> {
     let server_config = FsDataRepository::init()?.get_server_config()?;
-    if server_config.has_development_mode() {
+    if server_config.tls.mode != ServerTlsMode::Acme {
         return Ok(false);
     }
-    let expiration_time = cert.not_after();
-    let current_time = Asn1Time::days_from_now(0).unwrap();
 
-    let has_expired = expiration_time < current_time;
+    let seconds_until_renewal = get_seconds_until_renewal(cert)?;
 
-    let time_difference = expiration_time.diff(&current_time).unwrap();
-    let seconds_diff = time_difference.secs.abs();
-    let diff_less_than_wanted = seconds_diff < MIN_SECONDS_WITHOUT_RENEWAL;
+    Ok(seconds_until_renewal == 0)
+}
 
-    let needs_renewal = has_expired || diff_less_than_wanted;
+// With ACME, the current certificate is only replaced when the new one has
+// been obtained. There's nothing to generate in the manual and off modes
+pub async fn generate_new_certificate(
+    max_acme_attempts: u32
+) -> Result<(), DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
 
-    Ok(needs_renewal)
+    let server_config = fs_data_repository.get_server_config()?;
+    match server_config.tls.mode {
+        ServerTlsMode::Acme =>
+            obtain_lets_encrypt_certificate(max_acme_attempts).await?,
+        ServerTlsMode::SelfSigned => {
+            fs_data_repository.clean_certs_dir()?;
+            obtain_development_certificate().await?;
+        }
+        ServerTlsMode::Manual | ServerTlsMode::Off => {}
+    }
+
+    Ok(())
 }
 
-async fn generate_new_certificate() -> Result<(), DomainError> {
+pub fn get_certificate_status() -> Result<TlsCertificateStatus, DomainError> {
     let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
 
-    fs_data_repository.clean_certs_dir()?;
+    // The first certificate of the manual chain is the one of the server
+    let cert_bytes = match server_config.tls.mode {
+        ServerTlsMode::Acme | ServerTlsMode::SelfSigned =>
+            fs_data_repository.get_tls_certificate_file_bytes()?,
+        ServerTlsMode::Manual =>
+            file_system::read_file_as_vec_u8(
+                &get_manual_certificate_file_path(
+                    &server_config.tls.cert_chain_file
+                )?
+            )?,
+        ServerTlsMode::Off => {
+            return Err(error::TLS_IS_DISABLED);
+        }
+    };
+    let cert = X509::from_pem(&cert_bytes).map_err(
+        |_e| error::CANNOT_PARSE_TLS_CERTIFICATE
+    )?;
 
-    let server_config = fs_data_repository.get_server_config()?;
-    if server_config.has_production_mode() {
-        obtain_lets_encrypt_certificate().await?;
-    } else {
-        obtain_development_certificate().await?;
+    let expiration_time = cert.not_after();
+    let current_time = Asn1Time::days_from_now(0).map_err(
+        |_e| error::CANNOT_PARSE_TLS_CERTIFICATE
+    )?;
+    let days_until_expiration = current_time
+        .diff(expiration_time)
+        .map_err(|_e| error::CANNOT_PARSE_TLS_CERTIFICATE)?.days;
+
+    // E.g. "C=US, O=Let's Encrypt, CN=R11"
+    let issuer = cert
+        .issuer_name()
+        .entries()
+        .filter_map(|entry| {
+            let name = entry.object().nid().short_name().ok()?;
+            let value = entry.data().as_utf8().ok()?;
+            Some(format!("{}={}", name, value))
+        })
+        .collect::<Vec<String>>()
+        .join(", ");
+    let subject_alt_names = cert
+        .subject_alt_names()
+        .map(|names| {
+            names
+                .iter()
+                .filter_map(|name| name.dnsname().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(TlsCertificateStatus {
+        days_until_expiration,
+        expires_at: expiration_time.to_string(),
+        is_self_signed: cert_is_self_signed(&cert)?,
+        issued_at: cert.not_before().to_string(),
+        issuer,
+        needs_renewal: cert_needs_renewal(&cert)?,
+        subject_alt_names,
+    })
+}
+
+// Validated when Tukosmo.toml is read, so it's only None with another mode
+fn get_manual_certificate_file_path(
+    file_path: &Option<String>
+) -> Result<String, DomainError> {
+    file_path.clone().ok_or(error::MANUAL_TLS_MODE_REQUIRES_CERTIFICATE_FILES)
+}
+
+pub fn get_rustls_config(
+    certificate_resolver: &TlsCertificateResolver
+) -> rustls::ServerConfig {
+    rustls::ServerConfig
+        ::builder()
+        .with_safe_defaults()
+        .with_no_client_auth()
+        .with_cert_resolver(Arc::new(certificate_resolver.clone()))
+}
+
+// Negative when the certificate has already expired
+fn get_seconds_until_expiration(cert: &X509) -> Result<i64, DomainError> {
+    let current_time = Asn1Time::days_from_now(0).map_err(
+        |_e| error::CANNOT_PARSE_TLS_CERTIFICATE
+    )?;
+    let time_difference = current_time
+        .diff(cert.not_after())
+        .map_err(|_e| error::CANNOT_PARSE_TLS_CERTIFICATE)?;
+
+    Ok((time_difference.days as i64) * 86400 + (time_difference.secs as i64))
+}
+
+// Only meaningful in production mode (development certificates are never
+// renewed)
+fn get_seconds_until_renewal(cert: &X509) -> Result<u64, DomainError> {
+    if cert_is_self_signed(cert)? {
+        return Ok(0);
     }
 
-    Ok(())
+    let seconds_until_renewal =
+        get_seconds_until_expiration(cert)? - MIN_SECONDS_WITHOUT_RENEWAL;
+
+    Ok(seconds_until_renewal.max(0) as u64)
 }
 
-pub async fn load_rustls_config() -> Result<rustls::ServerConfig, DomainError> {
+fn get_seconds_until_renewal_of_current_cert() -> Result<u64, DomainError> {
     let fs_data_repository = FsDataRepository::init()?;
 
-    let cert_does_not_exist =
-        !fs_data_repository.check_tls_certificate_file_exists()?;
-    if cert_does_not_exist {
-        generate_new_certificate().await?;
-    } else {
-        let needs_renewal = match
-            fs_data_repository.get_tls_certificate_file_bytes()
-        {
-            Ok(cert_bytes) => {
-                let cert = X509::from_pem(&cert_bytes).map_err(
-                    |_e| error::CANNOT_PARSE_TLS_CERTIFICATE
-                )?;
-                cert_needs_renewal(&cert)?
+    let cert_bytes = fs_data_repository.get_tls_certificate_file_bytes()?;
+    let cert = X509::from_pem(&cert_bytes).map_err(
+        |_e| error::CANNOT_PARSE_TLS_CERTIFICATE
+    )?;
+
+    get_seconds_until_renewal(&cert)
+}
+
+// The account key is kept in the data dir, so every order (and the rate
+// limits of the CA) belongs to the same account
+fn load_acme_account(
+    fs_data_repository: &FsDataRepository,
+    directory: &Directory,
+    admin_email: &str
+) -> Result<Account, DomainError> {
+    let contact = vec![format!("mailto:{}", admin_email)];
+
+    match fs_data_repository.get_acme_account_key()? {
+        Some(account_key) =>
+            directory
+                .load_account(&account_key, contact)
+                .map_err(|_e| error::CANNOT_CREATE_ACME_ACCOUNT),
+        None => {
+            let account = directory
+                .register_account(contact)
+                .map_err(|_e| error::CANNOT_CREATE_ACME_ACCOUNT)?;
+            let account_key = account
+                .acme_private_key_pem()
+                .map_err(|_e| error::CANNOT_CREATE_ACME_ACCOUNT)?;
+            fs_data_repository.write_acme_account_key(&account_key)?;
+            Ok(account)
+        }
+    }
+}
+
+// ACME challenges are served by the running server, so a certificate can't
+// be obtained before it starts. Without one, a self-signed certificate is used
+// until the renewal thread obtains it (in the ACME mode)
+pub async fn load_certificate_resolver(
+) -> Result<TlsCertificateResolver, DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
+
+    match server_config.tls.mode {
+        ServerTlsMode::Acme | ServerTlsMode::SelfSigned => {
+            let cert_does_not_exist =
+                !fs_data_repository.check_tls_certificate_file_exists()?;
+            if cert_does_not_exist {
+                fs_data_repository.clean_certs_dir()?;
+                obtain_development_certificate().await?;
             }
-            Err(_) => true, // TODO: Fix error doesn't imply needs renewal
-        };
-        if needs_renewal {
-            generate_new_certificate().await?;
+        }
+        ServerTlsMode::Manual => {}
+        ServerTlsMode::Off => {
+            return Err(error::TLS_IS_DISABLED);
         }
     }
 
-    let rustls_config = rustls::ServerConfig
-        ::builder()
-        .with_safe_defaults()
-        .with_no_client_auth();
+    let certified_key = load_certified_key(
+        &fs_data_repository,
+        &server_config
+    )?;
 
-    let cert_chain_file = fs_data_repository.get_tls_certificate_chain_file()?;
-    let cert_chain_file_buffer = &mut BufReader::new(cert_chain_file);
+    Ok(TlsCertificateResolver {
+        certified_key: Arc::new(RwLock::new(Arc::new(certified_key))),
+    })
+}
 
-    let cert_pkey_file = fs_data_repository.get_tls_certificate_pkey_file()?;
+fn load_certified_key(
+    fs_data_repository: &FsDataRepository,
+    server_config: &ServerConfig
+) -> Result<CertifiedKey, DomainError> {
+    let (cert_chain_file, cert_pkey_file): (fs::File, fs::File) =
+        match server_config.tls.mode {
+            ServerTlsMode::Manual =>
+                (
+                    file_system::read_file(
+                        &get_manual_certificate_file_path(
+                            &server_config.tls.cert_chain_file
+                        )?
+                    )?,
+                    file_system::read_file(
+                        &get_manual_certificate_file_path(
+                            &server_config.tls.cert_pkey_file
+                        )?
+                    )?,
+                ),
+            _ =>
+                (
+                    fs_data_repository.get_tls_certificate_chain_file()?,
+                    fs_data_repository.get_tls_certificate_pkey_file()?,
+                ),
+        };
+    let cert_chain_file_buffer = &mut BufReader::new(cert_chain_file);
     let cert_pkey_file_buffer = &mut BufReader::new(cert_pkey_file);
 
     let cert_chain = rustls_pemfile
@@ -111,11 +370,14 @@ pub async fn load_rustls_config() -> Result<rustls::ServerConfig, DomainError> {
         .collect();
 
     if pkeys.is_empty() {
-        eprintln!("Could not locate PKCS 8 private keys.");
-        std::process::exit(1);
+        return Err(error::CANNOT_PARSE_TLS_CERTIFICATE_PKEY);
     }
 
-    Ok(rustls_config.with_single_cert(cert_chain, pkeys.remove(0)).unwrap())
+    let signing_key = sign
+        ::any_supported_type(&pkeys.remove(0))
+        .map_err(|_e| error::CANNOT_PARSE_TLS_CERTIFICATE_PKEY)?;
+
+    Ok(CertifiedKey::new(cert_chain, signing_key))
 }
 
 async fn obtain_development_certificate() -> Result<(), DomainError> {
@@ -141,131 +403,470 @@ async fn obtain_development_certificate() -> Result<(), DomainError> {
     Ok(())
 }
 
-async fn obtain_lets_encrypt_certificate() -> Result<(), DomainError> {
+async fn obtain_lets_encrypt_certificate(
+    max_attempts: u32
+) -> Result<(), DomainError> {
     let fs_data_repository = FsDataRepository::init()?;
     let server_config = fs_data_repository.get_server_config()?;
 
+    // The challenges are served from this directory by the running server,
+    // or by the temporary one of the "cert renew" command (see
+    // acme_challenge_handler), so port 80 of every domain must reach the HTTP
+    // port of Tukosmo
     fs_data_repository.create_new_acme_challenge_dir()?;
 
-    let server = start_acme_challenge_server(&server_config.domain)?;
-
-    let url = if server_config.has_production_mode() {
-        DirectoryUrl::LetsEncrypt
-    } else {
-        DirectoryUrl::LetsEncryptStaging
+    // The ACME client is blocking, so it doesn't run on the Actix runtime
+    // (a panic inside it is returned as an error too)
+    let mut attempt = 1;
+    let mut seconds_until_retry = SECONDS_UNTIL_FIRST_ACME_RETRY;
+    let result = loop {
+        let fs_data_repository = fs_data_repository.clone();
+        let server_config = server_config.clone();
+        let result = web
+            ::block(move || {
+                order_acme_certificate(&fs_data_repository, &server_config)
+            }).await
+            .unwrap_or(Err(error::CANNOT_GENERATE_TLS_CERTIFICATE));
+
+        match result {
+            Err(domain_error) if attempt < max_attempts => {
+                log::warn!(
+                    "Cannot obtain the TLS certificate (attempt {} of {}): {} \
+                    Retrying in {} seconds...",
+                    attempt,
+                    max_attempts,
+                    domain_error.message,
+                    seconds_until_retry
+                );
+                tokio::time::sleep(
+                    Duration::from_secs(seconds_until_retry)
+                ).await;
+                attempt += 1;
+                seconds_until_retry *= 2;
+            }
+            result => {
+                break result;
+            }
+        }
     };
-    let dir = Directory::from_url(url).unwrap();
-    let user_email_mailto: String = "mailto:{email}".replace(
-        "{email}",
+
+    fs_data_repository.remove_acme_challenge_dir()?;
+    let acme_certificate = result?;
+
+    let (cert_str, intermediate_cert_str) = split_certificate_chain(
+        &acme_certificate.cert_chain
+    )?;
+
+    fs_data_repository.clean_certs_dir()?;
+    fs_data_repository.write_tls_certificate_pkey_file(
+        &acme_certificate.cert_pkey
+    )?;
+    fs_data_repository.write_tls_certificate_file(&cert_str)?;
+    fs_data_repository.write_tls_intermediate_certificate_file(
+        &intermediate_cert_str
+    )?;
+    fs_data_repository.write_tls_certificate_chain_file(
+        &acme_certificate.cert_chain
+    )?;
+
+    Ok(())
+}
+
+fn order_acme_certificate(
+    fs_data_repository: &FsDataRepository,
+    server_config: &ServerConfig
+) -> Result<AcmeCertificate, DomainError> {
+    trust_acme_root_ca(fs_data_repository, server_config)?;
+
+    let directory_url = DirectoryUrl::Other(&server_config.acme.directory_url);
+    let directory = Directory::from_url(directory_url).map_err(
+        |_e| error::CANNOT_CONNECT_TO_ACME_DIRECTORY
+    )?;
+    let account = load_acme_account(
+        fs_data_repository,
+        &directory,
         &server_config.admin_email
-    );
-    let contact = vec![user_email_mailto];
-    let account = dir.register_account(contact.clone()).unwrap();
-    let account_pkey = account.acme_private_key_pem().unwrap();
-    let loaded_account = dir.load_account(&account_pkey, contact).unwrap();
-    let mut new_order = loaded_account
-        .new_order(&server_config.domain, &[])
-        .unwrap();
-
-    let csr_order = loop {
-        if let Some(csr_order) = new_order.confirm_validations() {
-            break csr_order;
-        }
+    )?;
 
-        let auths = new_order.authorizations().unwrap();
-        let challenge = auths[0].http_challenge().unwrap();
+    let certificate_domains = server_config.get_certificate_domains();
+    let alt_names: Vec<&str> = certificate_domains[1..]
+        .iter()
+        .map(|domain| domain.as_str())
+        .collect();
+    let mut new_order = account
+        .new_order(&certificate_domains[0], &alt_names)
+        .map_err(|_e| error::CANNOT_CREATE_ACME_ORDER)?;
+
+    let mut csr_order = new_order.confirm_validations();
+    if csr_order.is_none() {
+        let auths = new_order
+            .authorizations()
+            .map_err(|_e| error::CANNOT_CREATE_ACME_ORDER)?;
 
         // For HTTP, the challenge is a text file that needs to be accessible
-        // over the web for the domain we are trying to get a certificate for:
+        // over the web for every domain of the certificate:
         // http://mydomain.io/.well-known/acme-challenge/<token>
         // (the token is the filename and the proof is the content of the file)
-        let token = challenge.http_token();
-        let proof = challenge.http_proof().unwrap();
-        fs_data_repository.write_acme_challenge_token_file(token, &proof)?;
-
-        challenge
-            .validate(
-                Duration::from_millis(
-                    MILLISECONDS_UNTIL_ACME_CHALLENGE_VALIDATION
+        let mut challenges = vec![];
+        for auth in auths.iter().filter(|auth| auth.need_challenge()) {
+            let challenge = auth
+                .http_challenge()
+                .ok_or(error::CANNOT_VALIDATE_ACME_CHALLENGE)?;
+            let proof = challenge
+                .http_proof()
+                .map_err(|_e| error::CANNOT_VALIDATE_ACME_CHALLENGE)?;
+            fs_data_repository.write_acme_challenge_token_file(
+                challenge.http_token(),
+                &proof
+            )?;
+            challenges.push(challenge);
+        }
+
+        for challenge in challenges {
+            challenge
+                .validate(
+                    Duration::from_millis(
+                        MILLISECONDS_UNTIL_ACME_CHALLENGE_VALIDATION
+                    )
                 )
-            )
-            .unwrap();
+                .map_err(|_e| error::CANNOT_VALIDATE_ACME_CHALLENGE)?;
+        }
 
-        new_order.refresh().unwrap();
-    };
+        new_order
+            .refresh()
+            .map_err(|_e| error::CANNOT_VALIDATE_ACME_CHALLENGE)?;
+        csr_order = new_order.confirm_validations();
+    }
+    let csr_order = csr_order.ok_or(error::CANNOT_VALIDATE_ACME_CHALLENGE)?;
 
-    let cert_pkey = create_p384_key().unwrap();
+    let cert_pkey = create_p384_key().map_err(
+        |_e| error::CANNOT_GENERATE_TLS_CERTIFICATE
+    )?;
     let cert_order = csr_order
         .finalize_pkey(
             cert_pkey,
             Duration::from_millis(MILLISECONDS_UNTIL_ACME_CSR_VALIDATION)
         )
-        .unwrap();
-    let cert = cert_order.download_cert().unwrap();
-
-    server.handle.stop(true).await;
-    match server.thread.await {
-        Ok(_) => {}
-        Err(e) => {
-            panic!("SERVER ERROR: {}", e);
-        }
+        .map_err(|_e| error::CANNOT_FINALIZE_ACME_ORDER)?;
+    let cert = cert_order
+        .download_cert()
+        .map_err(|_e| error::CANNOT_DOWNLOAD_TLS_CERTIFICATE)?;
+
+    Ok(AcmeCertificate {
+        cert_chain: cert.certificate().to_string(),
+        cert_pkey: cert.private_key().to_string(),
+    })
+}
+
+// The certificate resolver is registered as app data in start_server_thread()
+// (None when TLS is off), and Leptos provides the Actix request to server
+// functions
+pub fn reload_certificate_of_running_server() -> Result<(), DomainError> {
+    let request = use_context::<HttpRequest>()
+        .ok_or(error::CANNOT_RELOAD_TLS_CERTIFICATE)?;
+    let certificate_resolver = request
+        .app_data::<web::Data<Option<TlsCertificateResolver>>>()
+        .and_then(|certificate_resolver| certificate_resolver.get_ref().clone())
+        .ok_or(error::CANNOT_RELOAD_TLS_CERTIFICATE)?;
+
+    certificate_resolver
+        .reload()
+        .map_err(|_e| error::CANNOT_RELOAD_TLS_CERTIFICATE)
+}
+
+// The running server keeps the previous certificate until it restarts, or
+// until the certificate resolver is reloaded. Every attempt is saved in the
+// renewal status
+pub async fn renew_certificate(
+    max_acme_attempts: u32
+) -> Result<(), DomainError> {
+    let server_config = FsDataRepository::init()?.get_server_config()?;
+    if !server_config.tls.mode.is_managed() {
+        return Err(error::TLS_CERTIFICATE_IS_NOT_MANAGED_BY_TUKOSMO);
     }
 
-    fs_data_repository.remove_acme_challenge_dir()?;
+    // The ACME client may panic, which is also a failed attempt
+    let result = AssertUnwindSafe(generate_new_certificate(max_acme_attempts))
+        .catch_unwind()
+        .await
+        .unwrap_or(Err(error::CANNOT_GENERATE_TLS_CERTIFICATE));
+
+    if let Err(domain_error) = save_renewal_attempt(&result) {
+        log::error!(
+            "Cannot save the TLS renewal status: {}",
+            domain_error.message
+        );
+    }
 
-    let intermediate_cert_str = reqwest::blocking
-        ::get(INTERMEDIATE_CERTIFICATE_LETS_ENCRYPT_URL)
-        .unwrap()
-        .text()
-        .unwrap();
+    result
+}
 
-    fs_data_repository.write_tls_certificate_pkey_file(&cert.private_key())?;
-    fs_data_repository.write_tls_certificate_file(&cert.certificate())?;
-    fs_data_repository.write_tls_intermediate_certificate_file(
-        &intermediate_cert_str
-    )?;
+fn save_renewal_attempt(
+    result: &Result<(), DomainError>
+) -> Result<(), DomainError> {
+    let fs_data_repository = FsDataRepository::init()?;
 
-    let cert_str = fs_data_repository.get_tls_certificate_file_string()?;
-    let intermediate_cert_str =
-        fs_data_repository.get_tls_intermediate_certificate_file_string()?;
-    let cert_chain_str = cert_str + "\n" + &intermediate_cert_str;
-    fs_data_repository.write_tls_certificate_chain_file(&cert_chain_str)?;
+    let mut tls_renewal_status = fs_data_repository.get_tls_renewal_status()?;
+    tls_renewal_status.last_attempt_at = Some(Utc::now().to_rfc3339());
+    tls_renewal_status.last_error_code = match result {
+        Ok(()) => None,
+        Err(domain_error) => Some(domain_error.get_full_code()),
+    };
+    if result.is_ok() {
+        tls_renewal_status.expiration_warning_sent_at = None;
+    }
 
-    Ok(())
+    fs_data_repository.set_tls_renewal_status(&tls_renewal_status)
+}
+
+// Manual certificates are renewed by other tools (e.g. Certbot), so they're
+// reloaded periodically. A failure keeps the current certificate. Like the
+// renewal, it's spawned on the Tokio runtime of main()
+pub fn spawn_reload_thread(
+    certificate_resolver: TlsCertificateResolver
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(
+                Duration::from_secs(SECONDS_BETWEEN_MANUAL_CERTIFICATE_RELOADS)
+            ).await;
+
+            if let Err(domain_error) = certificate_resolver.reload() {
+                log::error!(
+                    "Cannot reload the TLS certificate: {}",
+                    domain_error.message
+                );
+            }
+        }
+    })
 }
 
+// The renewed certificate is swapped in the resolver, so the server (with its
+// sessions and in-flight requests) keeps running. If the renewal fails, the
+// current certificate is still used and the renewal is retried later
 pub fn spawn_renewal_thread(
-    handle: Handle
+    certificate_resolver: TlsCertificateResolver
 ) -> Result<tokio::task::JoinHandle<()>, DomainError> {
+    let mut seconds_until_renewal =
+        get_seconds_until_renewal_of_current_cert()?;
+
+    // Spawned from start_server_thread(), which runs on the Tokio runtime of
+    // main() and not on an Actix worker (there's no LocalSet to spawn on)
+    let renewal_thread = tokio::spawn(async move {
+        loop {
+            if seconds_until_renewal > 0 {
+                tokio::time::sleep(
+                    Duration::from_secs(seconds_until_renewal)
+                ).await;
+            }
+
+            // The certificate may have been renewed meanwhile (from the TAP)
+            seconds_until_renewal =
+                get_seconds_until_renewal_of_current_cert().unwrap_or(0);
+            if seconds_until_renewal > 0 {
+                continue;
+            }
+
+            let result = renew_certificate(ACME_MAX_ATTEMPTS)
+                .await
+                .and_then(|()| certificate_resolver.reload());
+
+            seconds_until_renewal = match result {
+                Ok(()) => {
+                    log::info!("TLS certificate renewed");
+                    get_seconds_until_renewal_of_current_cert().unwrap_or(
+                        SECONDS_UNTIL_RENEWAL_RETRY
+                    )
+                }
+                Err(domain_error) => {
+                    log::error!(
+                        "Cannot renew the TLS certificate: {}",
+                        domain_error.message
+                    );
+                    if let Err(domain_error) = warn_about_expiration() {
+                        log::error!(
+                            "Cannot send the TLS expiration warning: {}",
+                            domain_error.message
+                        );
+                    }
+                    SECONDS_UNTIL_RENEWAL_RETRY
+                }
+            };
+        }
+    });
+
+    Ok(renewal_thread)
+}
+
+// The CA returns the certificate followed by its intermediate certificates
+fn split_certificate_chain(
+    cert_chain: &str
+) -> Result<(String, String), DomainError> {
+    let pem_end = "-----END CERTIFICATE-----";
+    let cert_end_index = cert_chain
+        .find(pem_end)
+        .ok_or(error::CANNOT_PARSE_TLS_CERTIFICATE)? + pem_end.len();
+    let (cert_str, intermediate_cert_str) = cert_chain.split_at(cert_end_index);
+
+    Ok((
+        format!("{}\n", cert_str.trim()),
+        format!("{}\n", intermediate_cert_str.trim()),
+    ))
+}
+
+// The HTTP client of acme-micro (ureq, with its native-certs feature) loads
+// the trusted certificates from SSL_CERT_FILE, or the bundle of the system,
+// on its first HTTPS request. So the root CA of acme.root_ca_file (e.g. the
+// one of a local Pebble) is added to a copy of that bundle before then
+fn trust_acme_root_ca(
+    fs_data_repository: &FsDataRepository,
+    server_config: &ServerConfig
+) -> Result<(), DomainError> {
+    let root_ca_file_path = &server_config.acme.root_ca_file;
+    if root_ca_file_path.is_empty() {
+        return Ok(());
+    }
+
+    let mut root_ca_is_trusted = ACME_ROOT_CA_IS_TRUSTED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if *root_ca_is_trusted {
+        return Ok(());
+    }
+
+    let root_ca = file_system::read_file_as_string(root_ca_file_path)?;
+    X509::from_pem(root_ca.as_bytes()).map_err(
+        |_e| error::INVALID_ACME_ROOT_CA
+    )?;
+
+    let mut root_cas = match openssl_probe::probe().cert_file {
+        Some(system_root_cas_file_path) =>
+            fs::read_to_string(&system_root_cas_file_path).unwrap_or_else(|e| {
+                log::warn!(
+                    "Cannot read the root CAs of the system ({}), so only the \
+                    one of the ACME directory is trusted: {}",
+                    system_root_cas_file_path.display(),
+                    e
+                );
+                String::new()
+            }),
+        None => String::new(),
+    };
+    root_cas.push('\n');
+    root_cas.push_str(&root_ca);
+    fs_data_repository.write_acme_root_cas_file(&root_cas)?;
+
+    env::set_var(
+        SSL_CERT_FILE_ENV_VAR,
+        &fs_data_repository.acme_root_cas_file_path
+    );
+    *root_ca_is_trusted = true;
+
+    Ok(())
+}
+
+// Sent to the admin (at most once a day) when the renewal keeps failing and
+// the certificate is about to expire
+fn warn_about_expiration() -> Result<(), DomainError> {
     let fs_data_repository = FsDataRepository::init()?;
+    let server_config = fs_data_repository.get_server_config()?;
 
     let cert_bytes = fs_data_repository.get_tls_certificate_file_bytes()?;
     let cert = X509::from_pem(&cert_bytes).map_err(
         |_e| error::CANNOT_PARSE_TLS_CERTIFICATE
     )?;
+    let seconds_until_expiration = get_seconds_until_expiration(&cert)?;
+    if seconds_until_expiration >= MAX_SECONDS_UNTIL_EXPIRATION_WARNING {
+        return Ok(());
+    }
 
-    let expiration_time: &Asn1TimeRef = cert.not_after();
-    let current_time = Asn1Time::days_from_now(0).unwrap();
+    let mut tls_renewal_status = fs_data_repository.get_tls_renewal_status()?;
+    let warning_was_recently_sent = tls_renewal_status
+        .expiration_warning_sent_at
+        .as_ref()
+        .and_then(|sent_at| DateTime::parse_from_rfc3339(sent_at).ok())
+        .map_or(false, |sent_at| {
+            let seconds_since_warning = Utc::now()
+                .signed_duration_since(sent_at)
+                .num_seconds();
+            seconds_since_warning < SECONDS_BETWEEN_EXPIRATION_WARNINGS
+        });
+    if warning_was_recently_sent {
+        return Ok(());
+    }
 
-    let time_until_expiration = expiration_time.diff(&current_time).unwrap();
+    let subject = format!(
+        "The TLS certificate of {} expires soon",
+        server_config.domain
+    );
+    let body = format!(
+        "The TLS certificate of {} expires in {} days ({}), and it couldn't \
+        be renewed (last error: {}).\n\n\
+        Check the TLS page of the Tukosmo Admin Panel.",
+        server_config.domain,
+        seconds_until_expiration.max(0) / 86400,
+        cert.not_after(),
+        tls_renewal_status.last_error_code.as_deref().unwrap_or("unknown")
+    );
+    email::send_email_to_admin(&subject, &body)?;
 
-    let seconds_until_expiration = time_until_expiration.secs.abs();
+    tls_renewal_status.expiration_warning_sent_at = Some(
+        Utc::now().to_rfc3339()
+    );
+    fs_data_repository.set_tls_renewal_status(&tls_renewal_status)
+}
 
-    let renewal_thread = tokio::spawn(async move {
-        let seconds_until_renewal =
-            seconds_until_expiration - MIN_SECONDS_WITHOUT_RENEWAL;
-        let more_than_1_hour_until_renewal = seconds_until_renewal > 3600;
-        if more_than_1_hour_until_renewal {
-            tokio::time::sleep(
-                Duration::from_secs(seconds_until_renewal.try_into().unwrap())
-            ).await;
-        }
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        // TODO: Prevent loss of input data while Tukosmo server is off.
-        // Currently (2022-09-24), Actix Web doesn't support hot reload
-        // of TLS/SSL credentials: https://github.com/actix/actix-net/issues/13
-        let _ = handle.stop(true);
-    });
+    fn unwrap<T>(result: Result<T, DomainError>) -> T {
+        result.unwrap_or_else(|domain_error| {
+            panic!("{}: {}", domain_error.get_full_code(), domain_error.message)
+        })
+    }
 
-    Ok(renewal_thread)
+    // Needs a local Pebble that doesn't validate the challenges (nothing
+    // answers them here) nor rejects nonces at random:
+    //
+    //   docker run -p 14000:14000 -e PEBBLE_VA_ALWAYS_VALID=1 \
+    //       -e PEBBLE_WFE_NONCEREJECT=0 ghcr.io/letsencrypt/pebble
+    //
+    // and TUKOSMO_DATA_DIR pointing to a copy of the data dir whose
+    // Tukosmo.toml has a domain like tukosmo.test and, in [acme]:
+    //
+    //   directory_url = "https://localhost:14000/dir"
+    //   root_ca_file = "<pebble>/test/certs/pebble.minica.pem"
+    //
+    // Then run: cargo test -p tukosmo_infrastructure --features ssr --
+    // --ignored orders_a_certificate_from_pebble
+    #[test]
+    #[ignore]
+    fn orders_a_certificate_from_pebble() {
+        let fs_data_repository = unwrap(FsDataRepository::init());
+        let server_config = unwrap(fs_data_repository.get_server_config());
+        unwrap(fs_data_repository.create_new_acme_challenge_dir());
+
+        let result = order_acme_certificate(
+            &fs_data_repository,
+            &server_config
+        );
+        unwrap(fs_data_repository.remove_acme_challenge_dir());
+        let acme_certificate = unwrap(result);
+
+        let (cert_str, intermediate_cert_str) = unwrap(
+            split_certificate_chain(&acme_certificate.cert_chain)
+        );
+        let cert = X509::from_pem(cert_str.as_bytes()).unwrap();
+        assert!(!unwrap(cert_is_self_signed(&cert)));
+        assert!(X509::from_pem(intermediate_cert_str.as_bytes()).is_ok());
+
+        let subject_alt_names: Vec<String> = cert
+            .subject_alt_names()
+            .unwrap()
+            .iter()
+            .filter_map(|name| name.dnsname().map(|name| name.to_string()))
+            .collect();
+        assert!(subject_alt_names.contains(&server_config.domain));
+    }
 }
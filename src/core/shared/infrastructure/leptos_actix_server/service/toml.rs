@@ -16,3 +16,16 @@ pub fn parse_file<T>(file_path: &str) -> Result<T, DomainError>
         .map_err(|_e| error::CANNOT_PARSE_TOML_FILE)?;
     Ok(parsed_structure)
 }
+
+pub fn write_file<T>(file_path: &str, structure: &T) -> Result<(), DomainError>
+    where T: serde::Serialize
+{
+    if !file_path.ends_with(".toml") {
+        return Err(error::INVALID_TOML_EXTENSION);
+    }
+    let toml_str = toml
+        ::to_string(structure)
+        .map_err(|_e| error::CANNOT_SERIALIZE_TOML)?;
+    file_system::write_file(file_path, &toml_str)?;
+    Ok(())
+}
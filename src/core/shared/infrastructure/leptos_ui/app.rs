@@ -6,6 +6,7 @@ use leptos::create_resource;
 use leptos::provide_context;
 use leptos::tracing;
 use leptos::view;
+use leptos_meta::Link;
 use leptos_meta::Stylesheet;
 use leptos_meta::Title;
 use leptos_meta::provide_meta_context;
@@ -13,35 +14,72 @@ use leptos_router::Outlet;
 use leptos_router::Route;
 use leptos_router::Router;
 use leptos_router::Routes;
+use leptos_router::SsrMode;
 use leptos_router::Location;
 use leptos_router::use_location;
 use tukosmo_application::core::shared::dto::DtoGetInitialData;
 use tukosmo_domain::core::shared::model::ServerResponse;
 
+use crate::core::gallery::leptos_ui::TapAddGalleryView;
+use crate::core::gallery::leptos_ui::TapDeleteGalleryView;
+use crate::core::gallery::leptos_ui::TapEditGalleryView;
+use crate::core::gallery::leptos_ui::TapGalleriesView;
+use crate::core::gallery::leptos_ui::WebGalleryView;
 use crate::core::language::leptos_ui::TapAddLanguageView;
 use crate::core::language::leptos_ui::TapDeleteLanguageView;
 use crate::core::language::leptos_ui::TapEditLanguageView;
 use crate::core::language::leptos_ui::TapLanguagesView;
+use crate::core::menu::leptos_ui::TapAddMenuView;
+use crate::core::menu::leptos_ui::TapDeleteMenuView;
+use crate::core::menu::leptos_ui::TapEditMenuView;
+use crate::core::menu::leptos_ui::TapMenusView;
 use crate::core::shared::leptos_actix_server::api::global_api;
 use crate::core::shared::leptos_ui::context::GlobalContext;
 use crate::core::shared::leptos_ui::widget::LoadingScreen;
+use crate::core::theme::leptos_ui::TapEditThemeView;
+use crate::core::theme::leptos_ui::TapThemesView;
 use crate::core::user::leptos_ui::WebLoginView;
+use crate::core::widget::leptos_ui::TapAddWidgetView;
+use crate::core::widget::leptos_ui::TapDeleteWidgetView;
+use crate::core::widget::leptos_ui::TapEditWidgetView;
+use crate::core::widget::leptos_ui::TapWidgetsView;
 use super::RootView;
+use super::TapCacheView;
 use super::TapDashboardView;
+use super::TapDataView;
+use super::TapDatabaseView;
+use super::TapDomainView;
+use super::TapFaviconView;
+use super::TapTlsView;
+use super::TapTukosmoView;
 use super::WebHomeView;
 use super::WebNotFoundView;
+use super::WebSearchView;
 use super::service::navigation;
 
 #[component]
 pub fn App() -> impl IntoView {
     provide_meta_context();
 
+    #[cfg(feature = "ssr")]
+    {
+        use crate::core::shared::leptos_actix_server::service::security_headers;
+
+        security_headers::insert_content_security_policy();
+    }
+
     view! {
         // id=leptos means cargo-leptos will hot-reload this stylesheet
         <Stylesheet id="leptos" href="/pkg/site.css" />
 
         <Title text="Tukosmo" />
 
+        <Link
+            href=navigation::CODE_PATH_FAVICON_APPLE_TOUCH_ICON
+            rel="apple-touch-icon"
+        />
+        <Link href=navigation::CODE_PATH_FAVICON_MANIFEST rel="manifest" />
+
         <Router>
             <Routes>
                 <Route path="" view=InitialDataLoader>
@@ -50,10 +88,46 @@ pub fn App() -> impl IntoView {
                         path=navigation::CODE_PATH_ADMIN
                         view=TapDashboardView
                     />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_CACHE
+                        view=TapCacheView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_DATA
+                        view=TapDataView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_DATABASE
+                        view=TapDatabaseView
+                    />
                     <Route
                         path=navigation::CODE_PATH_ADMIN_DASHBOARD
                         view=TapDashboardView
                     />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_DOMAIN
+                        view=TapDomainView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_FAVICON
+                        view=TapFaviconView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_GALLERIES
+                        view=TapGalleriesView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_GALLERIES_ADD
+                        view=TapAddGalleryView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_GALLERIES_DELETE
+                        view=TapDeleteGalleryView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_GALLERIES_EDIT
+                        view=TapEditGalleryView
+                    />
                     <Route
                         path=navigation::CODE_PATH_ADMIN_LANGUAGES
                         view=TapLanguagesView
@@ -70,6 +144,60 @@ pub fn App() -> impl IntoView {
                         path=navigation::CODE_PATH_ADMIN_LANGUAGES_EDIT
                         view=TapEditLanguageView
                     />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_MENUS
+                        view=TapMenusView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_MENUS_ADD
+                        view=TapAddMenuView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_MENUS_DELETE
+                        view=TapDeleteMenuView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_MENUS_EDIT
+                        view=TapEditMenuView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_THEMES
+                        view=TapThemesView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_THEMES_EDIT
+                        view=TapEditThemeView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_TLS
+                        view=TapTlsView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_TUKOSMO
+                        view=TapTukosmoView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_WIDGETS
+                        view=TapWidgetsView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_WIDGETS_ADD
+                        view=TapAddWidgetView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_WIDGETS_DELETE
+                        view=TapDeleteWidgetView
+                    />
+                    <Route
+                        path=navigation::CODE_PATH_ADMIN_WIDGETS_EDIT
+                        view=TapEditWidgetView
+                    />
+                    // Async, so the page is only cached when the gallery exists
+                    <Route
+                        path=navigation::CODE_PATH_GALLERY
+                        ssr=SsrMode::Async
+                        view=WebGalleryView
+                    />
                     <Route
                         path=navigation::CODE_PATH_LOGIN
                         view=WebLoginView
@@ -78,6 +206,10 @@ pub fn App() -> impl IntoView {
                         path=navigation::CODE_PATH_LOGOUT
                         view=|| view! { "logout" } // TODO
                     />
+                    <Route
+                        path=navigation::CODE_PATH_SEARCH
+                        view=WebSearchView
+                    />
                     <Route path=navigation::CODE_PATH_HOME view=WebHomeView />
                     <Route path="/*any" view=WebNotFoundView />
                 </Route>
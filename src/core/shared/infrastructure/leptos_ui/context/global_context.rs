@@ -135,6 +135,15 @@ macro_rules! t {
     };
 }
 
+#[macro_export]
+macro_rules! t_bytesize {
+    ($expr:expr) => {
+        crate::core::shared::leptos_ui::local_translation_callback(
+            move |local_i18n| local_i18n.bytesize.with($expr)
+        )
+    };
+}
+
 #[macro_export]
 macro_rules! t_date_long {
     ($expr:expr) => {
@@ -153,6 +162,15 @@ macro_rules! t_date_short {
     };
 }
 
+#[macro_export]
+macro_rules! t_duration {
+    ($expr:expr) => {
+        crate::core::shared::leptos_ui::local_translation_callback(
+            move |local_i18n| local_i18n.duration.duration.with(&local_i18n.duration, $expr)
+        )
+    };
+}
+
 #[macro_export]
 macro_rules! t_error {
     ($code:expr, $context:expr) => {
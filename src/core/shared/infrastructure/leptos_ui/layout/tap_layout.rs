@@ -305,30 +305,56 @@ fn Sidebar() -> impl IntoView {
 
 #[component]
 fn SidebarAppearanceMenu() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
     view! {
         <p class="menu-label">
             {t!(main.appearance)}
         </p>
         <ul class="menu-list">
-            <SidebarElement>
-                <icon::Star />
-                {t!(main.favicon)}
-            </SidebarElement>
+            <li>
+                <a
+                    href=move || navigation::path_admin_favicon(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Star />
+                    {t!(main.favicon)}
+                </a>
+            </li>
 
-            <SidebarElement>
-                <icon::Palette />
-                {t!(main.theme)}
-            </SidebarElement>
+            <li>
+                <a
+                    href=move || navigation::path_admin_themes(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Palette />
+                    {t!(main.theme)}
+                </a>
+            </li>
 
-            <SidebarElement>
-                <icon::List />
-                {t!(main.menus)}
-            </SidebarElement>
+            <li>
+                <a
+                    href=move || navigation::path_admin_menus(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::List />
+                    {t!(main.menus)}
+                </a>
+            </li>
 
-            <SidebarElement>
-                <icon::LayoutSidebar />
-                {t!(main.widgets)}
-            </SidebarElement>
+            <li>
+                <a
+                    href=move || navigation::path_admin_widgets(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::LayoutSidebar />
+                    {t!(main.widgets)}
+                </a>
+            </li>
         </ul>
     }
 }
@@ -389,6 +415,8 @@ fn SidebarElement(children: Children) -> impl IntoView {
 
 #[component]
 fn SidebarModulesMenu() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
     view! {
         <p class="menu-label">
             {t!(main.modules)}
@@ -399,10 +427,16 @@ fn SidebarModulesMenu() -> impl IntoView {
                 {t!(main.blog)}
             </SidebarElement>
 
-            <SidebarElement>
-                <icon::Gallery />
-                {t!(main.gallery)}
-            </SidebarElement>
+            <li>
+                <a
+                    href=move || navigation::path_admin_galleries(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Gallery />
+                    {t!(main.gallery)}
+                </a>
+            </li>
 
             <SidebarElement>
                 <icon::QuestionSquare />
@@ -439,6 +473,8 @@ fn SidebarModulesMenu() -> impl IntoView {
 
 #[component]
 fn SidebarSettingsMenu() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
     view! {
         <p class="menu-label">
             {t!(main.settings)}
@@ -449,20 +485,76 @@ fn SidebarSettingsMenu() -> impl IntoView {
                 {t!(main.website)}
             </SidebarElement>
 
-            <SidebarElement>
-                <icon::Ethernet />
-                {t!(main.domain_w_web)}
-            </SidebarElement>
+            <li>
+                <a
+                    href=move || navigation::path_admin_domain(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Ethernet />
+                    {t!(main.domain_w_web)}
+                </a>
+            </li>
 
-            <SidebarElement>
-                <icon::Stars />
-                {t!(main.tukosmo)}
-            </SidebarElement>
+            <li>
+                <a
+                    href=move || navigation::path_admin_tls(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::ShieldLock />
+                    {t!(main.tls)}
+                </a>
+            </li>
+
+            <li>
+                <a
+                    href=move || navigation::path_admin_cache(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Lightning />
+                    {t!(main.page_cache)}
+                </a>
+            </li>
+
+            <li>
+                <a
+                    href=move || navigation::path_admin_tukosmo(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Stars />
+                    {t!(main.tukosmo)}
+                </a>
+            </li>
 
             <SidebarElement>
                 <icon::Database />
                 {t!(main.server)}
             </SidebarElement>
+
+            <li>
+                <a
+                    href=move || navigation::path_admin_database(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Database />
+                    {t!(main.database)}
+                </a>
+            </li>
+
+            <li>
+                <a
+                    href=move || navigation::path_admin_data(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <icon::Archive />
+                    {t!(main.data)}
+                </a>
+            </li>
         </ul>
     }
 }
@@ -8,10 +8,15 @@ use leptos::IntoAttribute;
 use leptos::IntoClass;
 use leptos::IntoView;
 use leptos::ReadSignal;
+use leptos::Show;
+use leptos::Signal;
 use leptos::SignalGet;
 use leptos::SignalUpdate;
+use leptos::Transition;
+use leptos::View;
 use leptos::WriteSignal;
 use leptos::component;
+use leptos::create_resource;
 use leptos::create_signal;
 use leptos::tracing;
 use leptos::view;
@@ -20,8 +25,18 @@ use leptos_router::Location;
 use leptos_router::NavigateOptions;
 use leptos_router::use_location;
 use leptos_router::use_navigate;
+use tukosmo_application::core::menu::dto::DtoGetMenuByLocation;
+use tukosmo_application::core::theme::dto::DtoThemeLayout;
 use tukosmo_domain::core::language::model::Language;
+use tukosmo_domain::core::menu::model::MenuLocation;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::theme::model::render_theme_template;
+use tukosmo_domain::core::widget::model::WidgetArea;
 
+use crate::core::menu::leptos_actix_server::api::menu_api;
+use crate::core::menu::leptos_ui::WebMenuItems;
+use crate::core::theme::leptos_actix_server::api::theme_api;
+use crate::core::widget::leptos_ui::WebWidgetArea;
 use crate::t;
 use super::I18nLayout;
 use super::super::GlobalContext;
@@ -29,27 +44,93 @@ use super::super::service::navigation;
 use super::super::use_global_context;
 use super::super::widget::icon;
 
+// Themes can declare this variable to show a logo in the built-in header
+const THEME_VARIABLE_LOGO: &'static str = "logo";
+
 #[component]
-fn Footer() -> impl IntoView {
+fn Footer(theme_layout: Signal<Option<DtoThemeLayout>>) -> impl IntoView {
     let GlobalContext { website_title_reader, .. } = use_global_context();
 
     let current_year = Utc::now().year();
 
+    let footer_menu_items = web_menu_items(MenuLocation::Footer);
+    let footer_template = theme_slot_template(theme_layout, |layout| {
+        layout.slots.footer.clone()
+    });
+
     view! {
         <div class="core-shared-web_layout-footer">
             <div class="core-shared-web_layout-footer-container">
-                <div class="core-shared-web_layout-footer-credits">
-                    {website_title_reader}
-                    " © "
-                    {current_year}
-                </div>
+                <WebWidgetArea area=WidgetArea::Footer />
+
+                <ul class="core-shared-web_layout-footer-menu">
+                    <Transition fallback=|| ()>
+                        {footer_menu_items}
+                    </Transition>
+                </ul>
+
+                <Transition fallback=|| ()>
+                    <Show
+                        fallback=move || view! {
+                            <div class="core-shared-web_layout-footer-credits">
+                                {website_title_reader}
+                                " © "
+                                {current_year}
+                            </div>
+                        }
+                        when=move || footer_template().is_some()
+                    >
+                        <div
+                            class="core-shared-web_layout-footer-credits"
+                            inner_html=move || footer_template().unwrap_or_default()
+                        ></div>
+                    </Show>
+                </Transition>
             </div>
         </div>
     }
 }
 
 #[component]
-fn Header() -> impl IntoView {
+fn Header(theme_layout: Signal<Option<DtoThemeLayout>>) -> impl IntoView {
+    let header_template = theme_slot_template(theme_layout, |layout| {
+        layout.slots.header.clone()
+    });
+    let logo = move || {
+        theme_layout
+            .get()
+            .and_then(|layout| {
+                layout.variable_values
+                    .into_iter()
+                    .find(|(code, _)| code == THEME_VARIABLE_LOGO)
+                    .map(|(_, value)| value)
+            })
+            .unwrap_or_default()
+    };
+
+    view! {
+        <header class="core-shared-web_layout-header">
+            <Transition fallback=|| ()>
+                <Show
+                    fallback=move || view! {
+                        <div class="core-shared-web_layout-header-container">
+                            <HeaderBranding logo=Signal::derive(logo) />
+                        </div>
+                    }
+                    when=move || header_template().is_some()
+                >
+                    <div
+                        class="core-shared-web_layout-header-container"
+                        inner_html=move || header_template().unwrap_or_default()
+                    ></div>
+                </Show>
+            </Transition>
+        </header>
+    }
+}
+
+#[component]
+fn HeaderBranding(logo: Signal<String>) -> impl IntoView {
     let GlobalContext {
         current_language_reader,
         website_subtitle_reader,
@@ -58,25 +139,35 @@ fn Header() -> impl IntoView {
     } = use_global_context();
 
     view! {
-        <header class="core-shared-web_layout-header">
-            <div class="core-shared-web_layout-header-container">
-                <div class="core-shared-web_layout-header-branding">
-                    <h1 class="core-shared-web_layout-header-title">
-                        <a
-                            href=move || navigation::path_home(
-                                &current_language_reader.get().code
-                            )
-                        >
-                            {website_title_reader}
-                        </a>
-                    </h1>
-
-                    <p class="core-shared-web_layout-header-description">
-                        {website_subtitle_reader}
-                    </p>
-                </div>
-            </div>
-        </header>
+        <div class="core-shared-web_layout-header-branding">
+            <Show when=move || !logo.get().is_empty()>
+                <a
+                    href=move || navigation::path_home(
+                        &current_language_reader.get().code
+                    )
+                >
+                    <img
+                        alt=website_title_reader
+                        class="core-shared-web_layout-header-logo"
+                        src=logo
+                    />
+                </a>
+            </Show>
+
+            <h1 class="core-shared-web_layout-header-title">
+                <a
+                    href=move || navigation::path_home(
+                        &current_language_reader.get().code
+                    )
+                >
+                    {website_title_reader}
+                </a>
+            </h1>
+
+            <p class="core-shared-web_layout-header-description">
+                {website_subtitle_reader}
+            </p>
+        </div>
     }
 }
 
@@ -185,6 +276,8 @@ fn Navigation() -> impl IntoView {
             *draft = true;
         });
 
+    let header_menu_items = web_menu_items(MenuLocation::Header);
+
     let on_click_toggle_navbar_menu_in_small_screen = move |_|
         set_navbar_menu_is_showed_in_small_screen.update(|draft| {
             *draft = !draft.clone();
@@ -213,29 +306,9 @@ fn Navigation() -> impl IntoView {
                         move || navbar_menu_is_showed_in_small_screen()
                     )
                 >
-                    <li>
-                        <a href="">
-                            {t!(main.blog)}
-                        </a>
-                    </li>
-                    <li>
-                        <a
-                            href=move || navigation::path_login(
-                                &current_language_reader.get().code
-                            )
-                        >
-                            {t!(main.login_w_noun)}
-                        </a>
-                    </li>
-                    <li>
-                        <a
-                            href=move || navigation::path_admin_dashboard(
-                                &current_language_reader.get().code
-                            )
-                        >
-                            {t!(main.administration_panel)}
-                        </a>
-                    </li>
+                    <Transition fallback=|| ()>
+                        {header_menu_items}
+                    </Transition>
                     <li>
                         <button on:click=on_click_open_languages_modal>
                             <icon::Globe />
@@ -252,14 +325,92 @@ fn Navigation() -> impl IntoView {
     }
 }
 
+// Renders the slot's template with the theme variables and some website data
+fn theme_slot_template(
+    theme_layout: Signal<Option<DtoThemeLayout>>,
+    get_slot: fn(&DtoThemeLayout) -> Option<String>
+) -> impl Fn() -> Option<String> + Copy {
+    let GlobalContext {
+        current_language_reader,
+        website_subtitle_reader,
+        website_title_reader,
+        ..
+    } = use_global_context();
+
+    move || {
+        let layout = theme_layout.get()?;
+        let template = get_slot(&layout)?;
+
+        let mut values = layout.variable_values;
+        values.push(("current_year".to_string(), Utc::now().year().to_string()));
+        values.push((
+            "home_url".to_string(),
+            navigation::path_home(&current_language_reader.get().code),
+        ));
+        values.push((
+            "website_subtitle".to_string(),
+            website_subtitle_reader.get(),
+        ));
+        values.push(("website_title".to_string(), website_title_reader.get()));
+
+        Some(render_theme_template(&template, &values))
+    }
+}
+
+// Menus are optional, so loading errors just leave the location empty
+fn web_menu_items(location: MenuLocation) -> impl Fn() -> Option<View> {
+    let response_data = create_resource(
+        || (),
+        move |_| async move {
+            let dto = DtoGetMenuByLocation {
+                location: location.value().to_string(),
+            };
+            menu_api::get_by_location(dto).await
+        }
+    );
+
+    move || {
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(ServerResponse::Response(Some(menu))) =>
+                    view! { <WebMenuItems items=menu.items /> }.into_view(),
+                _ => ().into_view(),
+            }
+        })
+    }
+}
+
 #[component]
 pub fn WebLayout(children: ChildrenFn) -> impl IntoView {
+    // Without a theme the built-in layout and styles are used
+    let theme_layout_resource = create_resource(
+        || (),
+        |_| async move { theme_api::layout().await }
+    );
+    let theme_layout = Signal::derive(move || {
+        match theme_layout_resource.get() {
+            Some(Ok(ServerResponse::Response(layout))) => Some(layout),
+            _ => None,
+        }
+    });
+    let sidebar_template = theme_slot_template(theme_layout, |layout| {
+        layout.slots.sidebar.clone()
+    });
+
     view! {
         <Html class="web" />
         <I18nLayout>
+            <Transition fallback=|| ()>
+                {move || theme_layout.get().map(|layout| view! {
+                    <link
+                        href=navigation::path_theme_stylesheet(layout.revision)
+                        rel="stylesheet"
+                    />
+                })}
+            </Transition>
             <div class="core-shared-web_layout">
                 <div class="core-shared-web_layout-top">
-                    <Header />
+                    <Header theme_layout=theme_layout />
                     <Navigation />
                 </div>
 
@@ -271,13 +422,21 @@ pub fn WebLayout(children: ChildrenFn) -> impl IntoView {
                             </main>
 
                             <aside class="core-shared-web_layout-sidebar">
-                                // TODO: Widgets
+                                <Transition fallback=|| ()>
+                                    {move || sidebar_template().map(|html| view! {
+                                        <div
+                                            class="core-shared-web_layout-sidebar-theme"
+                                            inner_html=html
+                                        ></div>
+                                    })}
+                                </Transition>
+                                <WebWidgetArea area=WidgetArea::Sidebar />
                             </aside>
                         </div>
                     </div>
                 </div>
 
-                <Footer />
+                <Footer theme_layout=theme_layout />
             </div>
         </I18nLayout>
     }
@@ -3,3 +3,6 @@ pub use delete_form::*;
 
 mod form_field;
 pub use form_field::*;
+
+mod server_settings_form;
+pub use server_settings_form::*;
@@ -0,0 +1,157 @@
+use leptos::SignalGetUntracked;
+use leptos::StoredValue;
+use leptos::store_value;
+use tukosmo_application::core::shared::dto::DtoServerSettingsForm;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DATABASE_SSLMODES;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerMode;
+
+use crate::t;
+use super::FormFieldValue;
+
+#[derive(Clone)]
+pub struct ServerSettingsForm {
+    pub admin_email: StoredValue<FormFieldValue<String>>,
+    pub database_host: StoredValue<FormFieldValue<String>>,
+    pub database_name: StoredValue<FormFieldValue<String>>,
+    pub database_password: StoredValue<FormFieldValue<String>>,
+    pub database_port: StoredValue<FormFieldValue<String>>,
+    pub database_sslmode: StoredValue<FormFieldValue<String>>,
+    pub database_user: StoredValue<FormFieldValue<String>>,
+    pub http_port: StoredValue<FormFieldValue<String>>,
+    pub https_port: StoredValue<FormFieldValue<String>>,
+    pub mode: StoredValue<FormFieldValue<String>>,
+}
+
+impl ServerSettingsForm {
+    pub fn get_dto(&self) -> DtoServerSettingsForm {
+        DtoServerSettingsForm {
+            admin_email: self.admin_email.get_value().signal.get_untracked(),
+            database_host: self.database_host
+                .get_value()
+                .signal.get_untracked(),
+            database_name: self.database_name
+                .get_value()
+                .signal.get_untracked(),
+            database_password: self.database_password
+                .get_value()
+                .signal.get_untracked(),
+            database_port: self.database_port
+                .get_value()
+                .signal.get_untracked(),
+            database_sslmode: self.database_sslmode
+                .get_value()
+                .signal.get_untracked(),
+            database_user: self.database_user
+                .get_value()
+                .signal.get_untracked(),
+            http_port: self.http_port.get_value().signal.get_untracked(),
+            https_port: self.https_port.get_value().signal.get_untracked(),
+            mode: self.mode.get_value().signal.get_untracked(),
+        }
+    }
+
+    pub fn init_filled(form: DtoServerSettingsForm) -> StoredValue<Self> {
+        let admin_email = FormFieldValue::init(
+            form.admin_email,
+            ServerConfig::validate_admin_email
+        );
+        let database_host = FormFieldValue::init(
+            form.database_host,
+            validate_not_empty
+        );
+        let database_name = FormFieldValue::init(
+            form.database_name,
+            validate_not_empty
+        );
+        let database_password = FormFieldValue::init(
+            form.database_password,
+            |_| None
+        );
+        let database_port = FormFieldValue::init(
+            form.database_port,
+            validate_database_port
+        );
+        let database_sslmode = FormFieldValue::init(
+            form.database_sslmode,
+            |_| None
+        );
+        let database_user = FormFieldValue::init(
+            form.database_user,
+            validate_not_empty
+        );
+        let http_port = FormFieldValue::init(form.http_port, validate_port);
+        let https_port = FormFieldValue::init(form.https_port, validate_port);
+        let mode = FormFieldValue::init(form.mode, |_| None);
+
+        store_value(Self {
+            admin_email,
+            database_host,
+            database_name,
+            database_password,
+            database_port,
+            database_sslmode,
+            database_user,
+            http_port,
+            https_port,
+            mode,
+        })
+    }
+
+    pub fn validate(&self) {
+        self.admin_email.get_value().validate();
+        self.database_host.get_value().validate();
+        self.database_name.get_value().validate();
+        self.database_password.get_value().validate();
+        self.database_port.get_value().validate();
+        self.database_sslmode.get_value().validate();
+        self.database_user.get_value().validate();
+        self.http_port.get_value().validate();
+        self.https_port.get_value().validate();
+        self.mode.get_value().validate();
+    }
+}
+
+pub fn database_sslmode_options() -> Vec<(String, String)> {
+    DATABASE_SSLMODES
+        .iter()
+        .map(|sslmode| (sslmode.to_string(), sslmode.to_string()))
+        .collect()
+}
+
+pub fn server_mode_options() -> Vec<(String, String)> {
+    vec![
+        (
+            ServerMode::Development.value().to_string(),
+            t!(main.development)(),
+        ),
+        (
+            ServerMode::Production.value().to_string(),
+            t!(main.production)(),
+        )
+    ]
+}
+
+fn validate_database_port(value: &String) -> Option<DomainError> {
+    match value.trim().parse::<u16>() {
+        Ok(port) if port > 0 => None,
+        _ => Some(error::INVALID_DATABASE_PORT),
+    }
+}
+
+fn validate_not_empty(value: &String) -> Option<DomainError> {
+    if value.trim().is_empty() {
+        Some(error::FIELD_CANNOT_BE_EMPTY)
+    } else {
+        None
+    }
+}
+
+fn validate_port(value: &String) -> Option<DomainError> {
+    match value.trim().parse::<u16>() {
+        Ok(port) => ServerConfig::validate_port(&port),
+        Err(_e) => Some(error::INVALID_PORT),
+    }
+}
@@ -1,9 +1,30 @@
+use tukosmo_domain::core::gallery::model::GalleryId;
 use tukosmo_domain::core::language::model::LanguageCode;
 use tukosmo_domain::core::language::model::LanguageId;
+use tukosmo_domain::core::menu::model::MenuId;
+use tukosmo_domain::core::menu::model::MenuItemLink;
+use tukosmo_domain::core::theme::model::ThemeCode;
+use tukosmo_domain::core::widget::model::WidgetInstanceId;
 
 pub const CODE_PATH_ADMIN: &'static str = "/:language_code/admin";
+pub const CODE_PATH_ADMIN_CACHE: &'static str = "/:language_code/admin/cache";
 pub const CODE_PATH_ADMIN_DASHBOARD: &'static str =
     "/:language_code/admin/dashboard";
+pub const CODE_PATH_ADMIN_DATA: &'static str = "/:language_code/admin/data";
+pub const CODE_PATH_ADMIN_DATABASE: &'static str =
+    "/:language_code/admin/database";
+pub const CODE_PATH_ADMIN_DOMAIN: &'static str =
+    "/:language_code/admin/domain";
+pub const CODE_PATH_ADMIN_FAVICON: &'static str =
+    "/:language_code/admin/favicon";
+pub const CODE_PATH_ADMIN_GALLERIES: &'static str =
+    "/:language_code/admin/galleries";
+pub const CODE_PATH_ADMIN_GALLERIES_ADD: &'static str =
+    "/:language_code/admin/galleries/add";
+pub const CODE_PATH_ADMIN_GALLERIES_DELETE: &'static str =
+    "/:language_code/admin/galleries/delete/:id";
+pub const CODE_PATH_ADMIN_GALLERIES_EDIT: &'static str =
+    "/:language_code/admin/galleries/edit/:id";
 pub const CODE_PATH_ADMIN_LANGUAGES: &'static str =
     "/:language_code/admin/languages";
 pub const CODE_PATH_ADMIN_LANGUAGES_ADD: &'static str =
@@ -12,9 +33,46 @@ pub const CODE_PATH_ADMIN_LANGUAGES_DELETE: &'static str =
     "/:language_code/admin/languages/delete/:id";
 pub const CODE_PATH_ADMIN_LANGUAGES_EDIT: &'static str =
     "/:language_code/admin/languages/edit/:id";
+pub const CODE_PATH_ADMIN_MENUS: &'static str = "/:language_code/admin/menus";
+pub const CODE_PATH_ADMIN_MENUS_ADD: &'static str =
+    "/:language_code/admin/menus/add";
+pub const CODE_PATH_ADMIN_MENUS_DELETE: &'static str =
+    "/:language_code/admin/menus/delete/:id";
+pub const CODE_PATH_ADMIN_MENUS_EDIT: &'static str =
+    "/:language_code/admin/menus/edit/:id";
+pub const CODE_PATH_ADMIN_THEMES: &'static str =
+    "/:language_code/admin/themes";
+pub const CODE_PATH_ADMIN_THEMES_EDIT: &'static str =
+    "/:language_code/admin/themes/edit/:id";
+pub const CODE_PATH_ADMIN_TLS: &'static str = "/:language_code/admin/tls";
+pub const CODE_PATH_ADMIN_TUKOSMO: &'static str =
+    "/:language_code/admin/tukosmo";
+pub const CODE_PATH_ADMIN_WIDGETS: &'static str =
+    "/:language_code/admin/widgets";
+pub const CODE_PATH_ADMIN_WIDGETS_ADD: &'static str =
+    "/:language_code/admin/widgets/add";
+pub const CODE_PATH_ADMIN_WIDGETS_DELETE: &'static str =
+    "/:language_code/admin/widgets/delete/:id";
+pub const CODE_PATH_ADMIN_WIDGETS_EDIT: &'static str =
+    "/:language_code/admin/widgets/edit/:id";
+pub const CODE_PATH_API_BACKUP_EXPORT: &'static str = "/api/backup/export";
+pub const CODE_PATH_API_BACKUP_IMPORT: &'static str = "/api/backup/import";
+pub const CODE_PATH_API_CONTENT_EXPORT: &'static str = "/api/content/export";
+pub const CODE_PATH_API_UPLOAD_FAVICON: &'static str = "/api/upload/favicon";
+pub const CODE_PATH_API_UPLOAD_GALLERY_IMAGES: &'static str =
+    "/api/upload/gallery/:id";
+pub const CODE_PATH_FAVICON: &'static str = "/favicon.ico";
+pub const CODE_PATH_FAVICON_APPLE_TOUCH_ICON: &'static str =
+    "/apple-touch-icon.png";
+pub const CODE_PATH_FAVICON_MANIFEST: &'static str = "/manifest.webmanifest";
+pub const CODE_PATH_GALLERY: &'static str = "/:language_code/gallery/:id";
 pub const CODE_PATH_HOME: &'static str = "/:language_code/";
 pub const CODE_PATH_LOGIN: &'static str = "/:language_code/login";
 pub const CODE_PATH_LOGOUT: &'static str = "/:language_code/logout";
+pub const CODE_PATH_SEARCH: &'static str = "/:language_code/search";
+pub const CODE_PATH_THEME_ASSETS: &'static str = "/theme/assets";
+pub const CODE_PATH_THEME_STYLESHEET: &'static str = "/theme/style.css";
+pub const CODE_PATH_UPLOADS: &'static str = "/uploads";
 
 const PARAM_ID: &'static str = ":id";
 const PARAM_LANGUAGE_CODE: &'static str = ":language_code";
@@ -121,6 +179,10 @@ pub fn path_admin(language_code: &LanguageCode) -> String {
     CODE_PATH_ADMIN.replace(PARAM_LANGUAGE_CODE, language_code.value())
 }
 
+pub fn path_admin_cache(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_CACHE.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
 pub fn path_admin_dashboard(language_code: &LanguageCode) -> String {
     CODE_PATH_ADMIN_DASHBOARD.replace(
         PARAM_LANGUAGE_CODE,
@@ -128,6 +190,56 @@ pub fn path_admin_dashboard(language_code: &LanguageCode) -> String {
     )
 }
 
+pub fn path_admin_data(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_DATA.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_database(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_DATABASE.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_domain(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_DOMAIN.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_favicon(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_FAVICON.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_galleries(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_GALLERIES.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    )
+}
+
+pub fn path_admin_galleries_add(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_GALLERIES_ADD.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    )
+}
+
+pub fn path_admin_galleries_delete(
+    language_code: &LanguageCode,
+    gallery_id: &GalleryId
+) -> String {
+    CODE_PATH_ADMIN_GALLERIES_DELETE.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, &gallery_id.value().to_string())
+}
+
+pub fn path_admin_galleries_edit(
+    language_code: &LanguageCode,
+    gallery_id: &GalleryId
+) -> String {
+    CODE_PATH_ADMIN_GALLERIES_EDIT.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, &gallery_id.value().to_string())
+}
+
 pub fn path_admin_languages(language_code: &LanguageCode) -> String {
     CODE_PATH_ADMIN_LANGUAGES.replace(
         PARAM_LANGUAGE_CODE,
@@ -162,6 +274,146 @@ pub fn path_admin_languages_edit(
     ).replace(PARAM_ID, &language_id.value().to_string())
 }
 
+pub fn path_admin_menus(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_MENUS.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_menus_add(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_MENUS_ADD.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    )
+}
+
+pub fn path_admin_menus_delete(
+    language_code: &LanguageCode,
+    menu_id: &MenuId
+) -> String {
+    CODE_PATH_ADMIN_MENUS_DELETE.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, &menu_id.value().to_string())
+}
+
+pub fn path_admin_menus_edit(
+    language_code: &LanguageCode,
+    menu_id: &MenuId
+) -> String {
+    CODE_PATH_ADMIN_MENUS_EDIT.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, &menu_id.value().to_string())
+}
+
+pub fn path_admin_themes(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_THEMES.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_themes_edit(
+    language_code: &LanguageCode,
+    theme_code: &ThemeCode
+) -> String {
+    CODE_PATH_ADMIN_THEMES_EDIT.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, theme_code.value())
+}
+
+pub fn path_admin_tls(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_TLS.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_tukosmo(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_TUKOSMO.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_widgets(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_WIDGETS.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+pub fn path_admin_widgets_add(language_code: &LanguageCode) -> String {
+    CODE_PATH_ADMIN_WIDGETS_ADD.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    )
+}
+
+pub fn path_admin_widgets_delete(
+    language_code: &LanguageCode,
+    widget_instance_id: &WidgetInstanceId
+) -> String {
+    CODE_PATH_ADMIN_WIDGETS_DELETE.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, &widget_instance_id.value().to_string())
+}
+
+pub fn path_admin_widgets_edit(
+    language_code: &LanguageCode,
+    widget_instance_id: &WidgetInstanceId
+) -> String {
+    CODE_PATH_ADMIN_WIDGETS_EDIT.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, &widget_instance_id.value().to_string())
+}
+
+pub fn path_api_backup_export(language_code: &LanguageCode) -> String {
+    format!(
+        "{}?language_code={}",
+        CODE_PATH_API_BACKUP_EXPORT,
+        language_code.value()
+    )
+}
+
+pub fn path_api_backup_import(language_code: &LanguageCode) -> String {
+    format!(
+        "{}?language_code={}",
+        CODE_PATH_API_BACKUP_IMPORT,
+        language_code.value()
+    )
+}
+
+pub fn path_api_content_export(language_code: &LanguageCode) -> String {
+    format!(
+        "{}?language_code={}",
+        CODE_PATH_API_CONTENT_EXPORT,
+        language_code.value()
+    )
+}
+
+pub fn path_api_upload_favicon(language_code: &LanguageCode) -> String {
+    format!(
+        "{}?language_code={}",
+        CODE_PATH_API_UPLOAD_FAVICON,
+        language_code.value()
+    )
+}
+
+pub fn path_api_upload_gallery_images(
+    language_code: &LanguageCode,
+    gallery_id: &GalleryId
+) -> String {
+    format!(
+        "{}?language_code={}",
+        CODE_PATH_API_UPLOAD_GALLERY_IMAGES.replace(
+            PARAM_ID,
+            &gallery_id.value().to_string()
+        ),
+        language_code.value()
+    )
+}
+
+pub fn path_gallery(
+    language_code: &LanguageCode,
+    gallery_id: &GalleryId
+) -> String {
+    CODE_PATH_GALLERY.replace(
+        PARAM_LANGUAGE_CODE,
+        language_code.value()
+    ).replace(PARAM_ID, &gallery_id.value().to_string())
+}
+
 pub fn path_home(language_code: &LanguageCode) -> String {
     CODE_PATH_HOME.replace(PARAM_LANGUAGE_CODE, language_code.value())
 }
@@ -173,3 +425,30 @@ pub fn path_login(language_code: &LanguageCode) -> String {
 pub fn path_logout(language_code: &LanguageCode) -> String {
     CODE_PATH_LOGOUT.replace(PARAM_LANGUAGE_CODE, language_code.value())
 }
+
+pub fn path_menu_item_link(
+    language_code: &LanguageCode,
+    menu_item_link: &MenuItemLink
+) -> String {
+    match menu_item_link {
+        MenuItemLink::ExternalUrl(url) => url.clone(),
+        MenuItemLink::Gallery(gallery_id) =>
+            path_gallery(language_code, gallery_id),
+        MenuItemLink::Route(route) =>
+            format!("/{}{}", language_code.value(), route),
+    }
+}
+
+pub fn path_search(language_code: &LanguageCode) -> String {
+    CODE_PATH_SEARCH.replace(PARAM_LANGUAGE_CODE, language_code.value())
+}
+
+// The revision changes with every theme modification, so browsers don't keep
+// using an outdated stylesheet
+pub fn path_theme_stylesheet(revision: i64) -> String {
+    format!("{}?v={:x}", CODE_PATH_THEME_STYLESHEET, revision)
+}
+
+pub fn path_uploaded_file(file_name: &str) -> String {
+    format!("{}/{}", CODE_PATH_UPLOADS, file_name)
+}
@@ -1,11 +1,35 @@
 mod root_view;
 pub use root_view::*;
 
+mod tap_cache_view;
+pub use tap_cache_view::*;
+
 mod tap_dashboard_view;
 pub use tap_dashboard_view::*;
 
+mod tap_data_view;
+pub use tap_data_view::*;
+
+mod tap_database_view;
+pub use tap_database_view::*;
+
+mod tap_domain_view;
+pub use tap_domain_view::*;
+
+mod tap_favicon_view;
+pub use tap_favicon_view::*;
+
+mod tap_tls_view;
+pub use tap_tls_view::*;
+
+mod tap_tukosmo_view;
+pub use tap_tukosmo_view::*;
+
 mod web_home_view;
 pub use web_home_view::*;
 
 mod web_not_found_view;
 pub use web_not_found_view::*;
+
+mod web_search_view;
+pub use web_search_view::*;
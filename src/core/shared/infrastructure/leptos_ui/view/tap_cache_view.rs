@@ -0,0 +1,229 @@
+use leptos::IntoView;
+use leptos::RwSignal;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::SignalUpdate;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::shared::dto::DtoPageCacheStats;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::shared::leptos_actix_server::api::page_cache_api;
+use crate::t;
+use crate::t_error;
+use super::super::TapLayout;
+use super::super::TapLoadingError;
+use super::super::TapLoadingLeptosError;
+use super::super::TapLoadingResource;
+use super::super::service::server::manage_response;
+
+const BYTES_IN_A_MEGABYTE: f64 = 1048576.0;
+
+#[component]
+pub fn TapCacheView() -> impl IntoView {
+    // Increased to reload the stats after purging the cache
+    let reload_signal = create_rw_signal(0);
+    let success_signal = create_rw_signal(false);
+
+    let response_data = create_resource(
+        move || reload_signal.get(),
+        |_| async move {
+            let result = page_cache_api::get_stats().await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(dto_page_cache_stats) => {
+                            view! {
+                                <div>
+                                    <TapCacheViewContent
+                                        dto_page_cache_stats=dto_page_cache_stats
+                                        reload_signal=reload_signal
+                                        success_signal=success_signal
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapCacheViewContent(
+    dto_page_cache_stats: DtoPageCacheStats,
+    reload_signal: RwSignal<i32>,
+    success_signal: RwSignal<bool>
+) -> impl IntoView {
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+
+    let is_enabled = dto_page_cache_stats.enabled;
+    let requests = dto_page_cache_stats.hits + dto_page_cache_stats.misses;
+    let hit_rate = if requests == 0 {
+        0.0
+    } else {
+        dto_page_cache_stats.hits as f64 * 100.0 / requests as f64
+    };
+
+    let on_click_purge_button = move |_| {
+        waiting_response_signal.set(true);
+        server_error_signal.set(None);
+        success_signal.set(false);
+
+        spawn_local(async move {
+            let server_response_purge = page_cache_api::purge().await;
+
+            manage_response(
+                server_response_purge,
+                move |_response| {
+                    success_signal.set(true);
+                    waiting_response_signal.set(false);
+                    reload_signal.update(|value| *value += 1);
+                },
+                move |server_error| {
+                    server_error_signal.set(Some(server_error));
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <div class="box is-marginless mb-6">
+            <h1 class="title">{t!(main.page_cache)}</h1>
+
+            <Show when=move || success_signal.get()>
+                <div class="notification is-success">
+                    <button
+                        class="delete"
+                        on:click=move |_| success_signal.set(false)
+                    ></button>
+                    {t!(main.the_page_cache_has_been_successfully_purged)}
+                </div>
+            </Show>
+
+            <Show when=move || server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <Show when=move || !is_enabled>
+                <div class="notification is-info">
+                    {t!(main.the_page_cache_is_disabled_in_the_server_configuration)}
+                </div>
+            </Show>
+
+            <p class="mb-4">
+                {t!(main.the_pages_seen_by_anonymous_visitors_are_kept_in_memory)}
+            </p>
+
+            <table class="table is-bordered is-hoverable is-fullwidth">
+                <tbody>
+                    <tr>
+                        <th>{t!(main.status)}</th>
+                        <td>
+                            <Show
+                                fallback=|| view! {
+                                    <span class="tag is-warning">
+                                        {t!(main.disabled)}
+                                    </span>
+                                }
+                                when=move || is_enabled
+                            >
+                                <span class="tag is-success">
+                                    {t!(main.enabled)}
+                                </span>
+                            </Show>
+                        </td>
+                    </tr>
+                    <tr>
+                        <th>{t!(main.cached_pages)}</th>
+                        <td>{dto_page_cache_stats.entries}</td>
+                    </tr>
+                    <tr>
+                        <th>{t!(main.size)}</th>
+                        <td>
+                            {format!(
+                                "{:.2} MB / {:.2} MB",
+                                dto_page_cache_stats.size_bytes as f64
+                                    / BYTES_IN_A_MEGABYTE,
+                                dto_page_cache_stats.max_size_bytes as f64
+                                    / BYTES_IN_A_MEGABYTE
+                            )}
+                        </td>
+                    </tr>
+                    <tr>
+                        <th>{t!(main.hits)}</th>
+                        <td>{dto_page_cache_stats.hits}</td>
+                    </tr>
+                    <tr>
+                        <th>{t!(main.misses)}</th>
+                        <td>{dto_page_cache_stats.misses}</td>
+                    </tr>
+                    <tr>
+                        <th>{t!(main.hit_rate)}</th>
+                        <td>{format!("{:.1} %", hit_rate)}</td>
+                    </tr>
+                </tbody>
+            </table>
+
+            <button
+                class="button is-danger"
+                class=("is-loading", move || waiting_response_signal.get())
+                disabled=move || waiting_response_signal.get()
+                on:click=on_click_purge_button
+            >
+                {t!(main.purge_cache)}
+            </button>
+        </div>
+    }
+}
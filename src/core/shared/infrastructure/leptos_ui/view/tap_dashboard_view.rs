@@ -1,16 +1,242 @@
 use leptos::IntoView;
+use leptos::SignalGet;
+use leptos::SignalUpdate;
+use leptos::Transition;
 use leptos::component;
+use leptos::create_effect;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::on_cleanup;
+use leptos::set_interval_with_handle;
 use leptos::tracing;
 use leptos::view;
+use std::time::Duration;
+use tukosmo_application::core::shared::dto::DtoSystemInfo;
+use tukosmo_domain::core::shared::model::ServerResponse;
 
+use crate::core::shared::leptos_actix_server::api::system_info_api;
 use crate::t;
+use crate::t_bytesize;
+use crate::t_date_long;
+use crate::t_duration;
 use super::super::TapLayout;
+use super::super::TapLoadingError;
+use super::super::TapLoadingLeptosError;
+use super::super::TapLoadingResource;
+
+const REFRESH_INTERVAL_IN_SECONDS: u64 = 10;
 
 #[component]
 pub fn TapDashboardView() -> impl IntoView {
+    // Increased by the interval, so the resource reads the values again
+    let reload_signal = create_rw_signal(0);
+
+    let response_data = create_resource(
+        move || reload_signal.get(),
+        |_| async move {
+            let result = system_info_api::get().await;
+            result
+        }
+    );
+
+    // Effects only run in the browser, so the interval isn't set while
+    // rendering on the server
+    create_effect(move |_| {
+        let interval_handle = set_interval_with_handle(
+            move || reload_signal.update(|value| *value += 1),
+            Duration::from_secs(REFRESH_INTERVAL_IN_SECONDS)
+        );
+        if let Ok(interval_handle) = interval_handle {
+            on_cleanup(move || interval_handle.clear());
+        }
+    });
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(dto_system_info) => {
+                            view! {
+                                <div>
+                                    <TapDashboardViewContent
+                                        dto_system_info=dto_system_info
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
     view! {
         <TapLayout>
-            <h1>{t!(main.dashboard)}</h1>
+            <h1 class="title">{t!(main.dashboard)}</h1>
+            <p class="mb-4">
+                {move || t!(main.the_dashboard_is_refreshed_every_n_seconds)().with(
+                    REFRESH_INTERVAL_IN_SECONDS as i64
+                )}
+            </p>
+
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
         </TapLayout>
     }
 }
+
+#[component]
+fn TapDashboardViewContent(dto_system_info: DtoSystemInfo) -> impl IntoView {
+    let DtoSystemInfo {
+        booted_at,
+        cpu_cores,
+        cpu_model,
+        cpu_usage_percentage,
+        database_size_bytes,
+        database_version,
+        disk_total_bytes,
+        disk_used_bytes,
+        kernel_version,
+        memory_total_bytes,
+        memory_used_bytes,
+        os_name,
+        tukosmo_version,
+        uptime_seconds,
+    } = dto_system_info;
+
+    let disk_usage_percentage =
+        get_percentage(disk_used_bytes, disk_total_bytes);
+    let memory_usage_percentage =
+        get_percentage(memory_used_bytes, memory_total_bytes);
+
+    view! {
+        <div class="columns is-multiline">
+            <div class="column is-one-third">
+                <div class="box">
+                    <h2 class="title is-5">
+                        {move || t!(main.server_os)().with(&os_name)}
+                    </h2>
+                    <p>
+                        <strong>{t!(main.kernel_version)}": "</strong>
+                        {kernel_version}
+                    </p>
+                    <p>
+                        {move || t!(main.tukosmo_version)().with(&tukosmo_version)}
+                    </p>
+                </div>
+            </div>
+
+            <div class="column is-one-third">
+                <div class="box">
+                    <h2 class="title is-5">{t!(main.cpu)}</h2>
+                    <p>{cpu_model}</p>
+                    <p class="mb-3">
+                        {move || t!(main.n_logical_cores)().with(cpu_cores as i64)}
+                    </p>
+                    <p>
+                        <strong>{t!(main.cpu_current_usage)}": "</strong>
+                        {format!("{:.1} %", cpu_usage_percentage)}
+                    </p>
+                    <progress
+                        class="progress is-info"
+                        max="100"
+                        value=cpu_usage_percentage
+                    ></progress>
+                </div>
+            </div>
+
+            <div class="column is-one-third">
+                <div class="box">
+                    <h2 class="title is-5">{t!(main.memory)}</h2>
+                    <p>
+                        {move || t!(main.disk_used_info)().with(
+                            &t!(bytesize)(),
+                            memory_used_bytes,
+                            memory_total_bytes
+                        )}
+                        {format!(" ({:.1} %)", memory_usage_percentage)}
+                    </p>
+                    <progress
+                        class="progress is-info"
+                        max="100"
+                        value=memory_usage_percentage
+                    ></progress>
+                </div>
+            </div>
+
+            <div class="column is-one-third">
+                <div class="box">
+                    <h2 class="title is-5">
+                        {move || t!(main.disk_at_percentage)().with(
+                            round_percentage(disk_usage_percentage)
+                        )}
+                    </h2>
+                    <p>
+                        {move || t!(main.disk_used_info)().with(
+                            &t!(bytesize)(),
+                            disk_used_bytes,
+                            disk_total_bytes
+                        )}
+                    </p>
+                    <progress
+                        class="progress is-info"
+                        max="100"
+                        value=disk_usage_percentage
+                    ></progress>
+                </div>
+            </div>
+
+            <div class="column is-one-third">
+                <div class="box">
+                    <h2 class="title is-5">{t!(main.uptime)}</h2>
+                    <p>{t_duration!(uptime_seconds)}</p>
+                    <p>
+                        <strong>{t!(main.since)}": "</strong>
+                        {t_date_long!(&booted_at)}
+                    </p>
+                </div>
+            </div>
+
+            <div class="column is-one-third">
+                <div class="box">
+                    <h2 class="title is-5">{t!(main.database)}</h2>
+                    <p>
+                        <strong>{t!(main.version)}": "</strong>
+                        "PostgreSQL "{database_version}
+                    </p>
+                    <p>
+                        <strong>{t!(main.size)}": "</strong>
+                        {t_bytesize!(database_size_bytes)}
+                    </p>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+fn get_percentage(used: u64, total: u64) -> f64 {
+    if total == 0 {
+        0.0
+    } else {
+        used as f64 * 100.0 / total as f64
+    }
+}
+
+// One decimal, like the other percentages of the dashboard
+fn round_percentage(percentage: f64) -> f64 {
+    (percentage * 10.0).round() / 10.0
+}
@@ -0,0 +1,404 @@
+use leptos::For;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalGetUntracked;
+use leptos::SignalSet;
+use leptos::component;
+use leptos::create_rw_signal;
+use leptos::event_target_checked;
+use leptos::event_target_value;
+use leptos::spawn_local;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::use_query_map;
+use tukosmo_application::core::shared::dto::DtoContentImportAction;
+use tukosmo_application::core::shared::dto::DtoContentImportChange;
+use tukosmo_application::core::shared::dto::DtoContentImportReport;
+use tukosmo_application::core::shared::dto::DtoContentKind;
+use tukosmo_application::core::shared::dto::DtoImportContent;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::shared::leptos_actix_server::api::content_api;
+use crate::t;
+use crate::t_error;
+use super::super::GlobalContext;
+use super::super::TapLayout;
+use super::super::service::navigation;
+use super::super::service::server::manage_response;
+use super::super::use_global_context;
+use super::super::widget::icon;
+
+#[component]
+pub fn TapDataView() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+    let url_query_map = use_query_map();
+
+    // Both the export and the import are regular requests (not server
+    // functions), so errors come back from the server as query parameters
+    let server_error_signal = create_rw_signal::<Option<ServerResponseError>>(
+        url_query_map
+            .get_untracked()
+            .get("error")
+            .map(|error_code| ServerResponseError {
+                error_code: error_code.to_string(),
+                context: vec![],
+            })
+    );
+    let success_signal = create_rw_signal(
+        url_query_map.get_untracked().get("success").is_some()
+    );
+    let file_name_signal = create_rw_signal::<Option<String>>(None);
+
+    let content_json_signal = create_rw_signal(String::new());
+    let content_mode_signal = create_rw_signal("merge".to_string());
+    let content_dry_run_signal = create_rw_signal(true);
+    let content_report_signal =
+        create_rw_signal::<Option<DtoContentImportReport>>(None);
+    let content_server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+    let waiting_response_signal = create_rw_signal(false);
+
+    let on_click_import_content_button = move |_| {
+        waiting_response_signal.set(true);
+        content_server_error_signal.set(None);
+        content_report_signal.set(None);
+
+        spawn_local(async move {
+            let dto = DtoImportContent {
+                content_json: content_json_signal.get_untracked(),
+                dry_run: content_dry_run_signal.get_untracked(),
+                mode: content_mode_signal.get_untracked(),
+            };
+            let server_response_import = content_api::import(dto).await;
+
+            manage_response(
+                server_response_import,
+                move |report| {
+                    content_report_signal.set(Some(report));
+                    waiting_response_signal.set(false);
+                },
+                move |server_error| {
+                    content_server_error_signal.set(Some(server_error));
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <TapLayout>
+            <h1 class="title">{t!(main.data)}</h1>
+
+            <Show when=move || success_signal.get()>
+                <div class="notification is-success">
+                    <button
+                        class="delete"
+                        on:click=move |_| success_signal.set(false)
+                    ></button>
+                    {t!(main.the_backup_has_been_successfully_imported)}
+                </div>
+            </Show>
+
+            <Show when=move || server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            // A form, since creating the backup writes it to the server (the
+            // response is the file, so the page stays the same)
+            <form
+                action=move || navigation::path_api_backup_export(
+                    &current_language_reader.get().code
+                )
+                class="box is-marginless mb-6"
+                method="post"
+            >
+                <h2 class="title is-5">{t!(main.export_backup)}</h2>
+                <p class="mb-4">{t!(main.export_backup_note)}</p>
+                <button class="button is-link" type="submit">
+                    <span class="icon">
+                        <icon::CloudDownload />
+                    </span>
+                    <span>{t!(main.export_backup)}</span>
+                </button>
+            </form>
+
+            <form
+                action=move || navigation::path_api_backup_import(
+                    &current_language_reader.get().code
+                )
+                class="box is-marginless"
+                enctype="multipart/form-data"
+                method="post"
+            >
+                <h2 class="title is-5">{t!(main.import_backup)}</h2>
+
+                <div class="notification is-warning">
+                    {t!(main.import_backup_note)}
+                </div>
+
+                <div class="field">
+                    <label class="label">
+                        {t!(main.choose_a_tukosmo_backup_file)}
+                    </label>
+                    <div class="file has-name is-fullwidth">
+                        <label class="file-label">
+                            <input
+                                accept=".zst"
+                                class="file-input"
+                                name="backup"
+                                on:change=move |event| {
+                                    let file_path = event_target_value(&event);
+                                    // Browsers prefix the name with a fake path
+                                    let file_name = file_path
+                                        .rsplit(['/', '\\'])
+                                        .next()
+                                        .unwrap_or_default()
+                                        .to_string();
+                                    file_name_signal.set(
+                                        if file_name.is_empty() {
+                                            None
+                                        } else {
+                                            Some(file_name)
+                                        }
+                                    );
+                                }
+                                required=true
+                                type="file"
+                            />
+                            <span class="file-cta">
+                                <span class="file-icon">
+                                    <icon::CloudUpload />
+                                </span>
+                                <span class="file-label">
+                                    {t!(main.choose_a_file)}
+                                </span>
+                            </span>
+                            <span class="file-name">
+                                {move || match file_name_signal.get() {
+                                    Some(file_name) => file_name,
+                                    None => t!(main.no_file_uploaded)(),
+                                }}
+                            </span>
+                        </label>
+                    </div>
+                </div>
+
+                <div class="field">
+                    <div class="control">
+                        <button class="button is-danger" type="submit">
+                            {t!(main.import_backup)}
+                        </button>
+                    </div>
+                </div>
+            </form>
+
+            <div class="box is-marginless mt-6 mb-6">
+                <h2 class="title is-5">{t!(main.export_content)}</h2>
+                <p class="mb-4">{t!(main.export_content_note)}</p>
+                <a
+                    class="button is-link"
+                    href=move || navigation::path_api_content_export(
+                        &current_language_reader.get().code
+                    )
+                    rel="external"
+                >
+                    <span class="icon">
+                        <icon::CloudDownload />
+                    </span>
+                    <span>{t!(main.export_content)}</span>
+                </a>
+            </div>
+
+            <div class="box is-marginless">
+                <h2 class="title is-5">{t!(main.import_content)}</h2>
+                <p class="mb-4">{t!(main.import_content_note)}</p>
+
+                <Show when=move || content_server_error_signal.get().is_some()>
+                    <div class="notification is-danger">
+                        <button
+                            class="delete"
+                            on:click=move |_| content_server_error_signal.set(None)
+                        ></button>
+                        {move || {
+                            let server_error = content_server_error_signal.get();
+                            match server_error {
+                                Some(server_error) => {
+                                    t_error!(&server_error.error_code, &server_error.context)()
+                                },
+                                None => "".to_string(),
+                            }
+                        }}
+                    </div>
+                </Show>
+
+                <div class="field">
+                    <label class="label">
+                        {t!(main.paste_the_exported_content)}
+                    </label>
+                    <div class="control">
+                        <textarea
+                            class="textarea is-family-monospace"
+                            on:input=move |event| {
+                                content_json_signal.set(event_target_value(&event));
+                            }
+                            prop:value=content_json_signal
+                            rows="10"
+                        ></textarea>
+                    </div>
+                </div>
+
+                <div class="field">
+                    <label class="label">{t!(main.import_mode)}</label>
+                    <div class="control">
+                        <div class="select">
+                            <select
+                                on:change=move |event| {
+                                    content_mode_signal.set(event_target_value(&event));
+                                }
+                                prop:value=content_mode_signal
+                            >
+                                <option value="merge">
+                                    {t!(main.import_mode_merge)}
+                                </option>
+                                <option value="overwrite">
+                                    {t!(main.import_mode_overwrite)}
+                                </option>
+                            </select>
+                        </div>
+                    </div>
+                </div>
+
+                <div class="field">
+                    <label class="checkbox">
+                        <input
+                            on:change=move |event| {
+                                content_dry_run_signal.set(event_target_checked(&event));
+                            }
+                            prop:checked=content_dry_run_signal
+                            type="checkbox"
+                        />
+                        " "
+                        {t!(main.dry_run_only_show_the_changes)}
+                    </label>
+                </div>
+
+                <div class="field">
+                    <div class="control">
+                        <button
+                            class="button is-primary"
+                            class=("is-loading", move || waiting_response_signal.get())
+                            disabled=move || waiting_response_signal.get()
+                            on:click=on_click_import_content_button
+                        >
+                            {t!(main.import_content)}
+                        </button>
+                    </div>
+                </div>
+
+                {move || content_report_signal.get().map(|report| view! {
+                    <TapContentImportReport report=report />
+                })}
+            </div>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapContentImportReport(report: DtoContentImportReport) -> impl IntoView {
+    let is_dry_run = report.dry_run;
+    let changes = report.changes;
+    let has_changes = !changes.is_empty();
+
+    view! {
+        <div class="mt-5">
+            <div
+                class="notification"
+                class=("is-info", is_dry_run)
+                class=("is-success", !is_dry_run)
+            >
+                <Show
+                    fallback=|| t!(main.the_content_has_been_successfully_imported)
+                    when=move || is_dry_run
+                >
+                    {t!(main.dry_run_no_changes_have_been_saved)}
+                </Show>
+            </div>
+
+            <Show when=move || has_changes>
+                <table class="table is-bordered is-hoverable is-fullwidth">
+                    <thead>
+                        <tr>
+                            <th>{t!(main.type_w_content)}</th>
+                            <th>{t!(main.name)}</th>
+                            <th>{t!(main.result)}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        <For
+                            children=move |(_index, change): (usize, DtoContentImportChange)| {
+                                view! {
+                                    <tr>
+                                        <td>{content_kind_text(change.kind)}</td>
+                                        <td>{change.name}</td>
+                                        <td>
+                                            <span class=content_action_class(change.action)>
+                                                {content_action_text(change.action)}
+                                            </span>
+                                        </td>
+                                    </tr>
+                                }
+                            }
+                            each={
+                                let changes = changes.clone();
+                                move || changes.clone().into_iter().enumerate()
+                            }
+                            key=|(index, _change)| *index
+                        />
+                    </tbody>
+                </table>
+            </Show>
+        </div>
+    }
+}
+
+fn content_action_class(action: DtoContentImportAction) -> &'static str {
+    match action {
+        DtoContentImportAction::Created => "tag is-success",
+        DtoContentImportAction::Skipped => "tag is-warning",
+        DtoContentImportAction::Unchanged => "tag",
+        DtoContentImportAction::Updated => "tag is-info",
+    }
+}
+
+fn content_action_text(action: DtoContentImportAction) -> String {
+    match action {
+        DtoContentImportAction::Created => t!(main.created_w_content)(),
+        DtoContentImportAction::Skipped => t!(main.skipped_w_content)(),
+        DtoContentImportAction::Unchanged => t!(main.unchanged_w_content)(),
+        DtoContentImportAction::Updated => t!(main.updated_w_content)(),
+    }
+}
+
+fn content_kind_text(kind: DtoContentKind) -> String {
+    match kind {
+        DtoContentKind::Gallery => t!(main.gallery)(),
+        DtoContentKind::Language => t!(main.language)(),
+        DtoContentKind::Menu => t!(main.menu)(),
+        DtoContentKind::Widget => t!(main.widget)(),
+    }
+}
@@ -0,0 +1,135 @@
+use leptos::For;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::shared::dto::DtoDatabaseStatus;
+use tukosmo_domain::core::shared::model::DatabaseMigration;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+use crate::core::shared::leptos_actix_server::api::database_api;
+use crate::t;
+use super::super::TapLayout;
+use super::super::TapLoadingError;
+use super::super::TapLoadingLeptosError;
+use super::super::TapLoadingResource;
+
+#[component]
+pub fn TapDatabaseView() -> impl IntoView {
+    let response_data = create_resource(
+        || (),
+        |_| async move {
+            let result = database_api::status().await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(dto_database_status) => {
+                            view! {
+                                <div>
+                                    <TapDatabaseViewContent
+                                        dto_database_status=dto_database_status
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapDatabaseViewContent(
+    dto_database_status: DtoDatabaseStatus
+) -> impl IntoView {
+    // Migrations are applied when the server starts, so pending ones only
+    // appear if the binary was replaced without restarting
+    let there_are_pending_migrations = dto_database_status.migrations
+        .iter()
+        .any(|migration| !migration.is_applied);
+    let migrations = store_value(dto_database_status.migrations);
+
+    view! {
+        <div class="box is-marginless mb-6">
+            <h1 class="title">{t!(main.database)}</h1>
+
+            <Show when=move || there_are_pending_migrations>
+                <div class="notification is-warning">
+                    {t!(main.there_are_pending_migrations_restart_the_server_to_apply_them)}
+                </div>
+            </Show>
+
+            <h2 class="subtitle">{t!(main.migrations)}</h2>
+
+            <table class="table is-bordered is-hoverable is-fullwidth">
+                <thead>
+                    <tr>
+                        <th>{t!(main.version)}</th>
+                        <th>{t!(main.name)}</th>
+                        <th>{t!(main.status)}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    <For
+                        children=move |migration: DatabaseMigration| {
+                            view! {
+                                <tr>
+                                    <td>{migration.version}</td>
+                                    <td>{migration.name}</td>
+                                    <td>
+                                        <Show
+                                            fallback=|| view! {
+                                                <span class="tag is-warning">
+                                                    {t!(main.pending)}
+                                                </span>
+                                            }
+                                            when=move || migration.is_applied
+                                        >
+                                            <span class="tag is-success">
+                                                {t!(main.applied)}
+                                            </span>
+                                        </Show>
+                                    </td>
+                                </tr>
+                            }
+                        }
+                        each=move || migrations.get_value()
+                        key=|migration| migration.version.clone()
+                    />
+                </tbody>
+            </table>
+        </div>
+    }
+}
@@ -0,0 +1,164 @@
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalGetUntracked;
+use leptos::SignalSet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::shared::dto::DtoChangeDomain;
+use tukosmo_application::core::shared::dto::DtoDomain;
+use tukosmo_domain::core::shared::model::ServerConfig;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::shared::leptos_actix_server::api::settings_api;
+use crate::t;
+use super::super::FormFieldValue;
+use super::super::GlobalContext;
+use super::super::TapFormPage;
+use super::super::TapFormTextField;
+use super::super::TapLayout;
+use super::super::TapLoadingError;
+use super::super::TapLoadingLeptosError;
+use super::super::TapLoadingResource;
+use super::super::service::navigation;
+use super::super::service::server::manage_response;
+use super::super::use_global_context;
+
+#[component]
+pub fn TapDomainView() -> impl IntoView {
+    let response_data = create_resource(
+        || (),
+        |_| async move {
+            let result = settings_api::get_domain().await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(dto_domain) => {
+                            view! {
+                                <div>
+                                    <TapDomainViewContent
+                                        dto_domain=dto_domain
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapDomainViewContent(dto_domain: DtoDomain) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+    let success_signal = create_rw_signal(false);
+
+    let new_domain = FormFieldValue::init(
+        "".to_string(),
+        ServerConfig::validate_domain
+    );
+
+    let on_click_submit_button = move |_| {
+        waiting_response_signal.set(true);
+        server_error_signal.set(None);
+        success_signal.set(false);
+
+        spawn_local(async move {
+            let dto = DtoChangeDomain {
+                domain: new_domain.get_value().signal.get_untracked(),
+            };
+            let server_response_change = settings_api
+                ::change_domain(dto).await;
+
+            manage_response(
+                server_response_change,
+                move |_response| {
+                    success_signal.set(true);
+                    waiting_response_signal.set(false);
+                },
+                move |server_error| {
+                    new_domain.get_value().validate();
+                    server_error_signal.set(Some(server_error));
+
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <div class="notification is-warning">
+            {t!(main.warning_domain_page)}
+        </div>
+
+        <p class="mb-4">
+            {move || t!(main.your_current_domain_is)().with(&dto_domain.domain)}
+        </p>
+
+        <Show when=move || success_signal.get()>
+            <div class="notification is-success">
+                <button
+                    class="delete"
+                    on:click=move |_| success_signal.set(false)
+                ></button>
+                <p>{t!(main.domain_successfully_updated)}</p>
+                <p>
+                    {t!(main.tukosmo_will_automatically_restart_itself_apply_changes)}
+                </p>
+            </div>
+        </Show>
+
+        <TapFormPage
+            cancel_route_path=Box::new(move || navigation::path_admin_dashboard(
+                &current_language_reader.get().code
+            ))
+            on_click_submit_button=Box::new(on_click_submit_button)
+            server_error_signal=server_error_signal
+            title=Box::new(|| t!(main.change_domain)())
+            waiting_response_signal=waiting_response_signal
+        >
+            <TapFormTextField
+                name=Box::new(|| t!(main.new_domain)())
+                required=true
+                value=new_domain
+            />
+        </TapFormPage>
+    }
+}
@@ -0,0 +1,159 @@
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalGetUntracked;
+use leptos::SignalSet;
+use leptos::component;
+use leptos::create_rw_signal;
+use leptos::event_target_value;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::use_query_map;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::t;
+use crate::t_error;
+use super::super::GlobalContext;
+use super::super::TapLayout;
+use super::super::service::navigation;
+use super::super::use_global_context;
+use super::super::widget::icon;
+
+#[component]
+pub fn TapFaviconView() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+    let url_query_map = use_query_map();
+
+    // The upload is a regular form submission, so its result comes back from
+    // the server as a query parameter
+    let upload_code = url_query_map.get_untracked().get("success").cloned();
+    let server_error_signal = create_rw_signal::<Option<ServerResponseError>>(
+        url_query_map
+            .get_untracked()
+            .get("error")
+            .map(|error_code| ServerResponseError {
+                error_code: error_code.to_string(),
+                context: vec![],
+            })
+    );
+    let success_signal = create_rw_signal(upload_code.is_some());
+    let file_name_signal = create_rw_signal::<Option<String>>(None);
+
+    // Avoid showing the previous favicon from the browser's cache
+    let current_favicon_src = match upload_code {
+        Some(upload_code) =>
+            format!("{}?v={}", navigation::CODE_PATH_FAVICON, upload_code),
+        None => navigation::CODE_PATH_FAVICON.to_string(),
+    };
+
+    view! {
+        <TapLayout>
+            <h1 class="title">{t!(main.favicon)}</h1>
+
+            <Show when=move || success_signal.get()>
+                <div class="notification is-success">
+                    <button
+                        class="delete"
+                        on:click=move |_| success_signal.set(false)
+                    ></button>
+                    <p>
+                        {t!(main.the_websites_favicon_has_been_successfully_updated)}
+                    </p>
+                    <p>{t!(main.favicon_note)}</p>
+                </div>
+            </Show>
+
+            <Show when=move || server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <div class="box is-marginless mb-6">
+                <h2 class="title is-5">{t!(main.current_favicon)}</h2>
+                <figure class="image is-64x64 mb-4">
+                    <img
+                        alt=move || t!(main.current_favicon)()
+                        src=current_favicon_src
+                    />
+                </figure>
+            </div>
+
+            <form
+                action=move || navigation::path_api_upload_favicon(
+                    &current_language_reader.get().code
+                )
+                class="box is-marginless"
+                enctype="multipart/form-data"
+                method="post"
+            >
+                <div class="field">
+                    <label class="label">
+                        {t!(main.upload_new_favicon_png_image)}
+                    </label>
+                    <div class="file has-name is-fullwidth">
+                        <label class="file-label">
+                            <input
+                                accept="image/png"
+                                class="file-input"
+                                name="favicon"
+                                on:change=move |event| {
+                                    let file_path = event_target_value(&event);
+                                    // Browsers prefix the name with a fake path
+                                    let file_name = file_path
+                                        .rsplit(['/', '\\'])
+                                        .next()
+                                        .unwrap_or_default()
+                                        .to_string();
+                                    file_name_signal.set(
+                                        if file_name.is_empty() {
+                                            None
+                                        } else {
+                                            Some(file_name)
+                                        }
+                                    );
+                                }
+                                required=true
+                                type="file"
+                            />
+                            <span class="file-cta">
+                                <span class="file-icon">
+                                    <icon::CloudUpload />
+                                </span>
+                                <span class="file-label">
+                                    {t!(main.choose_a_file)}
+                                </span>
+                            </span>
+                            <span class="file-name">
+                                {move || match file_name_signal.get() {
+                                    Some(file_name) => file_name,
+                                    None => t!(main.no_file_uploaded)(),
+                                }}
+                            </span>
+                        </label>
+                    </div>
+                </div>
+
+                <div class="field">
+                    <div class="control">
+                        <button class="button is-link" type="submit">
+                            {t!(main.upload_file)}
+                        </button>
+                    </div>
+                </div>
+            </form>
+        </TapLayout>
+    }
+}
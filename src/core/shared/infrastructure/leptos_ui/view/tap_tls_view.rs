@@ -0,0 +1,300 @@
+use leptos::IntoView;
+use leptos::RwSignal;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::SignalUpdate;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::shared::dto::DtoTlsStatus;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::shared::leptos_actix_server::api::tls_api;
+use crate::t;
+use crate::t_error;
+use super::super::TapLayout;
+use super::super::TapLoadingError;
+use super::super::TapLoadingLeptosError;
+use super::super::TapLoadingResource;
+use super::super::service::server::manage_response;
+
+// Same margin as the expiration warning sent to the admin
+const DAYS_UNTIL_EXPIRATION_WARNING: i32 = 14;
+
+#[component]
+pub fn TapTlsView() -> impl IntoView {
+    // Increased to reload the status after renewing the certificate
+    let reload_signal = create_rw_signal(0);
+    let success_signal = create_rw_signal(false);
+
+    let response_data = create_resource(
+        move || reload_signal.get(),
+        |_| async move {
+            let result = tls_api::get_status().await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(dto_tls_status) => {
+                            view! {
+                                <div>
+                                    <TapTlsViewContent
+                                        dto_tls_status=dto_tls_status
+                                        reload_signal=reload_signal
+                                        success_signal=success_signal
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapTlsViewContent(
+    dto_tls_status: DtoTlsStatus,
+    reload_signal: RwSignal<i32>,
+    success_signal: RwSignal<bool>
+) -> impl IntoView {
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+
+    let mode = dto_tls_status.mode.clone();
+    let is_acme_mode = mode == "acme";
+    let is_self_signed_mode = mode == "self_signed";
+    let expires_soon = dto_tls_status.certificate
+        .as_ref()
+        .map_or(false, |certificate| {
+            certificate.days_until_expiration < DAYS_UNTIL_EXPIRATION_WARNING
+        });
+    let renewal_is_failing = dto_tls_status.last_renewal_error_code.is_some();
+    let dto_tls_status = store_value(dto_tls_status);
+
+    let on_click_renew_button = move |_| {
+        waiting_response_signal.set(true);
+        server_error_signal.set(None);
+        success_signal.set(false);
+
+        spawn_local(async move {
+            let server_response_renew = tls_api::renew_certificate().await;
+
+            manage_response(
+                server_response_renew,
+                move |_response| {
+                    success_signal.set(true);
+                    waiting_response_signal.set(false);
+                    reload_signal.update(|value| *value += 1);
+                },
+                move |server_error| {
+                    server_error_signal.set(Some(server_error));
+                    waiting_response_signal.set(false);
+                    reload_signal.update(|value| *value += 1);
+                }
+            );
+        });
+    };
+
+    view! {
+        <div class="box is-marginless mb-6">
+            <h1 class="title">{t!(main.tls_certificate)}</h1>
+
+            <Show when=move || success_signal.get()>
+                <div class="notification is-success">
+                    <button
+                        class="delete"
+                        on:click=move |_| success_signal.set(false)
+                    ></button>
+                    {t!(main.the_tls_certificate_has_been_successfully_renewed)}
+                </div>
+            </Show>
+
+            <Show when=move || server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <Show when=move || expires_soon && renewal_is_failing>
+                <div class="notification is-warning">
+                    {t!(main.the_certificate_expires_soon_and_couldnt_be_renewed)}
+                </div>
+            </Show>
+
+            {move || dto_tls_status.get_value().certificate_error_code.map(
+                |error_code| view! {
+                    <div class="notification is-danger">
+                        {t_error!(&error_code, &vec![])}
+                    </div>
+                }
+            )}
+
+            {match mode.as_str() {
+                "manual" => Some(view! {
+                    <div class="notification is-info">
+                        {t!(main.the_certificate_files_are_managed_outside_tukosmo)}
+                    </div>
+                }),
+                "off" => Some(view! {
+                    <div class="notification is-info">
+                        {t!(main.tls_is_disabled_it_is_terminated_by_a_reverse_proxy)}
+                    </div>
+                }),
+                _ => None,
+            }}
+
+            <table class="table is-bordered is-hoverable is-fullwidth">
+                <tbody>
+                    <tr>
+                        <th>{t!(main.tls_mode)}</th>
+                        <td>
+                            <span class="tag">{mode.clone()}</span>
+                        </td>
+                    </tr>
+                    {move || dto_tls_status.get_value().certificate.map(
+                        |certificate| {
+                            let is_self_signed = certificate.is_self_signed;
+                            view! {
+                                <tr>
+                                    <th>{t!(main.issuer)}</th>
+                                    <td>
+                                        {certificate.issuer}
+                                        <Show when=move || is_self_signed>
+                                            " "
+                                            <span class="tag is-warning">
+                                                {t!(main.self_signed)}
+                                            </span>
+                                        </Show>
+                                    </td>
+                                </tr>
+                                <tr>
+                                    <th>{t!(main.domains_w_certificate)}</th>
+                                    <td>
+                                        {certificate.subject_alt_names.join(", ")}
+                                    </td>
+                                </tr>
+                                <tr>
+                                    <th>{t!(main.issued_at)}</th>
+                                    <td>{certificate.issued_at}</td>
+                                </tr>
+                                <tr>
+                                    <th>{t!(main.expires_at)}</th>
+                                    <td>{certificate.expires_at}</td>
+                                </tr>
+                                <tr>
+                                    <th>{t!(main.days_until_expiration)}</th>
+                                    <td>
+                                        <span
+                                            class="tag"
+                                            class=("is-success", !expires_soon)
+                                            class=("is-danger", expires_soon)
+                                        >
+                                            {certificate.days_until_expiration}
+                                        </span>
+                                    </td>
+                                </tr>
+                            }
+                        }
+                    )}
+                    <tr>
+                        <th>{t!(main.last_renewal_attempt)}</th>
+                        <td>
+                            {move || dto_tls_status
+                                .get_value()
+                                .last_renewal_attempt_at
+                                .unwrap_or_else(|| t!(main.never)())}
+                        </td>
+                    </tr>
+                    <Show when=move || renewal_is_failing>
+                        <tr>
+                            <th>{t!(main.last_renewal_error)}</th>
+                            <td>
+                                {move || dto_tls_status
+                                    .get_value()
+                                    .last_renewal_error_code
+                                    .map(|error_code| {
+                                        t_error!(&error_code, &vec![])()
+                                    })}
+                            </td>
+                        </tr>
+                    </Show>
+                    {move || dto_tls_status
+                        .get_value()
+                        .expiration_warning_sent_at
+                        .map(|sent_at| view! {
+                            <tr>
+                                <th>{t!(main.expiration_warning_sent_at)}</th>
+                                <td>{sent_at}</td>
+                            </tr>
+                        })}
+                </tbody>
+            </table>
+
+            <Show when=move || is_acme_mode || is_self_signed_mode>
+                <p class="mb-4">
+                    {t!(main.obtaining_a_certificate_may_take_a_few_minutes)}
+                </p>
+                <button
+                    class="button is-primary"
+                    class=("is-loading", move || waiting_response_signal.get())
+                    disabled=move || waiting_response_signal.get()
+                    on:click=on_click_renew_button
+                >
+                    <Show
+                        fallback=|| t!(main.regenerate_self_signed_certificate)
+                        when=move || is_acme_mode
+                    >
+                        {t!(main.renew_now)}
+                    </Show>
+                </button>
+            </Show>
+        </div>
+    }
+}
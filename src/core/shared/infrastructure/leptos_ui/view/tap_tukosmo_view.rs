@@ -0,0 +1,213 @@
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::shared::dto::DtoEditServerSettings;
+use tukosmo_application::core::shared::dto::DtoServerSettingsForm;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::shared::leptos_actix_server::api::settings_api;
+use crate::t;
+use super::super::GlobalContext;
+use super::super::ServerSettingsForm;
+use super::super::TapFormPage;
+use super::super::TapFormPasswordField;
+use super::super::TapFormSelectField;
+use super::super::TapFormTextField;
+use super::super::TapLayout;
+use super::super::TapLoadingError;
+use super::super::TapLoadingLeptosError;
+use super::super::TapLoadingResource;
+use super::super::database_sslmode_options;
+use super::super::server_mode_options;
+use super::super::service::navigation;
+use super::super::service::server::manage_response;
+use super::super::use_global_context;
+
+#[component]
+pub fn TapTukosmoView() -> impl IntoView {
+    let response_data = create_resource(
+        || (),
+        |_| async move {
+            let result = settings_api::get_server().await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(dto_form) => {
+                            view! {
+                                <div>
+                                    <TapTukosmoViewContent
+                                        dto_form=dto_form
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapTukosmoViewContent(dto_form: DtoServerSettingsForm) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+    let success_signal = create_rw_signal(false);
+
+    let form = ServerSettingsForm::init_filled(dto_form);
+
+    let on_click_submit_button = move |_| {
+        waiting_response_signal.set(true);
+        server_error_signal.set(None);
+        success_signal.set(false);
+
+        spawn_local(async move {
+            let dto = DtoEditServerSettings {
+                form: form.get_value().get_dto(),
+            };
+            let server_response_edit = settings_api::edit_server(dto).await;
+
+            manage_response(
+                server_response_edit,
+                move |_response| {
+                    success_signal.set(true);
+                    waiting_response_signal.set(false);
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    server_error_signal.set(Some(server_error));
+
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <Show when=move || success_signal.get()>
+            <div class="notification is-success">
+                <button
+                    class="delete"
+                    on:click=move |_| success_signal.set(false)
+                ></button>
+                <p>{t!(main.tukosmo_settings_successfully_updated)}</p>
+                <p>
+                    {t!(main.tukosmo_will_automatically_restart_itself_apply_changes)}
+                </p>
+            </div>
+        </Show>
+
+        <TapFormPage
+            cancel_route_path=Box::new(move || navigation::path_admin_dashboard(
+                &current_language_reader.get().code
+            ))
+            on_click_submit_button=Box::new(on_click_submit_button)
+            server_error_signal=server_error_signal
+            title=Box::new(|| t!(main.tukosmo)())
+            waiting_response_signal=waiting_response_signal
+        >
+            <TapFormTextField
+                name=Box::new(|| t!(main.administrators_email)())
+                required=true
+                value=form.get_value().admin_email
+            />
+
+            <TapFormSelectField
+                name=Box::new(|| t!(main.mode_w_server)())
+                options=Box::new(server_mode_options)
+                required=true
+                value=form.get_value().mode
+            />
+
+            <TapFormTextField
+                name=Box::new(|| t!(main.http_port)())
+                required=true
+                value=form.get_value().http_port
+            />
+
+            <TapFormTextField
+                name=Box::new(|| t!(main.https_port)())
+                required=true
+                value=form.get_value().https_port
+            />
+
+            <TapFormTextField
+                name=Box::new(|| t!(main.database_host)())
+                required=true
+                value=form.get_value().database_host
+            />
+
+            <TapFormTextField
+                name=Box::new(|| t!(main.database_port)())
+                required=true
+                value=form.get_value().database_port
+            />
+
+            <TapFormTextField
+                name=Box::new(|| t!(main.database_name)())
+                required=true
+                value=form.get_value().database_name
+            />
+
+            <TapFormTextField
+                name=Box::new(|| t!(main.database_user)())
+                required=true
+                value=form.get_value().database_user
+            />
+
+            <TapFormPasswordField
+                help=Box::new(
+                    || t!(main.leave_empty_to_keep_the_current_password)()
+                )
+                name=Box::new(|| t!(main.database_password)())
+                required=false
+                value=form.get_value().database_password
+            />
+
+            <TapFormSelectField
+                name=Box::new(|| t!(main.database_sslmode)())
+                options=Box::new(database_sslmode_options)
+                required=true
+                value=form.get_value().database_sslmode
+            />
+        </TapFormPage>
+    }
+}
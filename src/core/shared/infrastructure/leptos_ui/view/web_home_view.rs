@@ -20,6 +20,13 @@ fn change_message(message_draft: &mut String) {
 
 #[component]
 pub fn WebHomeView() -> impl IntoView {
+    #[cfg(feature = "ssr")]
+    {
+        use crate::core::shared::leptos_actix_server::service::page_cache;
+
+        page_cache::allow_caching();
+    }
+
     let (message, set_message) = create_signal(DEFAULT_MESSAGE.to_string());
     let (count, set_count) = create_signal(DEFAULT_COUNT);
 
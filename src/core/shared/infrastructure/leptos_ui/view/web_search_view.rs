@@ -0,0 +1,32 @@
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::component;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::use_query_map;
+
+use crate::t;
+use super::super::WebLayout;
+
+#[component]
+pub fn WebSearchView() -> impl IntoView {
+    let url_query_map = use_query_map();
+
+    let query = move || url_query_map.get().get("q").cloned().unwrap_or_default();
+
+    view! {
+        <WebLayout>
+            <h1>{t!(main.search)}</h1>
+            <Show when=move || !query().trim().is_empty()>
+                <p class="core-shared-web_search_view-query">
+                    "\""
+                    {query}
+                    "\""
+                </p>
+            </Show>
+            // TODO: Search posts and pages when they exist
+            <p>{t!(main.no_results_found)}</p>
+        </WebLayout>
+    }
+}
@@ -7,6 +7,7 @@ use leptos_icons::BsIcon::BsArchiveFill;
 use leptos_icons::BsIcon::BsBlockquoteLeft;
 use leptos_icons::BsIcon::BsBookmarkStarFill;
 use leptos_icons::BsIcon::BsCloudDownloadFill;
+use leptos_icons::BsIcon::BsCloudUploadFill;
 use leptos_icons::BsIcon::BsCreditCardFill;
 use leptos_icons::BsIcon::BsDatabaseFill;
 use leptos_icons::BsIcon::BsEthernet;
@@ -19,12 +20,14 @@ use leptos_icons::BsIcon::BsKanbanFill;
 use leptos_icons::BsIcon::BsLayoutSidebarInsetReverse;
 use leptos_icons::BsIcon::BsLayoutTextWindow;
 use leptos_icons::BsIcon::BsLifePreserver;
+use leptos_icons::BsIcon::BsLightningChargeFill;
 use leptos_icons::BsIcon::BsList;
 use leptos_icons::BsIcon::BsListTask;
 use leptos_icons::BsIcon::BsPalette2;
 use leptos_icons::BsIcon::BsPeopleFill;
 use leptos_icons::BsIcon::BsPieChartFill;
 use leptos_icons::BsIcon::BsQuestionSquareFill;
+use leptos_icons::BsIcon::BsShieldLockFill;
 use leptos_icons::BsIcon::BsShop;
 use leptos_icons::BsIcon::BsStarFill;
 use leptos_icons::BsIcon::BsStars;
@@ -51,6 +54,11 @@ pub fn CloudDownload() -> impl IntoView {
     view! { <Icon icon=LeptosIcon::from(BsCloudDownloadFill) /> }
 }
 
+#[component]
+pub fn CloudUpload() -> impl IntoView {
+    view! { <Icon icon=LeptosIcon::from(BsCloudUploadFill) /> }
+}
+
 #[component]
 pub fn CreditCard() -> impl IntoView {
     view! { <Icon icon=LeptosIcon::from(BsCreditCardFill) /> }
@@ -115,6 +123,11 @@ pub fn LifeSaver() -> impl IntoView {
     view! { <Icon icon=LeptosIcon::from(BsLifePreserver) /> }
 }
 
+#[component]
+pub fn Lightning() -> impl IntoView {
+    view! { <Icon icon=LeptosIcon::from(BsLightningChargeFill) /> }
+}
+
 #[component]
 pub fn List() -> impl IntoView {
     view! { <Icon icon=LeptosIcon::from(BsListTask) /> }
@@ -140,6 +153,11 @@ pub fn QuestionSquare() -> impl IntoView {
     view! { <Icon icon=LeptosIcon::from(BsQuestionSquareFill) /> }
 }
 
+#[component]
+pub fn ShieldLock() -> impl IntoView {
+    view! { <Icon icon=LeptosIcon::from(BsShieldLockFill) /> }
+}
+
 #[component]
 pub fn Shop() -> impl IntoView {
     view! { <Icon icon=LeptosIcon::from(BsShop) /> }
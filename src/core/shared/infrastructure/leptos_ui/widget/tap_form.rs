@@ -1,4 +1,5 @@
 use leptos::Children;
+use leptos::For;
 #[cfg(feature = "ssr")]
 use leptos::IntoAttribute;
 #[cfg(feature = "ssr")]
@@ -7,6 +8,7 @@ use leptos::IntoView;
 use leptos::RwSignal;
 use leptos::Show;
 use leptos::SignalGet;
+use leptos::SignalGetUntracked;
 use leptos::SignalSet;
 use leptos::StoredValue;
 use leptos::component;
@@ -148,6 +150,121 @@ pub fn TapFormPage(
     }
 }
 
+// The current password is never sent to the browser, so the help text should
+// explain what happens when the field is left empty
+#[component]
+pub fn TapFormPasswordField(
+    help: Box<dyn Fn() -> String>,
+    name: Box<dyn Fn() -> String>,
+    required: bool,
+    value: StoredValue<FormFieldValue<String>>
+) -> impl IntoView {
+    view! {
+        <div class="field">
+            <label class="label">
+                {move || name()}
+                <Show when=move || required>
+                    "*"
+                </Show>
+            </label>
+            <p class="control">
+                <input
+                    autocomplete="new-password"
+                    class="input"
+                    class=(
+                        "is-danger",
+                        move || value.get_value().has_error(),
+                    )
+                    on:input=move |event| {
+                        value.get_value().set(event_target_value(&event));
+                    }
+                    prop:value=value.get_value().signal
+                    type="password"
+                />
+            </p>
+            <p class="help">{move || help()}</p>
+            <Show when=move || value.get_value().has_error()>
+                <p class="help is-danger">
+                    {move || {
+                        let domain_error = value.get_value().get_validation_error();
+                        match domain_error {
+                            Some(domain_error) => {
+                                let full_code = domain_error.get_full_code();
+                                t_error!(&full_code, &domain_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </p>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+pub fn TapFormSelectField(
+    name: Box<dyn Fn() -> String>,
+    options: Box<dyn Fn() -> Vec<(String, String)>>,
+    required: bool,
+    value: StoredValue<FormFieldValue<String>>
+) -> impl IntoView {
+    view! {
+        <div class="field">
+            <label class="label">
+                {move || name()}
+                <Show when=move || required>
+                    "*"
+                </Show>
+            </label>
+            <div
+                class="select"
+                class=(
+                    "is-danger",
+                    move || value.get_value().has_error(),
+                )
+            >
+                <select
+                    on:change=move |event| {
+                        value.get_value().set(event_target_value(&event));
+                    }
+                    prop:value=value.get_value().signal
+                >
+                    <For
+                        children=move |(option_value, option_text)| {
+                            let selected = option_value ==
+                                value.get_value().signal.get_untracked();
+                            view! {
+                                <option
+                                    selected=selected
+                                    value=option_value
+                                >
+                                    {option_text}
+                                </option>
+                            }
+                        }
+                        each=move || options()
+                        key=|(option_value, _)| option_value.clone()
+                    />
+                </select>
+            </div>
+            <Show when=move || value.get_value().has_error()>
+                <p class="help is-danger">
+                    {move || {
+                        let domain_error = value.get_value().get_validation_error();
+                        match domain_error {
+                            Some(domain_error) => {
+                                let full_code = domain_error.get_full_code();
+                                t_error!(&full_code, &domain_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </p>
+            </Show>
+        </div>
+    }
+}
+
 #[component]
 pub fn TapFormTextField(
     name: Box<dyn Fn() -> String>,
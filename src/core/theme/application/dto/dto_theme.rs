@@ -0,0 +1,41 @@
+use serde::Deserialize;
+use serde::Serialize;
+use tukosmo_domain::core::theme::model::Theme;
+use tukosmo_domain::core::theme::model::ThemeSlots;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoActivateTheme {
+    pub theme_code: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoEditThemeVariables {
+    pub theme_code: String,
+    pub variable_values: Vec<(String, String)>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetTheme {
+    pub theme_code: String,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DtoThemeDetails {
+    pub is_active: bool,
+    pub theme: Theme,
+    pub variable_values: Vec<(String, String)>,
+}
+
+// Everything the public layout needs from the active theme
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DtoThemeLayout {
+    pub revision: i64,
+    pub slots: ThemeSlots,
+    pub variable_values: Vec<(String, String)>,
+}
+
+#[derive(Clone, Deserialize, Serialize)]
+pub struct DtoThemes {
+    pub active_theme_code: String,
+    pub themes: Vec<Theme>,
+}
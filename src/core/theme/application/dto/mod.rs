@@ -0,0 +1,2 @@
+mod dto_theme;
+pub use dto_theme::*;
@@ -0,0 +1,4 @@
+#[cfg(feature = "ssr")]
+mod theme_use_case;
+#[cfg(feature = "ssr")]
+pub use theme_use_case::*;
@@ -0,0 +1,131 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::repository::PageCacheRepository;
+use tukosmo_domain::core::theme::model::ThemeCode;
+use tukosmo_domain::core::theme::repository::ThemeRepository;
+
+use super::super::dto::DtoActivateTheme;
+use super::super::dto::DtoEditThemeVariables;
+use super::super::dto::DtoGetTheme;
+use super::super::dto::DtoThemeDetails;
+use super::super::dto::DtoThemeLayout;
+use super::super::dto::DtoThemes;
+
+pub struct ThemeUseCase {
+    page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+    theme_repository: Rc<RefCell<dyn ThemeRepository>>,
+}
+
+impl ThemeUseCase {
+    pub fn activate(&self, dto: DtoActivateTheme) -> Result<(), DomainError> {
+        let theme_code = ThemeCode::from_string(&dto.theme_code)?;
+
+        let theme_repository = self.theme_repository.borrow_mut();
+        let theme = theme_repository.get(&theme_code)?;
+        let mut settings = theme_repository.get_settings()?;
+        let variable_values = settings.get_variable_values(&theme);
+
+        // Compile first, so a broken theme never becomes the active one
+        theme_repository.update_stylesheet(&theme, &variable_values)?;
+        settings.activate(&theme);
+        theme_repository.update_settings(&settings)?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn edit_variables(
+        &self,
+        dto: DtoEditThemeVariables
+    ) -> Result<(), DomainError> {
+        let theme_code = ThemeCode::from_string(&dto.theme_code)?;
+
+        let theme_repository = self.theme_repository.borrow_mut();
+        let theme = theme_repository.get(&theme_code)?;
+        let mut settings = theme_repository.get_settings()?;
+        settings.modify_variable_values(&theme, dto.variable_values)?;
+
+        let is_active_theme = settings.active_theme_code == theme.code;
+        if is_active_theme {
+            let variable_values = settings.get_variable_values(&theme);
+            theme_repository.update_stylesheet(&theme, &variable_values)?;
+        }
+        theme_repository.update_settings(&settings)?;
+
+        // The pages are rendered with the variables of the active theme
+        if is_active_theme {
+            self.page_cache_repository
+                .borrow_mut()
+                .invalidate(ContentChangeEvent::AllPages);
+        }
+
+        Ok(())
+    }
+
+    pub fn get_layout(&self) -> Result<DtoThemeLayout, DomainError> {
+        let theme_repository = self.theme_repository.borrow_mut();
+        let settings = theme_repository.get_settings()?;
+        let theme = theme_repository.get(&settings.active_theme_code)?;
+
+        Ok(DtoThemeLayout {
+            revision: settings.revision,
+            variable_values: settings.get_variable_values(&theme),
+            slots: theme.slots,
+        })
+    }
+
+    pub fn get_stylesheet(&self) -> Result<String, DomainError> {
+        let theme_repository = self.theme_repository.borrow_mut();
+        if let Some(stylesheet) = theme_repository.get_stylesheet()? {
+            return Ok(stylesheet);
+        }
+
+        // First request after installing or upgrading
+        let settings = theme_repository.get_settings()?;
+        let theme = theme_repository.get(&settings.active_theme_code)?;
+        let variable_values = settings.get_variable_values(&theme);
+        theme_repository.update_stylesheet(&theme, &variable_values)?;
+
+        Ok(theme_repository.get_stylesheet()?.unwrap_or_default())
+    }
+
+    pub fn get_theme(
+        &self,
+        dto: DtoGetTheme
+    ) -> Result<DtoThemeDetails, DomainError> {
+        let theme_code = ThemeCode::from_string(&dto.theme_code)?;
+
+        let theme_repository = self.theme_repository.borrow_mut();
+        let theme = theme_repository.get(&theme_code)?;
+        let settings = theme_repository.get_settings()?;
+
+        Ok(DtoThemeDetails {
+            is_active: settings.active_theme_code == theme.code,
+            variable_values: settings.get_variable_values(&theme),
+            theme,
+        })
+    }
+
+    pub fn get_themes(&self) -> Result<DtoThemes, DomainError> {
+        let theme_repository = self.theme_repository.borrow_mut();
+        let themes = theme_repository.find_all()?;
+        let settings = theme_repository.get_settings()?;
+
+        Ok(DtoThemes {
+            active_theme_code: settings.active_theme_code.value().to_string(),
+            themes,
+        })
+    }
+
+    pub fn init(
+        page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+        theme_repository: Rc<RefCell<dyn ThemeRepository>>
+    ) -> Self {
+        Self { page_cache_repository, theme_repository }
+    }
+}
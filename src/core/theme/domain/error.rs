@@ -0,0 +1,75 @@
+use crate::core::shared::model::CoreSubmoduleName::Theme;
+use crate::core::shared::model::DomainError;
+use crate::core::shared::model::DomainErrorId;
+use crate::core::shared::model::DomainErrorVisibility;
+use crate::core::shared::model::ModuleName::Core;
+
+pub const CANNOT_COMPILE_THEME_STYLESHEET: DomainError = get_domain_error(
+    "CANNOT_COMPILE_THEME_STYLESHEET",
+    "Cannot compile the theme's stylesheet.",
+    DomainErrorVisibility::Admin
+);
+
+pub const CANNOT_PARSE_THEME_CONFIG: DomainError = get_domain_error(
+    "CANNOT_PARSE_THEME_CONFIG",
+    "Cannot parse the theme's configuration file.",
+    DomainErrorVisibility::Admin
+);
+
+pub const INVALID_THEME_CODE: DomainError = get_domain_error(
+    "INVALID_THEME_CODE",
+    "The theme code can only contain lowercase letters, numbers, hyphens and underscores.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_THEME_COLOR: DomainError = get_domain_error(
+    "INVALID_THEME_COLOR",
+    "The colour must be written as #rrggbb.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_THEME_FONT: DomainError = get_domain_error(
+    "INVALID_THEME_FONT",
+    "The font can only contain letters, numbers, spaces, commas, hyphens and quotes.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_THEME_IMAGE: DomainError = get_domain_error(
+    "INVALID_THEME_IMAGE",
+    "The image must be a path starting with / or a URL starting with https://, without spaces.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_THEME_VARIABLE_KIND: DomainError = get_domain_error(
+    "INVALID_THEME_VARIABLE_KIND",
+    "Invalid theme variable kind.",
+    DomainErrorVisibility::Admin
+);
+
+pub const THEME_NOT_FOUND: DomainError = get_domain_error(
+    "THEME_NOT_FOUND",
+    "Theme not found.",
+    DomainErrorVisibility::Public
+);
+
+pub const UNKNOWN_THEME_VARIABLE: DomainError = get_domain_error(
+    "UNKNOWN_THEME_VARIABLE",
+    "Unknown theme variable.",
+    DomainErrorVisibility::Public
+);
+
+const fn get_domain_error(
+    error_code: &'static str,
+    message: &'static str,
+    visibility: DomainErrorVisibility
+) -> DomainError {
+    DomainError {
+        context: vec![],
+        id: DomainErrorId {
+            error_code,
+            module: Core(Theme),
+        },
+        message,
+        visibility,
+    }
+}
@@ -0,0 +1,5 @@
+mod theme;
+pub use theme::*;
+
+mod theme_settings;
+pub use theme_settings::*;
@@ -0,0 +1,191 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::core::shared::model::DomainError;
+use super::super::error;
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct Theme {
+    pub code: ThemeCode,
+    pub description: String,
+    pub name: String,
+    pub slots: ThemeSlots,
+    pub variables: Vec<ThemeVariable>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ThemeCode(String);
+
+// Each slot is an HTML template that replaces the built-in block of the
+// public layout (None keeps the built-in one)
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ThemeSlots {
+    pub footer: Option<String>,
+    pub header: Option<String>,
+    pub sidebar: Option<String>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ThemeVariable {
+    pub code: String,
+    pub default_value: String,
+    pub kind: ThemeVariableKind,
+    pub name: String,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ThemeVariableKind {
+    Color,
+    Font,
+    Image,
+}
+
+pub const THEME_CODE_DEFAULT: &'static str = "default";
+
+impl Theme {
+    pub fn get_variable(
+        &self,
+        variable_code: &str
+    ) -> Result<&ThemeVariable, DomainError> {
+        self.variables
+            .iter()
+            .find(|variable| variable.code == variable_code)
+            .ok_or(error::UNKNOWN_THEME_VARIABLE)
+    }
+}
+
+impl ThemeCode {
+    pub fn default() -> Self {
+        Self(THEME_CODE_DEFAULT.to_string())
+    }
+
+    pub fn from(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn from_string(value: &str) -> Result<Self, DomainError> {
+        // The code is also the name of the theme's directory
+        let is_valid =
+            !value.is_empty() &&
+            value
+                .chars()
+                .all(|c| {
+                    c.is_ascii_lowercase() ||
+                        c.is_ascii_digit() ||
+                        c == '-' ||
+                        c == '_'
+                });
+        if !is_valid {
+            return Err(error::INVALID_THEME_CODE);
+        }
+        Ok(Self(value.to_string()))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl ThemeVariableKind {
+    pub fn from_code(code: &str) -> Result<Self, DomainError> {
+        match code {
+            "color" => Ok(Self::Color),
+            "font" => Ok(Self::Font),
+            "image" => Ok(Self::Image),
+            _ => Err(error::INVALID_THEME_VARIABLE_KIND),
+        }
+    }
+
+    // Values end up inside the stylesheet and the slot templates, so only
+    // harmless characters are allowed
+    pub fn validate(&self, value: &String) -> Option<DomainError> {
+        match self {
+            Self::Color => {
+                let is_valid =
+                    value.len() == 7 &&
+                    value.starts_with('#') &&
+                    value[1..].chars().all(|c| c.is_ascii_hexdigit());
+                if is_valid { None } else { Some(error::INVALID_THEME_COLOR) }
+            }
+            Self::Font => {
+                let is_valid =
+                    !value.trim().is_empty() &&
+                    value
+                        .chars()
+                        .all(|c| {
+                            c.is_alphanumeric() ||
+                                c == ' ' ||
+                                c == ',' ||
+                                c == '-' ||
+                                c == '\'' ||
+                                c == '"'
+                        });
+                if is_valid { None } else { Some(error::INVALID_THEME_FONT) }
+            }
+            Self::Image => {
+                // An empty value means that there is no image
+                let is_valid =
+                    value.is_empty() ||
+                    ((value.starts_with('/') || value.starts_with("https://")) &&
+                        !value
+                            .chars()
+                            .any(|c| {
+                                c.is_whitespace() ||
+                                    "\"'()<>\\".contains(c)
+                            }));
+                if is_valid { None } else { Some(error::INVALID_THEME_IMAGE) }
+            }
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Color => "color",
+            Self::Font => "font",
+            Self::Image => "image",
+        }
+    }
+}
+
+// Placeholders are written as {{ name }}; unknown ones render as nothing
+pub fn render_theme_template(
+    template: &str,
+    values: &Vec<(String, String)>
+) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        rendered.push_str(&rest[..start]);
+        let after_start = &rest[start + 2..];
+        match after_start.find("}}") {
+            Some(end) => {
+                let name = after_start[..end].trim();
+                if
+                    let Some((_, value)) = values
+                        .iter()
+                        .find(|(code, _)| code == name)
+                {
+                    rendered.push_str(&escape_html(value));
+                }
+                rest = &after_start[end + 2..];
+            }
+            None => {
+                rendered.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    rendered.push_str(rest);
+
+    rendered
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
@@ -0,0 +1,86 @@
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::core::shared::model::DomainError;
+use super::Theme;
+use super::ThemeCode;
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ThemeSettings {
+    pub active_theme_code: ThemeCode,
+    // Changes on every modification, so browsers reload the stylesheet
+    pub revision: i64,
+    pub variable_values: Vec<ThemeVariableValue>,
+}
+
+// Values are kept per theme, so switching back and forth doesn't lose them
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct ThemeVariableValue {
+    pub theme_code: ThemeCode,
+    pub value: String,
+    pub variable_code: String,
+}
+
+impl ThemeSettings {
+    pub fn activate(&mut self, theme: &Theme) {
+        self.active_theme_code = theme.code.clone();
+        self.touch();
+    }
+
+    pub fn default() -> Self {
+        Self {
+            active_theme_code: ThemeCode::default(),
+            revision: 0,
+            variable_values: vec![],
+        }
+    }
+
+    pub fn get_variable_values(&self, theme: &Theme) -> Vec<(String, String)> {
+        theme.variables
+            .iter()
+            .map(|variable| {
+                let value = self.variable_values
+                    .iter()
+                    .find(|variable_value| {
+                        variable_value.theme_code == theme.code &&
+                            variable_value.variable_code == variable.code
+                    })
+                    .map(|variable_value| variable_value.value.clone())
+                    .unwrap_or(variable.default_value.clone());
+                (variable.code.clone(), value)
+            })
+            .collect()
+    }
+
+    pub fn modify_variable_values(
+        &mut self,
+        theme: &Theme,
+        values: Vec<(String, String)>
+    ) -> Result<(), DomainError> {
+        let mut new_variable_values = vec![];
+        for (variable_code, value) in values {
+            let variable = theme.get_variable(&variable_code)?;
+            let value = value.trim().to_string();
+            if let Some(validation_error) = variable.kind.validate(&value) {
+                return Err(validation_error);
+            }
+            new_variable_values.push(ThemeVariableValue {
+                theme_code: theme.code.clone(),
+                value,
+                variable_code,
+            });
+        }
+
+        self.variable_values.retain(|variable_value| {
+            variable_value.theme_code != theme.code
+        });
+        self.variable_values.append(&mut new_variable_values);
+        self.touch();
+        Ok(())
+    }
+
+    fn touch(&mut self) {
+        self.revision = Utc::now().timestamp_millis();
+    }
+}
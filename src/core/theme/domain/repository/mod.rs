@@ -0,0 +1,2 @@
+mod theme_repository;
+pub use theme_repository::*;
@@ -0,0 +1,23 @@
+use crate::core::shared::model::DomainError;
+use super::super::model::Theme;
+use super::super::model::ThemeCode;
+use super::super::model::ThemeSettings;
+
+pub trait ThemeRepository {
+    fn find_all(&self) -> Result<Vec<Theme>, DomainError>;
+
+    fn get(&self, theme_code: &ThemeCode) -> Result<Theme, DomainError>;
+
+    fn get_settings(&self) -> Result<ThemeSettings, DomainError>;
+
+    // None until the stylesheet of the active theme has been compiled
+    fn get_stylesheet(&self) -> Result<Option<String>, DomainError>;
+
+    fn update_settings(&self, settings: &ThemeSettings) -> Result<(), DomainError>;
+
+    fn update_stylesheet(
+        &self,
+        theme: &Theme,
+        variable_values: &Vec<(String, String)>
+    ) -> Result<(), DomainError>;
+}
@@ -0,0 +1,91 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::theme::dto::DtoActivateTheme;
+use tukosmo_application::core::theme::dto::DtoEditThemeVariables;
+use tukosmo_application::core::theme::dto::DtoGetTheme;
+use tukosmo_application::core::theme::dto::DtoThemeDetails;
+use tukosmo_application::core::theme::dto::DtoThemeLayout;
+use tukosmo_application::core::theme::dto::DtoThemes;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::theme::use_case::ThemeUseCase;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+
+#[server(ApiCoreThemeThemeActivate)]
+pub async fn activate(
+    dto: DtoActivateTheme
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let theme_use_case = common()?;
+        theme_use_case.activate(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreThemeThemeEditVariables)]
+pub async fn edit_variables(
+    dto: DtoEditThemeVariables
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let theme_use_case = common()?;
+        theme_use_case.edit_variables(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreThemeThemeGet)]
+pub async fn get(
+    dto: DtoGetTheme
+) -> Result<ServerResponse<DtoThemeDetails>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let theme_use_case = common()?;
+        theme_use_case.get_theme(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreThemeThemeLayout)]
+pub async fn layout() -> Result<ServerResponse<DtoThemeLayout>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let theme_use_case = common()?;
+        theme_use_case.get_layout()
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreThemeThemeList)]
+pub async fn list() -> Result<ServerResponse<DtoThemes>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let theme_use_case = common()?;
+        theme_use_case.get_themes()
+    });
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn common() -> Result<ThemeUseCase, DomainError> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::core::shared::leptos_actix_server::repository::MemoryPageCacheRepository;
+    use crate::core::shared::leptos_actix_server::service::page_cache;
+    use super::super::repository::FsThemeRepository;
+
+    let page_cache_repository = MemoryPageCacheRepository::init(
+        page_cache::get_page_cache()
+    );
+    let theme_repository = FsThemeRepository::init()?;
+
+    let theme_use_case = ThemeUseCase::init(
+        Rc::new(RefCell::new(page_cache_repository)),
+        Rc::new(RefCell::new(theme_repository))
+    );
+
+    Ok(theme_use_case)
+}
@@ -0,0 +1,55 @@
+use actix_files::NamedFile;
+use actix_web::HttpResponse;
+use actix_web::get;
+use actix_web::web;
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_application::core::theme::use_case::ThemeUseCase;
+use tukosmo_domain::core::shared::model::DomainError;
+
+use crate::core::shared::leptos_actix_server::repository::MemoryPageCacheRepository;
+use super::super::repository::FsThemeRepository;
+
+// Only reads the theme, so there's no page to invalidate
+fn common() -> Result<ThemeUseCase, DomainError> {
+    let page_cache_repository = MemoryPageCacheRepository::init(None);
+    let theme_repository = FsThemeRepository::init()?;
+
+    let theme_use_case = ThemeUseCase::init(
+        Rc::new(RefCell::new(page_cache_repository)),
+        Rc::new(RefCell::new(theme_repository))
+    );
+
+    Ok(theme_use_case)
+}
+
+#[get("/theme/assets/{asset_path:.*}")]
+pub async fn asset(path: web::Path<String>) -> actix_web::Result<NamedFile> {
+    let asset_path = path.into_inner();
+    let asset_file_path = FsThemeRepository::init()
+        .and_then(|theme_repository| {
+            theme_repository.get_active_theme_asset_file_path(&asset_path)
+        })
+        .map_err(|_e| actix_web::error::ErrorNotFound("Not Found"))?;
+
+    Ok(NamedFile::open(asset_file_path)?)
+}
+
+#[get("/theme/style.css")]
+pub async fn stylesheet() -> HttpResponse {
+    let result = common().and_then(|theme_use_case| {
+        theme_use_case.get_stylesheet()
+    });
+
+    // The built-in styles are enough to render the website, so a broken
+    // theme only loses its customizations
+    let stylesheet = match result {
+        Ok(stylesheet) => stylesheet,
+        Err(domain_error) => {
+            log::warn!("Cannot load the theme's stylesheet: {}", domain_error);
+            "".to_string()
+        }
+    };
+
+    HttpResponse::Ok().content_type("text/css; charset=utf-8").body(stylesheet)
+}
@@ -0,0 +1,5 @@
+pub mod api;
+#[cfg(feature = "ssr")]
+pub mod handler;
+#[cfg(feature = "ssr")]
+pub mod repository;
@@ -0,0 +1,287 @@
+use serde::Deserialize;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::theme::error;
+use tukosmo_domain::core::theme::model::Theme;
+use tukosmo_domain::core::theme::model::ThemeCode;
+use tukosmo_domain::core::theme::model::ThemeSettings;
+use tukosmo_domain::core::theme::model::ThemeSlots;
+use tukosmo_domain::core::theme::model::ThemeVariable;
+use tukosmo_domain::core::theme::model::ThemeVariableKind;
+use tukosmo_domain::core::theme::repository::ThemeRepository;
+
+use crate::core::shared::leptos_actix_server::repository::FsDataRepository;
+use crate::core::shared::leptos_actix_server::service::file_system;
+use crate::core::shared::leptos_actix_server::service::toml;
+
+// Each theme is a directory inside <data_dir>/themes:
+//
+//   <theme_code>/theme.toml       name, description, slots and variables
+//   <theme_code>/style.scss       compiled with the variables prepended
+//   <theme_code>/templates/       header, footer and sidebar slots (HTML)
+//   <theme_code>/assets/          served under /theme/assets
+
+#[derive(Clone)]
+pub struct FsThemeRepository {
+    pub settings_file_path: String,
+    pub stylesheet_file_path: String,
+    pub themes_dir_path: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FsThemeConfig {
+    #[serde(default)]
+    description: String,
+    name: String,
+    #[serde(default)]
+    slots: FsThemeSlotsConfig,
+    #[serde(default)]
+    variables: Vec<FsThemeVariableConfig>,
+}
+
+#[derive(Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FsThemeSlotsConfig {
+    footer: Option<String>,
+    header: Option<String>,
+    sidebar: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FsThemeVariableConfig {
+    code: String,
+    default: String,
+    kind: String,
+    name: String,
+}
+
+const ASSETS_DIR_NAME: &'static str = "assets";
+const CONFIG_FILE_NAME: &'static str = "theme.toml";
+const STYLESHEET_SOURCE_FILE_NAME: &'static str = "style.scss";
+
+impl ThemeRepository for FsThemeRepository {
+    fn find_all(&self) -> Result<Vec<Theme>, DomainError> {
+        let directory_names = file_system::list_directory_names(
+            &self.themes_dir_path
+        )?;
+
+        // Directories that aren't valid themes are ignored, so one broken
+        // theme doesn't hide the others
+        let themes = directory_names
+            .iter()
+            .filter_map(|directory_name| {
+                ThemeCode::from_string(directory_name).ok()
+            })
+            .filter_map(|theme_code| self.get(&theme_code).ok())
+            .collect();
+        Ok(themes)
+    }
+
+    fn get(&self, theme_code: &ThemeCode) -> Result<Theme, DomainError> {
+        let theme_dir_path = self.theme_dir_path(theme_code);
+        let config_file_path = format!(
+            "{}/{}",
+            theme_dir_path,
+            CONFIG_FILE_NAME
+        );
+        let config_file_exists = file_system::check_file_exists(
+            &config_file_path
+        )?;
+        if !config_file_exists {
+            return Err(error::THEME_NOT_FOUND);
+        }
+
+        let config: FsThemeConfig = toml
+            ::parse_file(&config_file_path)
+            .map_err(|_e| error::CANNOT_PARSE_THEME_CONFIG)?;
+
+        let mut variables = vec![];
+        for variable_config in config.variables {
+            // Codes are used as SCSS variable names
+            let code_is_valid =
+                !variable_config.code.is_empty() &&
+                variable_config.code
+                    .chars()
+                    .all(|c| {
+                        c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'
+                    });
+            if !code_is_valid {
+                return Err(error::CANNOT_PARSE_THEME_CONFIG);
+            }
+            let kind = ThemeVariableKind::from_code(&variable_config.kind)?;
+            if kind.validate(&variable_config.default).is_some() {
+                return Err(error::CANNOT_PARSE_THEME_CONFIG);
+            }
+            variables.push(ThemeVariable {
+                code: variable_config.code,
+                default_value: variable_config.default,
+                kind,
+                name: variable_config.name,
+            });
+        }
+
+        Ok(Theme {
+            code: theme_code.clone(),
+            description: config.description,
+            name: config.name,
+            slots: ThemeSlots {
+                footer: self.read_template(
+                    &theme_dir_path,
+                    &config.slots.footer
+                )?,
+                header: self.read_template(
+                    &theme_dir_path,
+                    &config.slots.header
+                )?,
+                sidebar: self.read_template(
+                    &theme_dir_path,
+                    &config.slots.sidebar
+                )?,
+            },
+            variables,
+        })
+    }
+
+    fn get_settings(&self) -> Result<ThemeSettings, DomainError> {
+        let settings_file_exists = file_system::check_file_exists(
+            &self.settings_file_path
+        )?;
+        if !settings_file_exists {
+            return Ok(ThemeSettings::default());
+        }
+
+        let settings: ThemeSettings = toml::parse_file(
+            &self.settings_file_path
+        )?;
+        Ok(settings)
+    }
+
+    fn get_stylesheet(&self) -> Result<Option<String>, DomainError> {
+        let stylesheet_file_exists = file_system::check_file_exists(
+            &self.stylesheet_file_path
+        )?;
+        if !stylesheet_file_exists {
+            return Ok(None);
+        }
+
+        let stylesheet = file_system::read_file_as_string(
+            &self.stylesheet_file_path
+        )?;
+        Ok(Some(stylesheet))
+    }
+
+    fn update_settings(
+        &self,
+        settings: &ThemeSettings
+    ) -> Result<(), DomainError> {
+        toml::write_file(&self.settings_file_path, settings)?;
+        Ok(())
+    }
+
+    fn update_stylesheet(
+        &self,
+        theme: &Theme,
+        variable_values: &Vec<(String, String)>
+    ) -> Result<(), DomainError> {
+        let theme_dir_path = self.theme_dir_path(&theme.code);
+        let source_file_path = format!(
+            "{}/{}",
+            theme_dir_path,
+            STYLESHEET_SOURCE_FILE_NAME
+        );
+        let source_file_exists = file_system::check_file_exists(
+            &source_file_path
+        )?;
+        let source = if source_file_exists {
+            file_system::read_file_as_string(&source_file_path)?
+        } else {
+            "".to_string()
+        };
+
+        let mut scss = String::new();
+        for (variable_code, value) in variable_values {
+            let variable = theme.get_variable(variable_code)?;
+            let scss_value = match variable.kind {
+                ThemeVariableKind::Image => format!("\"{}\"", value),
+                _ => value.clone(),
+            };
+            scss.push_str(&format!("${}: {};\n", variable_code, scss_value));
+        }
+        scss.push_str(&source);
+
+        let options = grass::Options
+            ::default()
+            .load_path(&theme_dir_path)
+            .style(grass::OutputStyle::Compressed);
+        let css = grass
+            ::from_string(scss, &options)
+            .map_err(|_e| error::CANNOT_COMPILE_THEME_STYLESHEET)?;
+
+        file_system::write_file(&self.stylesheet_file_path, &css)?;
+        Ok(())
+    }
+}
+
+impl FsThemeRepository {
+    // Assets always come from the active theme, so switching themes doesn't
+    // require restarting the server
+    pub fn get_active_theme_asset_file_path(
+        &self,
+        asset_path: &str
+    ) -> Result<String, DomainError> {
+        let path_is_safe = !asset_path
+            .split('/')
+            .any(|component| component == ".." || component.is_empty());
+        if !path_is_safe {
+            return Err(error::THEME_NOT_FOUND);
+        }
+
+        let settings = self.get_settings()?;
+        Ok(
+            format!(
+                "{}/{}/{}",
+                self.theme_dir_path(&settings.active_theme_code),
+                ASSETS_DIR_NAME,
+                asset_path
+            )
+        )
+    }
+
+    pub fn init() -> Result<FsThemeRepository, DomainError> {
+        let fs_data_repository = FsDataRepository::init()?;
+        let themes_dir_path = fs_data_repository.themes_dir_path;
+
+        let settings_file_path = format!("{}/settings.toml", &themes_dir_path);
+        let stylesheet_file_path = format!("{}/active.css", &themes_dir_path);
+
+        Ok(FsThemeRepository {
+            settings_file_path,
+            stylesheet_file_path,
+            themes_dir_path,
+        })
+    }
+
+    fn read_template(
+        &self,
+        theme_dir_path: &str,
+        template_path: &Option<String>
+    ) -> Result<Option<String>, DomainError> {
+        match template_path {
+            Some(template_path) => {
+                if template_path.split('/').any(|component| component == "..") {
+                    return Err(error::CANNOT_PARSE_THEME_CONFIG);
+                }
+                let template = file_system::read_file_as_string(
+                    &format!("{}/{}", theme_dir_path, template_path)
+                )?;
+                Ok(Some(template))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn theme_dir_path(&self, theme_code: &ThemeCode) -> String {
+        format!("{}/{}", self.themes_dir_path, theme_code.value())
+    }
+}
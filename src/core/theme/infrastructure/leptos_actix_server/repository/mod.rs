@@ -0,0 +1,2 @@
+mod fs_theme_repository;
+pub use fs_theme_repository::*;
@@ -0,0 +1,2 @@
+mod theme_form;
+pub use theme_form::*;
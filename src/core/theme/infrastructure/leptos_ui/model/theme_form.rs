@@ -0,0 +1,85 @@
+use leptos::SignalGetUntracked;
+use leptos::StoredValue;
+use leptos::store_value;
+use tukosmo_application::core::theme::dto::DtoEditThemeVariables;
+use tukosmo_application::core::theme::dto::DtoThemeDetails;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::theme::model::ThemeCode;
+use tukosmo_domain::core::theme::model::ThemeVariable;
+use tukosmo_domain::core::theme::model::ThemeVariableKind;
+
+use crate::core::shared::leptos_ui::FormFieldValue;
+
+#[derive(Clone)]
+pub struct ThemeForm {
+    pub theme_code: ThemeCode,
+    pub variables: Vec<ThemeFormVariable>,
+}
+
+#[derive(Clone)]
+pub struct ThemeFormVariable {
+    pub value: StoredValue<FormFieldValue<String>>,
+    pub variable: ThemeVariable,
+}
+
+impl ThemeForm {
+    pub fn get_dto(&self) -> DtoEditThemeVariables {
+        DtoEditThemeVariables {
+            theme_code: self.theme_code.value().to_string(),
+            variable_values: self.variables
+                .iter()
+                .map(|form_variable| {
+                    (
+                        form_variable.variable.code.clone(),
+                        form_variable.value.get_value().signal.get_untracked(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn init_filled(theme_details: DtoThemeDetails) -> StoredValue<Self> {
+        let variables = theme_details.theme.variables
+            .into_iter()
+            .map(|variable| {
+                let initial_value = theme_details.variable_values
+                    .iter()
+                    .find(|(code, _)| code == &variable.code)
+                    .map(|(_, value)| value.clone())
+                    .unwrap_or(variable.default_value.clone());
+                let value = FormFieldValue::init(
+                    initial_value,
+                    validate_function(variable.kind)
+                );
+                ThemeFormVariable { value, variable }
+            })
+            .collect();
+
+        store_value(Self {
+            theme_code: theme_details.theme.code,
+            variables,
+        })
+    }
+
+    pub fn validate(&self) {
+        for form_variable in self.variables.iter() {
+            form_variable.value.get_value().validate();
+        }
+    }
+}
+
+fn validate_function(
+    kind: ThemeVariableKind
+) -> fn(&String) -> Option<DomainError> {
+    match kind {
+        ThemeVariableKind::Color => |value| {
+            ThemeVariableKind::Color.validate(value)
+        },
+        ThemeVariableKind::Font => |value| {
+            ThemeVariableKind::Font.validate(value)
+        },
+        ThemeVariableKind::Image => |value| {
+            ThemeVariableKind::Image.validate(value)
+        },
+    }
+}
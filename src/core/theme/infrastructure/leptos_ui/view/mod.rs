@@ -0,0 +1,5 @@
+mod tap_edit_theme_view;
+pub use tap_edit_theme_view::*;
+
+mod tap_themes_view;
+pub use tap_themes_view::*;
@@ -0,0 +1,169 @@
+use leptos::For;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::NavigateOptions;
+use leptos_router::use_navigate;
+use leptos_router::use_params_map;
+use tukosmo_application::core::theme::dto::DtoGetTheme;
+use tukosmo_application::core::theme::dto::DtoThemeDetails;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapFormPage;
+use crate::core::shared::leptos_ui::TapLayout;
+use crate::core::shared::leptos_ui::TapLoadingError;
+use crate::core::shared::leptos_ui::TapLoadingLeptosError;
+use crate::core::shared::leptos_ui::TapLoadingResource;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::core::theme::leptos_actix_server::api::theme_api;
+use crate::t;
+use super::super::TapThemeVariableField;
+use super::super::ThemeForm;
+use super::super::ThemeFormVariable;
+
+#[component]
+pub fn TapEditThemeView() -> impl IntoView {
+    let params = use_params_map();
+
+    let response_data = create_resource(
+        move || params.get(),
+        move |params| async move {
+            let theme_code = params.get("id").unwrap();
+
+            let dto = DtoGetTheme { theme_code: theme_code.to_string() };
+            let result = theme_api::get(dto).await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(theme_details) => {
+                            view! {
+                                <div>
+                                    <TapEditThemeViewContent
+                                        theme_details=theme_details
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapEditThemeViewContent(theme_details: DtoThemeDetails) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+
+    let theme_name = store_value(theme_details.theme.name.clone());
+    let form = ThemeForm::init_filled(theme_details);
+    let theme_has_variables = !form.get_value().variables.is_empty();
+
+    let on_click_submit_button = move |_| {
+        waiting_response_signal.set(true);
+        let current_language_code = current_language_reader.get().code;
+
+        spawn_local(async move {
+            let dto = form.get_value().get_dto();
+            let server_response_edit = theme_api::edit_variables(dto).await;
+
+            manage_response(
+                server_response_edit,
+                move |_response| {
+                    let navigate = use_navigate();
+                    let path = navigation::path_admin_themes(
+                        &current_language_code
+                    );
+                    navigate(&path, NavigateOptions::default());
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    server_error_signal.set(Some(server_error));
+
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <TapFormPage
+            cancel_route_path=Box::new(move || navigation::path_admin_themes(
+                &current_language_reader.get().code
+            ))
+            on_click_submit_button=Box::new(on_click_submit_button)
+            server_error_signal=server_error_signal
+            title=Box::new(move || format!(
+                "{}: {}",
+                t!(main.customize_theme)(),
+                theme_name.get_value()
+            ))
+            waiting_response_signal=waiting_response_signal
+        >
+            // TODO
+            //<input name="csrf_token" type="hidden" value=csrf_token />
+
+            <Show when=move || !theme_has_variables>
+                <p class="has-text-grey">
+                    {t!(main.this_theme_has_no_customizable_variables)}
+                </p>
+            </Show>
+
+            <For
+                children=move |form_variable: ThemeFormVariable| {
+                    view! {
+                        <TapThemeVariableField
+                            value=form_variable.value
+                            variable=form_variable.variable
+                        />
+                    }
+                }
+                each=move || form.get_value().variables
+                key=|form_variable| form_variable.variable.code.clone()
+            />
+        </TapFormPage>
+    }
+}
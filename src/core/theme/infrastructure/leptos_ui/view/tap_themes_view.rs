@@ -0,0 +1,240 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::RwSignal;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalGetUntracked;
+use leptos::SignalSet;
+use leptos::SignalUpdate;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::theme::dto::DtoActivateTheme;
+use tukosmo_application::core::theme::dto::DtoThemes;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+use tukosmo_domain::core::theme::model::Theme;
+
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapLayout;
+use crate::core::shared::leptos_ui::TapLoadingError;
+use crate::core::shared::leptos_ui::TapLoadingLeptosError;
+use crate::core::shared::leptos_ui::TapLoadingResource;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::core::theme::leptos_actix_server::api::theme_api;
+use crate::t;
+use crate::t_error;
+
+#[derive(Clone, Copy)]
+struct TapThemesState {
+    reload_signal: RwSignal<i64>,
+    server_error_signal: RwSignal<Option<ServerResponseError>>,
+    waiting_response_signal: RwSignal<bool>,
+}
+
+#[component]
+pub fn TapThemesView() -> impl IntoView {
+    let state = TapThemesState {
+        reload_signal: create_rw_signal(0),
+        server_error_signal: create_rw_signal(None),
+        waiting_response_signal: create_rw_signal(false),
+    };
+
+    let response_data = create_resource(
+        move || state.reload_signal.get(),
+        move |_| async move {
+            let result = theme_api::list().await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(dto_themes) => {
+                            state.waiting_response_signal.set(false);
+                            view! {
+                                <div>
+                                    <TapThemesViewContent
+                                        dto_themes=dto_themes
+                                        state=state
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapThemesViewContent(
+    dto_themes: DtoThemes,
+    state: TapThemesState
+) -> impl IntoView {
+    let themes_are_empty = dto_themes.themes.is_empty();
+    let active_theme_code = store_value(dto_themes.active_theme_code);
+    let themes = store_value(dto_themes.themes);
+
+    view! {
+        <div class="box is-marginless mb-6">
+            <h1 class="title">{t!(main.theme)}</h1>
+
+            <Show when=move || state.server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| state.server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = state.server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <Show
+                fallback=move || view! {
+                    <table class="table is-bordered is-hoverable is-fullwidth">
+                        <thead>
+                            <tr>
+                                <th>{t!(main.theme)}</th>
+                                <th>{t!(main.description)}</th>
+                                <th></th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            <For
+                                children=move |theme: Theme| {
+                                    let is_active = theme.code.value() ==
+                                        active_theme_code.get_value();
+                                    view! {
+                                        <TapThemesViewRow
+                                            is_active=is_active
+                                            state=state
+                                            theme=theme
+                                        />
+                                    }
+                                }
+                                each=move || themes.get_value()
+                                key=|theme| theme.code.value().to_string()
+                            />
+                        </tbody>
+                    </table>
+                }
+                when=move || themes_are_empty
+            >
+                <p class="has-text-grey">
+                    {t!(main.there_are_no_themes_in_the_data_directory)}
+                </p>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn TapThemesViewRow(
+    is_active: bool,
+    state: TapThemesState,
+    theme: Theme
+) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let theme = store_value(theme);
+
+    let on_click_activate_button = move |_| {
+        // Only one request at a time
+        if state.waiting_response_signal.get_untracked() {
+            return;
+        }
+        state.waiting_response_signal.set(true);
+
+        spawn_local(async move {
+            let dto = DtoActivateTheme {
+                theme_code: theme.get_value().code.value().to_string(),
+            };
+            let server_response_activate = theme_api::activate(dto).await;
+
+            manage_response(
+                server_response_activate,
+                move |_response| {
+                    state.reload_signal.update(|draft| *draft += 1);
+                },
+                move |server_error| {
+                    state.server_error_signal.set(Some(server_error));
+                    state.waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <tr>
+            <td>
+                <a
+                    href=move || navigation::path_admin_themes_edit(
+                        &current_language_reader.get().code,
+                        &theme.get_value().code
+                    )
+                >
+                    {move || theme.get_value().name}
+                </a>
+            </td>
+            <td>{move || theme.get_value().description}</td>
+            <td>
+                <Show
+                    fallback=move || view! {
+                        <button
+                            class="button is-small is-link is-light"
+                            disabled=move || state.waiting_response_signal.get()
+                            on:click=on_click_activate_button
+                        >
+                            {t!(main.activate)}
+                        </button>
+                    }
+                    when=move || is_active
+                >
+                    <span class="tag is-success">{t!(main.active)}</span>
+                </Show>
+            </td>
+        </tr>
+    }
+}
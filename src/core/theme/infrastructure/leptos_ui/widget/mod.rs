@@ -0,0 +1,2 @@
+mod tap_theme_variable_field;
+pub use tap_theme_variable_field::*;
@@ -0,0 +1,83 @@
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+#[cfg(feature = "ssr")]
+use leptos::IntoClass;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::StoredValue;
+use leptos::component;
+use leptos::event_target_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_domain::core::theme::model::ThemeVariable;
+use tukosmo_domain::core::theme::model::ThemeVariableKind;
+
+use crate::core::shared::leptos_ui::FormFieldValue;
+use crate::t;
+use crate::t_error;
+
+#[component]
+pub fn TapThemeVariableField(
+    value: StoredValue<FormFieldValue<String>>,
+    variable: ThemeVariable
+) -> impl IntoView {
+    let kind = variable.kind;
+    let is_color = kind == ThemeVariableKind::Color;
+    let help = move || {
+        match kind {
+            ThemeVariableKind::Color => t!(main.theme_color_help)(),
+            ThemeVariableKind::Font => t!(main.theme_font_help)(),
+            ThemeVariableKind::Image => t!(main.theme_image_help)(),
+        }
+    };
+
+    view! {
+        <div class="field">
+            <label class="label">{variable.name}</label>
+            <div class="field has-addons is-marginless">
+                <Show when=move || is_color>
+                    <div class="control">
+                        <input
+                            class="input core-theme-tap_theme_variable_field-color"
+                            on:input=move |event| {
+                                value.get_value().set(event_target_value(&event));
+                            }
+                            prop:value=value.get_value().signal
+                            type="color"
+                        />
+                    </div>
+                </Show>
+                <div class="control is-expanded">
+                    <input
+                        class="input"
+                        class=(
+                            "is-danger",
+                            move || value.get_value().has_error(),
+                        )
+                        on:input=move |event| {
+                            value.get_value().set(event_target_value(&event));
+                        }
+                        prop:value=value.get_value().signal
+                        type="text"
+                    />
+                </div>
+            </div>
+            <p class="help">{help}</p>
+            <Show when=move || value.get_value().has_error()>
+                <p class="help is-danger">
+                    {move || {
+                        let domain_error = value.get_value().get_validation_error();
+                        match domain_error {
+                            Some(domain_error) => {
+                                let full_code = domain_error.get_full_code();
+                                t_error!(&full_code, &domain_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </p>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,3 @@
+pub mod leptos_actix_server;
+
+pub mod leptos_ui;
@@ -0,0 +1,51 @@
+use serde::Deserialize;
+use serde::Serialize;
+use tukosmo_domain::core::language::model::I18nTextValue;
+
+use crate::core::shared::dto::DtoDeleteForm;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoAddWidgetInstance {
+    pub form: DtoWidgetInstanceForm,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoDeleteWidgetInstance {
+    pub form: DtoDeleteForm,
+    pub widget_instance_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoEditWidgetInstance {
+    pub form: DtoWidgetInstanceForm,
+    pub widget_instance_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetVisibleWidgetInstances {
+    pub area: String,
+    pub language_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoGetWidgetInstance {
+    pub widget_instance_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoReorderWidgetInstances {
+    pub area: String,
+    pub widget_instance_ids: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DtoWidgetInstanceForm {
+    pub area: String,
+    pub code: String,
+    pub content: I18nTextValue,
+    // An empty list means that the widget is visible in every language
+    pub language_ids: Vec<String>,
+    pub limit: String,
+    pub title: I18nTextValue,
+}
+
@@ -0,0 +1,2 @@
+mod dto_widget_instance;
+pub use dto_widget_instance::*;
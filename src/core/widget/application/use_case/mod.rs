@@ -0,0 +1,4 @@
+#[cfg(feature = "ssr")]
+mod widget_instance_use_case;
+#[cfg(feature = "ssr")]
+pub use widget_instance_use_case::*;
@@ -0,0 +1,252 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::language::model::LanguageId;
+use tukosmo_domain::core::shared::error as error_shared;
+use tukosmo_domain::core::shared::model::ContentChangeEvent;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::TransactionExecutor;
+use tukosmo_domain::core::shared::repository::PageCacheRepository;
+use tukosmo_domain::core::widget::error;
+use tukosmo_domain::core::widget::model::WidgetArea;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+use tukosmo_domain::core::widget::model::WidgetInstanceId;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchCriteria;
+
+use crate::core::shared::service::run_transaction;
+use super::super::dto::DtoAddWidgetInstance;
+use super::super::dto::DtoDeleteWidgetInstance;
+use super::super::dto::DtoEditWidgetInstance;
+use super::super::dto::DtoGetVisibleWidgetInstances;
+use super::super::dto::DtoGetWidgetInstance;
+use super::super::dto::DtoReorderWidgetInstances;
+
+pub struct WidgetInstanceUseCase {
+    page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+    transaction_executor: Rc<RefCell<dyn TransactionExecutor>>,
+}
+
+impl WidgetInstanceUseCase {
+    pub fn add(&self, dto: DtoAddWidgetInstance) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let area = WidgetArea::from_code(&dto.form.area)?;
+            let language_ids = Self::parse_language_ids(&dto.form.language_ids)?;
+
+            let mut widget_instance_repository =
+                transaction.widget_instance_repository.borrow_mut();
+
+            // New widgets go to the end of their area
+            let position = widget_instance_repository.count(
+                WidgetInstanceSearchCriteria::has_area(area).filter
+            )?;
+            let widget_instance = WidgetInstance::new(
+                area,
+                dto.form.code,
+                dto.form.content,
+                language_ids,
+                &dto.form.limit,
+                position as i32,
+                dto.form.title
+            )?;
+
+            widget_instance_repository.add(widget_instance)?;
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn delete(
+        &self,
+        dto: DtoDeleteWidgetInstance
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let widget_instance_id = WidgetInstanceId::from_string(
+                &dto.widget_instance_id
+            )?;
+
+            let mut widget_instance_repository =
+                transaction.widget_instance_repository.borrow_mut();
+
+            if !dto.form.requested {
+                return Err(error_shared::FIELD_CANNOT_BE_EMPTY);
+            }
+            widget_instance_repository.delete(widget_instance_id)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn edit(&self, dto: DtoEditWidgetInstance) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let widget_instance_id = WidgetInstanceId::from_string(
+                &dto.widget_instance_id
+            )?;
+            let area = WidgetArea::from_code(&dto.form.area)?;
+            let language_ids = Self::parse_language_ids(&dto.form.language_ids)?;
+
+            let mut widget_instance_repository =
+                transaction.widget_instance_repository.borrow_mut();
+
+            let mut widget_instance = widget_instance_repository.get(
+                widget_instance_id
+            )?;
+            if widget_instance.area != area {
+                let position = widget_instance_repository.count(
+                    WidgetInstanceSearchCriteria::has_area(area).filter
+                )?;
+                widget_instance.move_to(position as i32);
+            }
+            widget_instance.modify(
+                area,
+                dto.form.code,
+                dto.form.content,
+                language_ids,
+                &dto.form.limit,
+                dto.form.title
+            )?;
+
+            widget_instance_repository.update(widget_instance)?;
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+
+    pub fn get(
+        &self,
+        dto: DtoGetWidgetInstance
+    ) -> Result<WidgetInstance, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let widget_instance_id = WidgetInstanceId::from_string(
+                &dto.widget_instance_id
+            )?;
+
+            let mut widget_instance_repository =
+                transaction.widget_instance_repository.borrow_mut();
+            let widget_instance = widget_instance_repository.get(
+                widget_instance_id
+            )?;
+
+            Ok(widget_instance)
+        })
+    }
+
+    pub fn get_visible_widget_instances(
+        &self,
+        dto: DtoGetVisibleWidgetInstances
+    ) -> Result<Vec<WidgetInstance>, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let area = WidgetArea::from_code(&dto.area)?;
+            let language_id = LanguageId::from_string(&dto.language_id)?;
+
+            let mut widget_instance_repository =
+                transaction.widget_instance_repository.borrow_mut();
+            let widget_instances = widget_instance_repository.find(
+                WidgetInstanceSearchCriteria::has_area(area)
+            )?;
+
+            Ok(
+                widget_instances
+                    .into_iter()
+                    .filter(|widget_instance| {
+                        widget_instance.is_visible_in(&language_id)
+                    })
+                    .collect()
+            )
+        })
+    }
+
+    pub fn get_widget_instances(
+        &self
+    ) -> Result<Vec<WidgetInstance>, DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let mut widget_instance_repository =
+                transaction.widget_instance_repository.borrow_mut();
+            let widget_instances = widget_instance_repository.find(
+                WidgetInstanceSearchCriteria::all()
+            )?;
+
+            Ok(widget_instances)
+        })
+    }
+
+    pub fn init(
+        page_cache_repository: Rc<RefCell<dyn PageCacheRepository>>,
+        transaction_executor: Rc<RefCell<dyn TransactionExecutor>>
+    ) -> Self {
+        Self { page_cache_repository, transaction_executor }
+    }
+
+    fn parse_language_ids(
+        language_ids: &Vec<String>
+    ) -> Result<Vec<LanguageId>, DomainError> {
+        language_ids
+            .iter()
+            .map(|language_id| LanguageId::from_string(language_id))
+            .collect()
+    }
+
+    pub fn reorder(
+        &self,
+        dto: DtoReorderWidgetInstances
+    ) -> Result<(), DomainError> {
+        run_transaction(self.transaction_executor.borrow_mut(), |transaction| {
+            let area = WidgetArea::from_code(&dto.area)?;
+            let widget_instance_ids = dto.widget_instance_ids
+                .iter()
+                .map(|id| WidgetInstanceId::from_string(id))
+                .collect::<Result<Vec<WidgetInstanceId>, DomainError>>()?;
+
+            let mut widget_instance_repository =
+                transaction.widget_instance_repository.borrow_mut();
+
+            let widget_instances = widget_instance_repository.find(
+                WidgetInstanceSearchCriteria::has_area(area)
+            )?;
+            let ids_match =
+                widget_instances.len() == widget_instance_ids.len() &&
+                widget_instances
+                    .iter()
+                    .all(|widget_instance| {
+                        widget_instance_ids.contains(&widget_instance.id)
+                    });
+            if !ids_match {
+                return Err(error::WIDGET_INSTANCES_DONT_MATCH);
+            }
+
+            for mut widget_instance in widget_instances {
+                let position = widget_instance_ids
+                    .iter()
+                    .position(|id| id == &widget_instance.id)
+                    .unwrap();
+                if widget_instance.position.value() != &(position as i32) {
+                    widget_instance.move_to(position as i32);
+                    widget_instance_repository.update(widget_instance)?;
+                }
+            }
+
+            Ok(())
+        })?;
+
+        self.page_cache_repository
+            .borrow_mut()
+            .invalidate(ContentChangeEvent::AllPages);
+
+        Ok(())
+    }
+}
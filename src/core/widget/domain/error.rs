@@ -0,0 +1,51 @@
+use crate::core::shared::model::CoreSubmoduleName::Widget;
+use crate::core::shared::model::DomainError;
+use crate::core::shared::model::DomainErrorId;
+use crate::core::shared::model::DomainErrorVisibility;
+use crate::core::shared::model::ModuleName::Core;
+
+pub const INVALID_WIDGET_AREA: DomainError = get_domain_error(
+    "INVALID_WIDGET_AREA",
+    "Invalid widget area.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_WIDGET_CODE: DomainError = get_domain_error(
+    "INVALID_WIDGET_CODE",
+    "Unknown widget.",
+    DomainErrorVisibility::Public
+);
+
+pub const INVALID_WIDGET_LIMIT: DomainError = get_domain_error(
+    "INVALID_WIDGET_LIMIT",
+    "The number of elements must be between 1 and 50.",
+    DomainErrorVisibility::Public
+);
+
+pub const WIDGET_INSTANCES_DONT_MATCH: DomainError = get_domain_error(
+    "WIDGET_INSTANCES_DONT_MATCH",
+    "The given widgets don't match the widgets of the area.",
+    DomainErrorVisibility::Public
+);
+
+pub const WIDGET_INSTANCE_NOT_FOUND: DomainError = get_domain_error(
+    "WIDGET_INSTANCE_NOT_FOUND",
+    "Widget not found.",
+    DomainErrorVisibility::Public
+);
+
+const fn get_domain_error(
+    error_code: &'static str,
+    message: &'static str,
+    visibility: DomainErrorVisibility
+) -> DomainError {
+    DomainError {
+        context: vec![],
+        id: DomainErrorId {
+            error_code,
+            module: Core(Widget),
+        },
+        message,
+        visibility,
+    }
+}
@@ -0,0 +1,5 @@
+mod widget;
+pub use widget::*;
+
+mod widget_instance;
+pub use widget_instance::*;
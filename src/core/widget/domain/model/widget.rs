@@ -0,0 +1,92 @@
+use crate::core::shared::model::DomainError;
+use super::super::error as error_widget;
+
+pub const WIDGET_CODE_LANGUAGE_SWITCHER: &'static str = "language_switcher";
+pub const WIDGET_CODE_RECENT_POSTS: &'static str = "recent_posts";
+pub const WIDGET_CODE_SEARCH_BOX: &'static str = "search_box";
+pub const WIDGET_CODE_TAG_CLOUD: &'static str = "tag_cloud";
+pub const WIDGET_CODE_TEXT: &'static str = "text";
+
+// A kind of widget that can be placed in the widget areas of the website.
+// Its instances are stored as `WidgetInstance` and only use the settings the
+// widget declares here; the rendering is done by the UI, based on the code
+pub trait Widget {
+    fn code(&self) -> &'static str;
+
+    fn uses_content(&self) -> bool {
+        false
+    }
+
+    fn uses_limit(&self) -> bool {
+        false
+    }
+}
+
+pub struct LanguageSwitcherWidget;
+
+pub struct RecentPostsWidget;
+
+pub struct SearchBoxWidget;
+
+pub struct TagCloudWidget;
+
+pub struct TextWidget;
+
+impl Widget for LanguageSwitcherWidget {
+    fn code(&self) -> &'static str {
+        WIDGET_CODE_LANGUAGE_SWITCHER
+    }
+}
+
+impl Widget for RecentPostsWidget {
+    fn code(&self) -> &'static str {
+        WIDGET_CODE_RECENT_POSTS
+    }
+
+    fn uses_limit(&self) -> bool {
+        true
+    }
+}
+
+impl Widget for SearchBoxWidget {
+    fn code(&self) -> &'static str {
+        WIDGET_CODE_SEARCH_BOX
+    }
+}
+
+impl Widget for TagCloudWidget {
+    fn code(&self) -> &'static str {
+        WIDGET_CODE_TAG_CLOUD
+    }
+
+    fn uses_limit(&self) -> bool {
+        true
+    }
+}
+
+impl Widget for TextWidget {
+    fn code(&self) -> &'static str {
+        WIDGET_CODE_TEXT
+    }
+
+    fn uses_content(&self) -> bool {
+        true
+    }
+}
+
+pub fn get_built_in_widgets() -> Vec<Box<dyn Widget>> {
+    vec![
+        Box::new(TextWidget),
+        Box::new(RecentPostsWidget),
+        Box::new(TagCloudWidget),
+        Box::new(LanguageSwitcherWidget),
+        Box::new(SearchBoxWidget)
+    ]
+}
+
+pub fn get_widget(code: &str) -> Result<Box<dyn Widget>, DomainError> {
+    get_built_in_widgets()
+        .into_iter()
+        .find(|widget| widget.code() == code)
+        .ok_or(error_widget::INVALID_WIDGET_CODE)
+}
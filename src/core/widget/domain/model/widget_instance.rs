@@ -0,0 +1,312 @@
+use chrono::DateTime;
+use chrono::Utc;
+use serde::Deserialize;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::core::language::model::I18nText;
+use crate::core::language::model::I18nTextValue;
+use crate::core::language::model::LanguageId;
+use crate::core::shared::error;
+use crate::core::shared::model::DomainError;
+use super::super::error as error_widget;
+use super::Widget;
+use super::get_widget;
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct WidgetInstance {
+    pub area: WidgetArea,
+    pub code: WidgetCode,
+    pub content: I18nText,
+    pub creation_date: WidgetInstanceCreationDate,
+    pub id: WidgetInstanceId,
+    // An empty list means that the widget is visible in every language
+    pub language_ids: Vec<LanguageId>,
+    pub limit: WidgetLimit,
+    pub position: WidgetPosition,
+    pub title: I18nText,
+    pub update_date: WidgetInstanceUpdateDate,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum WidgetArea {
+    Footer,
+    Sidebar,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct WidgetCode(String);
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct WidgetInstanceCreationDate(DateTime<Utc>);
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct WidgetInstanceId(Uuid);
+
+#[derive(Clone)]
+pub struct WidgetInstanceSearchCriteria {
+    pub filter: WidgetInstanceSearchFilterCriteria,
+    pub order_by: Option<WidgetInstanceSearchCriteriaOrderBy>,
+}
+
+#[derive(Clone)]
+pub enum WidgetInstanceSearchCriteriaOrderBy {
+    AreaAndPosition,
+}
+
+#[derive(Clone)]
+pub struct WidgetInstanceSearchFilterCriteria {
+    pub area: Option<WidgetArea>,
+    pub id: Option<WidgetInstanceId>,
+}
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct WidgetInstanceUpdateDate(DateTime<Utc>);
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct WidgetLimit(i32);
+
+#[derive(Clone, Deserialize, PartialEq, Serialize)]
+pub struct WidgetPosition(i32);
+
+const WIDGET_LIMIT_MAX: i32 = 50;
+
+impl WidgetArea {
+    pub fn from_code(code: &str) -> Result<Self, DomainError> {
+        match code {
+            "footer" => Ok(Self::Footer),
+            "sidebar" => Ok(Self::Sidebar),
+            _ => Err(error_widget::INVALID_WIDGET_AREA),
+        }
+    }
+
+    pub fn value(&self) -> &'static str {
+        match self {
+            Self::Footer => "footer",
+            Self::Sidebar => "sidebar",
+        }
+    }
+}
+
+impl WidgetCode {
+    pub fn from(value: String) -> Self {
+        Self(value)
+    }
+
+    pub fn get_widget(&self) -> Result<Box<dyn Widget>, DomainError> {
+        get_widget(&self.0)
+    }
+
+    fn new(value: String) -> Result<Self, DomainError> {
+        get_widget(&value)?;
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> &str {
+        &self.0
+    }
+}
+
+impl WidgetInstance {
+    pub fn is_visible_in(&self, language_id: &LanguageId) -> bool {
+        self.language_ids.is_empty() || self.language_ids.contains(language_id)
+    }
+
+    pub fn modify(
+        &mut self,
+        area: WidgetArea,
+        code_value: String,
+        content_value: I18nTextValue,
+        language_ids: Vec<LanguageId>,
+        limit_value: &String,
+        title_value: I18nTextValue
+    ) -> Result<(), DomainError> {
+        self.area = area;
+        self.code = WidgetCode::new(code_value)?;
+        self.content.modify(
+            content_value,
+            Self::validate_content_value,
+            Self::validate_content_value
+        )?;
+        self.language_ids = language_ids;
+        self.limit = WidgetLimit::new(limit_value)?;
+        self.title.modify(
+            title_value,
+            Self::validate_title_value,
+            Self::validate_title_value
+        )?;
+        self.update_date = WidgetInstanceUpdateDate::new();
+        Ok(())
+    }
+
+    pub fn move_to(&mut self, position_value: i32) {
+        self.position = WidgetPosition::from(position_value);
+        self.update_date = WidgetInstanceUpdateDate::new();
+    }
+
+    pub fn new(
+        area: WidgetArea,
+        code_value: String,
+        content_value: I18nTextValue,
+        language_ids: Vec<LanguageId>,
+        limit_value: &String,
+        position_value: i32,
+        title_value: I18nTextValue
+    ) -> Result<Self, DomainError> {
+        let code = WidgetCode::new(code_value)?;
+        let content = I18nText::new(
+            content_value,
+            Self::validate_content_value,
+            Self::validate_content_value
+        )?;
+        let limit = WidgetLimit::new(limit_value)?;
+        let title = I18nText::new(
+            title_value,
+            Self::validate_title_value,
+            Self::validate_title_value
+        )?;
+        Ok(Self {
+            area,
+            code,
+            content,
+            creation_date: WidgetInstanceCreationDate::new(),
+            id: WidgetInstanceId::new(),
+            language_ids,
+            limit,
+            position: WidgetPosition::from(position_value),
+            title,
+            update_date: WidgetInstanceUpdateDate::new(),
+        })
+    }
+
+    // The content is HTML written by administrators, so it isn't escaped
+    pub fn validate_content_value(_value: &String) -> Option<DomainError> {
+        None
+    }
+
+    // Widgets can be shown without a title
+    pub fn validate_title_value(_value: &String) -> Option<DomainError> {
+        None
+    }
+}
+
+impl WidgetInstanceCreationDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl WidgetInstanceId {
+    pub fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+
+    pub fn from_string(value: &str) -> Result<Self, DomainError> {
+        match Uuid::parse_str(value) {
+            Ok(value) => Ok(Self(value)),
+            Err(_) => Err(error::INVALID_UUID),
+        }
+    }
+
+    fn new() -> Self {
+        let value = Uuid::new_v4();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &Uuid {
+        &self.0
+    }
+}
+
+impl WidgetInstanceSearchCriteria {
+    pub fn all() -> Self {
+        let mut criteria = Self::default();
+        criteria.order_by = Some(
+            WidgetInstanceSearchCriteriaOrderBy::AreaAndPosition
+        );
+        criteria
+    }
+
+    fn default() -> Self {
+        Self {
+            filter: WidgetInstanceSearchFilterCriteria { area: None, id: None },
+            order_by: None,
+        }
+    }
+
+    pub fn has_area(area: WidgetArea) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.area = Some(area);
+        criteria.order_by = Some(
+            WidgetInstanceSearchCriteriaOrderBy::AreaAndPosition
+        );
+        criteria
+    }
+
+    pub fn has_id(widget_instance_id: WidgetInstanceId) -> Self {
+        let mut criteria = Self::default();
+        criteria.filter.id = Some(widget_instance_id);
+        criteria
+    }
+}
+
+impl WidgetInstanceUpdateDate {
+    pub fn from(value: DateTime<Utc>) -> Self {
+        Self(value)
+    }
+
+    fn new() -> Self {
+        let value = Utc::now();
+        Self(value)
+    }
+
+    pub fn value(&self) -> &DateTime<Utc> {
+        &self.0
+    }
+}
+
+impl WidgetLimit {
+    pub fn from(value: i32) -> Self {
+        Self(value)
+    }
+
+    fn new(value: &String) -> Result<Self, DomainError> {
+        match Self::validate(value) {
+            Some(validation_error) => Err(validation_error),
+            None => Ok(Self(value.trim().parse::<i32>().unwrap())),
+        }
+    }
+
+    pub fn validate(value: &String) -> Option<DomainError> {
+        if 0 == value.trim().len() {
+            return Some(error::FIELD_CANNOT_BE_EMPTY);
+        }
+        match value.trim().parse::<i32>() {
+            Ok(limit) if limit >= 1 && limit <= WIDGET_LIMIT_MAX => None,
+            _ => Some(error_widget::INVALID_WIDGET_LIMIT),
+        }
+    }
+
+    pub fn value(&self) -> &i32 {
+        &self.0
+    }
+}
+
+impl WidgetPosition {
+    pub fn from(value: i32) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> &i32 {
+        &self.0
+    }
+}
@@ -0,0 +1,34 @@
+use crate::core::shared::model::DomainError;
+use super::super::model::WidgetInstance;
+use super::super::model::WidgetInstanceId;
+use super::super::model::WidgetInstanceSearchCriteria;
+use super::super::model::WidgetInstanceSearchFilterCriteria;
+
+pub trait WidgetInstanceRepository {
+    fn add(&mut self, widget_instance: WidgetInstance) -> Result<(), DomainError>;
+
+    fn count(
+        &mut self,
+        criteria: WidgetInstanceSearchFilterCriteria
+    ) -> Result<i64, DomainError>;
+
+    fn delete(
+        &mut self,
+        widget_instance_id: WidgetInstanceId
+    ) -> Result<(), DomainError>;
+
+    fn find(
+        &mut self,
+        criteria: WidgetInstanceSearchCriteria
+    ) -> Result<Vec<WidgetInstance>, DomainError>;
+
+    fn get(
+        &mut self,
+        widget_instance_id: WidgetInstanceId
+    ) -> Result<WidgetInstance, DomainError>;
+
+    fn update(
+        &mut self,
+        widget_instance: WidgetInstance
+    ) -> Result<(), DomainError>;
+}
@@ -0,0 +1,95 @@
+use chrono::DateTime;
+use chrono::Utc;
+use diesel::pg::Pg;
+use diesel::prelude::AsChangeset;
+use diesel::prelude::Identifiable;
+use diesel::prelude::Insertable;
+use diesel::prelude::Queryable;
+use diesel::prelude::Selectable;
+use tukosmo_domain::core::language::model::I18nText;
+use tukosmo_domain::core::language::model::LanguageId;
+use tukosmo_domain::core::widget::model::WidgetArea;
+use tukosmo_domain::core::widget::model::WidgetCode;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+use tukosmo_domain::core::widget::model::WidgetInstanceCreationDate;
+use tukosmo_domain::core::widget::model::WidgetInstanceId;
+use tukosmo_domain::core::widget::model::WidgetInstanceUpdateDate;
+use tukosmo_domain::core::widget::model::WidgetLimit;
+use tukosmo_domain::core::widget::model::WidgetPosition;
+use uuid::Uuid;
+
+use crate::core::shared::diesel_orm::schema::widget_instance;
+
+#[derive(
+    AsChangeset,
+    Clone,
+    Debug,
+    Identifiable,
+    Insertable,
+    PartialEq,
+    Queryable,
+    Selectable
+)]
+#[diesel(table_name = widget_instance)]
+#[diesel(check_for_backend(Pg))]
+pub struct DbWidgetInstance {
+    pub area: String,
+    pub code: String,
+    pub creation_date: DateTime<Utc>,
+    pub i18n_text_id_content: Uuid,
+    pub i18n_text_id_title: Uuid,
+    pub id: Uuid,
+    pub item_limit: i32,
+    pub language_ids: Vec<Uuid>,
+    pub position: i32,
+    pub update_date: DateTime<Utc>,
+}
+
+impl DbWidgetInstance {
+    pub fn from_domain(widget_instance: WidgetInstance) -> Self {
+        Self {
+            area: widget_instance.area.value().to_string(),
+            code: widget_instance.code.value().to_string(),
+            creation_date: widget_instance.creation_date.value().clone(),
+            i18n_text_id_content: widget_instance.content.id.value().clone(),
+            i18n_text_id_title: widget_instance.title.id.value().clone(),
+            id: widget_instance.id.value().clone(),
+            item_limit: widget_instance.limit.value().clone(),
+            language_ids: widget_instance.language_ids
+                .iter()
+                .map(|l| l.value().clone())
+                .collect(),
+            position: widget_instance.position.value().clone(),
+            update_date: widget_instance.update_date.value().clone(),
+        }
+    }
+
+    pub fn to_domain(
+        self,
+        widget_instance_content: I18nText,
+        widget_instance_title: I18nText
+    ) -> WidgetInstance {
+        WidgetInstance {
+            // Areas are validated before being stored
+            area: WidgetArea::from_code(&self.area).unwrap_or(
+                WidgetArea::Sidebar
+            ),
+            code: WidgetCode::from(self.code.clone()),
+            content: widget_instance_content,
+            creation_date: WidgetInstanceCreationDate::from(
+                self.creation_date.clone()
+            ),
+            id: WidgetInstanceId::from(self.id.clone()),
+            language_ids: self.language_ids
+                .iter()
+                .map(|l| LanguageId::from(l.clone()))
+                .collect(),
+            limit: WidgetLimit::from(self.item_limit.clone()),
+            position: WidgetPosition::from(self.position.clone()),
+            title: widget_instance_title,
+            update_date: WidgetInstanceUpdateDate::from(
+                self.update_date.clone()
+            ),
+        }
+    }
+}
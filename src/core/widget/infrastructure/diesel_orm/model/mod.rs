@@ -0,0 +1,2 @@
+mod db_widget_instance;
+pub use db_widget_instance::*;
@@ -0,0 +1,71 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+use tukosmo_domain::core::widget::model::WidgetInstanceId;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchCriteria;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchFilterCriteria;
+use tukosmo_domain::core::widget::repository::WidgetInstanceRepository;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use super::super::service::WidgetInstanceManager;
+
+pub struct DbWidgetInstanceRepository {
+    widget_instance: WidgetInstanceManager,
+}
+
+impl DbWidgetInstanceRepository {
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { widget_instance: WidgetInstanceManager::init(connection) }
+    }
+}
+
+impl WidgetInstanceRepository for DbWidgetInstanceRepository {
+    fn add(
+        &mut self,
+        widget_instance: WidgetInstance
+    ) -> Result<(), DomainError> {
+        self.widget_instance.add(widget_instance)?;
+        Ok(())
+    }
+
+    fn count(
+        &mut self,
+        filter_criteria: WidgetInstanceSearchFilterCriteria
+    ) -> Result<i64, DomainError> {
+        let total = self.widget_instance.count(filter_criteria)?;
+        Ok(total)
+    }
+
+    fn delete(
+        &mut self,
+        widget_instance_id: WidgetInstanceId
+    ) -> Result<(), DomainError> {
+        self.widget_instance.delete(widget_instance_id)?;
+        Ok(())
+    }
+
+    fn find(
+        &mut self,
+        search_criteria: WidgetInstanceSearchCriteria
+    ) -> Result<Vec<WidgetInstance>, DomainError> {
+        let widget_instances = self.widget_instance.find(search_criteria)?;
+        Ok(widget_instances)
+    }
+
+    fn get(
+        &mut self,
+        widget_instance_id: WidgetInstanceId
+    ) -> Result<WidgetInstance, DomainError> {
+        let widget_instance = self.widget_instance.get(widget_instance_id)?;
+        Ok(widget_instance)
+    }
+
+    fn update(
+        &mut self,
+        widget_instance: WidgetInstance
+    ) -> Result<(), DomainError> {
+        self.widget_instance.update(widget_instance)?;
+        Ok(())
+    }
+}
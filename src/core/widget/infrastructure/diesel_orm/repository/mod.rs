@@ -0,0 +1,2 @@
+mod db_widget_instance_repository;
+pub use db_widget_instance_repository::*;
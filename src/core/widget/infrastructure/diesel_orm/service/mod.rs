@@ -0,0 +1,2 @@
+mod widget_instance_manager;
+pub use widget_instance_manager::*;
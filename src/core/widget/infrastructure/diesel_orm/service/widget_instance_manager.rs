@@ -0,0 +1,131 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use tukosmo_domain::core::language::model::I18nTextId;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::widget::error;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+use tukosmo_domain::core::widget::model::WidgetInstanceId;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchCriteria;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchFilterCriteria;
+
+use crate::core::language::diesel_orm::service::I18nTextManager;
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbWidgetInstance;
+use super::super::statement::WidgetInstanceSqlExecutor;
+
+pub struct WidgetInstanceManager {
+    i18n_text_manager: I18nTextManager,
+    widget_instance: WidgetInstanceSqlExecutor,
+}
+
+impl WidgetInstanceManager {
+    pub fn add(
+        &mut self,
+        widget_instance: WidgetInstance
+    ) -> Result<(), DomainError> {
+        self.i18n_text_manager.add(widget_instance.content.clone())?;
+        self.i18n_text_manager.add(widget_instance.title.clone())?;
+
+        let db_widget_instance = DbWidgetInstance::from_domain(widget_instance);
+        self.widget_instance.insert(db_widget_instance)?;
+
+        Ok(())
+    }
+
+    pub fn count(
+        &mut self,
+        filter_criteria: WidgetInstanceSearchFilterCriteria
+    ) -> Result<i64, DomainError> {
+        let total = self.widget_instance.select_count(filter_criteria)?;
+        Ok(total)
+    }
+
+    pub fn delete(
+        &mut self,
+        widget_instance_id: WidgetInstanceId
+    ) -> Result<(), DomainError> {
+        let widget_instance = self.get(widget_instance_id)?;
+
+        self.widget_instance.delete(
+            WidgetInstanceSearchCriteria::has_id(widget_instance.id).filter
+        )?;
+        self.i18n_text_manager.delete(widget_instance.content.id)?;
+        self.i18n_text_manager.delete(widget_instance.title.id)?;
+
+        Ok(())
+    }
+
+    pub fn find(
+        &mut self,
+        search_criteria: WidgetInstanceSearchCriteria
+    ) -> Result<Vec<WidgetInstance>, DomainError> {
+        let db_widget_instances = self.widget_instance.select(search_criteria)?;
+
+        let mut i18n_text_ids: Vec<I18nTextId> = vec![];
+        for db_widget_instance in db_widget_instances.iter() {
+            i18n_text_ids.push(
+                I18nTextId::from(db_widget_instance.i18n_text_id_content.clone())
+            );
+            i18n_text_ids.push(
+                I18nTextId::from(db_widget_instance.i18n_text_id_title.clone())
+            );
+        }
+        let i18n_texts = self.i18n_text_manager.get_in_bulk(i18n_text_ids)?;
+
+        let mut widget_instances: Vec<WidgetInstance> = vec![];
+        for db_widget_instance in db_widget_instances {
+            let content = i18n_texts
+                .iter()
+                .find(|t| t.id.value() == &db_widget_instance.i18n_text_id_content)
+                .unwrap()
+                .clone();
+            let title = i18n_texts
+                .iter()
+                .find(|t| t.id.value() == &db_widget_instance.i18n_text_id_title)
+                .unwrap()
+                .clone();
+
+            let widget_instance = db_widget_instance.to_domain(content, title);
+            widget_instances.push(widget_instance);
+        }
+
+        Ok(widget_instances)
+    }
+
+    pub fn get(
+        &mut self,
+        widget_instance_id: WidgetInstanceId
+    ) -> Result<WidgetInstance, DomainError> {
+        let widget_instances = self.find(
+            WidgetInstanceSearchCriteria::has_id(widget_instance_id)
+        )?;
+        let widget_instance = widget_instances
+            .into_iter()
+            .next()
+            .ok_or(error::WIDGET_INSTANCE_NOT_FOUND)?;
+        Ok(widget_instance)
+    }
+
+    pub fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self {
+            i18n_text_manager: I18nTextManager::init(Rc::clone(&connection)),
+            widget_instance: WidgetInstanceSqlExecutor::init(connection),
+        }
+    }
+
+    pub fn update(
+        &mut self,
+        widget_instance: WidgetInstance
+    ) -> Result<(), DomainError> {
+        let db_widget_instance = DbWidgetInstance::from_domain(
+            widget_instance.clone()
+        );
+        self.widget_instance.update(&db_widget_instance)?;
+
+        self.i18n_text_manager.update(widget_instance.content)?;
+        self.i18n_text_manager.update(widget_instance.title)?;
+
+        Ok(())
+    }
+}
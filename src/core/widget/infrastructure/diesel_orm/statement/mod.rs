@@ -0,0 +1,2 @@
+mod widget_instance_sql_executor;
+pub use widget_instance_sql_executor::*;
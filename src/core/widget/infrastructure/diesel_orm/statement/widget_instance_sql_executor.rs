@@ -0,0 +1,206 @@
+use diesel::ExpressionMethods;
+use diesel::QueryDsl;
+use diesel::RunQueryDsl;
+use diesel::SelectableHelper;
+use diesel::pg::Pg;
+use diesel::pg::PgConnection;
+use diesel::query_builder::BoxedSelectStatement;
+use diesel::query_builder::FromClause;
+use diesel;
+use std::cell::RefCell;
+use std::ops::DerefMut;
+use std::rc::Rc;
+use tukosmo_domain::core::shared::error;
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchCriteria;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchCriteriaOrderBy;
+use tukosmo_domain::core::widget::model::WidgetInstanceSearchFilterCriteria;
+
+use crate::core::shared::diesel_orm::model::DbConnection;
+use crate::core::shared::diesel_orm::schema::widget_instance;
+use crate::core::shared::diesel_orm::statement::ModelSqlExecutor;
+use super::super::model::DbWidgetInstance;
+
+pub struct WidgetInstanceSqlExecutor {
+    connection: Rc<RefCell<DbConnection>>,
+}
+
+impl ModelSqlExecutor<
+    DbWidgetInstance,
+    (
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Text,
+        diesel::sql_types::Text,
+        diesel::sql_types::Integer,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Uuid,
+        diesel::sql_types::Integer,
+        diesel::sql_types::Array<diesel::sql_types::Uuid>,
+        diesel::sql_types::Timestamptz,
+        diesel::sql_types::Timestamptz,
+    ),
+    WidgetInstanceSearchCriteria,
+    WidgetInstanceSearchFilterCriteria,
+    widget_instance::table
+>
+for WidgetInstanceSqlExecutor {
+    fn delete(
+        &mut self,
+        filter_criteria: WidgetInstanceSearchFilterCriteria
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut statement = diesel::delete(widget_instance::table).into_boxed();
+
+        if let Some(area) = filter_criteria.area {
+            statement = statement.filter(
+                widget_instance::area.eq(area.value())
+            );
+        }
+        if let Some(widget_instance_id) = filter_criteria.id {
+            statement = statement.filter(
+                widget_instance::id.eq(widget_instance_id.value().clone())
+            );
+        }
+
+        let result = statement.execute(connection);
+
+        match result {
+            Ok(_affected_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_DELETE_ON_DATABASE),
+        }
+    }
+
+    fn get_connection(&mut self) -> Rc<RefCell<DbConnection>> {
+        Rc::clone(&self.connection)
+    }
+
+    fn get_table() -> widget_instance::table {
+        widget_instance::table
+    }
+
+    fn init(connection: Rc<RefCell<DbConnection>>) -> Self {
+        Self { connection }
+    }
+
+    fn select(
+        &mut self,
+        search_criteria: WidgetInstanceSearchCriteria
+    ) -> Result<Vec<DbWidgetInstance>, DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let mut query = Self::select_query(search_criteria.filter);
+        if let Some(order_by) = search_criteria.order_by {
+            match order_by {
+                WidgetInstanceSearchCriteriaOrderBy::AreaAndPosition => {
+                    query = query.order((
+                        widget_instance::area.asc(),
+                        widget_instance::position.asc(),
+                    ));
+                }
+            }
+        }
+
+        let results = query
+            .select(DbWidgetInstance::as_select())
+            .load(connection)
+            .map_err(|_e| error::CANNOT_EXECUTE_SELECT_ON_DATABASE)?;
+
+        Ok(results)
+    }
+
+    fn select_query<'a>(
+        filter_criteria: WidgetInstanceSearchFilterCriteria
+    ) -> BoxedSelectStatement<
+        'a,
+        (
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Text,
+            diesel::sql_types::Text,
+            diesel::sql_types::Integer,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Uuid,
+            diesel::sql_types::Integer,
+            diesel::sql_types::Array<diesel::sql_types::Uuid>,
+            diesel::sql_types::Timestamptz,
+            diesel::sql_types::Timestamptz,
+        ),
+        FromClause<widget_instance::table>,
+        Pg
+    > {
+        let mut query = widget_instance::table.into_boxed();
+
+        if let Some(area) = filter_criteria.area {
+            query = query.filter(widget_instance::area.eq(area.value()));
+        }
+        if let Some(widget_instance_id) = filter_criteria.id {
+            query = query.filter(
+                widget_instance::id.eq(widget_instance_id.value().clone())
+            );
+        }
+
+        query
+    }
+
+    fn update(
+        &mut self,
+        db_widget_instance: &DbWidgetInstance
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::update(widget_instance::table.find(db_widget_instance.id))
+            .set(db_widget_instance)
+            .execute(connection);
+
+        match result {
+            Ok(0) => Err(error::NOTHING_TO_UPDATE_ON_DATABASE),
+            Ok(1) => Ok(()),
+            Ok(_) => Err(error::UNDESIRED_UPDATES_ON_DATABASE),
+            Err(_e) => Err(error::CANNOT_EXECUTE_UPDATE_ON_DATABASE),
+        }
+    }
+
+    fn upsert_in_bulk(
+        &mut self,
+        db_widget_instances: Vec<DbWidgetInstance>
+    ) -> Result<(), DomainError> {
+        let mut connection = self.connection.borrow_mut();
+        let connection: &mut PgConnection = connection.deref_mut();
+
+        let result = diesel
+            ::insert_into(widget_instance::table)
+            .values(db_widget_instances)
+            .on_conflict(widget_instance::id)
+            .do_update()
+            .set((
+                widget_instance::area.eq(
+                    diesel::pg::upsert::excluded(widget_instance::area)
+                ),
+                widget_instance::code.eq(
+                    diesel::pg::upsert::excluded(widget_instance::code)
+                ),
+                widget_instance::item_limit.eq(
+                    diesel::pg::upsert::excluded(widget_instance::item_limit)
+                ),
+                widget_instance::language_ids.eq(
+                    diesel::pg::upsert::excluded(widget_instance::language_ids)
+                ),
+                widget_instance::position.eq(
+                    diesel::pg::upsert::excluded(widget_instance::position)
+                ),
+                widget_instance::update_date.eq(
+                    diesel::pg::upsert::excluded(widget_instance::update_date)
+                ),
+            ))
+            .execute(connection);
+
+        match result {
+            Ok(_inserted_rows) => Ok(()),
+            Err(_e) => Err(error::CANNOT_EXECUTE_INSERT_ON_DATABASE),
+        }
+    }
+}
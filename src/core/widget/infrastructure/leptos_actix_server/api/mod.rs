@@ -0,0 +1 @@
+pub mod widget_instance_api;
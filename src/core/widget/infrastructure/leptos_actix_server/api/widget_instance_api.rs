@@ -0,0 +1,122 @@
+use leptos::ServerFnError;
+use leptos::server;
+use tukosmo_application::core::widget::dto::DtoAddWidgetInstance;
+use tukosmo_application::core::widget::dto::DtoDeleteWidgetInstance;
+use tukosmo_application::core::widget::dto::DtoEditWidgetInstance;
+use tukosmo_application::core::widget::dto::DtoGetVisibleWidgetInstances;
+use tukosmo_application::core::widget::dto::DtoGetWidgetInstance;
+use tukosmo_application::core::widget::dto::DtoReorderWidgetInstances;
+#[cfg(feature = "ssr")]
+use tukosmo_application::core::widget::use_case::WidgetInstanceUseCase;
+#[cfg(feature = "ssr")]
+use tukosmo_domain::core::shared::model::DomainError;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+
+#[server(ApiCoreWidgetWidgetInstanceAdd)]
+pub async fn add(
+    dto: DtoAddWidgetInstance
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let widget_instance_use_case = common()?;
+        widget_instance_use_case.add(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreWidgetWidgetInstanceDelete)]
+pub async fn delete(
+    dto: DtoDeleteWidgetInstance
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let widget_instance_use_case = common()?;
+        widget_instance_use_case.delete(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreWidgetWidgetInstanceEdit)]
+pub async fn edit(
+    dto: DtoEditWidgetInstance
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let widget_instance_use_case = common()?;
+        widget_instance_use_case.edit(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreWidgetWidgetInstanceGet)]
+pub async fn get(
+    dto: DtoGetWidgetInstance
+) -> Result<ServerResponse<WidgetInstance>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let widget_instance_use_case = common()?;
+        widget_instance_use_case.get(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreWidgetWidgetInstanceList)]
+pub async fn list() -> Result<
+    ServerResponse<Vec<WidgetInstance>>,
+    ServerFnError
+> {
+    let response = ServerResponse::build(|| {
+        let widget_instance_use_case = common()?;
+        widget_instance_use_case.get_widget_instances()
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreWidgetWidgetInstanceListVisible)]
+pub async fn list_visible(
+    dto: DtoGetVisibleWidgetInstances
+) -> Result<ServerResponse<Vec<WidgetInstance>>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let widget_instance_use_case = common()?;
+        widget_instance_use_case.get_visible_widget_instances(dto)
+    });
+
+    Ok(response)
+}
+
+#[server(ApiCoreWidgetWidgetInstanceReorder)]
+pub async fn reorder(
+    dto: DtoReorderWidgetInstances
+) -> Result<ServerResponse<()>, ServerFnError> {
+    let response = ServerResponse::build(|| {
+        let widget_instance_use_case = common()?;
+        widget_instance_use_case.reorder(dto)
+    });
+
+    Ok(response)
+}
+
+#[cfg(feature = "ssr")]
+pub(crate) fn common() -> Result<WidgetInstanceUseCase, DomainError> {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use crate::core::shared::diesel_orm::model::DbTransactionExecutor;
+    use crate::core::shared::leptos_actix_server::repository::MemoryPageCacheRepository;
+    use crate::core::shared::leptos_actix_server::service::database;
+    use crate::core::shared::leptos_actix_server::service::page_cache;
+
+    let db_pool = database::get_db_pool()?;
+    let page_cache_repository = MemoryPageCacheRepository::init(
+        page_cache::get_page_cache()
+    );
+    let transaction_executor = DbTransactionExecutor::init(&db_pool)?;
+    let widget_instance_use_case = WidgetInstanceUseCase::init(
+        Rc::new(RefCell::new(page_cache_repository)),
+        Rc::new(RefCell::new(transaction_executor))
+    );
+
+    Ok(widget_instance_use_case)
+}
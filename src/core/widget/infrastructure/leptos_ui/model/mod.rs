@@ -0,0 +1,2 @@
+mod widget_instance_form;
+pub use widget_instance_form::*;
@@ -0,0 +1,147 @@
+use leptos::SignalGetUntracked;
+use leptos::StoredValue;
+use leptos::store_value;
+use tukosmo_application::core::widget::dto::DtoWidgetInstanceForm;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_LANGUAGE_SWITCHER;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_RECENT_POSTS;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_SEARCH_BOX;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_TAG_CLOUD;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_TEXT;
+use tukosmo_domain::core::widget::model::WidgetArea;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+use tukosmo_domain::core::widget::model::WidgetLimit;
+use tukosmo_domain::core::widget::model::get_built_in_widgets;
+
+use crate::core::language::leptos_ui::I18nTextFormFieldInitialValue;
+use crate::core::language::leptos_ui::I18nTextFormFieldValue;
+use crate::core::shared::leptos_ui::FormFieldValue;
+use crate::t;
+
+const DEFAULT_LIMIT: &'static str = "5";
+
+#[derive(Clone)]
+pub struct WidgetInstanceForm {
+    pub area: StoredValue<FormFieldValue<String>>,
+    pub code: StoredValue<FormFieldValue<String>>,
+    pub content: StoredValue<I18nTextFormFieldValue>,
+    pub language_ids: StoredValue<FormFieldValue<Vec<String>>>,
+    pub limit: StoredValue<FormFieldValue<String>>,
+    pub title: StoredValue<I18nTextFormFieldValue>,
+}
+
+#[derive(Clone)]
+struct WidgetInstanceFormInitialValues {
+    pub area: String,
+    pub code: String,
+    pub content: I18nTextFormFieldInitialValue,
+    pub language_ids: Vec<String>,
+    pub limit: String,
+    pub title: I18nTextFormFieldInitialValue,
+}
+
+impl WidgetInstanceForm {
+    pub fn get_dto(&self) -> DtoWidgetInstanceForm {
+        DtoWidgetInstanceForm {
+            area: self.area.get_value().signal.get_untracked(),
+            code: self.code.get_value().signal.get_untracked(),
+            content: self.content.get_value().get_i18n_text_value(),
+            language_ids: self.language_ids
+                .get_value()
+                .signal.get_untracked(),
+            limit: self.limit.get_value().signal.get_untracked(),
+            title: self.title.get_value().get_i18n_text_value(),
+        }
+    }
+
+    fn init(
+        initial_values: WidgetInstanceFormInitialValues
+    ) -> StoredValue<Self> {
+        let area = FormFieldValue::init(initial_values.area, |_| None);
+        let code = FormFieldValue::init(initial_values.code, |_| None);
+        let content = I18nTextFormFieldValue::init(
+            initial_values.content,
+            WidgetInstance::validate_content_value,
+            WidgetInstance::validate_content_value
+        );
+        let language_ids = FormFieldValue::init(
+            initial_values.language_ids,
+            |_| None
+        );
+        let limit = FormFieldValue::init(
+            initial_values.limit,
+            WidgetLimit::validate
+        );
+        let title = I18nTextFormFieldValue::init(
+            initial_values.title,
+            WidgetInstance::validate_title_value,
+            WidgetInstance::validate_title_value
+        );
+
+        store_value(Self { area, code, content, language_ids, limit, title })
+    }
+
+    pub fn init_empty() -> StoredValue<Self> {
+        let initial_values = WidgetInstanceFormInitialValues {
+            area: WidgetArea::Sidebar.value().to_string(),
+            code: WIDGET_CODE_TEXT.to_string(),
+            content: I18nTextFormFieldInitialValue::empty(),
+            language_ids: vec![],
+            limit: DEFAULT_LIMIT.to_string(),
+            title: I18nTextFormFieldInitialValue::empty(),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn init_filled(widget_instance: WidgetInstance) -> StoredValue<Self> {
+        let initial_values = WidgetInstanceFormInitialValues {
+            area: widget_instance.area.value().to_string(),
+            code: widget_instance.code.value().to_string(),
+            content: I18nTextFormFieldInitialValue::filled(
+                widget_instance.content
+            ),
+            language_ids: widget_instance.language_ids
+                .iter()
+                .map(|l| l.value().to_string())
+                .collect(),
+            limit: widget_instance.limit.value().to_string(),
+            title: I18nTextFormFieldInitialValue::filled(widget_instance.title),
+        };
+
+        Self::init(initial_values)
+    }
+
+    pub fn validate(&self) {
+        self.area.get_value().validate();
+        self.code.get_value().validate();
+        self.content.get_value().validate();
+        self.language_ids.get_value().validate();
+        self.limit.get_value().validate();
+        self.title.get_value().validate();
+    }
+}
+
+pub fn widget_area_options() -> Vec<(String, String)> {
+    vec![
+        (WidgetArea::Sidebar.value().to_string(), t!(main.sidebar)()),
+        (WidgetArea::Footer.value().to_string(), t!(main.footer)())
+    ]
+}
+
+pub fn widget_code_options() -> Vec<(String, String)> {
+    get_built_in_widgets()
+        .iter()
+        .map(|widget| (widget.code().to_string(), widget_name(widget.code())))
+        .collect()
+}
+
+pub fn widget_name(code: &str) -> String {
+    match code {
+        WIDGET_CODE_LANGUAGE_SWITCHER => t!(main.language_switcher)(),
+        WIDGET_CODE_RECENT_POSTS => t!(main.recent_posts)(),
+        WIDGET_CODE_SEARCH_BOX => t!(main.search_box)(),
+        WIDGET_CODE_TAG_CLOUD => t!(main.tag_cloud)(),
+        WIDGET_CODE_TEXT => t!(main.text_html)(),
+        _ => code.to_string(),
+    }
+}
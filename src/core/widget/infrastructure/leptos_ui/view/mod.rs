@@ -0,0 +1,11 @@
+mod tap_add_widget_view;
+pub use tap_add_widget_view::*;
+
+mod tap_delete_widget_view;
+pub use tap_delete_widget_view::*;
+
+mod tap_edit_widget_view;
+pub use tap_edit_widget_view::*;
+
+mod tap_widgets_view;
+pub use tap_widgets_view::*;
@@ -0,0 +1,154 @@
+use leptos::IntoView;
+use leptos::SignalGet;
+use leptos::SignalSet;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::NavigateOptions;
+use leptos_router::use_navigate;
+use leptos_router::use_params_map;
+use tukosmo_application::core::widget::dto::DtoEditWidgetInstance;
+use tukosmo_application::core::widget::dto::DtoGetWidgetInstance;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapFormPage;
+use crate::core::shared::leptos_ui::TapLayout;
+use crate::core::shared::leptos_ui::TapLoadingError;
+use crate::core::shared::leptos_ui::TapLoadingLeptosError;
+use crate::core::shared::leptos_ui::TapLoadingResource;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::core::widget::leptos_actix_server::api::widget_instance_api;
+use crate::t;
+use super::super::TapWidgetInstanceFields;
+use super::super::WidgetInstanceForm;
+
+#[component]
+pub fn TapEditWidgetView() -> impl IntoView {
+    let params = use_params_map();
+
+    let response_data = create_resource(
+        move || params.get(),
+        move |params| async move {
+            let widget_instance_id = params.get("id").unwrap();
+
+            let dto = DtoGetWidgetInstance { widget_instance_id: widget_instance_id.to_string() };
+            let result = widget_instance_api::get(dto).await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(widget_instance) => {
+                            view! {
+                                <div>
+                                    <TapEditWidgetViewContent widget_instance=widget_instance />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapEditWidgetViewContent(widget_instance: WidgetInstance) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let waiting_response_signal = create_rw_signal(false);
+    let server_error_signal =
+        create_rw_signal::<Option<ServerResponseError>>(None);
+
+    let form = WidgetInstanceForm::init_filled(widget_instance.clone());
+
+    let stored_widget_instance_id = store_value(widget_instance.id.clone());
+    let on_click_submit_button = move |_| {
+        waiting_response_signal.set(true);
+        let current_language_code = current_language_reader.get().code;
+
+        spawn_local(async move {
+            let dto_form = form.get_value().get_dto();
+            let dto = DtoEditWidgetInstance {
+                form: dto_form,
+                widget_instance_id: stored_widget_instance_id
+                    .get_value()
+                    .value()
+                    .to_string(),
+            };
+            let server_response_edit = widget_instance_api::edit(dto).await;
+
+            manage_response(
+                server_response_edit,
+                move |_response| {
+                    let navigate = use_navigate();
+                    let path = navigation::path_admin_widgets(
+                        &current_language_code
+                    );
+                    navigate(&path, NavigateOptions::default());
+                },
+                move |server_error| {
+                    form.get_value().validate();
+                    server_error_signal.set(Some(server_error));
+
+                    waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    view! {
+        <TapFormPage
+            cancel_route_path=Box::new(move || navigation::path_admin_widgets(
+                &current_language_reader.get().code
+            ))
+            delete_route_path=Some(Box::new(move || navigation::path_admin_widgets_delete(
+                &current_language_reader.get().code,
+                &widget_instance.id
+            )))
+            on_click_submit_button=Box::new(on_click_submit_button)
+            server_error_signal=server_error_signal
+            title=Box::new(|| t!(main.edit_widget)())
+            waiting_response_signal=waiting_response_signal
+        >
+            // TODO
+            //<input name="csrf_token" type="hidden" value=csrf_token />
+
+            <TapWidgetInstanceFields form=form />
+        </TapFormPage>
+    }
+}
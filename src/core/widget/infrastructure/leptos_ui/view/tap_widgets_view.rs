@@ -0,0 +1,355 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::RwSignal;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::SignalGetUntracked;
+use leptos::SignalSet;
+use leptos::SignalUpdate;
+use leptos::Transition;
+use leptos::component;
+use leptos::create_resource;
+use leptos::create_rw_signal;
+use leptos::spawn_local;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_application::core::widget::dto::DtoReorderWidgetInstances;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::shared::model::ServerResponseError;
+use tukosmo_domain::core::widget::model::WidgetArea;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+use tukosmo_domain::core::widget::model::WidgetInstanceId;
+
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapLayout;
+use crate::core::shared::leptos_ui::TapLoadingError;
+use crate::core::shared::leptos_ui::TapLoadingLeptosError;
+use crate::core::shared::leptos_ui::TapLoadingResource;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::service::server::manage_response;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::core::widget::leptos_actix_server::api::widget_instance_api;
+use crate::t;
+use crate::t_error;
+use super::super::widget_name;
+
+#[derive(Clone, Copy)]
+struct TapWidgetsState {
+    reload_signal: RwSignal<i64>,
+    server_error_signal: RwSignal<Option<ServerResponseError>>,
+    waiting_response_signal: RwSignal<bool>,
+}
+
+#[component]
+pub fn TapWidgetsView() -> impl IntoView {
+    let state = TapWidgetsState {
+        reload_signal: create_rw_signal(0),
+        server_error_signal: create_rw_signal(None),
+        waiting_response_signal: create_rw_signal(false),
+    };
+
+    let response_data = create_resource(
+        move || state.reload_signal.get(),
+        move |_| async move {
+            let result = widget_instance_api::list().await;
+            result
+        }
+    );
+
+    let content = move ||
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(server_response) =>
+                    match server_response {
+                        ServerResponse::Response(widget_instances) => {
+                            state.waiting_response_signal.set(false);
+                            view! {
+                                <div>
+                                    <TapWidgetsViewContent
+                                        state=state
+                                        widget_instances=widget_instances
+                                    />
+                                </div>
+                            }
+                        }
+                        ServerResponse::Error(error) => {
+                            view! {
+                                <div>
+                                    <TapLoadingError error=error />
+                                </div>
+                            }
+                        }
+                    }
+                Err(error) => {
+                    view! {
+                        <div>
+                            <TapLoadingLeptosError error=error />
+                        </div>
+                    }
+                }
+            }
+        });
+
+    view! {
+        <TapLayout>
+            <Transition fallback=move || view! { <TapLoadingResource /> }>
+                {content}
+            </Transition>
+        </TapLayout>
+    }
+}
+
+#[component]
+fn TapWidgetsViewContent(
+    state: TapWidgetsState,
+    widget_instances: Vec<WidgetInstance>
+) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let widget_instances = store_value(widget_instances);
+    let widget_instances_in = move |area: WidgetArea| {
+        widget_instances
+            .get_value()
+            .into_iter()
+            .filter(|widget_instance| widget_instance.area == area)
+            .collect::<Vec<WidgetInstance>>()
+    };
+
+    view! {
+        <div class="box is-marginless mb-6">
+            <h1 class="title">
+                {t!(main.widgets)}
+
+                <a
+                    class="button is-link is-pulled-right has-text-weight-normal mr-4"
+                    href=move || navigation::path_admin_widgets_add(
+                        &current_language_reader.get().code
+                    )
+                >
+                    {t!(main.add_widget)}
+                </a>
+            </h1>
+
+            <Show when=move || state.server_error_signal.get().is_some()>
+                <div class="notification is-danger">
+                    <button
+                        class="delete"
+                        on:click=move |_| state.server_error_signal.set(None)
+                    ></button>
+                    {move || {
+                        let server_error = state.server_error_signal.get();
+                        match server_error {
+                            Some(server_error) => {
+                                t_error!(&server_error.error_code, &server_error.context)()
+                            },
+                            None => "".to_string(),
+                        }
+                    }}
+                </div>
+            </Show>
+
+            <TapWidgetsArea
+                area=WidgetArea::Sidebar
+                name=Box::new(|| t!(main.sidebar)())
+                state=state
+                widget_instances=widget_instances_in(WidgetArea::Sidebar)
+            />
+
+            <TapWidgetsArea
+                area=WidgetArea::Footer
+                name=Box::new(|| t!(main.footer)())
+                state=state
+                widget_instances=widget_instances_in(WidgetArea::Footer)
+            />
+        </div>
+    }
+}
+
+#[component]
+fn TapWidgetsArea(
+    area: WidgetArea,
+    name: Box<dyn Fn() -> String>,
+    state: TapWidgetsState,
+    widget_instances: Vec<WidgetInstance>
+) -> impl IntoView {
+    let widget_instance_ids = widget_instances
+        .iter()
+        .map(|widget_instance| widget_instance.id.clone())
+        .collect::<Vec<WidgetInstanceId>>();
+    let area_is_empty = widget_instances.is_empty();
+    let widget_instances = store_value(widget_instances);
+
+    view! {
+        <h2 class="subtitle mt-5">{move || name()}</h2>
+
+        <Show
+            fallback=move || view! {
+                <div>
+                    <TapWidgetsAreaTable
+                        area=area
+                        state=state
+                        widget_instance_ids=widget_instance_ids.clone()
+                        widget_instances=widget_instances.get_value()
+                    />
+                </div>
+            }
+            when=move || area_is_empty
+        >
+            <p class="has-text-grey">{t!(main.this_area_has_no_widgets_yet)}</p>
+        </Show>
+    }
+}
+
+#[component]
+fn TapWidgetsAreaTable(
+    area: WidgetArea,
+    state: TapWidgetsState,
+    widget_instance_ids: Vec<WidgetInstanceId>,
+    widget_instances: Vec<WidgetInstance>
+) -> impl IntoView {
+    let widget_instance_ids = store_value(widget_instance_ids);
+
+    view! {
+        <table class="table is-bordered is-hoverable is-fullwidth">
+            <thead>
+                <tr>
+                    <th>{t!(main.widget)}</th>
+                    <th>{t!(main.title)}</th>
+                    <th>{t!(main.languages)}</th>
+                    <th></th>
+                </tr>
+            </thead>
+            <tbody>
+                <For
+                    children=move |widget_instance: WidgetInstance| {
+                        view! {
+                            <TapWidgetsAreaTableRow
+                                area=area
+                                sibling_ids=widget_instance_ids.get_value()
+                                state=state
+                                widget_instance=widget_instance
+                            />
+                        }
+                    }
+                    each=move || widget_instances.clone()
+                    key=|widget_instance| widget_instance.id.value().to_string()
+                />
+            </tbody>
+        </table>
+    }
+}
+
+#[component]
+fn TapWidgetsAreaTableRow(
+    area: WidgetArea,
+    sibling_ids: Vec<WidgetInstanceId>,
+    state: TapWidgetsState,
+    widget_instance: WidgetInstance
+) -> impl IntoView {
+    let GlobalContext { current_language_reader, languages_reader, .. } =
+        use_global_context();
+
+    let widget_instance_index = sibling_ids
+        .iter()
+        .position(|sibling_id| sibling_id == &widget_instance.id)
+        .unwrap_or_default();
+    let is_first = widget_instance_index == 0;
+    let is_last = widget_instance_index + 1 == sibling_ids.len();
+    let sibling_ids = store_value(sibling_ids);
+    let widget_instance = store_value(widget_instance);
+
+    let move_widget_instance = move |new_index: usize| {
+        // Only one request at a time
+        if state.waiting_response_signal.get_untracked() {
+            return;
+        }
+        state.waiting_response_signal.set(true);
+
+        let mut widget_instance_ids = sibling_ids.get_value();
+        let moved_id = widget_instance_ids.remove(widget_instance_index);
+        widget_instance_ids.insert(new_index, moved_id);
+
+        spawn_local(async move {
+            let dto = DtoReorderWidgetInstances {
+                area: area.value().to_string(),
+                widget_instance_ids: widget_instance_ids
+                    .iter()
+                    .map(|id| id.value().to_string())
+                    .collect(),
+            };
+            let server_response_reorder =
+                widget_instance_api::reorder(dto).await;
+
+            manage_response(
+                server_response_reorder,
+                move |_response| {
+                    state.reload_signal.update(|draft| *draft += 1);
+                },
+                move |server_error| {
+                    state.server_error_signal.set(Some(server_error));
+                    state.waiting_response_signal.set(false);
+                }
+            );
+        });
+    };
+
+    let languages = move || {
+        let language_ids = widget_instance.get_value().language_ids;
+        if language_ids.is_empty() {
+            return t!(main.all)();
+        }
+        languages_reader
+            .get()
+            .iter()
+            .filter(|language| language_ids.contains(&language.id))
+            .map(|language| {
+                language.name.translate(current_language_reader.get().id)
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+
+    view! {
+        <tr>
+            <td>
+                <a
+                    href=move || navigation::path_admin_widgets_edit(
+                        &current_language_reader.get().code,
+                        &widget_instance.get_value().id
+                    )
+                >
+                    {move || widget_name(widget_instance.get_value().code.value())}
+                </a>
+            </td>
+            <td>
+                {move || widget_instance.get_value().title.translate(
+                    current_language_reader.get().id
+                )}
+            </td>
+            <td>{languages}</td>
+            <td>
+                <div class="buttons are-small">
+                    <button
+                        class="button"
+                        disabled=is_first
+                        on:click=move |_| move_widget_instance(widget_instance_index - 1)
+                        title=t!(main.move_up)
+                    >
+                        "↑"
+                    </button>
+                    <button
+                        class="button"
+                        disabled=is_last
+                        on:click=move |_| move_widget_instance(widget_instance_index + 1)
+                        title=t!(main.move_down)
+                    >
+                        "↓"
+                    </button>
+                </div>
+            </td>
+        </tr>
+    }
+}
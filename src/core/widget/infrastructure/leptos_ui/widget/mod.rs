@@ -0,0 +1,5 @@
+mod tap_widget_instance_fields;
+pub use tap_widget_instance_fields::*;
+
+mod web_widget;
+pub use web_widget::*;
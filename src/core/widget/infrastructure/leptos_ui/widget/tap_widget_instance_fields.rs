@@ -0,0 +1,136 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::StoredValue;
+use leptos::component;
+use leptos::tracing;
+use leptos::view;
+use tukosmo_domain::core::language::model::Language;
+use tukosmo_domain::core::widget::model::get_widget;
+
+use crate::core::language::leptos_ui::TapFormI18nTextField;
+use crate::core::shared::leptos_ui::FormFieldValue;
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::TapFormSelectField;
+use crate::core::shared::leptos_ui::TapFormTextField;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::t;
+use super::super::WidgetInstanceForm;
+use super::super::widget_area_options;
+use super::super::widget_code_options;
+
+#[component]
+pub fn TapWidgetInstanceFields(
+    form: StoredValue<WidgetInstanceForm>
+) -> impl IntoView {
+    let code_signal = form.get_value().code.get_value().signal;
+
+    let uses_content = move || {
+        match get_widget(&code_signal.get()) {
+            Ok(widget) => widget.uses_content(),
+            Err(_) => false,
+        }
+    };
+    let uses_limit = move || {
+        match get_widget(&code_signal.get()) {
+            Ok(widget) => widget.uses_limit(),
+            Err(_) => false,
+        }
+    };
+
+    view! {
+        <TapFormSelectField
+            name=Box::new(|| t!(main.widget)())
+            options=Box::new(widget_code_options)
+            required=true
+            value=form.get_value().code
+        />
+
+        <TapFormSelectField
+            name=Box::new(|| t!(main.area)())
+            options=Box::new(widget_area_options)
+            required=true
+            value=form.get_value().area
+        />
+
+        <TapFormI18nTextField
+            name=Box::new(|| t!(main.title)())
+            required=false
+            value=form.get_value().title
+        />
+
+        <Show when=uses_content>
+            <TapFormI18nTextField
+                name=Box::new(|| t!(main.content_html)())
+                required=false
+                value=form.get_value().content
+            />
+        </Show>
+
+        <Show when=uses_limit>
+            <TapFormTextField
+                name=Box::new(|| t!(main.number_of_elements)())
+                required=true
+                value=form.get_value().limit
+            />
+        </Show>
+
+        <TapWidgetInstanceLanguagesField value=form.get_value().language_ids />
+    }
+}
+
+#[component]
+fn TapWidgetInstanceLanguagesField(
+    value: StoredValue<FormFieldValue<Vec<String>>>
+) -> impl IntoView {
+    let GlobalContext { current_language_reader, languages_reader, .. } =
+        use_global_context();
+
+    let view_language_checkbox = move |language: Language| {
+        let language_id = language.id.value().to_string();
+        let stored_language_id = language_id.clone();
+        view! {
+            <label class="checkbox mr-4">
+                <input
+                    on:change=move |_event| {
+                        let language_id = stored_language_id.clone();
+                        value.get_value().update(|draft| {
+                            if draft.contains(&language_id) {
+                                draft.retain(|id| id != &language_id);
+                            } else {
+                                draft.push(language_id);
+                            }
+                        });
+                    }
+                    prop:checked=move || {
+                        value.get_value().signal.get().contains(&language_id)
+                    }
+                    type="checkbox"
+                />
+                " "
+                {move || language.name.translate(current_language_reader.get().id)}
+            </label>
+        }
+    };
+
+    view! {
+        <div class="field">
+            <label class="label">
+                {t!(main.visible_in_languages)}
+            </label>
+            <div class="control">
+                <For
+                    children=view_language_checkbox
+                    each=languages_reader
+                    key=|language| language.id.value().to_string()
+                />
+            </div>
+            <p class="help">
+                {t!(main.if_no_language_is_selected_the_widget_is_visible_in_all_of_them)}
+            </p>
+        </div>
+    }
+}
@@ -0,0 +1,206 @@
+use leptos::For;
+#[cfg(feature = "ssr")]
+use leptos::IntoAttribute;
+use leptos::IntoView;
+use leptos::Show;
+use leptos::SignalGet;
+use leptos::Transition;
+use leptos::View;
+use leptos::component;
+use leptos::create_resource;
+use leptos::store_value;
+use leptos::tracing;
+use leptos::view;
+use leptos_router::Location;
+use leptos_router::use_location;
+use tukosmo_application::core::widget::dto::DtoGetVisibleWidgetInstances;
+use tukosmo_domain::core::language::model::Language;
+use tukosmo_domain::core::shared::model::ServerResponse;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_LANGUAGE_SWITCHER;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_RECENT_POSTS;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_SEARCH_BOX;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_TAG_CLOUD;
+use tukosmo_domain::core::widget::model::WIDGET_CODE_TEXT;
+use tukosmo_domain::core::widget::model::WidgetArea;
+use tukosmo_domain::core::widget::model::WidgetInstance;
+
+use crate::core::gallery::leptos_ui::WebContentBody;
+use crate::core::shared::leptos_ui::GlobalContext;
+use crate::core::shared::leptos_ui::service::navigation;
+use crate::core::shared::leptos_ui::use_global_context;
+use crate::core::widget::leptos_actix_server::api::widget_instance_api;
+use crate::t;
+
+// Widgets are optional, so loading errors just leave the area empty
+#[component]
+pub fn WebWidgetArea(area: WidgetArea) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let response_data = create_resource(
+        move || current_language_reader.get().id.value().to_string(),
+        move |language_id| async move {
+            let dto = DtoGetVisibleWidgetInstances {
+                area: area.value().to_string(),
+                language_id,
+            };
+            widget_instance_api::list_visible(dto).await
+        }
+    );
+
+    let content = move || {
+        response_data.get().map(|server_response| {
+            match server_response {
+                Ok(ServerResponse::Response(widget_instances)) =>
+                    view! {
+                        <For
+                            children=move |widget_instance: WidgetInstance| {
+                                view! {
+                                    <WebWidget widget_instance=widget_instance />
+                                }
+                            }
+                            each=move || widget_instances.clone()
+                            key=|widget_instance| {
+                                widget_instance.id.value().to_string()
+                            }
+                        />
+                    }.into_view(),
+                _ => ().into_view(),
+            }
+        })
+    };
+
+    view! {
+        <div class=format!("core-widget-web_widget-area-{}", area.value())>
+            <Transition fallback=|| ()>
+                {content}
+            </Transition>
+        </div>
+    }
+}
+
+#[component]
+fn WebWidget(widget_instance: WidgetInstance) -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    let widget_instance = store_value(widget_instance);
+
+    let title = move || {
+        widget_instance.get_value().title.translate(
+            current_language_reader.get().id
+        )
+    };
+
+    let body = move || -> View {
+        let limit = *widget_instance.get_value().limit.value();
+        match widget_instance.get_value().code.value() {
+            WIDGET_CODE_LANGUAGE_SWITCHER =>
+                view! { <WebLanguageSwitcherWidget /> }.into_view(),
+            WIDGET_CODE_RECENT_POSTS =>
+                view! { <WebRecentPostsWidget limit=limit /> }.into_view(),
+            WIDGET_CODE_SEARCH_BOX =>
+                view! { <WebSearchBoxWidget /> }.into_view(),
+            WIDGET_CODE_TAG_CLOUD =>
+                view! { <WebTagCloudWidget limit=limit /> }.into_view(),
+            WIDGET_CODE_TEXT => {
+                let html = widget_instance.get_value().content.translate(
+                    current_language_reader.get().id
+                );
+                view! {
+                    <div class="core-widget-web_widget-text">
+                        <WebContentBody body=html />
+                    </div>
+                }.into_view()
+            }
+            _ => ().into_view(),
+        }
+    };
+
+    view! {
+        <section class="core-widget-web_widget">
+            <Show when=move || !title().is_empty()>
+                <h3 class="core-widget-web_widget-title">{title}</h3>
+            </Show>
+            {body}
+        </section>
+    }
+}
+
+#[component]
+fn WebLanguageSwitcherWidget() -> impl IntoView {
+    let GlobalContext { current_language_reader, languages_reader, .. } =
+        use_global_context();
+    let Location {
+        pathname: current_uri_path,
+        search: current_uri_query,
+        ..
+    } = use_location();
+
+    view! {
+        <ul class="core-widget-web_widget-list">
+            <For
+                children=move |language: Language| {
+                    let language = store_value(language);
+                    view! {
+                        <li>
+                            <a
+                                href=move || navigation::change_uri_language(
+                                    &current_uri_path(),
+                                    &current_uri_query(),
+                                    language.get_value().code.value()
+                                )
+                            >
+                                {move || language.get_value().name.translate(
+                                    current_language_reader.get().id
+                                )}
+                            </a>
+                        </li>
+                    }
+                }
+                each=languages_reader
+                key=|language| language.id.value().to_string()
+            />
+        </ul>
+    }
+}
+
+#[component]
+fn WebRecentPostsWidget(limit: i32) -> impl IntoView {
+    // TODO: List the last `limit` published posts when the blog exists
+    let _ = limit;
+
+    view! {
+        <p>{t!(main.there_are_no_posts_yet)}</p>
+    }
+}
+
+#[component]
+fn WebSearchBoxWidget() -> impl IntoView {
+    let GlobalContext { current_language_reader, .. } = use_global_context();
+
+    view! {
+        <form
+            action=move || navigation::path_search(
+                &current_language_reader.get().code
+            )
+            class="core-widget-web_widget-search"
+            method="get"
+        >
+            <input
+                name="q"
+                placeholder=t!(main.search)
+                type="search"
+            />
+            <button type="submit">{t!(main.search)}</button>
+        </form>
+    }
+}
+
+#[component]
+fn WebTagCloudWidget(limit: i32) -> impl IntoView {
+    // TODO: Show the `limit` most used tags when tags exist
+    let _ = limit;
+
+    view! {
+        <p>{t!(main.there_are_no_tags_yet)}</p>
+    }
+}
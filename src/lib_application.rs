@@ -1,8 +1,16 @@
 pub mod core {
+    pub mod gallery {
+        mod application;
+        pub use application::*;
+    }
     pub mod language {
         mod application;
         pub use application::*;
     }
+    pub mod menu {
+        mod application;
+        pub use application::*;
+    }
     pub mod shared {
         mod application;
         pub use application::*;
@@ -11,8 +19,16 @@ pub mod core {
         mod application;
         pub use application::*;
     }
+    pub mod theme {
+        mod application;
+        pub use application::*;
+    }
     pub mod user {
         mod application;
         pub use application::*;
     }
+    pub mod widget {
+        mod application;
+        pub use application::*;
+    }
 }
@@ -1,8 +1,16 @@
 pub mod core {
+    pub mod gallery {
+        mod domain;
+        pub use domain::*;
+    }
     pub mod language {
         mod domain;
         pub use domain::*;
     }
+    pub mod menu {
+        mod domain;
+        pub use domain::*;
+    }
     pub mod shared {
         mod domain;
         pub use domain::*;
@@ -11,8 +19,16 @@ pub mod core {
         mod domain;
         pub use domain::*;
     }
+    pub mod theme {
+        mod domain;
+        pub use domain::*;
+    }
     pub mod user {
         mod domain;
         pub use domain::*;
     }
+    pub mod widget {
+        mod domain;
+        pub use domain::*;
+    }
 }
@@ -1,8 +1,16 @@
 pub mod core {
+    pub mod gallery {
+        mod infrastructure;
+        pub use infrastructure::*;
+    }
     pub mod language {
         mod infrastructure;
         pub use infrastructure::*;
     }
+    pub mod menu {
+        mod infrastructure;
+        pub use infrastructure::*;
+    }
     pub mod shared {
         mod infrastructure;
         pub use infrastructure::*;
@@ -11,22 +19,24 @@ pub mod core {
         mod infrastructure;
         pub use infrastructure::*;
     }
+    pub mod theme {
+        mod infrastructure;
+        pub use infrastructure::*;
+    }
     pub mod user {
         mod infrastructure;
         pub use infrastructure::*;
     }
+    pub mod widget {
+        mod infrastructure;
+        pub use infrastructure::*;
+    }
 }
 
 #[cfg(feature = "ssr")]
-use crate::core::shared::leptos_actix_server::service::server::start_server;
+use crate::core::shared::leptos_actix_server::service::cli;
 
 #[cfg(feature = "ssr")]
-pub async fn run_server() {
-    let end_result = start_server().await;
-    match end_result {
-        Ok(_) => { println!("Server thread finished.") }
-        Err(e) => {
-            panic!("SERVER ERROR: {:?}", e);
-        }
-    }
+pub async fn run_cli() -> i32 {
+    cli::run().await
 }
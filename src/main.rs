@@ -1,8 +1,8 @@
 use tokio;
-use tukosmo_infrastructure::run_server;
+use tukosmo_infrastructure::run_cli;
 
 #[tokio::main]
-async fn main() -> std::io::Result<()> {
-    run_server().await;
-    Ok(())
+async fn main() {
+    let exit_code = run_cli().await;
+    std::process::exit(exit_code);
 }